@@ -0,0 +1,107 @@
+//! Transcription acceleration status and device selection
+//!
+//! Reports which backend local models run on and lets users force CPU or pin a GPU
+//! index. The preference is read whenever a Whisper model loads; changing it unloads
+//! an idle model so the next transcription picks it up.
+
+use crate::managers::model::{EngineType, ModelManager};
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::{AccelerationPreference, AppSettings};
+use serde::Serialize;
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Acceleration status for local transcription
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct AccelInfo {
+    /// "metal", "vulkan" or "cpu"
+    pub backend: String,
+    pub preference: AccelerationPreference,
+    pub gpu_index: Option<u32>,
+    /// Model currently loaded (None when no model is loaded)
+    pub model_id: Option<String>,
+    pub note: Option<String>,
+}
+
+/// GPU backend this build uses for Whisper on the current platform (None = CPU only)
+fn platform_gpu_backend() -> Option<&'static str> {
+    if cfg!(target_os = "macos") {
+        Some("metal")
+    } else if cfg!(any(target_os = "windows", target_os = "linux")) {
+        Some("vulkan")
+    } else {
+        None
+    }
+}
+
+/// Backend an engine runs on with the given device preference.
+/// ONNX engines (Parakeet, Moonshine) always run on CPU.
+pub fn backend_for(engine_type: &EngineType, preference: AccelerationPreference) -> String {
+    match engine_type {
+        EngineType::Whisper if preference != AccelerationPreference::Cpu => {
+            platform_gpu_backend().unwrap_or("cpu").to_string()
+        }
+        _ => "cpu".to_string(),
+    }
+}
+
+/// Whether a Whisper model should load on the GPU, and which one (None = default)
+pub fn whisper_device(settings: &AppSettings) -> (bool, Option<u32>) {
+    match settings.acceleration_preference {
+        AccelerationPreference::Cpu => (false, None),
+        AccelerationPreference::Gpu => (true, settings.acceleration_gpu_index),
+        AccelerationPreference::Auto => (true, None),
+    }
+}
+
+/// Current acceleration status, based on the loaded model (or the selected one).
+pub fn status(app: &AppHandle, settings: &AppSettings) -> AccelInfo {
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    let model_manager = app.state::<Arc<ModelManager>>();
+
+    let model_id = tm.get_current_model();
+    let engine_type = model_id
+        .as_deref()
+        .or(Some(settings.selected_model.as_str()))
+        .and_then(|id| model_manager.get_model_info(id))
+        .map(|info| info.engine_type);
+
+    // The loaded model keeps the device it was loaded with
+    let requested = (
+        settings.acceleration_preference,
+        settings.acceleration_gpu_index,
+    );
+    let loaded = tm.loaded_acceleration();
+    let (preference, gpu_index) = loaded.unwrap_or(requested);
+    let backend = engine_type
+        .as_ref()
+        .map(|engine_type| backend_for(engine_type, preference))
+        .unwrap_or_else(|| "cpu".to_string());
+
+    let note = if platform_gpu_backend().is_none() {
+        Some("GPU acceleration is not supported on this platform".to_string())
+    } else if cfg!(target_os = "macos")
+        && settings.acceleration_preference == AccelerationPreference::Gpu
+        && settings.acceleration_gpu_index.is_some()
+    {
+        Some("Metal always uses the system GPU; the GPU index is ignored".to_string())
+    } else if matches!(
+        engine_type,
+        Some(EngineType::Parakeet) | Some(EngineType::Moonshine)
+    ) {
+        Some("This model runs on CPU regardless of the acceleration setting".to_string())
+    } else if loaded.is_some_and(|loaded| loaded != requested) {
+        Some("The new device setting applies when the model is next loaded".to_string())
+    } else {
+        None
+    };
+
+    AccelInfo {
+        backend,
+        preference,
+        gpu_index,
+        model_id,
+        note,
+    }
+}
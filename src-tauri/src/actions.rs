@@ -2,15 +2,18 @@
 use crate::apple_intelligence;
 use crate::audio_feedback::{play_feedback_sound, play_feedback_sound_blocking, SoundType};
 use crate::audio_toolkit::apply_custom_words;
+use crate::last_errors::{self, ErrorFeature};
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::connector::ConnectorManager;
-use crate::managers::history::HistoryManager;
+use crate::managers::history::{HistoryManager, PostProcessStatus};
 use crate::managers::llm_operation::LlmOperationTracker;
 use crate::managers::remote_stt::RemoteSttManager;
-use crate::managers::transcription::TranscriptionManager;
+use crate::managers::transcription::{TranscriptionDetails, TranscriptionManager};
+use crate::pipeline_timing::{PipelineStage, PipelineTimer};
 use crate::session_manager::{self, ManagedSessionState};
 use crate::settings::{
-    get_settings, AppSettings, TranscriptionProvider, APPLE_INTELLIGENCE_PROVIDER_ID,
+    get_settings, AiReplaceOutputMode, AppSettings, PasteMethod, PostProcessProvider,
+    TranscriptionProvider, APPLE_INTELLIGENCE_PROVIDER_ID,
 };
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils::{
@@ -18,7 +21,6 @@ use crate::utils::{
     show_transcribing_overlay,
 };
 use crate::ManagedToggleState;
-use ferrous_opencc::{config::BuiltinConfig, OpenCC};
 use log::{debug, error, warn};
 use once_cell::sync::Lazy;
 use natural::phonetics::soundex;
@@ -58,31 +60,80 @@ struct RepastLastAction;
 
 struct CycleProfileAction;
 
+struct TogglePttModeAction;
+
 use crate::settings::TranscriptionProfile;
+use crate::text_pipeline::{run_text_pipeline, PostProcessTranscriptionOutcome};
+
+/// If `field` is set, parse the LLM response as JSON (optionally inside a ``` fence) and
+/// return that field's value. Dotted paths ("result.text") reach nested objects.
+/// Falls back to the raw response when parsing fails or the field is missing.
+fn extract_json_field(content: String, field: Option<&str>) -> String {
+    let Some(field) = field.map(str::trim).filter(|f| !f.is_empty()) else {
+        return content;
+    };
 
-enum PostProcessTranscriptionOutcome {
-    Skipped,
-    Cancelled,
-    Processed {
-        text: String,
-        prompt_template: String,
-    },
+    let body = content.trim();
+    let body = body
+        .strip_prefix("```json")
+        .or_else(|| body.strip_prefix("```"))
+        .and_then(|b| b.trim_end().strip_suffix("```"))
+        .unwrap_or(body);
+
+    let value = match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(value) => value,
+        Err(e) => {
+            warn!(
+                "Post-process response is not valid JSON ({}), using raw response",
+                e
+            );
+            return content;
+        }
+    };
+
+    let pointer = format!("/{}", field.replace('.', "/"));
+    match value.pointer(&pointer) {
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
+        None => {
+            warn!(
+                "Field '{}' not found in post-process JSON response, using raw response",
+                field
+            );
+            content
+        }
+    }
 }
 
-/// Post-process transcription with LLM, optionally using profile-specific settings.
-///
-/// If `profile` is Some, uses the profile's LLM settings:
-/// - `profile.llm_post_process_enabled` determines if post-processing is enabled
-/// - `profile.llm_prompt_override` overrides the global prompt (if set)
-/// - `profile.llm_model_override` overrides the global model (if set and valid for current provider)
-///
-/// If `profile` is None (default profile), uses global settings.
-async fn maybe_post_process_transcription(
-    app: &AppHandle,
+/// Turn a raw post-process response into the text to paste: extract the configured JSON
+/// field, then unwrap fences/quotes unless disabled or the prompt asks for code.
+fn finish_post_process_output(
+    settings: &AppSettings,
+    content: String,
+    prompt_template: &str,
+) -> String {
+    let text = extract_json_field(content, settings.post_process_extract_json_field.as_deref());
+    if settings.strip_llm_formatting {
+        let keep_fences = crate::llm_output::asks_for_code(prompt_template);
+        crate::llm_output::strip_llm_formatting(&text, keep_fences)
+    } else {
+        text
+    }
+}
+
+/// What an LLM post-process call would use
+struct PostProcessPlan {
+    provider: PostProcessProvider,
+    model: String,
+    prompt_template: String,
+}
+
+/// Decides whether post-processing runs for this profile and the foreground app, and
+/// with which provider, model and prompt. `Err` carries the outcome to report instead.
+fn plan_post_process(
     settings: &AppSettings,
-    transcription: &str,
     profile: Option<&TranscriptionProfile>,
-) -> PostProcessTranscriptionOutcome {
+) -> Result<PostProcessPlan, PostProcessTranscriptionOutcome> {
     // Determine if post-processing is enabled based on profile or global setting
     let is_enabled = match profile {
         Some(p) => p.llm_post_process_enabled,
@@ -90,23 +141,33 @@ async fn maybe_post_process_transcription(
     };
 
     if !is_enabled {
-        return PostProcessTranscriptionOutcome::Skipped;
+        return Err(PostProcessTranscriptionOutcome::Disabled);
+    }
+
+    // App-context gate: paste raw text into matched apps (e.g., IDEs, terminals)
+    if !settings.post_process_disabled_apps.is_empty() {
+        if let Some(foreground) = crate::foreground_app::get_foreground_app() {
+            let disabled_apps = &settings.post_process_disabled_apps;
+            if crate::foreground_app::matches_any(&foreground, disabled_apps) {
+                debug!(
+                    "Post-processing disabled for foreground app {:?}",
+                    foreground.process_name
+                );
+                return Err(PostProcessTranscriptionOutcome::Disabled);
+            }
+        }
     }
 
     let provider = match settings.active_post_process_provider().cloned() {
         Some(provider) => provider,
         None => {
             debug!("Post-processing enabled but no provider is selected");
-            return PostProcessTranscriptionOutcome::Skipped;
+            return Err(PostProcessTranscriptionOutcome::Skipped);
         }
     };
 
     // Determine model: profile override > global setting
-    let global_model = settings
-        .post_process_models
-        .get(&provider.id)
-        .cloned()
-        .unwrap_or_default();
+    let global_model = settings.post_process_model(&provider.id);
 
     let model = match profile {
         Some(p) => {
@@ -125,7 +186,7 @@ async fn maybe_post_process_transcription(
             "Post-processing skipped because provider '{}' has no model configured",
             provider.id
         );
-        return PostProcessTranscriptionOutcome::Skipped;
+        return Err(PostProcessTranscriptionOutcome::Skipped);
     }
 
     // Determine prompt: profile override > global selected prompt
@@ -144,7 +205,7 @@ async fn maybe_post_process_transcription(
                 Some(id) => id.clone(),
                 None => {
                     debug!("Post-processing skipped because no prompt is selected");
-                    return PostProcessTranscriptionOutcome::Skipped;
+                    return Err(PostProcessTranscriptionOutcome::Skipped);
                 }
             };
 
@@ -159,7 +220,7 @@ async fn maybe_post_process_transcription(
                         "Post-processing skipped because prompt '{}' was not found",
                         selected_prompt_id
                     );
-                    return PostProcessTranscriptionOutcome::Skipped;
+                    return Err(PostProcessTranscriptionOutcome::Skipped);
                 }
             }
         }
@@ -167,16 +228,90 @@ async fn maybe_post_process_transcription(
 
     if prompt_template.trim().is_empty() {
         debug!("Post-processing skipped because the selected prompt is empty");
-        return PostProcessTranscriptionOutcome::Skipped;
+        return Err(PostProcessTranscriptionOutcome::Skipped);
+    }
+
+    Ok(PostProcessPlan {
+        provider,
+        model,
+        prompt_template,
+    })
+}
+
+/// Built-in hint prepended to the post-processing prompt on low STT confidence
+const DEFAULT_LOW_CONFIDENCE_HINT: &str =
+    "This transcription may contain speech recognition errors. \
+Correct likely mistakes (misheard words, wrong homophones, broken sentences) more aggressively \
+than usual, while keeping the intended meaning.";
+
+/// The hint to prepend when `confidence` is below the configured threshold
+/// (None when no threshold is set or the engine reported no confidence)
+fn low_confidence_hint(settings: &AppSettings, confidence: Option<f32>) -> Option<String> {
+    let threshold = settings.post_process_low_confidence_threshold?;
+    if confidence? >= threshold {
+        return None;
     }
+    Some(
+        settings
+            .post_process_low_confidence_hint
+            .clone()
+            .filter(|hint| !hint.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_LOW_CONFIDENCE_HINT.to_string()),
+    )
+}
+
+/// Whether transcriptions for this profile id go through an LLM call (not skipped,
+/// disabled, or replaced by Chinese variant conversion)
+fn llm_post_process_will_run(settings: &AppSettings, profile_id: Option<&str>) -> bool {
+    let profile = profile_id
+        .filter(|id| *id != "default")
+        .and_then(|id| settings.transcription_profile(id));
+    let chinese_conversion = matches!(settings.selected_language.as_str(), "zh-Hans" | "zh-Hant");
+    !chinese_conversion && plan_post_process(settings, profile).is_ok()
+}
+
+/// Post-process transcription with LLM, optionally using profile-specific settings.
+///
+/// If `profile` is Some, uses the profile's LLM settings:
+/// - `profile.llm_post_process_enabled` determines if post-processing is enabled
+/// - `profile.llm_prompt_override` overrides the global prompt (if set)
+/// - `profile.llm_model_override` overrides the global model (if set and valid for current provider)
+///
+/// If `profile` is None (default profile), uses global settings.
+///
+/// `confidence` is the speech-to-text confidence (Whisper only); below the configured
+/// threshold a hint asking for more aggressive correction is prepended to the prompt.
+async fn maybe_post_process_transcription(
+    app: &AppHandle,
+    settings: &AppSettings,
+    transcription: &str,
+    profile: Option<&TranscriptionProfile>,
+    confidence: Option<f32>,
+) -> PostProcessTranscriptionOutcome {
+    let PostProcessPlan {
+        provider,
+        model,
+        prompt_template,
+    } = match plan_post_process(settings, profile) {
+        Ok(plan) => plan,
+        Err(outcome) => return outcome,
+    };
 
     debug!(
         "Starting LLM post-processing with provider '{}' (model: {})",
         provider.id, model
     );
 
-    // Replace ${output} variable in the prompt with the actual text
-    let processed_prompt = prompt_template.replace("${output}", transcription);
+    // Replace ${output} (and the other prompt variables) with the actual text
+    let mut processed_prompt =
+        crate::prompt_variables::render_prompt(app, &prompt_template, &[("output", transcription)]);
+    if let Some(hint) = low_confidence_hint(settings, confidence) {
+        debug!(
+            "Transcription confidence {:?} is below the threshold, adding the low-confidence hint",
+            confidence
+        );
+        processed_prompt = format!("{}\n\n{}", hint, processed_prompt);
+    }
     debug!("Processed prompt length: {} chars", processed_prompt.len());
 
     if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
@@ -204,14 +339,19 @@ async fn maybe_post_process_transcription(
 
                     if result.trim().is_empty() {
                         debug!("Apple Intelligence returned an empty response");
-                        PostProcessTranscriptionOutcome::Skipped
+                        last_errors::record_error(
+                            app,
+                            ErrorFeature::PostProcess,
+                            "Apple Intelligence returned an empty response",
+                        );
+                        PostProcessTranscriptionOutcome::Failed
                     } else {
                         debug!(
                             "Apple Intelligence post-processing succeeded. Output length: {} chars",
                             result.len()
                         );
                         PostProcessTranscriptionOutcome::Processed {
-                            text: result,
+                            text: finish_post_process_output(settings, result, &prompt_template),
                             prompt_template,
                         }
                     }
@@ -226,7 +366,12 @@ async fn maybe_post_process_transcription(
                     }
 
                     error!("Apple Intelligence post-processing failed: {}", err);
-                    PostProcessTranscriptionOutcome::Skipped
+                    last_errors::record_error(
+                        app,
+                        ErrorFeature::PostProcess,
+                        format!("Apple Intelligence post-processing failed: {}", err),
+                    );
+                    PostProcessTranscriptionOutcome::Failed
                 }
             };
         }
@@ -241,18 +386,14 @@ async fn maybe_post_process_transcription(
     let llm_tracker = app.state::<Arc<LlmOperationTracker>>();
     let operation_id = llm_tracker.start_operation();
     show_thinking_overlay(app);
+    let _heartbeat = crate::managers::llm_operation::HeartbeatGuard::start(
+        app,
+        llm_tracker.inner().clone(),
+        operation_id,
+    );
 
-    // On Windows, use secure key storage
-    #[cfg(target_os = "windows")]
-    let api_key = crate::secure_keys::get_post_process_api_key(&provider.id);
-
-    // On non-Windows, use JSON settings
-    #[cfg(not(target_os = "windows"))]
-    let api_key = settings
-        .post_process_api_keys
-        .get(&provider.id)
-        .cloned()
-        .unwrap_or_default();
+    // Post-processing only ever uses its own key (secure storage on Windows)
+    let api_key = settings.post_process_api_key(&provider.id);
 
     // Build reasoning config from settings
     let reasoning_config = crate::llm_client::ReasoningConfig::new(
@@ -267,6 +408,7 @@ async fn maybe_post_process_transcription(
         &model,
         processed_prompt,
         reasoning_config,
+        &settings.post_process_stop_sequences,
     )
     .await
     {
@@ -285,7 +427,7 @@ async fn maybe_post_process_transcription(
                 content.len()
             );
             PostProcessTranscriptionOutcome::Processed {
-                text: content,
+                text: finish_post_process_output(settings, content, &prompt_template),
                 prompt_template,
             }
         }
@@ -299,7 +441,12 @@ async fn maybe_post_process_transcription(
             }
 
             error!("LLM API response has no content");
-            PostProcessTranscriptionOutcome::Skipped
+            last_errors::record_error(
+                app,
+                ErrorFeature::PostProcess,
+                "LLM API response has no content",
+            );
+            PostProcessTranscriptionOutcome::Failed
         }
         Err(e) => {
             if llm_tracker.is_cancelled(operation_id) {
@@ -315,51 +462,15 @@ async fn maybe_post_process_transcription(
                 provider.id,
                 e
             );
-            PostProcessTranscriptionOutcome::Skipped
-        }
-    }
-}
-
-async fn maybe_convert_chinese_variant(
-    settings: &AppSettings,
-    transcription: &str,
-) -> Option<String> {
-    // Check if language is set to Simplified or Traditional Chinese
-    let is_simplified = settings.selected_language == "zh-Hans";
-    let is_traditional = settings.selected_language == "zh-Hant";
-
-    if !is_simplified && !is_traditional {
-        debug!("selected_language is not Simplified or Traditional Chinese; skipping translation");
-        return None;
-    }
-
-    debug!(
-        "Starting Chinese translation using OpenCC for language: {}",
-        settings.selected_language
-    );
-
-    // Use OpenCC to convert based on selected language
-    let config = if is_simplified {
-        // Convert Traditional Chinese to Simplified Chinese
-        BuiltinConfig::Tw2sp
-    } else {
-        // Convert Simplified Chinese to Traditional Chinese
-        BuiltinConfig::S2twp
-    };
-
-    match OpenCC::from_config(config) {
-        Ok(converter) => {
-            let converted = converter.convert(transcription);
-            debug!(
-                "OpenCC translation completed. Input length: {}, Output length: {}",
-                transcription.len(),
-                converted.len()
+            last_errors::record_error(
+                app,
+                ErrorFeature::PostProcess,
+                format!(
+                    "LLM post-processing failed for provider '{}': {}",
+                    provider.id, e
+                ),
             );
-            Some(converted)
-        }
-        Err(e) => {
-            error!("Failed to initialize OpenCC converter: {}. Falling back to original transcription.", e);
-            None
+            PostProcessTranscriptionOutcome::Failed
         }
     }
 }
@@ -373,7 +484,9 @@ fn reset_toggle_state(app: &AppHandle, binding_id: &str) {
 }
 
 fn emit_ai_replace_error(app: &AppHandle, message: impl Into<String>) {
-    let _ = app.emit("ai-replace-error", message.into());
+    let message = message.into();
+    last_errors::record_error(app, ErrorFeature::AiReplace, message.clone());
+    let _ = app.emit("ai-replace-error", message);
 }
 
 // ============================================================================
@@ -395,7 +508,16 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
     // Load model in the background if using local transcription
     let tm = app.state::<Arc<TranscriptionManager>>();
     if settings.transcription_provider == TranscriptionProvider::Local {
-        tm.initiate_model_load();
+        if settings.models_by_language.is_empty() {
+            tm.initiate_model_load();
+        } else {
+            // Preload the model mapped to this binding's language
+            let profile = settings.profile_for_binding(binding_id);
+            let language = profile
+                .map(|p| p.language.as_str())
+                .unwrap_or(settings.selected_language.as_str());
+            tm.initiate_model_load_for(settings.model_for_language(language));
+        }
     }
 
     // Hold the lock for the entire operation to prevent race conditions
@@ -420,29 +542,25 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
     // Capture the effective profile ID at recording start time.
     // This ensures transcription uses the profile that was active when recording started,
     // even if the user switches profiles mid-recording.
-    let captured_profile_id =
-        if binding_id == "transcribe" && settings.active_profile_id != "default" {
-            // Main transcribe shortcut with an active profile - capture that profile ID
-            Some(settings.active_profile_id.clone())
-        } else if binding_id.starts_with("transcribe_profile_") {
-            // Profile-specific shortcut - extract and capture the profile ID
-            binding_id
-                .strip_prefix("transcribe_")
-                .map(|s| s.to_string())
-        } else {
-            // No profile to capture (ai_replace, send_to_extension, etc.)
-            None
-        };
+    // Main shortcut: the active profile; profile shortcuts: their own profile;
+    // transcribe_default and non-transcribe actions (ai_replace, ...): none, global settings
+    let captured_profile_id = settings
+        .profile_for_binding(binding_id)
+        .map(|p| p.id.clone());
 
     debug!(
         "start_recording_with_feedback: captured_profile_id={:?} for binding={}",
         captured_profile_id, binding_id
     );
 
+    // Remember where the user is typing before the overlay shows
+    let paste_target = crate::foreground_app::capture_focus_target();
+
     *state_guard = session_manager::SessionState::Recording {
         session: Arc::clone(&session),
         binding_id: binding_id.to_string(),
         captured_profile_id,
+        paste_target,
     };
 
     // Now release the lock before doing I/O operations
@@ -452,6 +570,12 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
     show_recording_overlay(app);
 
     let rm = app.state::<Arc<AudioRecordingManager>>();
+
+    // Profiles can carry their own VAD threshold (noisy office vs quiet home); set it
+    // before the stream starts, and always, so a profile without one restores the global
+    let vad_profile = settings.profile_for_binding(binding_id);
+    rm.update_vad_threshold(settings.vad_threshold_for(vad_profile));
+
     let is_always_on = settings.always_on_microphone;
     debug!("Microphone mode - always_on: {}", is_always_on);
 
@@ -501,6 +625,12 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
 
         // Show microphone error overlay instead of just hiding
         crate::plus_overlay_state::show_mic_error_overlay(app);
+        last_errors::record_error_with_category(
+            app,
+            ErrorFeature::Transcription,
+            crate::plus_overlay_state::OverlayErrorCategory::MicrophoneUnavailable,
+            "Failed to start recording: microphone unavailable",
+        );
     }
 
     recording_started
@@ -510,8 +640,9 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
 
 /// Result of a transcription operation
 pub enum TranscriptionOutcome {
-    /// Transcription succeeded with the given text
-    Success(String),
+    /// Transcription succeeded with the given text, plus what the local engine reported:
+    /// segment timings when `history_store_segments` is on, and its confidence
+    Success(String, TranscriptionDetails),
     /// Operation was cancelled (Remote STT only)
     Cancelled,
     /// Error occurred - for Remote STT, error is already shown in overlay
@@ -544,6 +675,7 @@ async fn perform_transcription_for_profile(
     captured_profile_id: Option<String>,
 ) -> TranscriptionOutcome {
     let settings = get_settings(app);
+    let tm = app.state::<Arc<TranscriptionManager>>();
 
     // Use the captured profile ID from recording start, not the current active_profile_id.
     // This ensures that if the user switches profiles mid-recording, we still use
@@ -561,6 +693,8 @@ async fn perform_transcription_for_profile(
         profile.as_ref().map(|p| &p.name)
     );
 
+    let filler_filter_enabled = settings.filler_filter_enabled_for(profile);
+
     if settings.transcription_provider == TranscriptionProvider::RemoteOpenAiCompatible {
         // Determine translate_to_english: use profile setting if available, otherwise global setting
         let translate_to_english = profile
@@ -624,7 +758,7 @@ async fn perform_transcription_for_profile(
                         text
                     };
                 // Apply filler word filter (if enabled)
-                if settings.filler_word_filter_enabled {
+                if filler_filter_enabled {
                     crate::audio_toolkit::filter_transcription_output(&corrected)
                 } else {
                     corrected
@@ -641,7 +775,7 @@ async fn perform_transcription_for_profile(
         }
 
         match result {
-            Ok(text) => TranscriptionOutcome::Success(text),
+            Ok(text) => TranscriptionOutcome::Success(text, TranscriptionDetails::default()),
             Err(err) => {
                 let err_str = format!("{}", err);
                 let _ = app.emit("remote-stt-error", err_str.clone());
@@ -653,39 +787,60 @@ async fn perform_transcription_for_profile(
             }
         }
     } else {
-        let tm = app.state::<Arc<TranscriptionManager>>();
+        // Pick the model for the resolved language (models_by_language), swapping if needed
+        let language = profile
+            .as_ref()
+            .map(|p| p.language.as_str())
+            .unwrap_or(settings.selected_language.as_str());
+        let model_id = settings.model_for_language(language);
+        if !settings.models_by_language.is_empty() {
+            if let Err(err) = tm.ensure_model_loaded(&model_id) {
+                let err_str = format!("Failed to load model '{}': {}", model_id, err);
+                debug!("Local transcription error: {}", err_str);
+                return TranscriptionOutcome::Error {
+                    message: err_str,
+                    shown_in_overlay: false,
+                };
+            }
+        }
 
-        // Use profile overrides for local transcription if available
-        let result = if let Some(p) = &profile {
-            log::info!(
+        // Profile overrides (language, translation, prompt) when a profile is active
+        match &profile {
+            Some(p) => log::info!(
                 "Transcription using Local model '{}' with profile '{}' (lang={}, translate={})",
-                settings.selected_model,
+                model_id,
                 p.name,
                 p.language,
                 p.translate_to_english
-            );
-            tm.transcribe_with_overrides(
+            ),
+            None => log::info!("Transcription using Local model: {}", model_id),
+        }
+        let result = tm
+            .transcribe_with_segments(
                 samples,
-                Some(&p.language),
-                Some(p.translate_to_english),
+                profile.as_ref().map(|p| p.language.as_str()),
+                profile.as_ref().map(|p| p.translate_to_english),
                 // Use resolve_stt_prompt to respect stt_prompt_override_enabled flag
-                crate::settings::resolve_stt_prompt(
-                    Some(p),
-                    &settings.transcription_prompts,
-                    &settings.selected_model,
-                ),
+                profile.as_ref().and_then(|p| {
+                    crate::settings::resolve_stt_prompt(
+                        Some(p),
+                        &settings.transcription_prompts,
+                        &model_id,
+                    )
+                }),
                 settings.custom_words_enabled,
+                filler_filter_enabled,
             )
-        } else {
-            log::info!(
-                "Transcription using Local model: {}",
-                settings.selected_model
-            );
-            tm.transcribe(samples, settings.custom_words_enabled)
-        };
+            .map(|(text, mut details)| {
+                // Segment timings are only kept for subtitle export when enabled
+                if !settings.history_store_segments {
+                    details.segments = None;
+                }
+                (text, details)
+            });
 
         match result {
-            Ok(text) => TranscriptionOutcome::Success(text),
+            Ok((text, details)) => TranscriptionOutcome::Success(text, details),
             Err(err) => {
                 let err_str = format!("{}", err);
                 debug!("Local transcription error: {}", err_str);
@@ -722,12 +877,14 @@ fn prepare_stop_recording(app: &AppHandle, binding_id: &str) -> Option<Option<St
             binding_id: current_binding_id,
             session,
             captured_profile_id,
+            paste_target,
         } if current_binding_id == binding_id => {
             let session = Arc::clone(session);
             let captured = captured_profile_id.clone();
             // Transition to Processing state
             *state_guard = session_manager::SessionState::Processing {
                 binding_id: binding_id.to_string(),
+                paste_target: *paste_target,
             };
             Some((session, captured))
         }
@@ -786,14 +943,23 @@ fn prepare_stop_recording(app: &AppHandle, binding_id: &str) -> Option<Option<St
 ///
 /// The captured_profile_id is the profile that was active when recording started,
 /// ensuring transcription uses the correct profile even if the user switches mid-recording.
+/// When a `timer` is given, the stop and transcribe stages are recorded on it.
+/// Returns the text, the recorded samples and what the engine reported about the text.
 async fn get_transcription_or_cleanup(
     app: &AppHandle,
     binding_id: &str,
     captured_profile_id: Option<String>,
-) -> Option<(String, Vec<f32>)> {
+    mut timer: Option<&mut PipelineTimer>,
+) -> Option<(String, Vec<f32>, TranscriptionDetails)> {
     let rm = Arc::clone(&app.state::<Arc<AudioRecordingManager>>());
+    let cancel_generation = session_manager::cancel_generation();
 
     if let Some(samples) = rm.stop_recording(binding_id) {
+        if let Some(timer) = timer.as_deref_mut() {
+            timer.set_recorded_samples(samples.len());
+            timer.mark(PipelineStage::Stop);
+        }
+
         // Quick Tap Optimization: Only apply to AI Replace action
         let settings = get_settings(app);
         let is_ai_replace = binding_id.starts_with("ai_replace");
@@ -809,22 +975,63 @@ async fn get_transcription_or_cleanup(
                 samples.len(),
                 (settings.ai_replace_quick_tap_threshold_ms as f32 / 1000.0 * 16000.0) as usize
             );
-            return Some((String::new(), samples));
+            return Some((String::new(), samples, TranscriptionDetails::default()));
         }
 
-        match perform_transcription_for_profile(
+        // Accidental triggers: VAD kept nothing, or nothing is loud enough to be speech.
+        // AI Replace is exempt: an empty instruction is meaningful there (quick tap).
+        if !is_ai_replace
+            && settings.skip_non_speech_recordings
+            && !crate::managers::audio::has_speech_energy(
+                &samples,
+                settings.speech_energy_threshold_dbfs,
+            )
+        {
+            debug!(
+                "No speech detected in {} samples (threshold {} dBFS), skipping transcription",
+                samples.len(),
+                settings.speech_energy_threshold_dbfs
+            );
+            let _ = app.emit("transcription-empty", ());
+            utils::hide_recording_overlay(app);
+            change_tray_icon(app, TrayIconState::Idle);
+            return None;
+        }
+
+        let outcome = perform_transcription_for_profile(
             app,
             samples.clone(),
             Some(binding_id),
             captured_profile_id,
         )
-        .await
-        {
-            TranscriptionOutcome::Success(text) => Some((text, samples)),
-            TranscriptionOutcome::Cancelled => None,
+        .await;
+        if let Some(timer) = timer {
+            timer.mark(PipelineStage::Transcribe);
+        }
+
+        match outcome {
+            // Cancelled while transcribing (local STT can't be interrupted): never
+            // paste or save it as a completed transcription
+            TranscriptionOutcome::Success(text, _)
+                if session_manager::cancelled_since(cancel_generation) =>
+            {
+                debug!("Transcription finished after cancellation, discarding result");
+                utils::retain_cancelled_recording(app, samples, text);
+                None
+            }
+            TranscriptionOutcome::Success(text, details) => {
+                last_errors::clear_error(app, ErrorFeature::Transcription);
+                Some((text, samples, details))
+            }
+            TranscriptionOutcome::Cancelled => {
+                utils::retain_cancelled_recording(app, samples, String::new());
+                None
+            }
             TranscriptionOutcome::Error {
-                shown_in_overlay, ..
+                message,
+                shown_in_overlay,
             } => {
+                last_errors::record_error(app, ErrorFeature::Transcription, message);
                 if !shown_in_overlay {
                     utils::hide_recording_overlay(app);
                     change_tray_icon(app, TrayIconState::Idle);
@@ -840,24 +1047,23 @@ async fn get_transcription_or_cleanup(
     }
 }
 
-/// Applies Chinese conversion, LLM post-processing and saves to history.
+/// Runs the text pipeline (see `text_pipeline`) with LLM post-processing and saves to history.
 ///
 /// `profile_id` is the ID of the active transcription profile (e.g., "default" or "profile_1234").
 /// If a custom profile is used, its LLM settings will be applied for post-processing.
 ///
-/// Text replacement order is controlled by `text_replacements_before_llm`:
-/// - When true:  STT → Text Replacement → LLM → Output
-/// - When false: STT → LLM → Text Replacement → Output (default)
+/// `allow_text_replacements` lets callers opt out (e.g., the connector path when
+/// `connector_apply_text_replacements` is off).
 async fn apply_post_processing_and_history(
     app: &AppHandle,
     transcription: String,
     samples: Vec<f32>,
+    details: TranscriptionDetails,
     profile_id: Option<String>,
+    allow_text_replacements: bool,
 ) -> Option<String> {
-    let settings = get_settings(app);
-    let mut final_text = transcription.clone();
-    let mut post_processed_text: Option<String> = None;
-    let mut post_process_prompt: Option<String> = None;
+    let settings = &get_settings(app);
+    let confidence = details.confidence;
 
     // Look up the profile if a custom profile is being used
     let profile = profile_id
@@ -865,63 +1071,44 @@ async fn apply_post_processing_and_history(
         .filter(|id| *id != "default")
         .and_then(|id| settings.transcription_profile(id));
 
-    // Helper closure for applying text replacements
-    let apply_replacements = |text: &str| -> String {
-        if settings.text_replacements_enabled && !settings.text_replacements.is_empty() {
-            let original_len = text.len();
-            let result =
-                crate::settings::apply_text_replacements(text, &settings.text_replacements);
-            if result.len() != original_len {
-                debug!(
-                    "Text replacements applied: {} chars -> {} chars",
-                    original_len,
-                    result.len()
-                );
-            }
-            result
-        } else {
-            text.to_string()
-        }
-    };
-
-    // Apply text replacements BEFORE LLM if configured
-    if settings.text_replacements_before_llm {
-        final_text = apply_replacements(&final_text);
-    }
-
-    if let Some(converted_text) = maybe_convert_chinese_variant(&settings, &final_text).await {
-        final_text = converted_text.clone();
-        post_processed_text = Some(converted_text);
-    } else {
-        match maybe_post_process_transcription(app, &settings, &final_text, profile).await {
-            PostProcessTranscriptionOutcome::Skipped => {}
-            PostProcessTranscriptionOutcome::Cancelled => {
-                return None;
-            }
-            PostProcessTranscriptionOutcome::Processed {
-                text,
-                prompt_template,
-            } => {
-                final_text = text.clone();
-                post_processed_text = Some(text);
-                post_process_prompt = Some(prompt_template);
+    let output = run_text_pipeline(
+        settings,
+        profile,
+        &transcription,
+        allow_text_replacements,
+        |text| async move {
+            let outcome =
+                maybe_post_process_transcription(app, settings, &text, profile, confidence).await;
+            if matches!(outcome, PostProcessTranscriptionOutcome::Processed { .. }) {
+                last_errors::clear_error(app, ErrorFeature::PostProcess);
             }
-        }
-    }
-
-    // Apply text replacements AFTER LLM if NOT configured for before
-    if !settings.text_replacements_before_llm {
-        final_text = apply_replacements(&final_text);
-    }
+            outcome
+        },
+    )
+    .await?;
+    debug!("Text pipeline stages: {:?}", output.stages);
+
+    let log_entry = settings.transcription_log_path.clone().map(|path| {
+        let profile_name = profile.map_or("Default", |p| p.name.as_str());
+        let entry =
+            crate::transcription_log::LogEntry::now(profile_name, &transcription, &output.text);
+        (path, entry)
+    });
+    let log_format = settings.transcription_log_format;
 
     let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
     tauri::async_runtime::spawn(async move {
+        if let Some((path, entry)) = log_entry {
+            crate::transcription_log::append(&path, log_format, &entry);
+        }
         if let Err(e) = hm
             .save_transcription(
                 samples,
                 transcription,
-                post_processed_text,
-                post_process_prompt,
+                output.post_processed_text,
+                output.post_process_prompt,
+                output.post_process_status,
+                details.segments,
             )
             .await
         {
@@ -929,12 +1116,115 @@ async fn apply_post_processing_and_history(
         }
     });
 
-    Some(final_text)
+    Some(output.text)
+}
+
+/// Session binding id while a stored recording is being re-transcribed
+const RETRANSCRIBE_BINDING_ID: &str = "retranscribe";
+
+/// Runs previously recorded samples through the current STT provider, model and active
+/// profile, then post-processes, saves to history and pastes like a normal transcription.
+/// Returns the pasted text.
+pub async fn retranscribe_samples(app: &AppHandle, samples: Vec<f32>) -> Result<String, String> {
+    let settings = get_settings(app);
+    {
+        let state = app.state::<ManagedSessionState>();
+        let mut state_guard = state.lock().expect("Failed to lock session state");
+        if !matches!(*state_guard, session_manager::SessionState::Idle) {
+            return Err("Cannot re-transcribe while recording or processing".to_string());
+        }
+        *state_guard = session_manager::SessionState::Processing {
+            binding_id: RETRANSCRIBE_BINDING_ID.to_string(),
+            paste_target: None,
+        };
+    }
+    let _in_flight = crate::transcribing_watchdog::track_processing();
+
+    let profile_id = Some(settings.active_profile_id.clone()).filter(|id| id.as_str() != "default");
+
+    change_tray_icon(app, TrayIconState::Transcribing);
+    if settings.transcription_provider == TranscriptionProvider::RemoteOpenAiCompatible {
+        show_sending_overlay(app);
+    } else {
+        show_transcribing_overlay(app);
+    }
+
+    let outcome = perform_transcription_for_profile(
+        app,
+        samples.clone(),
+        Some(RETRANSCRIBE_BINDING_ID),
+        profile_id.clone(),
+    )
+    .await;
+
+    let (transcription, details) = match outcome {
+        TranscriptionOutcome::Success(text, details) => {
+            last_errors::clear_error(app, ErrorFeature::Transcription);
+            (text, details)
+        }
+        TranscriptionOutcome::Cancelled => {
+            session_manager::exit_processing(app);
+            return Err("Re-transcription was cancelled".to_string());
+        }
+        TranscriptionOutcome::Error {
+            message,
+            shown_in_overlay,
+        } => {
+            last_errors::record_error(app, ErrorFeature::Transcription, message.clone());
+            if !shown_in_overlay {
+                utils::hide_recording_overlay(app);
+                change_tray_icon(app, TrayIconState::Idle);
+            }
+            session_manager::exit_processing(app);
+            return Err(message);
+        }
+    };
+
+    if transcription.is_empty() {
+        utils::hide_recording_overlay(app);
+        change_tray_icon(app, TrayIconState::Idle);
+        session_manager::exit_processing(app);
+        return Ok(String::new());
+    }
+
+    let final_text = match apply_post_processing_and_history(
+        app,
+        transcription,
+        samples,
+        details,
+        profile_id,
+        true,
+    )
+    .await
+    {
+        Some(text) => text,
+        None => {
+            session_manager::exit_processing(app);
+            return Err("Re-transcription was cancelled".to_string());
+        }
+    };
+
+    let ah = app.clone();
+    let text = final_text.clone();
+    app.run_on_main_thread(move || {
+        let _ = utils::paste(text, ah.clone());
+        utils::hide_recording_overlay(&ah);
+        change_tray_icon(&ah, TrayIconState::Idle);
+    })
+    .ok();
+
+    session_manager::exit_processing(app);
+    Ok(final_text)
 }
 
 // ============================================================================
 
-fn build_extension_message(settings: &AppSettings, instruction: &str, selection: &str) -> String {
+fn build_extension_message(
+    app: &AppHandle,
+    settings: &AppSettings,
+    instruction: &str,
+    selection: &str,
+) -> String {
     let instruction_trimmed = instruction.trim();
     let selection_trimmed = selection.trim();
 
@@ -964,9 +1254,15 @@ fn build_extension_message(settings: &AppSettings, instruction: &str, selection:
             instruction_trimmed, selection
         )
     } else {
-        user_template
-            .replace("${instruction}", instruction_trimmed)
-            .replace("${output}", selection)
+        crate::prompt_variables::render_prompt(
+            app,
+            user_template,
+            &[
+                ("instruction", instruction_trimmed),
+                ("output", selection),
+                ("selection", selection),
+            ],
+        )
     };
 
     let system_prompt = settings
@@ -979,7 +1275,8 @@ fn build_extension_message(settings: &AppSettings, instruction: &str, selection:
     }
 }
 
-async fn ai_replace_with_llm(
+pub async fn ai_replace_with_llm(
+    app: &AppHandle,
     settings: &AppSettings,
     selected_text: &str,
     instruction: &str,
@@ -1010,9 +1307,15 @@ async fn ai_replace_with_llm(
         return Err("AI replace prompt template is empty".to_string());
     }
 
-    let user_prompt = user_template
-        .replace("${output}", selected_text)
-        .replace("${instruction}", instruction);
+    let user_prompt = crate::prompt_variables::render_prompt(
+        app,
+        &user_template,
+        &[
+            ("output", selected_text),
+            ("instruction", instruction),
+            ("selection", selected_text),
+        ],
+    );
 
     debug!(
         "AI replace LLM request using provider '{}' (model: {})",
@@ -1035,12 +1338,21 @@ async fn ai_replace_with_llm(
         system_prompt,
         user_prompt,
         reasoning_config,
+        &settings.ai_replace_stop_sequences,
     )
     .await
     {
         Ok(Some(content)) => {
             debug!("AI replace LLM response length: {} chars", content.len());
-            Ok(content)
+            if settings.strip_llm_formatting {
+                let keep_fences = crate::llm_output::asks_for_code(instruction);
+                Ok(crate::llm_output::strip_llm_formatting(
+                    &content,
+                    keep_fences,
+                ))
+            } else {
+                Ok(content)
+            }
         }
         Ok(None) => Err("LLM API response has no content".to_string()),
         Err(e) => Err(format!("LLM request failed: {}", e)),
@@ -1065,6 +1377,7 @@ impl ShortcutAction for TranscribeAction {
     }
 
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        let mut timer = PipelineTimer::start(binding_id);
         let captured_profile_id = match prepare_stop_recording(app, binding_id) {
             Some(profile_id) => profile_id,
             None => return, // No active session - nothing to do
@@ -1074,15 +1387,22 @@ impl ShortcutAction for TranscribeAction {
         let binding_id = binding_id.to_string();
 
         tauri::async_runtime::spawn(async move {
+            let _in_flight = crate::transcribing_watchdog::track_processing();
             let profile_id_for_postprocess = captured_profile_id.clone();
-            let (transcription, samples) =
-                match get_transcription_or_cleanup(&ah, &binding_id, captured_profile_id).await {
-                    Some(res) => res,
-                    None => {
-                        session_manager::exit_processing(&ah);
-                        return;
-                    }
-                };
+            let (transcription, samples, details) = match get_transcription_or_cleanup(
+                &ah,
+                &binding_id,
+                captured_profile_id,
+                Some(&mut timer),
+            )
+            .await
+            {
+                Some(res) => res,
+                None => {
+                    session_manager::exit_processing(&ah);
+                    return;
+                }
+            };
 
             if transcription.is_empty() {
                 utils::hide_recording_overlay(&ah);
@@ -1091,11 +1411,33 @@ impl ShortcutAction for TranscribeAction {
                 return;
             }
 
+            // Paste-then-replace: the raw text goes in now, post-processing replaces it
+            // later. Only worth it when the LLM will actually run; otherwise the pipeline
+            // is fast and replacing would just flicker the text.
+            let settings = get_settings(&ah);
+            let scratchpad_mode = settings.scratchpad_mode;
+            let pasted_raw = (settings.post_process_paste_then_replace
+                && !scratchpad_mode
+                && settings.paste_method != PasteMethod::None
+                && llm_post_process_will_run(&settings, profile_id_for_postprocess.as_deref()))
+            .then(|| transcription.clone());
+            // Taken while still Processing; the main-thread paste may run after exit
+            let paste_target = session_manager::take_paste_target(&ah);
+            if let Some(raw) = pasted_raw.clone() {
+                let ah_clone = ah.clone();
+                ah.run_on_main_thread(move || {
+                    let _ = utils::paste_to(raw, paste_target, ah_clone);
+                })
+                .ok();
+            }
+
             let final_text = match apply_post_processing_and_history(
                 &ah,
                 transcription,
                 samples,
+                details,
                 profile_id_for_postprocess,
+                true,
             )
             .await
             {
@@ -1105,11 +1447,24 @@ impl ShortcutAction for TranscribeAction {
                     return;
                 }
             };
+            timer.mark(PipelineStage::PostProcess);
 
             let ah_clone = ah.clone();
             let binding_id_clone = binding_id.clone();
             ah.run_on_main_thread(move || {
-                let _ = utils::paste(final_text, ah_clone.clone());
+                if scratchpad_mode {
+                    crate::scratchpad::append(&ah_clone, &final_text);
+                } else if let Some(raw) = pasted_raw {
+                    if final_text != raw {
+                        if let Err(e) = utils::replace_pasted(ah_clone.clone(), raw, final_text) {
+                            warn!("Failed to replace pasted text: {}", e);
+                        }
+                    }
+                } else {
+                    let _ = utils::paste_to(final_text, paste_target, ah_clone.clone());
+                }
+                timer.mark(PipelineStage::Paste);
+                timer.finish(&ah_clone);
                 utils::hide_recording_overlay(&ah_clone);
                 change_tray_icon(&ah_clone, TrayIconState::Idle);
                 // Clear toggle state now that transcription is complete
@@ -1140,6 +1495,12 @@ impl ShortcutAction for SendToExtensionAction {
                 app,
                 crate::plus_overlay_state::OverlayErrorCategory::ExtensionOffline,
             );
+            last_errors::record_error_with_category(
+                app,
+                ErrorFeature::Connector,
+                crate::plus_overlay_state::OverlayErrorCategory::ExtensionOffline,
+                "Browser extension is offline",
+            );
             return;
         }
 
@@ -1161,6 +1522,7 @@ impl ShortcutAction for SendToExtensionAction {
             return;
         }
 
+        let mut timer = PipelineTimer::start(binding_id);
         if prepare_stop_recording(app, binding_id).is_none() {
             return; // No active session - nothing to do
         }
@@ -1170,14 +1532,21 @@ impl ShortcutAction for SendToExtensionAction {
         let binding_id = binding_id.to_string();
 
         tauri::async_runtime::spawn(async move {
-            let (transcription, samples) =
-                match get_transcription_or_cleanup(&ah, &binding_id, None).await {
-                    Some(res) => res,
-                    None => {
-                        session_manager::exit_processing(&ah);
-                        return;
-                    }
-                };
+            let _in_flight = crate::transcribing_watchdog::track_processing();
+            let (transcription, samples, details) = match get_transcription_or_cleanup(
+                &ah,
+                &binding_id,
+                None,
+                Some(&mut timer),
+            )
+            .await
+            {
+                Some(res) => res,
+                None => {
+                    session_manager::exit_processing(&ah);
+                    return;
+                }
+            };
 
             if transcription.is_empty() {
                 utils::hide_recording_overlay(&ah);
@@ -1187,19 +1556,41 @@ impl ShortcutAction for SendToExtensionAction {
             }
 
             // Use default profile (None) for extension actions
-            let final_text =
-                match apply_post_processing_and_history(&ah, transcription, samples, None).await {
-                    Some(text) => text,
-                    None => {
-                        session_manager::exit_processing(&ah);
-                        return;
-                    }
-                };
+            let apply_replacements = get_settings(&ah).connector_apply_text_replacements;
+            let final_text = match apply_post_processing_and_history(
+                &ah,
+                transcription,
+                samples,
+                details,
+                None,
+                apply_replacements,
+            )
+            .await
+            {
+                Some(text) => text,
+                None => {
+                    session_manager::exit_processing(&ah);
+                    return;
+                }
+            };
+            timer.mark(PipelineStage::PostProcess);
 
             match cm.queue_message(&final_text) {
-                Ok(id) => debug!("Connector message queued with id: {}", id),
-                Err(e) => error!("Failed to queue connector message: {}", e),
+                Ok(id) => {
+                    debug!("Connector message queued with id: {}", id);
+                    last_errors::clear_error(&ah, ErrorFeature::Connector);
+                }
+                Err(e) => {
+                    error!("Failed to queue connector message: {}", e);
+                    last_errors::record_error(
+                        &ah,
+                        ErrorFeature::Connector,
+                        format!("Failed to queue connector message: {}", e),
+                    );
+                }
             }
+            timer.mark(PipelineStage::Paste);
+            timer.finish(&ah);
 
             let ah_clone = ah.clone();
             ah.run_on_main_thread(move || {
@@ -1229,6 +1620,12 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
                 app,
                 crate::plus_overlay_state::OverlayErrorCategory::ExtensionOffline,
             );
+            last_errors::record_error_with_category(
+                app,
+                ErrorFeature::Connector,
+                crate::plus_overlay_state::OverlayErrorCategory::ExtensionOffline,
+                "Browser extension is offline",
+            );
             return;
         }
 
@@ -1250,23 +1647,31 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
             return;
         }
 
+        let mut timer = PipelineTimer::start(binding_id);
         if prepare_stop_recording(app, binding_id).is_none() {
             return; // No active session - nothing to do
         }
 
         let ah = app.clone();
-        let cm = Arc::clone(&app.state::<Arc<ConnectorManager>>());
-        let binding_id = binding_id.to_string();
-
-        tauri::async_runtime::spawn(async move {
-            let (transcription, samples) =
-                match get_transcription_or_cleanup(&ah, &binding_id, None).await {
-                    Some(res) => res,
-                    None => {
-                        session_manager::exit_processing(&ah);
-                        return;
-                    }
-                };
+        let cm = Arc::clone(&app.state::<Arc<ConnectorManager>>());
+        let binding_id = binding_id.to_string();
+
+        tauri::async_runtime::spawn(async move {
+            let _in_flight = crate::transcribing_watchdog::track_processing();
+            let (transcription, samples, details) = match get_transcription_or_cleanup(
+                &ah,
+                &binding_id,
+                None,
+                Some(&mut timer),
+            )
+            .await
+            {
+                Some(res) => res,
+                None => {
+                    session_manager::exit_processing(&ah);
+                    return;
+                }
+            };
 
             let settings = get_settings(&ah);
             let final_transcription = if transcription.trim().is_empty() {
@@ -1279,7 +1684,16 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
                 String::new()
             } else {
                 // Use default profile (None) for extension actions
-                match apply_post_processing_and_history(&ah, transcription, samples, None).await {
+                match apply_post_processing_and_history(
+                    &ah,
+                    transcription,
+                    samples,
+                    details,
+                    None,
+                    settings.connector_apply_text_replacements,
+                )
+                .await
+                {
                     Some(text) => text,
                     None => {
                         session_manager::exit_processing(&ah);
@@ -1288,12 +1702,17 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
                 }
             };
 
+            timer.mark(PipelineStage::PostProcess);
+
             let selected_text = utils::capture_selection_text_copy(&ah).unwrap_or_default();
-            let message = build_extension_message(&settings, &final_transcription, &selected_text);
+            let message =
+                build_extension_message(&ah, &settings, &final_transcription, &selected_text);
 
             if !message.trim().is_empty() {
                 let _ = cm.queue_message(&message);
             }
+            timer.mark(PipelineStage::Paste);
+            timer.finish(&ah);
 
             let ah_clone = ah.clone();
             ah.run_on_main_thread(move || {
@@ -1308,7 +1727,9 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
 }
 
 fn emit_screenshot_error(app: &AppHandle, message: impl Into<String>) {
-    let _ = app.emit("screenshot-error", message.into());
+    let message = message.into();
+    last_errors::record_error(app, ErrorFeature::Screenshot, message.clone());
+    let _ = app.emit("screenshot-error", message);
 }
 
 /// Expands Windows-style environment variables like %USERPROFILE% in a path string.
@@ -1572,6 +1993,12 @@ impl ShortcutAction for SendScreenshotToExtensionAction {
                 app,
                 crate::plus_overlay_state::OverlayErrorCategory::ExtensionOffline,
             );
+            last_errors::record_error_with_category(
+                app,
+                ErrorFeature::Connector,
+                crate::plus_overlay_state::OverlayErrorCategory::ExtensionOffline,
+                "Browser extension is offline",
+            );
             return;
         }
 
@@ -1602,13 +2029,15 @@ impl ShortcutAction for SendScreenshotToExtensionAction {
         let binding_id = binding_id.to_string();
 
         tauri::async_runtime::spawn(async move {
-            let (voice_text, _) = match get_transcription_or_cleanup(&ah, &binding_id, None).await {
-                Some(res) => res,
-                None => {
-                    session_manager::exit_processing(&ah);
-                    return;
-                }
-            };
+            let _in_flight = crate::transcribing_watchdog::track_processing();
+            let (voice_text, _, _) =
+                match get_transcription_or_cleanup(&ah, &binding_id, None, None).await {
+                    Some(res) => res,
+                    None => {
+                        session_manager::exit_processing(&ah);
+                        return;
+                    }
+                };
 
             let settings = get_settings(&ah);
             let final_voice_text =
@@ -1639,6 +2068,7 @@ impl ShortcutAction for SendScreenshotToExtensionAction {
                                 image_data,
                                 "image/png",
                             );
+                            last_errors::clear_error(&ah, ErrorFeature::Screenshot);
                         }
                         RegionCaptureResult::Cancelled => {
                             debug!("Screenshot capture cancelled by user");
@@ -1714,6 +2144,7 @@ impl ShortcutAction for SendScreenshotToExtensionAction {
             {
                 Ok(path) => {
                     let _ = cm.queue_bundle_message(&final_voice_text, &path);
+                    last_errors::clear_error(&ah, ErrorFeature::Screenshot);
                 }
                 Err(e) => {
                     emit_screenshot_error(&ah, &e);
@@ -1750,6 +2181,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
     }
 
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        let mut timer = PipelineTimer::start(binding_id);
         if prepare_stop_recording(app, binding_id).is_none() {
             return; // No active session - nothing to do
         }
@@ -1758,14 +2190,21 @@ impl ShortcutAction for AiReplaceSelectionAction {
         let binding_id = binding_id.to_string();
 
         tauri::async_runtime::spawn(async move {
-            let (transcription, _) =
-                match get_transcription_or_cleanup(&ah, &binding_id, None).await {
-                    Some(res) => res,
-                    None => {
-                        session_manager::exit_processing(&ah);
-                        return;
-                    }
-                };
+            let _in_flight = crate::transcribing_watchdog::track_processing();
+            let (transcription, _, _) = match get_transcription_or_cleanup(
+                &ah,
+                &binding_id,
+                None,
+                Some(&mut timer),
+            )
+            .await
+            {
+                Some(res) => res,
+                None => {
+                    session_manager::exit_processing(&ah);
+                    return;
+                }
+            };
 
             let settings = get_settings(&ah);
 
@@ -1806,7 +2245,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
             let instruction_for_history = transcription.clone();
             let selection_for_history = selected_text.clone();
 
-            match ai_replace_with_llm(&settings, &selected_text, &transcription).await {
+            match ai_replace_with_llm(&ah, &settings, &selected_text, &transcription).await {
                 Ok(output) => {
                     // Check if operation was cancelled while we were waiting
                     if llm_tracker.is_cancelled(operation_id) {
@@ -1818,6 +2257,8 @@ impl ShortcutAction for AiReplaceSelectionAction {
                         // exit_processing already called by cancel
                         return;
                     }
+                    last_errors::clear_error(&ah, ErrorFeature::AiReplace);
+                    timer.mark(PipelineStage::PostProcess);
 
                     // Save to history with AI response
                     let hm_clone = Arc::clone(&hm);
@@ -1837,15 +2278,29 @@ impl ShortcutAction for AiReplaceSelectionAction {
                         }
                     });
 
+                    let output_mode = settings.ai_replace_output_mode;
+                    let paste_target = session_manager::take_paste_target(&ah);
                     let ah_clone = ah.clone();
                     ah.run_on_main_thread(move || {
-                        let _ = utils::paste(output, ah_clone.clone());
+                        if output_mode != AiReplaceOutputMode::Clipboard {
+                            let _ = utils::paste_to(output.clone(), paste_target, ah_clone.clone());
+                        }
+                        // Copy after pasting so clipboard restore doesn't overwrite it
+                        if output_mode != AiReplaceOutputMode::Replace {
+                            if let Err(e) =
+                                utils::copy_to_clipboard(&ah_clone, &output, "ai_replace")
+                            {
+                                error!("Failed to copy AI Replace result: {}", e);
+                            }
+                        }
+                        timer.mark(PipelineStage::Paste);
+                        timer.finish(&ah_clone);
                         utils::hide_recording_overlay(&ah_clone);
                         change_tray_icon(&ah_clone, TrayIconState::Idle);
                     })
                     .ok();
                 }
-                Err(_) => {
+                Err(e) => {
                     // Check if cancelled - if so, skip error reporting
                     if llm_tracker.is_cancelled(operation_id) {
                         debug!(
@@ -1870,7 +2325,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
                         }
                     });
 
-                    emit_ai_replace_error(&ah, "AI replace failed.");
+                    emit_ai_replace_error(&ah, format!("AI replace failed: {}", e));
                     utils::hide_recording_overlay(&ah);
                     change_tray_icon(&ah, TrayIconState::Idle);
                 }
@@ -1996,18 +2451,8 @@ impl ShortcutAction for CycleProfileAction {
     fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
         debug!("CycleProfileAction::start called");
 
-        // Prevent profile switching during active recording or processing
-        // to avoid overlay conflicts and user confusion
-        {
-            let state = app.state::<ManagedSessionState>();
-            let state_guard = state.lock().expect("Failed to lock session state");
-
-            if !matches!(*state_guard, session_manager::SessionState::Idle) {
-                debug!("CycleProfileAction: System busy (recording or processing), ignoring");
-                return;
-            }
-        }
-
+        // Mid-recording switches follow `profile_switch_during_recording`
+        // (set_active_profile applies or rejects them).
         // Call the cycle function directly (it handles overlay and events)
         match crate::shortcut::cycle_to_next_profile(app.clone()) {
             Ok(next_id) => {
@@ -2028,6 +2473,28 @@ impl ShortcutAction for CycleProfileAction {
     }
 }
 
+impl ShortcutAction for TogglePttModeAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Switching mid-recording would change how the running recording stops
+        if !session_manager::is_idle(app) {
+            debug!("TogglePttModeAction: System busy (recording or processing), ignoring");
+            return;
+        }
+
+        if let Err(e) = crate::shortcut::toggle_ptt_mode(app.clone()) {
+            warn!("Failed to toggle push-to-talk mode: {}", e);
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Toggling is instant, nothing to do on stop
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
 // ============================================================================
 // Voice Command Action (Windows only)
 // ============================================================================
@@ -2035,6 +2502,79 @@ impl ShortcutAction for CycleProfileAction {
 #[cfg(target_os = "windows")]
 struct VoiceCommandAction;
 
+/// Runs one voice command directly from a `run_voice_command_<command_id>` binding
+#[cfg(target_os = "windows")]
+struct RunVoiceCommandAction;
+
+#[cfg(target_os = "windows")]
+impl ShortcutAction for RunVoiceCommandAction {
+    fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        let Some(command_id) = binding_id.strip_prefix(crate::shortcut::RUN_VOICE_COMMAND_PREFIX)
+        else {
+            return;
+        };
+
+        let settings = get_settings(app);
+        let Some(command) = settings
+            .voice_commands
+            .iter()
+            .find(|c| c.id == command_id && c.enabled)
+            .cloned()
+        else {
+            emit_voice_command_error(
+                app,
+                format!("Voice command '{}' not found or disabled", command_id),
+            );
+            return;
+        };
+
+        debug!("Running voice command '{}' from shortcut", command.name);
+        let options = command.resolve_execution_options(&settings.voice_command_defaults);
+        let app = app.clone();
+        std::thread::spawn(
+            move || match crate::commands::voice_command::execute_powershell_command(
+                &command.script,
+                &options,
+            ) {
+                Ok(_) => last_errors::clear_error(&app, ErrorFeature::VoiceCommand),
+                Err(e) => {
+                    error!("Voice command '{}' failed: {}", command.name, e);
+                    emit_voice_command_error(&app, e);
+                }
+            },
+        );
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Runs once per press, nothing to do on release
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
+/// Aborts a pending voice command auto-run countdown
+#[cfg(target_os = "windows")]
+struct CancelVoiceCommandCountdownAction;
+
+#[cfg(target_os = "windows")]
+impl ShortcutAction for CancelVoiceCommandCountdownAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        if !crate::voice_command_countdown::cancel(app) {
+            debug!("No voice command countdown to cancel");
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Cancelling is instant, nothing to do on stop
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
 /// Event payload for showing the command confirmation overlay
 #[derive(Clone, serde::Serialize, specta::Type)]
 pub struct CommandConfirmPayload {
@@ -2044,6 +2584,8 @@ pub struct CommandConfirmPayload {
     pub spoken_text: String,
     /// Whether this came from LLM (true) or predefined match (false)
     pub from_llm: bool,
+    /// The LLM's explanation of the command (structured output only)
+    pub explanation: Option<String>,
     // ==================== Execution Options ====================
     /// Silent execution (hidden window, non-interactive)
     pub silent: bool,
@@ -2203,6 +2745,59 @@ fn format_execution_policy(policy: crate::settings::ExecutionPolicy) -> Option<S
     }
 }
 
+/// Score of a phrase against a single voice command (for tuning the matcher)
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct VoiceCommandScore {
+    pub command_id: String,
+    pub command_name: String,
+    pub trigger_phrase: String,
+    pub score: f64,
+    /// Effective threshold (per-command, or the default when unset)
+    pub threshold: f64,
+    /// True if score >= threshold
+    pub matched: bool,
+}
+
+/// Per-command threshold, falling back to the default when unset
+fn effective_threshold(cmd: &crate::settings::VoiceCommand, default_threshold: f64) -> f64 {
+    if cmd.similarity_threshold > 0.0 {
+        cmd.similarity_threshold
+    } else {
+        default_threshold
+    }
+}
+
+/// Scores every phrase against every enabled command.
+/// Rows follow `phrases`; columns follow the enabled commands in order.
+pub fn score_matrix(
+    phrases: &[String],
+    commands: &[crate::settings::VoiceCommand],
+    default_threshold: f64,
+    config: &FuzzyMatchConfig,
+) -> Vec<Vec<VoiceCommandScore>> {
+    phrases
+        .iter()
+        .map(|phrase| {
+            commands
+                .iter()
+                .filter(|c| c.enabled)
+                .map(|cmd| {
+                    let threshold = effective_threshold(cmd, default_threshold);
+                    let score = compute_similarity(phrase, &cmd.trigger_phrase, config);
+                    VoiceCommandScore {
+                        command_id: cmd.id.clone(),
+                        command_name: cmd.name.clone(),
+                        trigger_phrase: cmd.trigger_phrase.clone(),
+                        score,
+                        threshold,
+                        matched: score >= threshold,
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
 /// Finds the best matching predefined command for the given transcription.
 /// Returns (command, similarity_score) if a match above threshold is found.
 pub fn find_matching_command(
@@ -2214,11 +2809,7 @@ pub fn find_matching_command(
     let mut best_match: Option<(crate::settings::VoiceCommand, f64)> = None;
 
     for cmd in commands.iter().filter(|c| c.enabled) {
-        let threshold = if cmd.similarity_threshold > 0.0 {
-            cmd.similarity_threshold
-        } else {
-            default_threshold
-        };
+        let threshold = effective_threshold(cmd, default_threshold);
 
         let score = compute_similarity(transcription, &cmd.trigger_phrase, config);
 
@@ -2238,12 +2829,100 @@ pub fn find_matching_command(
     best_match
 }
 
+/// ID prefixes of the built-in meta commands, which change app state instead of running a script
+const META_PROFILE_PREFIX: &str = "meta_profile:";
+const META_PROMPT_PREFIX: &str = "meta_prompt:";
+
+fn meta_voice_command(
+    id: String,
+    name: &str,
+    trigger_phrase: String,
+) -> crate::settings::VoiceCommand {
+    crate::settings::VoiceCommand {
+        id,
+        name: name.to_string(),
+        trigger_phrase,
+        script: String::new(),
+        // Use the default threshold, like a user command without its own
+        similarity_threshold: 0.0,
+        enabled: true,
+        silent: true,
+        no_profile: false,
+        use_pwsh: false,
+        execution_policy: None,
+        working_directory: None,
+    }
+}
+
+/// Built-in "switch to <profile name>" and "use <prompt name>" commands.
+/// Empty unless `voice_command_meta_commands_enabled` is set.
+pub fn meta_voice_commands(settings: &AppSettings) -> Vec<crate::settings::VoiceCommand> {
+    if !settings.voice_command_meta_commands_enabled {
+        return Vec::new();
+    }
+
+    let profiles = std::iter::once(("default", "Default"))
+        .chain(
+            settings
+                .transcription_profiles
+                .iter()
+                .map(|p| (p.id.as_str(), p.name.as_str())),
+        )
+        .map(|(id, name)| {
+            meta_voice_command(
+                format!("{}{}", META_PROFILE_PREFIX, id),
+                name,
+                format!("switch to {}", name),
+            )
+        });
+    let prompts = settings.post_process_prompts.iter().map(|p| {
+        meta_voice_command(
+            format!("{}{}", META_PROMPT_PREFIX, p.id),
+            &p.name,
+            format!("use {}", p.name),
+        )
+    });
+
+    profiles.chain(prompts).collect()
+}
+
+/// Predefined commands followed by the built-in meta commands (when enabled).
+/// Matching and scoring treat both the same way.
+pub fn voice_command_candidates(settings: &AppSettings) -> Vec<crate::settings::VoiceCommand> {
+    let mut candidates = settings.voice_commands.clone();
+    candidates.extend(meta_voice_commands(settings));
+    candidates
+}
+
+/// Whether a matched command is a built-in meta command rather than a script
+#[cfg(target_os = "windows")]
+pub fn is_meta_voice_command(cmd: &crate::settings::VoiceCommand) -> bool {
+    cmd.id.starts_with(META_PROFILE_PREFIX) || cmd.id.starts_with(META_PROMPT_PREFIX)
+}
+
+/// Runs a matched meta command, returning a description of what changed
+#[cfg(target_os = "windows")]
+pub fn run_meta_voice_command(
+    app: &AppHandle,
+    cmd: &crate::settings::VoiceCommand,
+) -> Result<String, String> {
+    if let Some(profile_id) = cmd.id.strip_prefix(META_PROFILE_PREFIX) {
+        crate::shortcut::set_active_profile(app.clone(), profile_id.to_string())?;
+        Ok(format!("Switched to profile '{}'", cmd.name))
+    } else if let Some(prompt_id) = cmd.id.strip_prefix(META_PROMPT_PREFIX) {
+        crate::shortcut::set_post_process_selected_prompt(app.clone(), prompt_id.to_string())?;
+        Ok(format!("Using prompt '{}'", cmd.name))
+    } else {
+        Err(format!("'{}' is not a meta command", cmd.name))
+    }
+}
+
 /// Generates a PowerShell command using LLM based on user's spoken request
 #[cfg(target_os = "windows")]
 pub async fn generate_command_with_llm(
     app: &AppHandle,
     spoken_text: &str,
-) -> Result<String, String> {
+) -> Result<LlmCommandSuggestion, String> {
     let settings = get_settings(app);
 
     // Use Voice Command specific provider (falls back to post-processing if not set)
@@ -2253,12 +2932,7 @@ pub async fn generate_command_with_llm(
         .ok_or_else(|| "No LLM provider configured for Voice Commands".to_string())?;
 
     // Use Voice Command specific model, fallback to post-processing model
-    let model = settings
-        .voice_command_models
-        .get(&provider.id)
-        .cloned()
-        .or_else(|| settings.post_process_models.get(&provider.id).cloned())
-        .unwrap_or_default();
+    let model = settings.voice_command_model(&provider.id);
 
     if model.trim().is_empty() {
         return Err(format!(
@@ -2267,23 +2941,18 @@ pub async fn generate_command_with_llm(
         ));
     }
 
-    let system_prompt = settings.voice_command_system_prompt.clone();
+    let system_prompt = if settings.voice_command_structured_output {
+        format!(
+            "{}{}",
+            settings.voice_command_system_prompt, STRUCTURED_COMMAND_INSTRUCTIONS
+        )
+    } else {
+        settings.voice_command_system_prompt.clone()
+    };
     let user_prompt = spoken_text.to_string();
 
     // Use Voice Command specific API key, fallback to post-processing key
-    #[cfg(target_os = "windows")]
-    let api_key = crate::secure_keys::get_voice_command_api_key(&provider.id)
-        .filter(|k| !k.is_empty())
-        .unwrap_or_else(|| crate::secure_keys::get_post_process_api_key(&provider.id));
-
-    #[cfg(not(target_os = "windows"))]
-    let api_key = settings
-        .voice_command_api_keys
-        .get(&provider.id)
-        .cloned()
-        .filter(|k| !k.is_empty())
-        .or_else(|| settings.post_process_api_keys.get(&provider.id).cloned())
-        .unwrap_or_default();
+    let api_key = settings.voice_command_api_key(&provider.id);
 
     // Build reasoning config from settings
     let reasoning_config = crate::llm_client::ReasoningConfig::new(
@@ -2291,31 +2960,116 @@ pub async fn generate_command_with_llm(
         settings.voice_command_reasoning_budget,
     );
 
-    match crate::llm_client::send_chat_completion_with_system_and_reasoning(
-        &provider,
-        api_key,
-        &model,
-        system_prompt,
-        user_prompt,
-        reasoning_config,
-    )
-    .await
-    {
-        Ok(Some(content)) => {
-            let trimmed = content.trim();
-            if trimmed == "UNSAFE_REQUEST" {
-                Err("Request was deemed unsafe by the LLM".to_string())
-            } else {
-                Ok(trimmed.to_string())
-            }
-        }
+    let response = if settings.voice_command_structured_output {
+        crate::llm_client::send_chat_completion_json(
+            &provider,
+            api_key,
+            &model,
+            system_prompt,
+            user_prompt,
+            reasoning_config,
+        )
+        .await
+    } else {
+        crate::llm_client::send_chat_completion_with_system_and_reasoning(
+            &provider,
+            api_key,
+            &model,
+            system_prompt,
+            user_prompt,
+            reasoning_config,
+            &[],
+        )
+        .await
+    };
+
+    match response {
+        Ok(Some(content)) => parse_llm_command_response(&content),
         Ok(None) => Err("LLM returned empty response".to_string()),
         Err(e) => Err(format!("LLM request failed: {}", e)),
     }
 }
 
+/// Appended to the voice command system prompt when structured output is enabled
+/// (JSON mode also requires the prompt to mention JSON)
+const STRUCTURED_COMMAND_INSTRUCTIONS: &str = "\n\nRespond with a JSON object only: \
+{\"command\": \"<PowerShell command>\", \"safe\": true, \"explanation\": \"<one short sentence>\"}. \
+If the request is unclear or dangerous, set \"safe\" to false, \"command\" to \"\" and explain why.";
+
+/// Command suggested by the Voice Command LLM fallback
+#[derive(Debug, Clone, PartialEq)]
+pub struct LlmCommandSuggestion {
+    pub command: String,
+    pub safe: bool,
+    pub explanation: Option<String>,
+}
+
+/// Structured reply as sent by the LLM; a missing `safe` flag is treated as unsafe
+#[derive(serde::Deserialize)]
+struct LlmCommandReply {
+    command: String,
+    #[serde(default)]
+    safe: Option<bool>,
+    #[serde(default)]
+    explanation: Option<String>,
+}
+
+/// Parses the LLM reply: structured JSON (`{command, safe, explanation}`, optionally in a
+/// code fence) when present, otherwise the plain one-liner with the `UNSAFE_REQUEST` sentinel.
+pub fn parse_llm_command_response(content: &str) -> Result<LlmCommandSuggestion, String> {
+    let trimmed = content.trim();
+    let unfenced = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|rest| rest.strip_suffix("```"))
+        .map(str::trim)
+        .unwrap_or(trimmed);
+
+    if unfenced.starts_with('{') {
+        if let Ok(reply) = serde_json::from_str::<LlmCommandReply>(unfenced) {
+            let Some(safe) = reply.safe else {
+                return Err("LLM reply did not say whether the command is safe".to_string());
+            };
+            if !safe {
+                return Err(match reply.explanation.as_deref() {
+                    Some(reason) if !reason.trim().is_empty() => {
+                        format!("Request was deemed unsafe by the LLM: {}", reason.trim())
+                    }
+                    _ => "Request was deemed unsafe by the LLM".to_string(),
+                });
+            }
+            let command = reply.command.trim().to_string();
+            if command.is_empty() {
+                return Err("LLM returned an empty command".to_string());
+            }
+            return Ok(LlmCommandSuggestion {
+                command,
+                safe,
+                explanation: reply.explanation,
+            });
+        }
+    }
+
+    if trimmed == "UNSAFE_REQUEST" {
+        return Err("Request was deemed unsafe by the LLM".to_string());
+    }
+    Ok(LlmCommandSuggestion {
+        command: trimmed.to_string(),
+        safe: true,
+        explanation: None,
+    })
+}
+
 fn emit_voice_command_error(app: &AppHandle, message: impl Into<String>) {
-    let _ = app.emit("voice-command-error", message.into());
+    let message = message.into();
+    last_errors::record_error(app, ErrorFeature::VoiceCommand, message.clone());
+    let _ = app.emit("voice-command-error", message);
+}
+
+/// Returns true if the utterance is too short to justify an LLM fallback call.
+/// A minimum of 0 disables the check.
+pub fn is_below_llm_min_words(text: &str, min_words: usize) -> bool {
+    text.split_whitespace().count() < min_words
 }
 
 #[cfg(target_os = "windows")]
@@ -2346,8 +3100,9 @@ impl ShortcutAction for VoiceCommandAction {
         let binding_id = binding_id.to_string();
 
         tauri::async_runtime::spawn(async move {
-            let (transcription, _) =
-                match get_transcription_or_cleanup(&ah, &binding_id, None).await {
+            let _in_flight = crate::transcribing_watchdog::track_processing();
+            let (transcription, _, _) =
+                match get_transcription_or_cleanup(&ah, &binding_id, None, None).await {
                     Some(res) => res,
                     None => {
                         session_manager::exit_processing(&ah);
@@ -2366,10 +3121,10 @@ impl ShortcutAction for VoiceCommandAction {
             let settings = get_settings(&ah);
             let fuzzy_config = FuzzyMatchConfig::from_settings(&settings);
 
-            // Step 1: Try to match against predefined commands
+            // Step 1: Try to match against predefined (and meta) commands
             if let Some((matched_cmd, score)) = find_matching_command(
                 &transcription,
-                &settings.voice_commands,
+                &voice_command_candidates(&settings),
                 settings.voice_command_default_threshold,
                 &fuzzy_config,
             ) {
@@ -2378,6 +3133,18 @@ impl ShortcutAction for VoiceCommandAction {
                     matched_cmd.trigger_phrase, matched_cmd.script, score
                 );
 
+                if is_meta_voice_command(&matched_cmd) {
+                    // Leave Processing first so the profile switch overlay can show
+                    utils::hide_recording_overlay(&ah);
+                    change_tray_icon(&ah, TrayIconState::Idle);
+                    session_manager::exit_processing(&ah);
+                    match run_meta_voice_command(&ah, &matched_cmd) {
+                        Ok(message) => debug!("{}", message),
+                        Err(e) => emit_voice_command_error(&ah, e),
+                    }
+                    return;
+                }
+
                 // Resolve execution options for this command
                 let resolved = matched_cmd.resolve_execution_options(&settings.voice_command_defaults);
 
@@ -2388,6 +3155,7 @@ impl ShortcutAction for VoiceCommandAction {
                         command: matched_cmd.script.clone(),
                         spoken_text: transcription.clone(),
                         from_llm: false,
+                        explanation: None,
                         silent: resolved.silent,
                         no_profile: resolved.no_profile,
                         use_pwsh: resolved.use_pwsh,
@@ -2405,7 +3173,15 @@ impl ShortcutAction for VoiceCommandAction {
             }
 
             // Step 2: No predefined match - try LLM fallback if enabled
-            if settings.voice_command_llm_fallback {
+            if settings.voice_command_llm_fallback
+                && is_below_llm_min_words(&transcription, settings.voice_command_llm_min_words)
+            {
+                debug!(
+                    "No predefined match and utterance '{}' is shorter than {} words, skipping LLM fallback",
+                    transcription, settings.voice_command_llm_min_words
+                );
+                let _ = ah.emit("voice-command-no-match", transcription.clone());
+            } else if settings.voice_command_llm_fallback {
                 debug!(
                     "No predefined match, using LLM fallback for: '{}'",
                     transcription
@@ -2414,8 +3190,8 @@ impl ShortcutAction for VoiceCommandAction {
                 show_thinking_overlay(&ah);
 
                 match generate_command_with_llm(&ah, &transcription).await {
-                    Ok(suggested_command) => {
-                        debug!("LLM suggested command: '{}'", suggested_command);
+                    Ok(suggestion) => {
+                        debug!("LLM suggested command: '{}'", suggestion.command);
 
                         // LLM fallback uses global defaults
                         let resolved = settings.voice_command_defaults.to_resolved_options();
@@ -2424,9 +3200,10 @@ impl ShortcutAction for VoiceCommandAction {
                         crate::overlay::show_command_confirm_overlay(
                             &ah,
                             CommandConfirmPayload {
-                                command: suggested_command,
+                                command: suggestion.command,
                                 spoken_text: transcription,
                                 from_llm: true,
+                                explanation: suggestion.explanation,
                                 silent: resolved.silent,
                                 no_profile: resolved.no_profile,
                                 use_pwsh: resolved.use_pwsh,
@@ -2494,10 +3271,180 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "cycle_profile".to_string(),
         Arc::new(CycleProfileAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "toggle_ptt_mode".to_string(),
+        Arc::new(TogglePttModeAction) as Arc<dyn ShortcutAction>,
+    );
     #[cfg(target_os = "windows")]
     map.insert(
         "voice_command".to_string(),
         Arc::new(VoiceCommandAction) as Arc<dyn ShortcutAction>,
     );
+    #[cfg(target_os = "windows")]
+    map.insert(
+        "run_voice_command".to_string(),
+        Arc::new(RunVoiceCommandAction) as Arc<dyn ShortcutAction>,
+    );
+    #[cfg(target_os = "windows")]
+    map.insert(
+        "cancel_voice_command_countdown".to_string(),
+        Arc::new(CancelVoiceCommandCountdownAction) as Arc<dyn ShortcutAction>,
+    );
     map
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(id: &str, trigger_phrase: &str, threshold: f64) -> crate::settings::VoiceCommand {
+        crate::settings::VoiceCommand {
+            similarity_threshold: threshold,
+            silent: false,
+            ..meta_voice_command(id.to_string(), id, trigger_phrase.to_string())
+        }
+    }
+
+    #[test]
+    fn score_matrix_scores_every_phrase_against_enabled_commands() {
+        let mut mute = command("mute", "mute audio", 0.0);
+        mute.enabled = false;
+        let commands = [
+            command("browser", "open browser", 0.0),
+            mute,
+            command("lock", "lock screen", 0.95),
+        ];
+        let phrases = ["open browser".to_string(), "lock screen".to_string()];
+        let matrix = score_matrix(&phrases, &commands, 0.8, &FuzzyMatchConfig::default());
+
+        assert_eq!(matrix.len(), 2);
+        let ids: Vec<&str> = matrix[0].iter().map(|s| s.command_id.as_str()).collect();
+        assert_eq!(ids, ["browser", "lock"]);
+        assert_eq!(matrix[0][0].threshold, 0.8);
+        assert_eq!(matrix[0][1].threshold, 0.95);
+        assert!(matrix[0][0].matched && !matrix[0][1].matched);
+        assert!(!matrix[1][0].matched && matrix[1][1].matched);
+        assert_eq!(matrix[1][1].score, 1.0);
+    }
+
+    #[test]
+    fn meta_voice_commands_follow_profiles_and_prompts() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.voice_commands = vec![command("lock", "lock screen", 0.0)];
+        settings.post_process_prompts = vec![crate::settings::LLMPrompt {
+            id: "prompt_email".to_string(),
+            name: "Email".to_string(),
+            prompt: String::new(),
+        }];
+        assert!(meta_voice_commands(&settings).is_empty());
+        assert_eq!(voice_command_candidates(&settings).len(), 1);
+
+        settings.voice_command_meta_commands_enabled = true;
+        let candidates = voice_command_candidates(&settings);
+        let triggers: Vec<&str> = candidates
+            .iter()
+            .map(|c| c.trigger_phrase.as_str())
+            .collect();
+        assert_eq!(triggers, ["lock screen", "switch to Default", "use Email"]);
+        assert_eq!(candidates[1].id, "meta_profile:default");
+        assert_eq!(candidates[2].id, "meta_prompt:prompt_email");
+        assert!(candidates[1..].iter().all(|c| c.script.is_empty()));
+    }
+
+    #[test]
+    fn extract_json_field_reads_dotted_paths_from_fenced_json() {
+        let reply = "```json\n{\"result\": {\"text\": \"Hello\", \"words\": 1}}\n```";
+        assert_eq!(
+            extract_json_field(reply.to_string(), Some(" result.text ")),
+            "Hello"
+        );
+        assert_eq!(
+            extract_json_field(reply.to_string(), Some("result.words")),
+            "1"
+        );
+    }
+
+    #[test]
+    fn extract_json_field_falls_back_to_raw_response() {
+        let json = "{\"text\": \"Hello\"}".to_string();
+        assert_eq!(extract_json_field(json.clone(), None), json);
+        assert_eq!(extract_json_field(json.clone(), Some("")), json);
+        assert_eq!(extract_json_field(json.clone(), Some("missing")), json);
+        assert_eq!(
+            extract_json_field("plain text".to_string(), Some("text")),
+            "plain text"
+        );
+    }
+
+    #[test]
+    fn llm_command_response_accepts_fenced_json() {
+        let reply =
+            "```json\n{\"command\": \" Get-Date \", \"safe\": true, \"explanation\": \"Shows the date\"}\n```";
+        assert_eq!(
+            parse_llm_command_response(reply),
+            Ok(LlmCommandSuggestion {
+                command: "Get-Date".to_string(),
+                safe: true,
+                explanation: Some("Shows the date".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn llm_command_response_without_safe_flag_is_rejected() {
+        let reply = "{\"command\": \"Remove-Item -Recurse C:\\\\\"}";
+        assert_eq!(
+            parse_llm_command_response(reply),
+            Err("LLM reply did not say whether the command is safe".to_string())
+        );
+    }
+
+    #[test]
+    fn low_confidence_hint_needs_a_threshold_and_a_low_confidence() {
+        let mut settings = crate::settings::get_default_settings();
+        assert_eq!(low_confidence_hint(&settings, Some(0.1)), None);
+
+        settings.post_process_low_confidence_threshold = Some(0.6);
+        assert_eq!(low_confidence_hint(&settings, None), None);
+        assert_eq!(low_confidence_hint(&settings, Some(0.6)), None);
+        assert_eq!(
+            low_confidence_hint(&settings, Some(0.4)),
+            Some(DEFAULT_LOW_CONFIDENCE_HINT.to_string())
+        );
+
+        settings.post_process_low_confidence_hint = Some("Fix misheard words.".to_string());
+        assert_eq!(
+            low_confidence_hint(&settings, Some(0.4)),
+            Some("Fix misheard words.".to_string())
+        );
+    }
+
+    #[test]
+    fn llm_command_response_rejects_unsafe_json() {
+        let reply = "{\"command\": \"\", \"safe\": false, \"explanation\": \"Deletes files\"}";
+        assert_eq!(
+            parse_llm_command_response(reply),
+            Err("Request was deemed unsafe by the LLM: Deletes files".to_string())
+        );
+        assert_eq!(
+            parse_llm_command_response("{\"command\": \"  \", \"safe\": true}"),
+            Err("LLM returned an empty command".to_string())
+        );
+    }
+
+    #[test]
+    fn llm_command_response_falls_back_to_plain_text() {
+        assert_eq!(
+            parse_llm_command_response(" UNSAFE_REQUEST \n"),
+            Err("Request was deemed unsafe by the LLM".to_string())
+        );
+        assert_eq!(
+            parse_llm_command_response("Start-Process notepad"),
+            Ok(LlmCommandSuggestion {
+                command: "Start-Process notepad".to_string(),
+                safe: true,
+                explanation: None,
+            })
+        );
+    }
+}
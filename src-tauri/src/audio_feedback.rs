@@ -2,7 +2,7 @@ use crate::settings::SoundTheme;
 use crate::settings::{self, AppSettings};
 use cpal::traits::{DeviceTrait, HostTrait};
 use log::{debug, error, warn};
-use rodio::OutputStreamBuilder;
+use rodio::{OutputStream, OutputStreamBuilder};
 use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
@@ -85,45 +85,76 @@ fn play_sound_blocking(app: &AppHandle, path: &Path) {
 fn play_sound_at_path(app: &AppHandle, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     let settings = settings::get_settings(app);
     let volume = settings.audio_feedback_volume;
-    let selected_device = settings.selected_output_device.clone();
-    play_audio_file(path, selected_device, volume)
+    let devices = output_device_chain(
+        settings.selected_output_device.as_deref(),
+        &settings.output_device_fallbacks,
+    );
+    play_audio_file(path, &devices, volume)
+}
+
+/// Devices to try in order: the selected device, then the fallbacks, then the
+/// default device (None). Duplicates and explicit "Default" entries collapse.
+fn output_device_chain(selected: Option<&str>, fallbacks: &[String]) -> Vec<Option<String>> {
+    let mut chain: Vec<Option<String>> = Vec::new();
+    let named = selected
+        .into_iter()
+        .chain(fallbacks.iter().map(String::as_str))
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+    for name in named {
+        let device = (!name.eq_ignore_ascii_case("default")).then(|| name.to_string());
+        if !chain.contains(&device) {
+            chain.push(device);
+        }
+    }
+    if !chain.contains(&None) {
+        chain.push(None);
+    }
+    chain
+}
+
+/// Open an output stream on the named device (None = system default)
+fn open_output_stream(
+    device_name: Option<&str>,
+) -> Result<OutputStream, Box<dyn std::error::Error>> {
+    let stream_builder = match device_name {
+        None => OutputStreamBuilder::from_default_device()?,
+        Some(device_name) => {
+            let host = crate::audio_toolkit::get_cpal_host();
+            let device = host
+                .output_devices()?
+                .find(|device| device.name().is_ok_and(|name| name == device_name))
+                .ok_or_else(|| format!("Device '{}' not found", device_name))?;
+            OutputStreamBuilder::from_device(device)?
+        }
+    };
+    Ok(stream_builder.open_stream()?)
 }
 
 fn play_audio_file(
     path: &std::path::Path,
-    selected_device: Option<String>,
+    devices: &[Option<String>],
     volume: f32,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let stream_builder = if let Some(device_name) = selected_device {
-        if device_name == "Default" {
-            debug!("Using default device");
-            OutputStreamBuilder::from_default_device()?
-        } else {
-            let host = crate::audio_toolkit::get_cpal_host();
-            let devices = host.output_devices()?;
-
-            let mut found_device = None;
-            for device in devices {
-                if device.name()? == device_name {
-                    found_device = Some(device);
-                    break;
-                }
+    let mut last_error = None;
+    let mut stream_handle = None;
+    for device in devices {
+        let label = device.as_deref().unwrap_or("Default");
+        match open_output_stream(device.as_deref()) {
+            Ok(stream) => {
+                debug!("Playing feedback sound on output device '{}'", label);
+                stream_handle = Some(stream);
+                break;
             }
-
-            match found_device {
-                Some(device) => OutputStreamBuilder::from_device(device)?,
-                None => {
-                    warn!("Device '{}' not found, using default device", device_name);
-                    OutputStreamBuilder::from_default_device()?
-                }
+            Err(e) => {
+                warn!("Output device '{}' unavailable, trying next: {}", label, e);
+                last_error = Some(e);
             }
         }
-    } else {
-        debug!("Using default device");
-        OutputStreamBuilder::from_default_device()?
+    }
+    let Some(stream_handle) = stream_handle else {
+        return Err(last_error.unwrap_or_else(|| "No output device available".into()));
     };
-
-    let stream_handle = stream_builder.open_stream()?;
     let mixer = stream_handle.mixer();
 
     let file = File::open(path)?;
@@ -135,3 +166,29 @@ fn play_audio_file(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_device_chain_tries_selected_then_fallbacks_then_default() {
+        let fallbacks = vec![
+            "Speakers".to_string(),
+            "Headphones".to_string(),
+            " ".to_string(),
+        ];
+        assert_eq!(
+            output_device_chain(Some("Headphones"), &fallbacks),
+            vec![
+                Some("Headphones".to_string()),
+                Some("Speakers".to_string()),
+                None
+            ]
+        );
+        assert_eq!(
+            output_device_chain(None, &["Default".to_string(), "Speakers".to_string()]),
+            vec![None, Some("Speakers".to_string())]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::settings::CustomWord;
 use natural::phonetics::soundex;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -13,17 +14,19 @@ use strsim::levenshtein;
 /// # Arguments
 /// * `text` - The input text to correct
 /// * `custom_words` - List of custom words to match against
-/// * `threshold` - Maximum similarity score to accept (0.0 = exact match, 1.0 = any match)
+/// * `threshold` - Maximum similarity score to accept (0.0 = exact match, 1.0 = any match),
+///   used for words without their own `threshold`
 ///
 /// # Returns
 /// The corrected text with custom words applied
-pub fn apply_custom_words(text: &str, custom_words: &[String], threshold: f64) -> String {
+pub fn apply_custom_words(text: &str, custom_words: &[CustomWord], threshold: f64) -> String {
     if custom_words.is_empty() {
         return text.to_string();
     }
 
     // Pre-compute lowercase versions to avoid repeated allocations
-    let custom_words_lower: Vec<String> = custom_words.iter().map(|w| w.to_lowercase()).collect();
+    let custom_words_lower: Vec<String> =
+        custom_words.iter().map(|w| w.word.to_lowercase()).collect();
 
     let words: Vec<&str> = text.split_whitespace().collect();
     let mut corrected_words = Vec::new();
@@ -73,9 +76,10 @@ pub fn apply_custom_words(text: &str, custom_words: &[String], threshold: f64) -
                 levenshtein_score
             };
 
-            // Accept if the score is good enough (configurable threshold)
-            if combined_score < threshold && combined_score < best_score {
-                best_match = Some(&custom_words[i]);
+            // Accept if the score is good enough (per-word threshold, else global)
+            let word_threshold = custom_words[i].threshold.unwrap_or(threshold);
+            if combined_score < word_threshold && combined_score < best_score {
+                best_match = Some(&custom_words[i].word);
                 best_score = combined_score;
             }
         }
@@ -249,7 +253,7 @@ mod tests {
     #[test]
     fn test_apply_custom_words_exact_match() {
         let text = "hello world";
-        let custom_words = vec!["Hello".to_string(), "World".to_string()];
+        let custom_words = vec![CustomWord::new("Hello"), CustomWord::new("World")];
         let result = apply_custom_words(text, &custom_words, 0.5);
         assert_eq!(result, "Hello World");
     }
@@ -257,11 +261,25 @@ mod tests {
     #[test]
     fn test_apply_custom_words_fuzzy_match() {
         let text = "helo wrold";
-        let custom_words = vec!["hello".to_string(), "world".to_string()];
+        let custom_words = vec![CustomWord::new("hello"), CustomWord::new("world")];
         let result = apply_custom_words(text, &custom_words, 0.5);
         assert_eq!(result, "hello world");
     }
 
+    #[test]
+    fn test_apply_custom_words_per_word_threshold() {
+        let text = "helo wrold";
+        let custom_words = vec![
+            CustomWord::new("hello"),
+            CustomWord {
+                word: "world".to_string(),
+                threshold: Some(0.1),
+            },
+        ];
+        let result = apply_custom_words(text, &custom_words, 0.5);
+        assert_eq!(result, "hello wrold");
+    }
+
     #[test]
     fn test_preserve_case_pattern() {
         assert_eq!(preserve_case_pattern("HELLO", "world"), "WORLD");
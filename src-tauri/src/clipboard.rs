@@ -1,8 +1,9 @@
+use crate::foreground_app::FocusTarget;
 use crate::input::{self, EnigoState};
-use crate::settings::{get_settings, ClipboardHandling, PasteMethod};
+use crate::settings::{get_settings, AppSettings, ClipboardHandling, PasteMethod, TextReplacement};
 use enigo::Enigo;
-use log::{info, warn};
-use tauri::{AppHandle, Manager};
+use log::{debug, info, warn};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
 #[cfg(target_os = "linux")]
@@ -555,23 +556,71 @@ fn paste_direct(enigo: &mut Enigo, text: &str) -> Result<(), String> {
     input::paste_text_direct(enigo, text)
 }
 
-pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
-    let settings = get_settings(&app_handle);
-    let paste_method = settings.paste_method;
-    let clipboard_handling = settings.clipboard_handling;
+/// The text as `paste` inserts it: with the per-app suffix or the trailing space
+fn with_paste_suffix(settings: &AppSettings, text: String) -> String {
+    // Per-app suffix (e.g., "\n" for chat apps) takes precedence over the trailing space
+    let app_suffix = if settings.paste_suffix_by_app.is_empty() {
+        None
+    } else {
+        crate::foreground_app::get_foreground_app().and_then(|fg| {
+            crate::foreground_app::resolve_app_suffix(&fg, &settings.paste_suffix_by_app).map(
+                |suffix| {
+                    info!(
+                        "Using paste suffix {:?} for foreground app {:?}",
+                        suffix, fg
+                    );
+                    TextReplacement::process_escapes(suffix)
+                },
+            )
+        })
+    };
 
-    // Append trailing space if setting is enabled
-    let text = if settings.append_trailing_space {
+    if let Some(suffix) = app_suffix {
+        format!("{}{}", text, suffix)
+    } else if settings.append_trailing_space {
         format!("{} ", text)
     } else {
         text
-    };
+    }
+}
+
+pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
+    paste_to(text, None, app_handle)
+}
+
+/// Like `paste`, but first hands focus back to `target` (the window that had it when
+/// the recording started) if one of our own windows took it in the meantime.
+pub fn paste_to(
+    text: String,
+    target: Option<FocusTarget>,
+    app_handle: AppHandle,
+) -> Result<(), String> {
+    let settings = get_settings(&app_handle);
+    let paste_method = settings.paste_method;
+    let clipboard_handling = settings.clipboard_handling;
+    let text = with_paste_suffix(&settings, text);
 
     info!(
         "Using paste method: {:?}, clipboard handling: {:?}",
         paste_method, clipboard_handling
     );
 
+    if let Some(target) = target.filter(|_| paste_method != PasteMethod::None) {
+        let own_window_focused = app_handle
+            .webview_windows()
+            .values()
+            .any(|window| window.is_focused().unwrap_or(false));
+        if own_window_focused {
+            let restored = crate::foreground_app::restore_focus_target(target);
+            info!("Restoring focus to paste target {:?}: {}", target, restored);
+        }
+    }
+
+    // Give the target app time to regain focus after the shortcut release
+    if paste_method != PasteMethod::None && settings.paste_delay_ms > 0 {
+        std::thread::sleep(std::time::Duration::from_millis(settings.paste_delay_ms));
+    }
+
     // Get the managed Enigo instance
     let enigo_state = app_handle
         .try_state::<EnigoState>()
@@ -582,13 +631,12 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
 
     // Perform the paste operation
-    match paste_method {
+    let paste_result = match paste_method {
         PasteMethod::None => {
             info!("PasteMethod::None selected - skipping paste action");
+            Ok(())
         }
-        PasteMethod::Direct => {
-            paste_direct(&mut enigo, &text)?;
-        }
+        PasteMethod::Direct => paste_direct(&mut enigo, &text),
         PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert => {
             paste_via_clipboard(
                 &mut enigo,
@@ -597,22 +645,101 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
                 &paste_method,
                 settings.convert_lf_to_crlf,
                 clipboard_handling,
-            )?
+            )
         }
-    }
+    };
 
     // After pasting, optionally copy to clipboard based on settings
-    // (only if CopyToClipboard mode, which means we intentionally want to keep the transcription)
-    if clipboard_handling == ClipboardHandling::CopyToClipboard {
+    // (CopyToClipboard keeps the transcription after a successful paste;
+    // always_copy_result keeps it as a backup even when the paste failed)
+    let keep_on_clipboard = settings.always_copy_result
+        || (paste_result.is_ok() && clipboard_handling == ClipboardHandling::CopyToClipboard);
+    if keep_on_clipboard {
         let clipboard = app_handle.clipboard();
-        clipboard
+        let copy_result = clipboard
             .write_text(&text)
-            .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
+            .map_err(|e| format!("Failed to copy to clipboard: {}", e));
+        paste_result?;
+        copy_result?;
+    } else {
+        paste_result?;
     }
 
     Ok(())
 }
 
+/// Shift+Left presses needed to select `text` back after pasting it. A line break is one
+/// caret step whether pasted as LF or CRLF, and a character outside the BMP is one step
+/// even though it is two UTF-16 units.
+fn caret_steps(text: &str) -> usize {
+    text.replace("\r\n", "\n").chars().count()
+}
+
+/// Replaces text that `paste` just inserted: selects it back from the caret and pastes
+/// `replacement` over it. Best-effort - if the caret moved since the first paste, the
+/// wrong range is replaced.
+pub fn replace_pasted(
+    app_handle: AppHandle,
+    pasted: String,
+    replacement: String,
+) -> Result<(), String> {
+    let settings = get_settings(&app_handle);
+    let count = caret_steps(&with_paste_suffix(&settings, pasted));
+    {
+        let enigo_state = app_handle
+            .try_state::<EnigoState>()
+            .ok_or("Enigo state not initialized")?;
+        let mut enigo = enigo_state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
+        input::send_select_back(&mut enigo, count)?;
+    }
+    debug!("Selected back {} pasted characters for replacement", count);
+    paste(replacement, app_handle)
+}
+
+/// Puts `text` on the clipboard (without pasting) and emits `copied-to-clipboard`
+pub fn copy_to_clipboard(app_handle: &AppHandle, text: &str, source: &str) -> Result<(), String> {
+    app_handle
+        .clipboard()
+        .write_text(text)
+        .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
+    let _ = app_handle.emit(
+        "copied-to-clipboard",
+        serde_json::json!({ "source": source, "chars": text.chars().count() }),
+    );
+    Ok(())
+}
+
+/// Reads the clipboard after a cut/copy keystroke. Empty or failed reads are retried
+/// (`selection_capture_retries` times, `selection_capture_retry_delay_ms` apart) since
+/// the copy often lands late in slow apps. Keystroke failures are not retried - callers
+/// return those before getting here.
+fn read_captured_selection(app_handle: &AppHandle) -> Result<String, String> {
+    let settings = get_settings(app_handle);
+    let clipboard = app_handle.clipboard();
+    let delay = std::time::Duration::from_millis(settings.selection_capture_retry_delay_ms);
+
+    let mut attempt = 0;
+    loop {
+        let result = clipboard
+            .read_text()
+            .map_err(|e| format!("Failed to read clipboard: {}", e));
+        let empty = result.as_ref().map_or(true, |text| text.is_empty());
+        if !empty || attempt >= settings.selection_capture_retries {
+            return result;
+        }
+
+        attempt += 1;
+        debug!(
+            "Selection capture read nothing, retrying ({}/{})",
+            attempt, settings.selection_capture_retries
+        );
+        std::thread::sleep(delay);
+    }
+}
+
 pub fn capture_selection_text(app_handle: &AppHandle) -> Result<String, String> {
     let clipboard = app_handle.clipboard();
     let clipboard_backup = clipboard.read_text().unwrap_or_default();
@@ -632,9 +759,7 @@ pub fn capture_selection_text(app_handle: &AppHandle) -> Result<String, String>
         input::send_cut_ctrl_x(&mut enigo)?;
         std::thread::sleep(std::time::Duration::from_millis(80));
 
-        clipboard
-            .read_text()
-            .map_err(|e| format!("Failed to read clipboard: {}", e))
+        read_captured_selection(app_handle)
     })();
 
     if let Err(err) = clipboard.write_text(&clipboard_backup) {
@@ -666,9 +791,7 @@ pub fn capture_selection_text_copy(app_handle: &AppHandle) -> Result<String, Str
         input::send_copy_ctrl_c(&mut enigo)?;
         std::thread::sleep(std::time::Duration::from_millis(80));
 
-        clipboard
-            .read_text()
-            .map_err(|e| format!("Failed to read clipboard: {}", e))
+        read_captured_selection(app_handle)
     })();
 
     if let Err(err) = clipboard.write_text(&clipboard_backup) {
@@ -680,3 +803,21 @@ pub fn capture_selection_text_copy(app_handle: &AppHandle) -> Result<String, Str
 
     capture_result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    #[test]
+    fn caret_steps_count_suffix_crlf_and_astral_chars_once() {
+        let mut settings = get_default_settings();
+        settings.paste_suffix_by_app.clear();
+        settings.append_trailing_space = true;
+        let pasted = with_paste_suffix(&settings, "ok\r\n\u{1F600}".to_string());
+        // "o", "k", line break, emoji, trailing space
+        assert_eq!(caret_steps(&pasted), 5);
+        assert_eq!(caret_steps("a\nb"), 3);
+        assert_eq!(caret_steps(""), 0);
+    }
+}
@@ -1,11 +1,15 @@
 use crate::audio_feedback;
 use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
-use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
+use crate::managers::audio::{
+    set_audio_meter_hz, AudioRecordingManager, MicTestResult, MicrophoneMode, AUDIO_METER_MAX_HZ,
+    AUDIO_METER_MIN_HZ, SPEECH_ENERGY_MAX_DBFS, SPEECH_ENERGY_MIN_DBFS,
+};
 use crate::settings::{get_settings, write_settings};
 use log::warn;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
 
 #[derive(Serialize, Type)]
@@ -32,7 +36,7 @@ pub fn check_custom_sounds(app: AppHandle) -> CustomSounds {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
 pub struct AudioDevice {
     pub index: String,
     pub name: String,
@@ -106,6 +110,24 @@ pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<()
     Ok(())
 }
 
+/// Capture from a microphone for `duration_ms` (clamped to 100-10000 ms) and report
+/// peak/RMS levels and clipping. Nothing is saved or transcribed.
+#[tauri::command]
+#[specta::specta]
+pub async fn test_microphone(
+    app: AppHandle,
+    device_id: Option<String>,
+    duration_ms: u32,
+) -> Result<MicTestResult, String> {
+    let rm = Arc::clone(&app.state::<Arc<AudioRecordingManager>>());
+    let duration = Duration::from_millis(duration_ms.clamp(100, 10_000) as u64);
+
+    tauri::async_runtime::spawn_blocking(move || rm.test_microphone(device_id, duration))
+        .await
+        .map_err(|e| format!("Microphone test task failed: {}", e))?
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_selected_microphone(app: AppHandle) -> Result<String, String> {
@@ -158,6 +180,23 @@ pub fn get_selected_output_device(app: AppHandle) -> Result<String, String> {
         .unwrap_or_else(|| "default".to_string()))
 }
 
+/// Set the ordered output devices tried when the selected one can't be opened
+#[tauri::command]
+#[specta::specta]
+pub fn set_output_device_fallbacks(
+    app: AppHandle,
+    device_names: Vec<String>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.output_device_fallbacks = device_names
+        .into_iter()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn play_test_sound(app: AppHandle, sound_type: String) {
@@ -214,3 +253,34 @@ pub fn change_vad_threshold_setting(app: AppHandle, threshold: f32) -> Result<()
 
     Ok(())
 }
+
+/// Skip transcription for recordings without speech, with the energy threshold (dBFS)
+/// a 30 ms window must reach to count as speech
+#[tauri::command]
+#[specta::specta]
+pub fn change_non_speech_gate_setting(
+    app: AppHandle,
+    enabled: bool,
+    threshold_dbfs: f32,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.skip_non_speech_recordings = enabled;
+    settings.speech_energy_threshold_dbfs =
+        threshold_dbfs.clamp(SPEECH_ENERGY_MIN_DBFS, SPEECH_ENERGY_MAX_DBFS);
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_audio_meter_hz_setting(app: AppHandle, hz: u32) -> Result<(), String> {
+    let hz = hz.clamp(AUDIO_METER_MIN_HZ, AUDIO_METER_MAX_HZ);
+    let mut settings = get_settings(&app);
+    settings.audio_meter_hz = hz;
+    write_settings(&app, settings);
+
+    // Applies to the running level callback without reopening the microphone
+    set_audio_meter_hz(hz);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@
 //!
 //! Commands to control and query the connector server status.
 
-use crate::managers::connector::{ConnectorManager, ConnectorStatus};
+use crate::managers::connector::{ConnectorDiagnosis, ConnectorManager, ConnectorStatus};
 use std::sync::Arc;
 use tauri::State;
 
@@ -13,6 +13,13 @@ pub fn connector_get_status(manager: State<Arc<ConnectorManager>>) -> ConnectorS
     manager.get_status()
 }
 
+/// Run the connector health checks (server, port, password, extension, handshake)
+#[tauri::command]
+#[specta::specta]
+pub fn diagnose_connector(manager: State<Arc<ConnectorManager>>) -> ConnectorDiagnosis {
+    manager.diagnose()
+}
+
 /// Check if extension is currently online
 #[tauri::command]
 #[specta::specta]
@@ -55,3 +62,13 @@ pub fn connector_cancel_message(
 ) -> Result<bool, String> {
     manager.cancel_queued_message(&message_id)
 }
+
+/// Ack a message as if the extension had received it (testing / lost-ack recovery)
+#[tauri::command]
+#[specta::specta]
+pub fn ack_connector_message(
+    manager: State<Arc<ConnectorManager>>,
+    id: String,
+) -> Result<(), String> {
+    manager.ack_message(&id)
+}
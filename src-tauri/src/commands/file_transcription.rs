@@ -224,11 +224,21 @@ pub async fn transcribe_audio_file(
                         &settings.selected_model,
                     ),
                     apply_custom_words_enabled,
+                    settings.filler_word_filter_enabled,
                 )
+                .map(|(text, details)| (text, details.segments))
                 .map_err(|e| format!("Local transcription failed: {}", e))
             } else {
-                tm.transcribe_with_segments(samples, None, None, None, apply_custom_words_enabled)
-                    .map_err(|e| format!("Local transcription failed: {}", e))
+                tm.transcribe_with_segments(
+                    samples,
+                    None,
+                    None,
+                    None,
+                    apply_custom_words_enabled,
+                    settings.filler_word_filter_enabled,
+                )
+                .map(|(text, details)| (text, details.segments))
+                .map_err(|e| format!("Local transcription failed: {}", e))
             }
         } else {
             // Use the standard method for plain text
@@ -243,6 +253,7 @@ pub async fn transcribe_audio_file(
                         &settings.selected_model,
                     ),
                     apply_custom_words_enabled,
+                    settings.filler_word_filter_enabled,
                 )
                 .map_err(|e| format!("Local transcription failed: {}", e))
             } else {
@@ -391,7 +402,7 @@ fn decode_audio_file(path: &PathBuf) -> Result<Vec<f32>, String> {
 }
 
 /// Decode a WAV file directly using hound
-fn decode_wav_file(path: &PathBuf) -> Result<Vec<f32>, String> {
+pub(crate) fn decode_wav_file(path: &PathBuf) -> Result<Vec<f32>, String> {
     let reader =
         hound::WavReader::open(path).map_err(|e| format!("Failed to open WAV file: {}", e))?;
 
@@ -1,7 +1,15 @@
+use crate::audio_toolkit::apply_custom_words;
 use crate::managers::history::{HistoryEntry, HistoryManager};
+use crate::settings::{apply_text_replacements_by_phase, get_settings};
+use crate::subtitle::{segments_to_srt, segments_to_vtt, OutputFormat};
+use serde::Serialize;
+use specta::Type;
 use std::sync::Arc;
 use tauri::{AppHandle, State};
 
+/// Most history entries `simulate_rules_on_history` looks at
+const MAX_SIMULATED_ENTRIES: u32 = 500;
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_history_entries(
@@ -110,3 +118,85 @@ pub async fn get_latest_history_entry(
         .get_latest_entry()
         .map_err(|e| e.to_string())
 }
+
+/// How the current rules would change one history entry
+#[derive(Serialize, Type)]
+pub struct RuleDiff {
+    pub id: i64,
+    pub timestamp: i64,
+    pub before: String,
+    pub after: String,
+}
+
+/// Run the current custom words and text replacements over the raw text of the most
+/// recent `limit` transcriptions (max 500) and return the entries they would change.
+/// Rules apply even while their global toggle is off, so they can be checked before
+/// enabling. Read-only: history is not modified.
+#[tauri::command]
+#[specta::specta]
+pub async fn simulate_rules_on_history(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    limit: u32,
+) -> Result<Vec<RuleDiff>, String> {
+    let settings = get_settings(&app);
+    let entries = history_manager
+        .get_history_entries()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(entries
+        .into_iter()
+        .filter(|entry| entry.action_type == "transcribe")
+        .take(limit.min(MAX_SIMULATED_ENTRIES) as usize)
+        .filter_map(|entry| {
+            let mut after = entry.transcription_text.clone();
+            if !settings.custom_words.is_empty() {
+                after = apply_custom_words(
+                    &after,
+                    &settings.custom_words,
+                    settings.word_correction_threshold,
+                );
+            }
+            after = apply_text_replacements_by_phase(
+                &after,
+                &settings.text_replacements,
+                &settings.text_replacement_groups_enabled,
+                settings.text_replacements_before_llm,
+            );
+            (after != entry.transcription_text).then(|| RuleDiff {
+                id: entry.id,
+                timestamp: entry.timestamp,
+                before: entry.transcription_text,
+                after,
+            })
+        })
+        .collect())
+}
+
+/// Export a history entry's stored segment timings as SRT or VTT subtitle text.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_transcription_subtitles(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    format: OutputFormat,
+) -> Result<String, String> {
+    let segments = history_manager
+        .get_entry_segments(entry_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| {
+            "This recording has no stored segment timings. Enable \"Store segment timings\" in history settings before recording to export subtitles.".to_string()
+        })?;
+
+    if segments.is_empty() {
+        return Err("This recording has no segments to export".to_string());
+    }
+
+    match format {
+        OutputFormat::Srt => Ok(segments_to_srt(&segments)),
+        OutputFormat::Vtt => Ok(segments_to_vtt(&segments)),
+        OutputFormat::Text => Err("Subtitle export requires SRT or VTT format".to_string()),
+    }
+}
@@ -6,11 +6,15 @@ pub mod key_listener;
 pub mod models;
 pub mod region_capture;
 pub mod remote_stt;
+pub mod scratchpad;
 pub mod transcription;
 pub mod voice_command;
 
 use crate::settings::{get_settings, write_settings, AppSettings, LogLevel};
 use crate::utils::cancel_current_operation;
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_opener::OpenerExt;
 
@@ -122,6 +126,109 @@ pub fn open_app_data_dir(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Language codes for the language selector, preferred languages first.
+/// "auto" is always the first entry.
+#[specta::specta]
+#[tauri::command]
+pub fn get_language_options(app: AppHandle) -> Result<Vec<String>, String> {
+    let settings = get_settings(&app);
+    Ok(crate::language_compat::language_options(
+        &settings.preferred_languages,
+        settings.preferred_languages_only,
+    ))
+}
+
+/// Monitor names for the overlay monitor selector, in `MonitorIndex` order
+#[specta::specta]
+#[tauri::command]
+pub fn get_overlay_monitors(app: AppHandle) -> Vec<String> {
+    crate::overlay::monitor_names(&app)
+}
+
+/// Dismiss the error overlay (clicked by the user)
+#[specta::specta]
+#[tauri::command]
+pub fn dismiss_error_overlay(app: AppHandle) {
+    crate::plus_overlay_state::dismiss_error_overlay(&app);
+}
+
+/// Cleanly restart the app (for settings that can't be applied without a relaunch).
+/// Cancels any active session, unmutes, hides overlays, stops the connector server
+/// and flushes settings before relaunching.
+#[specta::specta]
+#[tauri::command]
+pub fn restart_app(app: AppHandle) {
+    log::info!("Restart requested from the UI");
+
+    // Dropping the session removes mute and hides the overlay; repeat explicitly
+    // so a relaunch can never leave the system muted.
+    cancel_current_operation(&app);
+    app.state::<std::sync::Arc<crate::managers::audio::AudioRecordingManager>>()
+        .remove_mute();
+    crate::utils::hide_recording_overlay_immediately(&app);
+
+    app.state::<std::sync::Arc<crate::managers::connector::ConnectorManager>>()
+        .stop_server();
+
+    // History entries are committed to SQLite as they are saved; settings go through
+    // the store and are flushed to disk here.
+    write_settings(&app, get_settings(&app));
+
+    app.restart();
+}
+
+/// Aggregated transcription pipeline timings (record → stop → transcribe → post-process → paste)
+/// since app launch
+#[specta::specta]
+#[tauri::command]
+pub fn get_pipeline_timing_stats() -> crate::pipeline_timing::PipelineTimingStats {
+    crate::pipeline_timing::session_stats()
+}
+
+/// The application that currently has focus (process name, window title, executable
+/// path), or None where the platform can't tell. Called from the settings window this
+/// is usually AivoRelay itself; it is meant for polling while dictating.
+#[specta::specta]
+#[tauri::command]
+pub fn get_foreground_app() -> Option<crate::foreground_app::ForegroundApp> {
+    crate::foreground_app::get_foreground_app()
+}
+
+/// Most recent failure per feature (transcription, post_process, ai_replace, connector,
+/// screenshot, voice_command). Entries are cleared when the feature next succeeds.
+#[specta::specta]
+#[tauri::command]
+pub fn get_last_errors() -> HashMap<String, crate::last_errors::LastError> {
+    crate::last_errors::last_errors()
+}
+
+/// Which provider, model and base URL an LLM feature will use, whether a key is present,
+/// and the fallback path taken. The API key itself is never returned.
+#[specta::specta]
+#[tauri::command]
+pub fn resolve_llm_config(
+    app: AppHandle,
+    feature: crate::settings::LlmFeature,
+) -> Result<crate::settings::LlmConfigDebug, String> {
+    get_settings(&app)
+        .llm_config_debug(feature)
+        .ok_or_else(|| "No provider configured for this feature".to_string())
+}
+
+/// Runs the AI Replace LLM request with the given selection and instruction and returns
+/// the output. Skips selection capture and pasting, so prompts and provider config can be
+/// verified in isolation.
+#[specta::specta]
+#[tauri::command]
+pub async fn test_ai_replace(
+    app: AppHandle,
+    selection: String,
+    instruction: String,
+) -> Result<String, String> {
+    let settings = crate::settings::get_settings(&app);
+    crate::actions::ai_replace_with_llm(&app, &settings, &selection, &instruction).await
+}
+
 /// Check if Apple Intelligence is available on this device.
 /// Called by the frontend when the user selects Apple Intelligence provider.
 #[specta::specta]
@@ -136,3 +243,53 @@ pub fn check_apple_intelligence_available() -> bool {
         false
     }
 }
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AppleIntelligenceState {
+    /// Usable for post-processing
+    Available,
+    /// Supported hardware, but the model isn't ready (disabled, downloading, or OS too old)
+    Unavailable,
+    /// Not an Apple Silicon Mac
+    Unsupported,
+}
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct AppleIntelligenceStatus {
+    pub state: AppleIntelligenceState,
+    /// Why Apple Intelligence can't be used (None when available)
+    pub reason: Option<String>,
+}
+
+/// Apple Intelligence availability with a reason, for the settings UI.
+/// Only queries the system model (never runs it), so it is safe to call any time
+/// after startup; post-processing keeps its own lazy check.
+#[specta::specta]
+#[tauri::command]
+pub fn check_apple_intelligence() -> AppleIntelligenceStatus {
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    {
+        if crate::apple_intelligence::check_apple_intelligence_availability() {
+            AppleIntelligenceStatus {
+                state: AppleIntelligenceState::Available,
+                reason: None,
+            }
+        } else {
+            AppleIntelligenceStatus {
+                state: AppleIntelligenceState::Unavailable,
+                reason: Some(
+                    "Apple Intelligence is off, still downloading, or needs macOS 26 or later"
+                        .to_string(),
+                ),
+            }
+        }
+    }
+    #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+    {
+        AppleIntelligenceStatus {
+            state: AppleIntelligenceState::Unsupported,
+            reason: Some("Apple Intelligence requires a Mac with Apple Silicon".to_string()),
+        }
+    }
+}
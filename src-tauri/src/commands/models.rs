@@ -1,8 +1,43 @@
 use crate::managers::model::{ModelInfo, ModelManager};
 use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{get_settings, write_settings};
-use std::sync::Arc;
-use tauri::{AppHandle, State};
+use crate::settings::{get_settings, write_settings, TranscriptionProvider};
+use log::info;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tauri::{AppHandle, Manager, State};
+
+const BENCHMARK_SAMPLE_RATE: usize = 16000;
+/// Bundled speech clip every benchmark runs on, so results are comparable across models
+const BENCHMARK_SAMPLE: &str = "resources/benchmark_sample.wav";
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct BenchmarkResult {
+    pub model_id: String,
+    pub audio_secs: f32,
+    /// 0 when the model was already loaded
+    pub load_ms: u64,
+    pub inference_ms: u64,
+    /// Inference time / audio duration (lower is faster; < 1.0 is faster than real time)
+    pub real_time_factor: f32,
+    /// Expected backend (see `get_acceleration_status`)
+    pub accelerator: String,
+    pub was_loaded: bool,
+    pub cached: bool,
+}
+
+/// Benchmark results for this machine, keyed by "model_id:sample_secs"
+static BENCHMARK_CACHE: Lazy<Mutex<HashMap<String, BenchmarkResult>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Loop or trim a clip to exactly `secs` seconds at 16kHz.
+fn fit_clip(clip: &[f32], secs: u32) -> Vec<f32> {
+    let target = secs as usize * BENCHMARK_SAMPLE_RATE;
+    clip.iter().copied().cycle().take(target).collect()
+}
 
 #[tauri::command]
 #[specta::specta]
@@ -74,6 +109,41 @@ pub async fn set_active_model(
     Ok(())
 }
 
+/// Map a language to a specific local model. Pass `None` to remove the mapping
+/// so the language falls back to the selected model.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_model_for_language(
+    app_handle: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+    language: String,
+    model_id: Option<String>,
+) -> Result<(), String> {
+    let language = language.trim().to_string();
+    if language.is_empty() || language == "auto" {
+        return Err("A specific language is required for a model mapping".to_string());
+    }
+
+    let mut settings = get_settings(&app_handle);
+    match model_id.filter(|id| !id.is_empty()) {
+        Some(model_id) => {
+            let model_info = model_manager
+                .get_model_info(&model_id)
+                .ok_or_else(|| format!("Model not found: {}", model_id))?;
+            if !model_info.is_downloaded {
+                return Err(format!("Model not downloaded: {}", model_id));
+            }
+            settings.models_by_language.insert(language, model_id);
+        }
+        None => {
+            settings.models_by_language.remove(&language);
+        }
+    }
+    write_settings(&app_handle, settings);
+
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_current_model(app_handle: AppHandle) -> Result<String, String> {
@@ -135,3 +205,139 @@ pub async fn get_recommended_first_model() -> Result<String, String> {
     // Recommend Parakeet V3 model for first-time users - fastest and most accurate
     Ok("parakeet-tdt-0.6b-v3".to_string())
 }
+
+/// Advisory check: returns a warning when the model is known not to support the language.
+/// Remote models are judged by name hints; local models by their declared languages.
+#[tauri::command]
+#[specta::specta]
+pub async fn check_language_model_compatibility(
+    app_handle: AppHandle,
+    language: String,
+    provider: TranscriptionProvider,
+    model: String,
+) -> Result<Option<String>, String> {
+    Ok(crate::language_compat::check_language_model(
+        &app_handle,
+        &language,
+        provider,
+        &model,
+    ))
+}
+
+/// Measure load time, inference time and real-time factor for a local model.
+/// The clip is the bundled benchmark sample (looped/trimmed to `sample_secs`,
+/// clamped to 5-120s). Refused while recording or transcribing; new recordings are
+/// blocked until it finishes and the previously active model has been restored.
+#[tauri::command]
+#[specta::specta]
+pub async fn benchmark_model(
+    app_handle: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    model_id: String,
+    sample_secs: u32,
+    refresh: Option<bool>,
+) -> Result<BenchmarkResult, String> {
+    let sample_secs = sample_secs.clamp(5, 120);
+    let cache_key = format!("{}:{}", model_id, sample_secs);
+    if !refresh.unwrap_or(false) {
+        if let Some(cached) = BENCHMARK_CACHE.lock().unwrap().get(&cache_key) {
+            return Ok(BenchmarkResult {
+                cached: true,
+                ..cached.clone()
+            });
+        }
+    }
+
+    let model_info = model_manager
+        .get_model_info(&model_id)
+        .ok_or_else(|| format!("Model not found: {}", model_id))?;
+    if !model_info.is_downloaded {
+        return Err(format!("Model not downloaded: {}", model_id));
+    }
+
+    let clip_path = app_handle
+        .path()
+        .resolve(BENCHMARK_SAMPLE, tauri::path::BaseDirectory::Resource)
+        .map_err(|e| format!("Failed to resolve benchmark clip: {}", e))?;
+    let clip = crate::commands::file_transcription::decode_wav_file(&clip_path)?;
+    if clip.is_empty() {
+        return Err("Benchmark clip contains no audio".to_string());
+    }
+    let samples = fit_clip(&clip, sample_secs);
+
+    if !crate::session_manager::try_enter_processing(&app_handle, "benchmark") {
+        return Err("Can't benchmark while recording or transcribing".to_string());
+    }
+
+    let tm = Arc::clone(&transcription_manager);
+    let accelerator = crate::acceleration::backend_for(
+        &model_info.engine_type,
+        get_settings(&app_handle).acceleration_preference,
+    );
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let previous_model = tm
+            .is_model_loaded()
+            .then(|| tm.get_current_model())
+            .flatten();
+        let was_loaded = previous_model.as_deref() == Some(model_id.as_str());
+
+        let timings = (|| {
+            let load_start = Instant::now();
+            tm.ensure_model_loaded(&model_id)?;
+            let load_ms = if was_loaded {
+                0
+            } else {
+                load_start.elapsed().as_millis() as u64
+            };
+
+            let inference_start = Instant::now();
+            tm.transcribe(samples, false)?;
+            let inference_ms = inference_start.elapsed().as_millis() as u64;
+            Ok::<_, anyhow::Error>((load_ms, inference_ms))
+        })();
+
+        // Put the user's model back (or unload) before recordings are allowed again
+        match previous_model {
+            Some(previous) if previous != model_id => {
+                if let Err(e) = tm.ensure_model_loaded(&previous) {
+                    log::warn!(
+                        "Failed to restore model {} after benchmark: {}",
+                        previous,
+                        e
+                    );
+                }
+            }
+            Some(_) => {}
+            None => {
+                let _ = tm.unload_model();
+            }
+        }
+        let (load_ms, inference_ms) = timings.map_err(|e| e.to_string())?;
+
+        let audio_secs = sample_secs as f32;
+        Ok::<_, String>(BenchmarkResult {
+            model_id,
+            audio_secs,
+            load_ms,
+            inference_ms,
+            real_time_factor: (inference_ms as f32 / 1000.0) / audio_secs,
+            accelerator,
+            was_loaded,
+            cached: false,
+        })
+    })
+    .await;
+    crate::session_manager::exit_processing(&app_handle);
+    let result = result.map_err(|e| format!("Benchmark task failed: {}", e))??;
+
+    info!(
+        "Benchmark {}: load {}ms, inference {}ms, RTF {:.3}",
+        result.model_id, result.load_ms, result.inference_ms, result.real_time_factor
+    );
+    BENCHMARK_CACHE
+        .lock()
+        .unwrap()
+        .insert(cache_key, result.clone());
+    Ok(result)
+}
@@ -1,6 +1,6 @@
 use crate::managers::remote_stt::{
     clear_remote_stt_api_key, has_remote_stt_api_key, set_remote_stt_api_key, supports_translation,
-    RemoteSttManager,
+    RemoteSttDebugData, RemoteSttManager,
 };
 use crate::settings::get_settings;
 use std::sync::Arc;
@@ -35,6 +35,16 @@ pub fn remote_stt_get_debug_dump(
     Ok(remote_manager.get_debug_dump())
 }
 
+/// The last captured remote transcription request/response (headers redacted, body
+/// truncated in Normal debug mode). None when debug capture recorded nothing.
+#[tauri::command]
+#[specta::specta]
+pub fn get_remote_stt_debug(
+    remote_manager: State<'_, Arc<RemoteSttManager>>,
+) -> Option<RemoteSttDebugData> {
+    remote_manager.get_last_exchange()
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn remote_stt_clear_debug(
@@ -0,0 +1,45 @@
+//! Scratchpad commands
+//!
+//! Read, extend, clear and paste the dictation scratchpad (see `crate::scratchpad`).
+
+use crate::scratchpad::{self, ScratchpadState};
+use tauri::{AppHandle, State};
+
+/// Append text to the scratchpad
+#[tauri::command]
+#[specta::specta]
+pub fn scratchpad_append(app: AppHandle, text: String) {
+    scratchpad::append(&app, &text);
+}
+
+/// Current scratchpad contents
+#[tauri::command]
+#[specta::specta]
+pub fn scratchpad_get(state: State<'_, ScratchpadState>) -> String {
+    state.get()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn scratchpad_clear(app: AppHandle) {
+    scratchpad::clear(&app);
+}
+
+/// Paste the whole scratchpad into the focused app. The contents are kept;
+/// call `scratchpad_clear` to start over.
+#[tauri::command]
+#[specta::specta]
+pub fn scratchpad_paste(app: AppHandle, state: State<'_, ScratchpadState>) -> Result<(), String> {
+    let text = state.get();
+    if text.trim().is_empty() {
+        return Err("Scratchpad is empty".to_string());
+    }
+
+    let ah = app.clone();
+    app.run_on_main_thread(move || {
+        if let Err(e) = crate::utils::paste(text, ah) {
+            log::error!("Failed to paste scratchpad: {}", e);
+        }
+    })
+    .map_err(|e| format!("Failed to paste scratchpad: {}", e))
+}
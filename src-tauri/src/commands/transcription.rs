@@ -1,8 +1,17 @@
-use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{get_settings, write_settings, ModelUnloadTimeout};
+use crate::acceleration::AccelInfo;
+use crate::audio_toolkit::{apply_custom_words, filter_transcription_output};
+use crate::managers::history::HistoryManager;
+use crate::managers::remote_stt::RemoteSttManager;
+use crate::managers::transcription::{ModelMemoryInfo, TranscriptionManager};
+use crate::settings::{
+    get_settings, write_settings, AccelerationPreference, ModelUnloadTimeout, TranscriptionProvider,
+};
+use log::info;
 use serde::Serialize;
 use specta::Type;
-use tauri::{AppHandle, State};
+use std::sync::Arc;
+use std::time::Instant;
+use tauri::{AppHandle, Manager, State};
 
 #[derive(Serialize, Type)]
 pub struct ModelLoadStatus {
@@ -38,3 +47,189 @@ pub fn unload_model_manually(
         .unload_model()
         .map_err(|e| format!("Failed to unload model: {}", e))
 }
+
+/// Approximate memory used by the loaded local model (None when no model is loaded).
+#[tauri::command]
+#[specta::specta]
+pub fn get_model_memory_usage(
+    transcription_manager: State<Arc<TranscriptionManager>>,
+) -> Option<ModelMemoryInfo> {
+    transcription_manager.memory_usage()
+}
+
+/// Report which backend (CPU/Metal/Vulkan) local transcription is using.
+#[tauri::command]
+#[specta::specta]
+pub fn get_acceleration_status(app: AppHandle) -> AccelInfo {
+    crate::acceleration::status(&app, &get_settings(&app))
+}
+
+/// Set the preferred device for local transcription. A loaded model is unloaded when
+/// nothing is recording so the next transcription loads it on the new device.
+#[tauri::command]
+#[specta::specta]
+pub fn change_acceleration_setting(
+    app: AppHandle,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    preference: AccelerationPreference,
+    gpu_index: Option<u32>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    let changed = (
+        settings.acceleration_preference,
+        settings.acceleration_gpu_index,
+    ) != (preference, gpu_index);
+    settings.acceleration_preference = preference;
+    settings.acceleration_gpu_index = gpu_index;
+    write_settings(&app, settings);
+
+    if changed
+        && transcription_manager.loaded_acceleration().is_some()
+        && crate::session_manager::is_idle(&app)
+    {
+        info!("Unloading the Whisper model to apply the new device setting");
+        transcription_manager
+            .unload_model()
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Result of a one-off transcription through an explicit provider (A/B testing)
+#[derive(Serialize, Type)]
+pub struct ProviderTranscriptionResult {
+    provider: TranscriptionProvider,
+    text: String,
+    duration_ms: u64,
+}
+
+/// Transcribe samples with the given provider, ignoring the global
+/// `transcription_provider`. Uses the global language/translation settings.
+async fn transcribe_with_provider(
+    app: &AppHandle,
+    provider: TranscriptionProvider,
+    samples: Vec<f32>,
+) -> Result<ProviderTranscriptionResult, String> {
+    let settings = get_settings(app);
+    let start = Instant::now();
+
+    let text = match provider {
+        TranscriptionProvider::Local => {
+            let tm = Arc::clone(&app.state::<Arc<TranscriptionManager>>());
+            let model_id = settings.model_for_language(&settings.selected_language);
+            let custom_words_enabled = settings.custom_words_enabled;
+            tauri::async_runtime::spawn_blocking(move || {
+                tm.ensure_model_loaded(&model_id)?;
+                tm.transcribe(samples, custom_words_enabled)
+            })
+            .await
+            .map_err(|e| format!("Transcription task failed: {}", e))?
+            .map_err(|e| format!("Local transcription failed: {}", e))?
+        }
+        TranscriptionProvider::RemoteOpenAiCompatible => {
+            let remote_manager = app.state::<Arc<RemoteSttManager>>();
+            let prompt = crate::settings::resolve_stt_prompt(
+                None,
+                &settings.transcription_prompts,
+                &settings.remote_stt.model_id,
+            );
+            let text = remote_manager
+                .transcribe(
+                    &settings.remote_stt,
+                    &samples,
+                    prompt,
+                    Some(settings.selected_language.clone()),
+                    settings.translate_to_english,
+                )
+                .await
+                .map_err(|e| format!("Remote transcription failed: {}", e))?;
+
+            // Match the local path, which applies these inside the manager
+            let text = if settings.custom_words_enabled && !settings.custom_words.is_empty() {
+                apply_custom_words(
+                    &text,
+                    &settings.custom_words,
+                    settings.word_correction_threshold,
+                )
+            } else {
+                text
+            };
+            if settings.filler_word_filter_enabled {
+                filter_transcription_output(&text)
+            } else {
+                text
+            }
+        }
+    };
+
+    Ok(ProviderTranscriptionResult {
+        provider,
+        text,
+        duration_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// Transcribe raw 16kHz samples with a specific provider without changing settings.
+#[tauri::command]
+#[specta::specta]
+pub async fn transcribe_samples_with_provider(
+    app: AppHandle,
+    provider: TranscriptionProvider,
+    samples: Vec<f32>,
+) -> Result<ProviderTranscriptionResult, String> {
+    transcribe_with_provider(&app, provider, samples).await
+}
+
+/// Re-run a history entry's recording through a specific provider, so the same
+/// clip can be compared across local and remote transcription.
+#[tauri::command]
+#[specta::specta]
+pub async fn transcribe_history_entry_with_provider(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    provider: TranscriptionProvider,
+) -> Result<ProviderTranscriptionResult, String> {
+    let entry = history_manager
+        .get_entry_by_id(entry_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", entry_id))?;
+    let path = history_manager.get_audio_file_path(&entry.file_name);
+    if !path.exists() {
+        return Err(format!(
+            "Recording for history entry {} not found",
+            entry_id
+        ));
+    }
+
+    let samples = crate::commands::file_transcription::decode_wav_file(&path)?;
+    transcribe_with_provider(&app, provider, samples).await
+}
+
+/// Re-run STT on the most recent recording (e.g. after changing the model or prompt)
+/// and paste the new result. Needs that recording's audio to still be on disk.
+#[tauri::command]
+#[specta::specta]
+pub async fn retranscribe_last(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<String, String> {
+    let entry = history_manager
+        .get_latest_recording_entry()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No recording in history to re-transcribe".to_string())?;
+    let path = history_manager.get_audio_file_path(&entry.file_name);
+    if !path.exists() {
+        return Err(
+            "The audio of the last recording was not retained (it may have been removed by the recording retention settings)"
+                .to_string(),
+        );
+    }
+
+    let samples = crate::commands::file_transcription::decode_wav_file(&path)?;
+    if samples.is_empty() {
+        return Err("The last recording contains no audio".to_string());
+    }
+    crate::actions::retranscribe_samples(&app, samples).await
+}
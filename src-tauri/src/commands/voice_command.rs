@@ -59,7 +59,7 @@ pub fn execute_voice_command(
 
 /// Internal function to execute PowerShell commands.
 #[cfg(target_os = "windows")]
-fn execute_powershell_command(
+pub(crate) fn execute_powershell_command(
     script: &str,
     options: &ResolvedExecutionOptions,
 ) -> Result<String, String> {
@@ -172,6 +172,41 @@ pub fn execute_voice_command(
     Err("Voice commands are only supported on Windows".to_string())
 }
 
+/// Aborts a pending auto-run countdown so the command doesn't run.
+/// Returns whether a countdown was pending.
+#[tauri::command]
+#[specta::specta]
+pub fn cancel_voice_command_countdown(app: tauri::AppHandle) -> bool {
+    crate::voice_command_countdown::cancel(&app)
+}
+
+/// Pauses or resumes a pending auto-run countdown.
+/// Returns whether a countdown was pending.
+#[tauri::command]
+#[specta::specta]
+pub fn pause_voice_command_countdown(paused: bool) -> bool {
+    crate::voice_command_countdown::set_paused(paused)
+}
+
+/// Scores each test phrase against every enabled voice command (including the built-in
+/// meta commands when enabled) using the configured fuzzy-match weights. Useful for spotting phrases that match the wrong command.
+#[tauri::command]
+#[specta::specta]
+pub fn voice_command_score_matrix(
+    app: tauri::AppHandle,
+    phrases: Vec<String>,
+) -> Vec<Vec<crate::actions::VoiceCommandScore>> {
+    use crate::actions::{score_matrix, voice_command_candidates, FuzzyMatchConfig};
+
+    let settings = crate::settings::get_settings(&app);
+    score_matrix(
+        &phrases,
+        &voice_command_candidates(&settings),
+        settings.voice_command_default_threshold,
+        &FuzzyMatchConfig::from_settings(&settings),
+    )
+}
+
 /// Tests voice command matching with mock text (simulates STT output).
 /// Runs the same matching logic as if the text was spoken.
 #[tauri::command]
@@ -182,7 +217,9 @@ pub async fn test_voice_command_mock(
     mock_text: String,
 ) -> Result<String, String> {
     use crate::actions::{
-        find_matching_command, generate_command_with_llm, CommandConfirmPayload, FuzzyMatchConfig,
+        find_matching_command, generate_command_with_llm, is_below_llm_min_words,
+        is_meta_voice_command, run_meta_voice_command, voice_command_candidates,
+        CommandConfirmPayload, FuzzyMatchConfig,
     };
     use crate::settings::get_settings;
     use log::debug;
@@ -196,10 +233,10 @@ pub async fn test_voice_command_mock(
     let settings = get_settings(&app);
     let fuzzy_config = FuzzyMatchConfig::from_settings(&settings);
 
-    // Step 1: Try to match against predefined commands
+    // Step 1: Try to match against predefined (and meta) commands
     if let Some((matched_cmd, score)) = find_matching_command(
         &mock_text,
-        &settings.voice_commands,
+        &voice_command_candidates(&settings),
         settings.voice_command_default_threshold,
         &fuzzy_config,
     ) {
@@ -208,6 +245,11 @@ pub async fn test_voice_command_mock(
             matched_cmd.trigger_phrase, matched_cmd.script, score
         );
 
+        if is_meta_voice_command(&matched_cmd) {
+            let message = run_meta_voice_command(&app, &matched_cmd)?;
+            return Ok(format!("{} (score: {:.0}%)", message, score * 100.0));
+        }
+
         // Resolve execution options for this command
         let resolved = matched_cmd.resolve_execution_options(&settings.voice_command_defaults);
 
@@ -218,6 +260,7 @@ pub async fn test_voice_command_mock(
                 command: matched_cmd.script.clone(),
                 spoken_text: mock_text.clone(),
                 from_llm: false,
+                explanation: None,
                 silent: resolved.silent,
                 no_profile: resolved.no_profile,
                 use_pwsh: resolved.use_pwsh,
@@ -236,6 +279,15 @@ pub async fn test_voice_command_mock(
     }
 
     // Step 2: No predefined match - try LLM fallback if enabled
+    if settings.voice_command_llm_fallback
+        && is_below_llm_min_words(&mock_text, settings.voice_command_llm_min_words)
+    {
+        return Err(format!(
+            "No matching command found for: '{}' (too short for LLM fallback, minimum {} words)",
+            mock_text, settings.voice_command_llm_min_words
+        ));
+    }
+
     if settings.voice_command_llm_fallback {
         debug!(
             "No predefined match, using LLM fallback for mock text: '{}'",
@@ -243,7 +295,8 @@ pub async fn test_voice_command_mock(
         );
 
         match generate_command_with_llm(&app, &mock_text).await {
-            Ok(suggested_command) => {
+            Ok(suggestion) => {
+                let suggested_command = suggestion.command;
                 debug!("LLM suggested command: '{}'", suggested_command);
 
                 // LLM fallback uses global defaults
@@ -256,6 +309,7 @@ pub async fn test_voice_command_mock(
                         command: suggested_command.clone(),
                         spoken_text: mock_text,
                         from_llm: true,
+                        explanation: suggestion.explanation,
                         silent: resolved.silent,
                         no_profile: resolved.no_profile,
                         use_pwsh: resolved.use_pwsh,
@@ -0,0 +1,327 @@
+//! Dictation Command Mode
+//!
+//! Converts spoken formatting commands ("new line", "open quote", "period") into
+//! the formatting they describe. Built-in commands are seeded per language
+//! (English for now); `settings.dictation_commands` adds to or overrides them.
+//! Ambiguous phrases that are also ordinary words are only converted where a
+//! command is clearly meant (end of the transcription, or right before another command).
+
+use crate::settings::{
+    AppSettings, DictationAttach, DictationCommand, TextReplacement, TranscriptionProfile,
+};
+
+/// Words that make a following ambiguous phrase a noun ("the period", "a colon")
+const DETERMINERS: &[&str] = &[
+    "a", "an", "the", "this", "that", "each", "every", "per", "one", "my", "your", "his", "her",
+    "our", "their", "its",
+];
+
+/// Punctuation a command can replace when STT already inserted its own
+const SENTENCE_PUNCTUATION: &[char] = &[',', '.', ';', ':', '!', '?'];
+
+fn command(
+    language: &str,
+    phrase: &str,
+    output: &str,
+    attach: DictationAttach,
+    ambiguous: bool,
+) -> DictationCommand {
+    DictationCommand {
+        language: language.to_string(),
+        phrase: phrase.to_string(),
+        output: output.to_string(),
+        attach,
+        ambiguous,
+        enabled: true,
+    }
+}
+
+/// Built-in commands for a language code. Languages without a curated map return
+/// an empty list (users can still add commands for them in settings).
+pub fn builtin_commands(language: &str) -> Vec<DictationCommand> {
+    use DictationAttach::*;
+    match language {
+        "en" => vec![
+            command("en", "new line", "\\n", Both, false),
+            command("en", "new paragraph", "\\n\\n", Both, false),
+            command("en", "open quote", "\"", Right, false),
+            command("en", "begin quote", "\"", Right, false),
+            command("en", "close quote", "\"", Left, false),
+            command("en", "end quote", "\"", Left, false),
+            command("en", "unquote", "\"", Left, false),
+            command("en", "open paren", "(", Right, false),
+            command("en", "open parenthesis", "(", Right, false),
+            command("en", "close paren", ")", Left, false),
+            command("en", "close parenthesis", ")", Left, false),
+            command("en", "question mark", "?", Left, false),
+            command("en", "exclamation mark", "!", Left, false),
+            command("en", "exclamation point", "!", Left, false),
+            command("en", "semicolon", ";", Left, false),
+            command("en", "period", ".", Left, true),
+            command("en", "full stop", ".", Left, true),
+            command("en", "comma", ",", Left, true),
+            command("en", "colon", ":", Left, true),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// Built-in commands for `language` merged with the user's entries for that language
+/// (or "*"). User entries replace built-ins with the same phrase; disabled entries are dropped.
+pub fn effective_commands(language: &str, custom: &[DictationCommand]) -> Vec<DictationCommand> {
+    let mut commands = builtin_commands(language);
+    for entry in custom
+        .iter()
+        .filter(|c| c.language == language || c.language == "*")
+    {
+        let phrase = entry.phrase.trim().to_lowercase();
+        commands.retain(|c| c.phrase.to_lowercase() != phrase);
+        commands.push(entry.clone());
+    }
+    commands.retain(|c| c.enabled && !c.phrase.trim().is_empty());
+    commands
+}
+
+/// Language whose command map applies to a transcription: the profile's (or global)
+/// language reduced to its base code. Translated output and "auto" use English.
+pub fn command_language(settings: &AppSettings, profile: Option<&TranscriptionProfile>) -> String {
+    let (language, translate) = match profile {
+        Some(p) => (p.language.clone(), p.translate_to_english),
+        None => (
+            settings.selected_language.clone(),
+            settings.translate_to_english,
+        ),
+    };
+    if translate {
+        return "en".to_string();
+    }
+
+    let language = if language == "os_input" {
+        crate::input_source::get_language_from_input_source().unwrap_or_default()
+    } else {
+        language
+    };
+    let base = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    if base.is_empty() || base == "auto" {
+        "en".to_string()
+    } else {
+        base
+    }
+}
+
+/// Applies dictation commands if enabled for the profile (or globally when no profile).
+pub fn apply_for_profile(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    text: &str,
+) -> String {
+    let enabled = profile
+        .and_then(|p| p.dictation_commands_enabled)
+        .unwrap_or(settings.dictation_commands_enabled);
+    if !enabled {
+        return text.to_string();
+    }
+
+    let language = command_language(settings, profile);
+    let commands = effective_commands(&language, &settings.dictation_commands);
+    apply_dictation_commands(text, &commands)
+}
+
+struct Token<'a> {
+    raw: &'a str,
+    /// Lowercased with surrounding punctuation removed
+    word: String,
+}
+
+enum Piece {
+    Word(String),
+    Command {
+        output: String,
+        attach: DictationAttach,
+    },
+}
+
+/// Returns (command index, token count) of the longest command starting at `start`
+fn match_at(
+    tokens: &[Token],
+    start: usize,
+    phrases: &[(usize, Vec<String>)],
+) -> Option<(usize, usize)> {
+    phrases.iter().find_map(|(index, words)| {
+        let end = start + words.len();
+        if end > tokens.len() {
+            return None;
+        }
+        let matches = words.iter().enumerate().all(|(k, word)| {
+            let token = &tokens[start + k];
+            // Punctuation inside a multi-word phrase means the words weren't a command
+            let inner_punct =
+                k + 1 < words.len() && token.raw.ends_with(|c: char| c.is_ascii_punctuation());
+            token.word == *word && !inner_punct
+        });
+        matches.then_some((*index, words.len()))
+    })
+}
+
+/// Replaces spoken commands in `text` with their output.
+pub fn apply_dictation_commands(text: &str, commands: &[DictationCommand]) -> String {
+    if commands.is_empty() || text.trim().is_empty() {
+        return text.to_string();
+    }
+
+    let tokens: Vec<Token> = text
+        .split_whitespace()
+        .map(|raw| Token {
+            raw,
+            word: raw
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase(),
+        })
+        .collect();
+
+    // Longest phrases first so "new paragraph" wins over a shorter overlap
+    let mut phrases: Vec<(usize, Vec<String>)> = commands
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let words = c
+                .phrase
+                .split_whitespace()
+                .map(|w| w.to_lowercase())
+                .collect();
+            (i, words)
+        })
+        .collect();
+    phrases.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let matched = match_at(&tokens, i, &phrases).filter(|&(index, len)| {
+            if !commands[index].ambiguous {
+                return true;
+            }
+            let after_determiner = i > 0 && DETERMINERS.contains(&tokens[i - 1].word.as_str());
+            let standalone =
+                i + len == tokens.len() || match_at(&tokens, i + len, &phrases).is_some();
+            standalone && !after_determiner
+        });
+
+        match matched {
+            Some((index, len)) => {
+                let cmd = &commands[index];
+                let output = TextReplacement::process_escapes(&cmd.output);
+                if let Some(Piece::Word(prev)) = pieces.last_mut() {
+                    // Drop punctuation STT added before the command ("Hello, new line")
+                    let is_punctuation = cmd.attach == DictationAttach::Left
+                        && output.chars().all(|c| SENTENCE_PUNCTUATION.contains(&c));
+                    let trimmed = if is_punctuation {
+                        prev.trim_end_matches(SENTENCE_PUNCTUATION)
+                    } else if matches!(cmd.attach, DictationAttach::Left | DictationAttach::Both) {
+                        prev.trim_end_matches(',')
+                    } else {
+                        prev.as_str()
+                    };
+                    *prev = trimmed.to_string();
+                }
+                pieces.push(Piece::Command {
+                    output,
+                    attach: cmd.attach,
+                });
+                i += len;
+            }
+            None => {
+                pieces.push(Piece::Word(tokens[i].raw.to_string()));
+                i += 1;
+            }
+        }
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut glue_next = true;
+    for piece in pieces {
+        let (content, glue_left, glue_right) = match piece {
+            Piece::Word(word) => (word, false, false),
+            Piece::Command { output, attach } => (
+                output,
+                matches!(attach, DictationAttach::Left | DictationAttach::Both),
+                matches!(attach, DictationAttach::Right | DictationAttach::Both),
+            ),
+        };
+        if content.is_empty() {
+            continue;
+        }
+        if !glue_next && !glue_left {
+            result.push(' ');
+        }
+        result.push_str(&content);
+        glue_next = glue_right;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_en(text: &str) -> String {
+        apply_dictation_commands(text, &builtin_commands("en"))
+    }
+
+    #[test]
+    fn converts_line_breaks_and_quotes() {
+        assert_eq!(
+            apply_en("Hello, new line. How are you"),
+            "Hello\nHow are you"
+        );
+        assert_eq!(
+            apply_en("he said open quote hi close quote"),
+            "he said \"hi\""
+        );
+        assert_eq!(apply_en("end new paragraph start"), "end\n\nstart");
+    }
+
+    #[test]
+    fn ambiguous_words_only_convert_when_standalone() {
+        assert_eq!(
+            apply_en("that was a long period of time"),
+            "that was a long period of time"
+        );
+        assert_eq!(apply_en("I agree period"), "I agree.");
+        assert_eq!(apply_en("I agree. Period."), "I agree.");
+        assert_eq!(apply_en("see you soon period new line"), "see you soon.\n");
+        assert_eq!(apply_en("it ended the period"), "it ended the period");
+    }
+
+    #[test]
+    fn custom_entries_override_builtins() {
+        let custom = vec![
+            DictationCommand {
+                language: "en".to_string(),
+                phrase: "New Line".to_string(),
+                output: "<br>".to_string(),
+                attach: DictationAttach::None,
+                ambiguous: false,
+                enabled: true,
+            },
+            DictationCommand {
+                language: "*".to_string(),
+                phrase: "comma".to_string(),
+                output: String::new(),
+                attach: DictationAttach::Left,
+                ambiguous: false,
+                enabled: false,
+            },
+        ];
+        let commands = effective_commands("en", &custom);
+        assert!(!commands.iter().any(|c| c.phrase == "comma"));
+        assert_eq!(
+            apply_dictation_commands("a new line b", &commands),
+            "a <br> b"
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Foreground application detection
+//!
+//! Identifies the application that currently has keyboard focus (the paste target),
+//! so output can be tailored per app (e.g., a trailing newline for chat apps), and
+//! captures the focused window at recording start so focus can be restored before pasting.
+
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
+
+/// The application that currently owns the foreground window
+#[derive(Debug, Clone, Default, Serialize, Type)]
+pub struct ForegroundApp {
+    /// Executable / process name (e.g., "slack.exe", "Slack")
+    pub process_name: Option<String>,
+    /// Title of the focused window
+    pub window_title: Option<String>,
+    /// Full path of the executable, where the platform exposes it
+    pub executable_path: Option<String>,
+}
+
+impl ForegroundApp {
+    /// Case-insensitive substring match against the process name or window title.
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern.trim().to_lowercase();
+        if pattern.is_empty() {
+            return false;
+        }
+        [&self.process_name, &self.window_title]
+            .iter()
+            .filter_map(|v| v.as_deref())
+            .any(|v| v.to_lowercase().contains(&pattern))
+    }
+
+    /// Human-readable app name: process name without ".exe", else the window title.
+    pub fn display_name(&self) -> Option<String> {
+        let process = self.process_name.as_deref().map(|name| {
+            let lower = name.to_lowercase();
+            match lower.strip_suffix(".exe") {
+                Some(_) => &name[..name.len() - 4],
+                None => name,
+            }
+        });
+        process
+            .or(self.window_title.as_deref())
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    }
+}
+
+/// Resolve the paste suffix for the foreground app from a pattern -> suffix map.
+/// When several patterns match, the longest (most specific) pattern wins.
+pub fn resolve_app_suffix<'a>(
+    app: &ForegroundApp,
+    suffixes: &'a HashMap<String, String>,
+) -> Option<&'a String> {
+    suffixes
+        .iter()
+        .filter(|(pattern, _)| app.matches(pattern))
+        .max_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+        .map(|(_, suffix)| suffix)
+}
+
+/// Whether the foreground app matches any of the given patterns.
+pub fn matches_any(app: &ForegroundApp, patterns: &[String]) -> bool {
+    patterns.iter().any(|pattern| app.matches(pattern))
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_foreground_app() -> Option<ForegroundApp> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetWindowTextW, GetWindowThreadProcessId,
+    };
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.0.is_null() {
+            return None;
+        }
+
+        let mut title_buf = [0u16; 512];
+        let title_len = GetWindowTextW(hwnd, &mut title_buf);
+        let window_title = if title_len > 0 {
+            Some(String::from_utf16_lossy(&title_buf[..title_len as usize]))
+        } else {
+            None
+        };
+
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+
+        let executable_path = if pid != 0 {
+            OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid)
+                .ok()
+                .and_then(|handle| {
+                    let mut path_buf = [0u16; 1024];
+                    let mut size = path_buf.len() as u32;
+                    let result = QueryFullProcessImageNameW(
+                        handle,
+                        PROCESS_NAME_WIN32,
+                        PWSTR(path_buf.as_mut_ptr()),
+                        &mut size,
+                    );
+                    let _ = CloseHandle(handle);
+                    result.ok()?;
+                    Some(String::from_utf16_lossy(&path_buf[..size as usize]))
+                })
+        } else {
+            None
+        };
+        let process_name = executable_path.as_deref().and_then(|path| {
+            std::path::Path::new(path)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+        });
+
+        Some(ForegroundApp {
+            process_name,
+            window_title,
+            executable_path,
+        })
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub fn get_foreground_app() -> Option<ForegroundApp> {
+    use std::process::Command;
+    // Name and bundle path on separate lines; the path lookup is best-effort
+    let output = Command::new("osascript")
+        .args([
+            "-e",
+            "tell application \"System Events\" to set p to first application process whose frontmost is true",
+            "-e",
+            "set appName to name of p",
+            "-e",
+            "try",
+            "-e",
+            "tell application \"System Events\" to set appPath to POSIX path of (application file of p)",
+            "-e",
+            "on error",
+            "-e",
+            "set appPath to \"\"",
+            "-e",
+            "end try",
+            "-e",
+            "return appName & linefeed & appPath",
+        ])
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines().map(str::trim);
+    let name = lines.next().filter(|n| !n.is_empty())?.to_string();
+    let executable_path = lines.next().filter(|p| !p.is_empty()).map(str::to_string);
+    Some(ForegroundApp {
+        process_name: Some(name),
+        window_title: None,
+        executable_path,
+    })
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_foreground_app() -> Option<ForegroundApp> {
+    use std::process::Command;
+    let run = |args: &[&str]| -> Option<String> {
+        let output = Command::new("xdotool").args(args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!value.is_empty()).then_some(value)
+    };
+
+    let window_title = run(&["getactivewindow", "getwindowname"]);
+    let pid = run(&["getactivewindow", "getwindowpid"]);
+    let process_name = pid.as_ref().and_then(|pid| {
+        std::fs::read_to_string(format!("/proc/{}/comm", pid))
+            .ok()
+            .map(|s| s.trim().to_string())
+    });
+    let executable_path = pid.as_ref().and_then(|pid| {
+        std::fs::read_link(format!("/proc/{}/exe", pid))
+            .ok()
+            .map(|p| p.to_string_lossy().to_string())
+    });
+
+    if window_title.is_none() && process_name.is_none() {
+        return None;
+    }
+    Some(ForegroundApp {
+        process_name,
+        window_title,
+        executable_path,
+    })
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+pub fn get_foreground_app() -> Option<ForegroundApp> {
+    None
+}
+
+/// A window that had focus, captured so focus can be given back to it before pasting.
+/// Holds the HWND. Windows only: elsewhere capturing would mean spawning a helper
+/// process (and on macOS an Automation prompt) at every recording start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub struct FocusTarget(i64);
+
+#[cfg(target_os = "windows")]
+pub fn capture_focus_target() -> Option<FocusTarget> {
+    use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    (!hwnd.0.is_null()).then(|| FocusTarget(hwnd.0 as i64))
+}
+
+/// Give focus back to `target`. Returns whether focus was restored.
+#[cfg(target_os = "windows")]
+pub fn restore_focus_target(target: FocusTarget) -> bool {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::WindowsAndMessaging::{IsWindow, SetForegroundWindow};
+
+    let hwnd = HWND(target.0 as *mut std::ffi::c_void);
+    unsafe { IsWindow(Some(hwnd)).as_bool() && SetForegroundWindow(hwnd).as_bool() }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn capture_focus_target() -> Option<FocusTarget> {
+    None
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn restore_focus_target(_target: FocusTarget) -> bool {
+    false
+}
+
+/// Center of the focused window in physical screen coordinates
+#[cfg(target_os = "windows")]
+pub fn focused_window_center() -> Option<(i32, i32)> {
+    use windows::Win32::Foundation::RECT;
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowRect};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.0.is_null() {
+            return None;
+        }
+        let mut rect = RECT::default();
+        GetWindowRect(hwnd, &mut rect).ok()?;
+        Some(((rect.left + rect.right) / 2, (rect.top + rect.bottom) / 2))
+    }
+}
+
+/// Center of the focused window in physical screen coordinates
+#[cfg(target_os = "linux")]
+pub fn focused_window_center() -> Option<(i32, i32)> {
+    use std::process::Command;
+    let output = Command::new("xdotool")
+        .args(["getactivewindow", "getwindowgeometry", "--shell"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Lines like "X=10", "Y=20", "WIDTH=800", "HEIGHT=600"
+    let geometry: HashMap<String, i32> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            Some((key.to_string(), value.trim().parse().ok()?))
+        })
+        .collect();
+    let (x, y) = (*geometry.get("X")?, *geometry.get("Y")?);
+    let (width, height) = (*geometry.get("WIDTH")?, *geometry.get("HEIGHT")?);
+    Some((x + width / 2, y + height / 2))
+}
+
+/// Window geometry isn't available without accessibility scripting; callers fall back
+/// to the cursor position
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn focused_window_center() -> Option<(i32, i32)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slack() -> ForegroundApp {
+        ForegroundApp {
+            process_name: Some("Slack.exe".to_string()),
+            window_title: Some("general - Team Chat".to_string()),
+            executable_path: None,
+        }
+    }
+
+    #[test]
+    fn matches_process_name_or_title_case_insensitively() {
+        let app = slack();
+        assert!(app.matches("slack"));
+        assert!(app.matches(" TEAM chat "));
+        assert!(!app.matches("discord"));
+        assert!(!app.matches("  "));
+    }
+
+    #[test]
+    fn longest_matching_pattern_wins() {
+        let suffixes: HashMap<String, String> = [
+            ("slack".to_string(), "\n".to_string()),
+            ("slack.exe".to_string(), " ".to_string()),
+            ("discord".to_string(), "\t".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            resolve_app_suffix(&slack(), &suffixes).map(String::as_str),
+            Some(" ")
+        );
+        assert_eq!(
+            resolve_app_suffix(&ForegroundApp::default(), &suffixes),
+            None
+        );
+    }
+}
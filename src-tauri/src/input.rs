@@ -80,6 +80,23 @@ pub fn send_cut_ctrl_x(enigo: &mut Enigo) -> Result<(), String> {
     }
 }
 
+/// Selects the `count` characters before the caret (Shift+Left once per character).
+/// Shift is released even if a keystroke fails.
+pub fn send_select_back(enigo: &mut Enigo, count: usize) -> Result<(), String> {
+    enigo
+        .key(Key::Shift, enigo::Direction::Press)
+        .map_err(|e| format!("Failed to press Shift key: {}", e))?;
+    let result = (0..count).try_for_each(|_| {
+        enigo
+            .key(Key::LeftArrow, enigo::Direction::Click)
+            .map_err(|e| format!("Failed to click Left key: {}", e))
+    });
+    enigo
+        .key(Key::Shift, enigo::Direction::Release)
+        .map_err(|e| format!("Failed to release Shift key: {}", e))?;
+    result
+}
+
 /// Sends a Ctrl+C or Cmd+C copy command using platform-specific virtual key codes.
 pub fn send_copy_ctrl_c(enigo: &mut Enigo) -> Result<(), String> {
     #[cfg(target_os = "macos")]
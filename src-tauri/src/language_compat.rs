@@ -0,0 +1,164 @@
+//! Language / model compatibility checks
+//!
+//! Advisory warnings for a language paired with a model that can't transcribe it
+//! (e.g., a Japanese profile on an English-only model). Never blocks saving.
+
+use crate::managers::model::ModelManager;
+use crate::managers::remote_stt::WHISPER_SUPPORTED_LANGUAGES;
+use crate::settings::{AppSettings, TranscriptionProvider};
+use log::warn;
+use serde::Serialize;
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Payload for the `language-compatibility-warning` event
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct LanguageCompatibilityWarning {
+    pub profile_id: Option<String>,
+    pub language: String,
+    pub model: String,
+    pub message: String,
+}
+
+/// Base language code ("zh-Hans" -> "zh", "pt-BR" -> "pt")
+fn base_language(language: &str) -> String {
+    language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(language)
+        .to_lowercase()
+}
+
+/// Guess the languages a remote model supports from its name.
+/// Returns None when the name gives no hint (assume multilingual).
+pub fn remote_model_language_hint(model: &str) -> Option<Vec<String>> {
+    let model = model.trim().to_lowercase();
+    let english_only = model.ends_with(".en")
+        || model.ends_with("-en")
+        || model.contains("distil-whisper")
+        || model.contains("parakeet-tdt-0.6b-v2")
+        || model.contains("moonshine");
+    english_only.then(|| vec!["en".to_string()])
+}
+
+/// Compare a language against a model's supported languages.
+/// `auto` is always accepted; an empty list means multilingual.
+pub fn check_compatibility(language: &str, model: &str, supported: &[String]) -> Option<String> {
+    if language == "auto" || supported.is_empty() {
+        return None;
+    }
+    let language = base_language(language);
+    if supported.iter().any(|l| base_language(l) == language) {
+        return None;
+    }
+    Some(format!(
+        "Model \"{}\" does not appear to support \"{}\" (supports: {})",
+        model,
+        language,
+        supported.join(", ")
+    ))
+}
+
+/// Resolve a language ("os_input" included) against a provider/model pair.
+pub fn check_language_model(
+    app: &AppHandle,
+    language: &str,
+    provider: TranscriptionProvider,
+    model: &str,
+) -> Option<String> {
+    let language = if language == "os_input" {
+        crate::input_source::get_language_from_input_source()?
+    } else {
+        language.to_string()
+    };
+
+    let supported = match provider {
+        TranscriptionProvider::RemoteOpenAiCompatible => remote_model_language_hint(model)?,
+        TranscriptionProvider::Local => {
+            let model_manager = app.state::<Arc<ModelManager>>();
+            model_manager.get_model_info(model)?.supported_languages
+        }
+    };
+
+    check_compatibility(&language, model, &supported)
+}
+
+/// Check a profile's language against the model that would run it and emit
+/// `language-compatibility-warning` when they look incompatible.
+pub fn warn_for_profile(
+    app: &AppHandle,
+    settings: &AppSettings,
+    profile_id: Option<&str>,
+    language: &str,
+) {
+    let model = match settings.transcription_provider {
+        TranscriptionProvider::RemoteOpenAiCompatible => settings.remote_stt.model_id.clone(),
+        TranscriptionProvider::Local => settings.model_for_language(language),
+    };
+
+    if let Some(message) =
+        check_language_model(app, language, settings.transcription_provider, &model)
+    {
+        warn!("Language compatibility: {}", message);
+        let _ = app.emit(
+            "language-compatibility-warning",
+            LanguageCompatibilityWarning {
+                profile_id: profile_id.map(|s| s.to_string()),
+                language: language.to_string(),
+                model,
+                message,
+            },
+        );
+    }
+}
+
+/// Language selector options: "auto", then preferred languages, then (unless
+/// `preferred_only`) the remaining Whisper languages.
+pub fn language_options(preferred: &[String], preferred_only: bool) -> Vec<String> {
+    let mut options = vec!["auto".to_string()];
+    for language in preferred {
+        if !options.contains(language) {
+            options.push(language.clone());
+        }
+    }
+    if !preferred_only || options.len() == 1 {
+        for language in WHISPER_SUPPORTED_LANGUAGES {
+            if !options.iter().any(|l| l == language) {
+                options.push(language.to_string());
+            }
+        }
+    }
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_only_remote_models_are_detected() {
+        assert!(remote_model_language_hint("distil-whisper-large-v3-en").is_some());
+        assert!(remote_model_language_hint("whisper-small.en").is_some());
+        assert!(remote_model_language_hint("whisper-large-v3-turbo").is_none());
+    }
+
+    #[test]
+    fn check_compatibility_flags_unsupported_language() {
+        let english = vec!["en".to_string()];
+        assert!(check_compatibility("ja", "moonshine-base", &english).is_some());
+        assert!(check_compatibility("en", "moonshine-base", &english).is_none());
+        assert!(check_compatibility("auto", "moonshine-base", &english).is_none());
+        assert!(check_compatibility("zh-Hans", "small", &[]).is_none());
+    }
+
+    #[test]
+    fn language_options_put_preferred_first() {
+        let preferred = vec!["ru".to_string(), "en".to_string()];
+        let options = language_options(&preferred, false);
+        assert_eq!(&options[..3], &["auto", "ru", "en"]);
+        assert_eq!(options.iter().filter(|l| *l == "en").count(), 1);
+        assert_eq!(language_options(&preferred, true), vec!["auto", "ru", "en"]);
+        assert!(language_options(&[], true).len() > 1);
+    }
+}
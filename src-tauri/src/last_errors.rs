@@ -0,0 +1,90 @@
+//! Last Errors
+//!
+//! Runtime record of the most recent failure per feature, so the settings UI can show
+//! a "recent problems" panel after the overlay error is gone. A feature's entry is
+//! cleared when it next succeeds. Not persisted across restarts.
+
+use crate::plus_overlay_state::{categorize_error, OverlayErrorCategory};
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+static LAST_ERRORS: Mutex<Option<HashMap<String, LastError>>> = Mutex::new(None);
+
+/// Features whose failures are tracked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFeature {
+    Transcription,
+    PostProcess,
+    AiReplace,
+    Connector,
+    Screenshot,
+    VoiceCommand,
+}
+
+impl ErrorFeature {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorFeature::Transcription => "transcription",
+            ErrorFeature::PostProcess => "post_process",
+            ErrorFeature::AiReplace => "ai_replace",
+            ErrorFeature::Connector => "connector",
+            ErrorFeature::Screenshot => "screenshot",
+            ErrorFeature::VoiceCommand => "voice_command",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct LastError {
+    pub message: String,
+    pub category: OverlayErrorCategory,
+    /// Unix timestamp (milliseconds)
+    pub timestamp: i64,
+}
+
+/// Record a failure, categorizing it from the message
+pub fn record_error(app: &AppHandle, feature: ErrorFeature, message: impl Into<String>) {
+    let message = message.into();
+    let category = categorize_error(&message);
+    record_error_with_category(app, feature, category, message);
+}
+
+/// Record a failure whose category is already known (e.g. extension offline)
+pub fn record_error_with_category(
+    app: &AppHandle,
+    feature: ErrorFeature,
+    category: OverlayErrorCategory,
+    message: impl Into<String>,
+) {
+    let error = LastError {
+        message: message.into(),
+        category,
+        timestamp: chrono::Utc::now().timestamp_millis(),
+    };
+    LAST_ERRORS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(feature.as_str().to_string(), error);
+    let _ = app.emit("last-errors-changed", feature.as_str());
+}
+
+/// Forget the feature's last error after it succeeded
+pub fn clear_error(app: &AppHandle, feature: ErrorFeature) {
+    let removed = LAST_ERRORS
+        .lock()
+        .unwrap()
+        .as_mut()
+        .and_then(|errors| errors.remove(feature.as_str()))
+        .is_some();
+    if removed {
+        let _ = app.emit("last-errors-changed", feature.as_str());
+    }
+}
+
+pub fn last_errors() -> HashMap<String, LastError> {
+    LAST_ERRORS.lock().unwrap().clone().unwrap_or_default()
+}
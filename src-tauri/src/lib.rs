@@ -1,3 +1,4 @@
+mod acceleration;
 mod actions;
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 mod apple_intelligence;
@@ -5,24 +6,39 @@ mod audio_feedback;
 pub mod audio_toolkit;
 mod clipboard;
 mod commands;
+mod dictation_commands;
+mod foreground_app;
 mod helpers;
 mod input;
 mod input_source;
+mod language_compat;
+mod last_errors;
 mod llm_client;
+mod llm_output;
 mod managers;
+mod output_case;
 mod overlay;
+mod pipeline_timing;
 mod plus_overlay_state;
+mod prompt_variables;
 #[cfg(target_os = "windows")]
 mod region_capture;
+mod scratchpad;
 mod secure_keys;
 mod session_manager;
 mod settings;
 mod shortcut;
 mod signal_handle;
 pub mod subtitle;
+mod text_pipeline;
+mod transcribing_watchdog;
+mod transcription_log;
 mod tray;
 mod tray_i18n;
 mod utils;
+mod voice_command_countdown;
+mod whisper_engine;
+mod workspaces;
 use specta_typescript::{BigIntExportBehavior, Typescript};
 use tauri_specta::{collect_commands, Builder};
 
@@ -151,6 +167,9 @@ fn initialize_core_logic(app_handle: &AppHandle) {
         ConnectorManager::new(app_handle).expect("Failed to initialize connector manager"),
     );
     let llm_operation_tracker = Arc::new(LlmOperationTracker::new());
+    llm_client::set_max_concurrent_requests(
+        settings::get_settings(app_handle).llm_max_concurrent_requests,
+    );
 
     // Initialize key listener
     let key_listener_state = KeyListenerState::new(app_handle.clone());
@@ -179,6 +198,9 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     // Initialize the shortcuts
     shortcut::init_shortcuts(app_handle);
 
+    // Recover from a stuck Transcribing state
+    transcribing_watchdog::start(app_handle);
+
     #[cfg(unix)]
     let signals = Signals::new(&[SIGUSR2]).unwrap();
     // Set up SIGUSR2 signal handler for toggling transcription
@@ -243,7 +265,7 @@ fn initialize_core_logic(app_handle: &AppHandle) {
             "quit" => {
                 app.exit(0);
             }
-            _ => {}
+            id => tray::handle_post_process_menu_event(app, id),
         })
         .build(app_handle)
         .unwrap();
@@ -288,8 +310,13 @@ pub fn run() {
 
     let specta_builder = Builder::<tauri::Wry>::new().commands(collect_commands![
         shortcut::change_binding,
+        shortcut::resolve_binding_target,
+        shortcut::trigger_action,
+        shortcut::export_keymap,
         shortcut::reset_binding,
+        shortcut::change_additional_bindings,
         shortcut::change_ptt_setting,
+        shortcut::toggle_ptt_mode,
         shortcut::change_audio_feedback_setting,
         shortcut::change_audio_feedback_volume_setting,
         shortcut::change_sound_theme_setting,
@@ -297,13 +324,24 @@ pub fn run() {
         shortcut::change_autostart_setting,
         shortcut::change_translate_to_english_setting,
         shortcut::change_selected_language_setting,
+        shortcut::change_auto_language_candidates_setting,
+        shortcut::change_preferred_languages_setting,
+        shortcut::change_auto_language_min_confidence_setting,
         shortcut::change_transcription_provider_setting,
         shortcut::change_transcription_prompt_setting,
         shortcut::change_overlay_position_setting,
+        shortcut::change_overlay_monitor_setting,
+        shortcut::change_overlay_appearance_setting,
+        shortcut::change_overlay_error_duration_setting,
         shortcut::change_debug_mode_setting,
         shortcut::change_word_correction_threshold_setting,
+        shortcut::change_local_decode_params_setting,
         shortcut::change_paste_method_setting,
         shortcut::change_clipboard_handling_setting,
+        shortcut::change_always_copy_result_setting,
+        shortcut::change_paste_delay_ms_setting,
+        shortcut::change_selection_capture_retries_setting,
+        shortcut::change_selection_capture_retry_delay_ms_setting,
         shortcut::change_convert_lf_to_crlf_setting,
         shortcut::change_remote_stt_base_url_setting,
         shortcut::change_remote_stt_model_id_setting,
@@ -312,12 +350,23 @@ pub fn run() {
         shortcut::change_post_process_enabled_setting,
         shortcut::change_post_process_reasoning_enabled_setting,
         shortcut::change_post_process_reasoning_budget_setting,
+        shortcut::change_post_process_extract_json_field_setting,
+        shortcut::change_post_process_low_confidence_threshold_setting,
+        shortcut::change_post_process_low_confidence_hint_setting,
+        shortcut::change_post_process_stop_sequences_setting,
+        shortcut::change_post_process_paste_then_replace_setting,
+        shortcut::change_llm_max_concurrent_requests_setting,
+        shortcut::change_ai_replace_stop_sequences_setting,
+        shortcut::change_strip_llm_formatting_setting,
         shortcut::change_ai_replace_reasoning_enabled_setting,
         shortcut::change_ai_replace_reasoning_budget_setting,
         shortcut::change_voice_command_reasoning_enabled_setting,
+        shortcut::change_voice_command_structured_output_setting,
         shortcut::change_voice_command_reasoning_budget_setting,
         shortcut::change_voice_command_enabled_setting,
         shortcut::change_voice_command_llm_fallback_setting,
+        shortcut::change_voice_command_llm_min_words_setting,
+        shortcut::change_voice_command_meta_commands_enabled_setting,
         shortcut::change_voice_command_system_prompt_setting,
         shortcut::change_voice_command_template_setting,
         shortcut::change_voice_command_keep_window_open_setting,
@@ -325,12 +374,17 @@ pub fn run() {
         shortcut::change_voice_command_auto_run_seconds_setting,
         shortcut::change_voice_command_default_threshold_setting,
         shortcut::change_voice_commands_setting,
+        shortcut::add_voice_command_binding,
+        shortcut::remove_voice_command_binding,
         shortcut::change_voice_command_use_levenshtein_setting,
         shortcut::change_voice_command_levenshtein_threshold_setting,
         shortcut::change_voice_command_use_phonetic_setting,
         shortcut::change_voice_command_phonetic_boost_setting,
         shortcut::change_voice_command_word_similarity_threshold_setting,
         shortcut::change_post_process_base_url_setting,
+        shortcut::change_unlock_builtin_provider_base_urls_setting,
+        shortcut::reset_post_process_base_url,
+        shortcut::change_post_process_organization_setting,
         shortcut::change_post_process_api_key_setting,
         shortcut::change_post_process_model_setting,
         shortcut::set_post_process_provider,
@@ -340,6 +394,8 @@ pub fn run() {
         shortcut::update_post_process_prompt,
         shortcut::delete_post_process_prompt,
         shortcut::set_post_process_selected_prompt,
+        shortcut::set_post_process_prompt_by_name,
+        shortcut::get_active_prompt,
         shortcut::add_transcription_profile,
         shortcut::update_transcription_profile,
         shortcut::delete_transcription_profile,
@@ -347,25 +403,36 @@ pub fn run() {
         shortcut::set_active_profile,
         shortcut::cycle_to_next_profile,
         shortcut::change_profile_switch_overlay_enabled_setting,
+        shortcut::change_profile_switch_during_recording_setting,
         shortcut::update_custom_words,
+        shortcut::set_custom_word_threshold,
         shortcut::change_custom_words_enabled_setting,
         shortcut::suspend_binding,
         shortcut::resume_binding,
         shortcut::change_mute_while_recording_setting,
         shortcut::change_append_trailing_space_setting,
+        shortcut::change_scratchpad_mode_setting,
+        shortcut::set_paste_suffix_for_app,
+        shortcut::remove_paste_suffix_for_app,
+        shortcut::change_post_process_disabled_apps_setting,
+        shortcut::change_transcribing_watchdog_secs_setting,
         shortcut::change_ai_replace_system_prompt_setting,
         shortcut::change_ai_replace_user_prompt_setting,
         shortcut::change_ai_replace_max_chars_setting,
         shortcut::change_ai_replace_allow_no_selection_setting,
         shortcut::change_ai_replace_no_selection_system_prompt_setting,
         shortcut::change_ai_replace_allow_quick_tap_setting,
+        shortcut::change_ai_replace_output_mode_setting,
         shortcut::change_ai_replace_quick_tap_threshold_ms_setting,
         shortcut::change_ai_replace_quick_tap_system_prompt_setting,
         shortcut::set_ai_replace_provider,
         shortcut::change_ai_replace_api_key_setting,
         shortcut::change_ai_replace_model_setting,
+        shortcut::set_model_manual,
         shortcut::set_voice_command_provider,
         shortcut::change_voice_command_api_key_setting,
+        shortcut::clear_api_key,
+        shortcut::clear_all_api_keys,
         shortcut::change_voice_command_model_setting,
         shortcut::change_send_to_extension_enabled_setting,
         shortcut::change_send_to_extension_push_to_talk_setting,
@@ -379,7 +446,12 @@ pub fn run() {
         shortcut::change_ai_replace_selection_push_to_talk_setting,
         shortcut::change_connector_auto_open_enabled_setting,
         shortcut::change_connector_auto_open_url_setting,
+        shortcut::change_connector_apply_text_replacements_setting,
+        shortcut::change_connector_message_ttl_setting,
+        shortcut::change_connector_tag_with_profile_setting,
+        shortcut::change_connector_allowed_origins_setting,
         shortcut::change_connector_port_setting,
+        shortcut::change_connector_port_auto_fallback_setting,
         shortcut::change_connector_password_setting,
         shortcut::change_screenshot_capture_method_setting,
         shortcut::change_screenshot_capture_command_setting,
@@ -398,7 +470,19 @@ pub fn run() {
         shortcut::change_beta_voice_commands_enabled_setting,
         shortcut::change_text_replacements_enabled_setting,
         shortcut::change_text_replacements_setting,
+        shortcut::change_text_replacement_group_enabled_setting,
+        shortcut::test_text_replacement_suite,
+        shortcut::preview_text_replacements,
         shortcut::change_text_replacements_before_llm_setting,
+        shortcut::change_dictation_commands_setting,
+        shortcut::change_profile_dictation_commands_setting,
+        shortcut::change_profile_filler_filter_override_setting,
+        shortcut::change_profile_vad_threshold_setting,
+        shortcut::get_builtin_dictation_commands,
+        shortcut::change_output_case_transform_setting,
+        shortcut::change_profile_output_case_transform_setting,
+        shortcut::change_auto_capitalize_sentences_setting,
+        shortcut::change_profile_auto_capitalize_sentences_setting,
         shortcut::change_sidebar_pinned_setting,
         shortcut::change_sidebar_width_setting,
         shortcut::get_language_from_os_input,
@@ -419,17 +503,35 @@ pub fn run() {
         commands::remote_stt::remote_stt_set_api_key,
         commands::remote_stt::remote_stt_clear_api_key,
         commands::remote_stt::remote_stt_get_debug_dump,
+        commands::remote_stt::get_remote_stt_debug,
         commands::remote_stt::remote_stt_clear_debug,
         commands::remote_stt::remote_stt_test_connection,
         commands::remote_stt::remote_stt_get_prompt_limit,
         commands::remote_stt::remote_stt_supports_translation,
         commands::check_apple_intelligence_available,
+        commands::check_apple_intelligence,
+        commands::get_language_options,
+        commands::get_overlay_monitors,
+        commands::dismiss_error_overlay,
+        commands::restart_app,
+        commands::get_pipeline_timing_stats,
+        commands::get_last_errors,
+        commands::get_foreground_app,
+        commands::resolve_llm_config,
+        commands::test_ai_replace,
+        workspaces::save_workspace,
+        workspaces::list_workspaces,
+        workspaces::load_workspace,
+        workspaces::delete_workspace,
         commands::models::get_available_models,
         commands::models::get_model_info,
         commands::models::download_model,
         commands::models::delete_model,
         commands::models::cancel_download,
         commands::models::set_active_model,
+        commands::models::set_model_for_language,
+        commands::models::check_language_model_compatibility,
+        commands::models::benchmark_model,
         commands::models::get_current_model,
         commands::models::get_transcription_model_status,
         commands::models::is_model_loading,
@@ -441,18 +543,32 @@ pub fn run() {
         commands::audio::get_available_microphones,
         commands::audio::set_selected_microphone,
         commands::audio::get_selected_microphone,
+        commands::audio::test_microphone,
         commands::audio::get_available_output_devices,
         commands::audio::set_selected_output_device,
         commands::audio::get_selected_output_device,
+        commands::audio::set_output_device_fallbacks,
         commands::audio::play_test_sound,
         commands::audio::check_custom_sounds,
         commands::audio::set_clamshell_microphone,
         commands::audio::get_clamshell_microphone,
         commands::audio::is_recording,
         commands::audio::change_vad_threshold_setting,
+        commands::audio::change_non_speech_gate_setting,
+        commands::audio::change_audio_meter_hz_setting,
         commands::transcription::set_model_unload_timeout,
         commands::transcription::get_model_load_status,
+        commands::transcription::get_model_memory_usage,
         commands::transcription::unload_model_manually,
+        commands::transcription::get_acceleration_status,
+        commands::transcription::change_acceleration_setting,
+        commands::transcription::transcribe_samples_with_provider,
+        commands::transcription::transcribe_history_entry_with_provider,
+        commands::transcription::retranscribe_last,
+        commands::scratchpad::scratchpad_append,
+        commands::scratchpad::scratchpad_get,
+        commands::scratchpad::scratchpad_clear,
+        commands::scratchpad::scratchpad_paste,
         commands::history::get_history_entries,
         commands::history::toggle_history_entry_saved,
         commands::history::get_audio_file_path,
@@ -460,17 +576,27 @@ pub fn run() {
         commands::history::update_history_limit,
         commands::history::update_recording_retention_period,
         commands::history::get_latest_history_entry,
+        commands::history::simulate_rules_on_history,
+        commands::history::export_transcription_subtitles,
+        shortcut::change_history_store_segments_setting,
+        shortcut::change_discard_cancelled_recordings_setting,
+        shortcut::change_transcription_log_setting,
         commands::connector::connector_get_status,
+        commands::connector::diagnose_connector,
         commands::connector::connector_is_online,
         commands::connector::connector_start_server,
         commands::connector::connector_stop_server,
         commands::connector::connector_queue_message,
         commands::connector::connector_cancel_message,
+        commands::connector::ack_connector_message,
         commands::region_capture::region_capture_get_data,
         commands::region_capture::region_capture_confirm,
         commands::region_capture::region_capture_cancel,
         commands::voice_command::execute_voice_command,
+        commands::voice_command::cancel_voice_command_countdown,
+        commands::voice_command::pause_voice_command_countdown,
         commands::voice_command::test_voice_command_mock,
+        commands::voice_command::voice_command_score_matrix,
         commands::file_transcription::get_supported_audio_extensions,
         commands::file_transcription::transcribe_audio_file,
         commands::key_listener::key_listener_start,
@@ -544,6 +670,7 @@ pub fn run() {
         .manage(Mutex::new(ShortcutToggleStates::default()))
         .manage(Mutex::new(PressTimestamps::default()))
         .manage(Mutex::new(session_manager::SessionState::default()))
+        .manage(scratchpad::ScratchpadState::default())
         .manage(std::sync::Mutex::new(std::collections::HashSet::<String>::new()) as shortcut::RdevShortcutsSet)
         .manage(std::sync::Mutex::new(settings::ShortcutEngine::default()) as shortcut::ActiveShortcutEngine)
         .setup(move |app| {
@@ -1,7 +1,47 @@
 use crate::settings::PostProcessProvider;
 use log::{debug, info, warn};
+use once_cell::sync::Lazy;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, REFERER, USER_AGENT};
 use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Concurrent chat requests allowed per provider (`llm_max_concurrent_requests`)
+static MAX_CONCURRENT_REQUESTS: AtomicUsize = AtomicUsize::new(2);
+
+/// Request slots per provider id, with the limit each semaphore was created for
+static PROVIDER_SLOTS: Lazy<Mutex<HashMap<String, (usize, Arc<Semaphore>)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub fn set_max_concurrent_requests(limit: usize) {
+    MAX_CONCURRENT_REQUESTS.store(limit.max(1), Ordering::SeqCst);
+}
+
+/// Wait for a free request slot for the provider. When the limit changes the provider
+/// gets a fresh semaphore; requests holding permits of the old one finish normally.
+async fn acquire_request_slot(provider_id: &str) -> OwnedSemaphorePermit {
+    let limit = MAX_CONCURRENT_REQUESTS.load(Ordering::SeqCst);
+    let semaphore = {
+        let mut slots = PROVIDER_SLOTS.lock().unwrap();
+        let slot = slots
+            .entry(provider_id.to_string())
+            .or_insert_with(|| (limit, Arc::new(Semaphore::new(limit))));
+        if slot.0 != limit {
+            *slot = (limit, Arc::new(Semaphore::new(limit)));
+        }
+        slot.1.clone()
+    };
+    if semaphore.available_permits() == 0 {
+        debug!("Waiting for a free LLM request slot for {}", provider_id);
+    }
+    semaphore
+        .acquire_owned()
+        .await
+        .expect("LLM request semaphores are never closed")
+}
 
 /// Configuration for Extended Thinking / Reasoning (OpenRouter)
 #[derive(Debug, Clone, Default)]
@@ -26,11 +66,18 @@ struct ChatMessage {
 }
 
 /// Reasoning object for OpenRouter API
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize)]
 struct ReasoningParams {
     max_tokens: u32,
 }
 
+/// OpenAI-style `response_format` (JSON mode)
+#[derive(Debug, Serialize)]
+struct ResponseFormat {
+    #[serde(rename = "type")]
+    kind: &'static str,
+}
+
 #[derive(Debug, Serialize)]
 struct ChatCompletionRequest {
     model: String,
@@ -39,6 +86,25 @@ struct ChatCompletionRequest {
     max_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reasoning: Option<ReasoningParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+}
+
+/// Most providers (OpenAI included) accept at most 4 stop sequences
+const MAX_STOP_SEQUENCES: usize = 4;
+
+/// Stop sequences to send: escapes processed (`\n`), empty entries dropped, capped at 4.
+/// None when nothing is left, so the `stop` field is omitted.
+fn stop_param(stop: &[String]) -> Option<Vec<String>> {
+    let sequences: Vec<String> = stop
+        .iter()
+        .map(|s| crate::settings::TextReplacement::process_escapes(s))
+        .filter(|s| !s.is_empty())
+        .take(MAX_STOP_SEQUENCES)
+        .collect();
+    (!sequences.is_empty()).then_some(sequences)
 }
 
 #[derive(Debug, Deserialize)]
@@ -59,6 +125,27 @@ struct ChatMessageResponse {
     reasoning: Option<String>,
 }
 
+/// `anthropic-version` sent with Anthropic requests
+const ANTHROPIC_API_VERSION: &str = "2023-06-01";
+
+/// How a provider expects the API key to be sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AuthScheme {
+    /// `Authorization: Bearer <key>` (OpenAI and compatible APIs)
+    Bearer,
+    /// `x-api-key: <key>` plus `anthropic-version`
+    AnthropicApiKey,
+}
+
+/// The built-in Anthropic provider, or a custom one pointed at the Anthropic API
+fn auth_scheme(provider: &PostProcessProvider) -> AuthScheme {
+    if provider.id == "anthropic" || provider.base_url.contains("api.anthropic.com") {
+        AuthScheme::AnthropicApiKey
+    } else {
+        AuthScheme::Bearer
+    }
+}
+
 /// Build headers for API requests based on provider type
 fn build_headers(provider: &PostProcessProvider, api_key: &str) -> Result<HeaderMap, String> {
     let mut headers = HeaderMap::new();
@@ -77,22 +164,44 @@ fn build_headers(provider: &PostProcessProvider, api_key: &str) -> Result<Header
 
     // Provider-specific auth headers
     if !api_key.is_empty() {
-        if provider.id == "anthropic" {
-            headers.insert(
-                "x-api-key",
-                HeaderValue::from_str(api_key)
-                    .map_err(|e| format!("Invalid API key header value: {}", e))?,
-            );
-            headers.insert("anthropic-version", HeaderValue::from_static("2023-06-01"));
-        } else {
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", api_key))
-                    .map_err(|e| format!("Invalid authorization header value: {}", e))?,
-            );
+        match auth_scheme(provider) {
+            AuthScheme::AnthropicApiKey => {
+                headers.insert(
+                    "x-api-key",
+                    HeaderValue::from_str(api_key)
+                        .map_err(|e| format!("Invalid API key header value: {}", e))?,
+                );
+                headers.insert(
+                    "anthropic-version",
+                    HeaderValue::from_static(ANTHROPIC_API_VERSION),
+                );
+            }
+            AuthScheme::Bearer => {
+                headers.insert(
+                    AUTHORIZATION,
+                    HeaderValue::from_str(&format!("Bearer {}", api_key))
+                        .map_err(|e| format!("Invalid authorization header value: {}", e))?,
+                );
+            }
         }
     }
 
+    // Optional OpenAI org/project scoping (billing separation)
+    if let Some(org) = provider.organization.as_deref().filter(|v| !v.is_empty()) {
+        headers.insert(
+            "OpenAI-Organization",
+            HeaderValue::from_str(org)
+                .map_err(|e| format!("Invalid organization header value: {}", e))?,
+        );
+    }
+    if let Some(project) = provider.project.as_deref().filter(|v| !v.is_empty()) {
+        headers.insert(
+            "OpenAI-Project",
+            HeaderValue::from_str(project)
+                .map_err(|e| format!("Invalid project header value: {}", e))?,
+        );
+    }
+
     Ok(headers)
 }
 
@@ -105,13 +214,15 @@ fn create_client(provider: &PostProcessProvider, api_key: &str) -> Result<reqwes
         .map_err(|e| format!("Failed to build HTTP client: {}", e))
 }
 
-/// Send a chat completion with Extended Thinking / Reasoning support
+/// Send a chat completion with Extended Thinking / Reasoning support.
+/// `stop` sequences end generation early (empty = none).
 pub async fn send_chat_completion_with_reasoning(
     provider: &PostProcessProvider,
     api_key: String,
     model: &str,
     prompt: String,
     reasoning: ReasoningConfig,
+    stop: &[String],
 ) -> Result<Option<String>, String> {
     send_chat_completion_with_messages_internal(
         provider,
@@ -122,11 +233,14 @@ pub async fn send_chat_completion_with_reasoning(
             content: prompt,
         }],
         reasoning,
+        false,
+        stop_param(stop),
     )
     .await
 }
 
-/// Send a chat completion with system/user prompts and Extended Thinking support
+/// Send a chat completion with system/user prompts and Extended Thinking support.
+/// `stop` sequences end generation early (empty = none).
 pub async fn send_chat_completion_with_system_and_reasoning(
     provider: &PostProcessProvider,
     api_key: String,
@@ -134,7 +248,40 @@ pub async fn send_chat_completion_with_system_and_reasoning(
     system_prompt: String,
     user_prompt: String,
     reasoning: ReasoningConfig,
+    stop: &[String],
+) -> Result<Option<String>, String> {
+    let messages = system_user_messages(system_prompt, user_prompt);
+    send_chat_completion_with_messages_internal(
+        provider,
+        api_key,
+        model,
+        messages,
+        reasoning,
+        false,
+        stop_param(stop),
+    )
+    .await
+}
+
+/// Like `send_chat_completion_with_system_and_reasoning`, but requests JSON output
+/// (`response_format: json_object`). Providers that reject it with a 400 are retried
+/// without it, so callers must still handle non-JSON content.
+pub async fn send_chat_completion_json(
+    provider: &PostProcessProvider,
+    api_key: String,
+    model: &str,
+    system_prompt: String,
+    user_prompt: String,
+    reasoning: ReasoningConfig,
 ) -> Result<Option<String>, String> {
+    let messages = system_user_messages(system_prompt, user_prompt);
+    send_chat_completion_with_messages_internal(
+        provider, api_key, model, messages, reasoning, true, None,
+    )
+    .await
+}
+
+fn system_user_messages(system_prompt: String, user_prompt: String) -> Vec<ChatMessage> {
     let mut messages = Vec::new();
 
     if !system_prompt.trim().is_empty() {
@@ -149,17 +296,19 @@ pub async fn send_chat_completion_with_system_and_reasoning(
         content: user_prompt,
     });
 
-    send_chat_completion_with_messages_internal(provider, api_key, model, messages, reasoning).await
+    messages
 }
 
 /// Internal function that sends the actual chat completion request
-/// with optional reasoning and fail-soft retry
+/// with optional reasoning / JSON mode / stop sequences and fail-soft retry
 async fn send_chat_completion_with_messages_internal(
     provider: &PostProcessProvider,
     api_key: String,
     model: &str,
     messages: Vec<ChatMessage>,
     reasoning: ReasoningConfig,
+    json_mode: bool,
+    stop: Option<Vec<String>>,
 ) -> Result<Option<String>, String> {
     let base_url = provider.base_url.trim_end_matches('/');
     let url = format!("{}/chat/completions", base_url);
@@ -167,6 +316,8 @@ async fn send_chat_completion_with_messages_internal(
     debug!("Sending chat completion request to: {}", url);
 
     let client = create_client(provider, &api_key)?;
+    // Held until the response (and any retry) is done
+    let _slot = acquire_request_slot(&provider.id).await;
 
     // Calculate max_tokens: if reasoning is enabled, ensure enough room for answer
     // Formula: max(4000, reasoning_budget + 2000)
@@ -182,71 +333,60 @@ async fn send_chat_completion_with_messages_internal(
         (None, None)
     };
 
-    let request_body = ChatCompletionRequest {
-        model: model.to_string(),
-        messages: messages.clone(),
-        max_tokens,
-        reasoning: reasoning_params,
-    };
-
-    let response = client
-        .post(&url)
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("HTTP request failed: {}", e))?;
-
-    let status = response.status();
-
-    // Fail-soft retry: if we get 400 and reasoning was enabled, retry without reasoning
-    if status.as_u16() == 400 && reasoning.enabled {
-        let error_text = response
-            .text()
-            .await
-            .unwrap_or_else(|_| "Failed to read error response".to_string());
-
-        warn!(
-            "Extended Thinking request failed with 400, retrying without reasoning: {}",
-            error_text
-        );
-
-        // Retry without reasoning
-        let fallback_request = ChatCompletionRequest {
+    // Fail-soft retry: not every provider/model supports JSON mode, stop sequences or
+    // reasoning. On a 400, drop one of them (in that order) and retry until none are left.
+    let mut json_mode = json_mode;
+    let mut stop = stop;
+    let mut reasoning_params = reasoning_params;
+    let mut max_tokens = max_tokens;
+    let response = loop {
+        let request_body = ChatCompletionRequest {
             model: model.to_string(),
-            messages,
-            max_tokens: None,
-            reasoning: None,
+            messages: messages.clone(),
+            max_tokens,
+            reasoning: reasoning_params,
+            response_format: json_mode.then_some(ResponseFormat {
+                kind: "json_object",
+            }),
+            stop: stop.clone(),
         };
 
-        let fallback_response = client
+        let response = client
             .post(&url)
-            .json(&fallback_request)
+            .json(&request_body)
             .send()
             .await
-            .map_err(|e| format!("HTTP request failed (fallback): {}", e))?;
-
-        let fallback_status = fallback_response.status();
-        if !fallback_status.is_success() {
-            let fallback_error = fallback_response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Failed to read error response".to_string());
-            return Err(format!(
-                "API request failed with status {}: {}",
-                fallback_status, fallback_error
-            ));
+            .map_err(|e| format!("HTTP request failed: {}", e))?;
+
+        if response.status().as_u16() != 400 {
+            break response;
         }
 
-        let completion: ChatCompletionResponse = fallback_response
-            .json()
+        let dropped = if json_mode {
+            json_mode = false;
+            "JSON mode"
+        } else if stop.is_some() {
+            stop = None;
+            "stop sequences"
+        } else if reasoning_params.is_some() {
+            reasoning_params = None;
+            max_tokens = None;
+            "reasoning"
+        } else {
+            break response;
+        };
+
+        let error_text = response
+            .text()
             .await
-            .map_err(|e| format!("Failed to parse API response: {}", e))?;
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+        warn!(
+            "Request failed with 400, retrying without {}: {}",
+            dropped, error_text
+        );
+    };
 
-        return Ok(completion
-            .choices
-            .first()
-            .and_then(|choice| choice.message.content.clone()));
-    }
+    let status = response.status();
 
     if !status.is_success() {
         let error_text = response
@@ -286,24 +426,60 @@ async fn send_chat_completion_with_messages_internal(
         .and_then(|choice| choice.message.content.clone()))
 }
 
+/// Why a provider's model list couldn't be fetched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelListUnavailableReason {
+    /// The provider has no `models_endpoint` configured
+    NoEndpoint,
+    /// The endpoint errored (404, auth, network) or returned an unrecognized shape
+    EndpointFailed,
+}
+
+/// Error of `fetch_models`, also the payload of `llm-models-unavailable`
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ModelListUnavailable {
+    pub provider_id: String,
+    pub reason: ModelListUnavailableReason,
+    pub message: String,
+}
+
 /// Fetch available models from an OpenAI-compatible API
 /// Returns a list of model IDs
 pub async fn fetch_models(
     provider: &PostProcessProvider,
     api_key: String,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, ModelListUnavailable> {
+    let failed = |message: String| ModelListUnavailable {
+        provider_id: provider.id.clone(),
+        reason: ModelListUnavailableReason::EndpointFailed,
+        message,
+    };
+
+    let Some(endpoint) = provider
+        .models_endpoint
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+    else {
+        return Err(ModelListUnavailable {
+            provider_id: provider.id.clone(),
+            reason: ModelListUnavailableReason::NoEndpoint,
+            message: format!("{} has no model list endpoint", provider.label),
+        });
+    };
     let base_url = provider.base_url.trim_end_matches('/');
-    let url = format!("{}/models", base_url);
+    let url = format!("{}/{}", base_url, endpoint.trim_start_matches('/'));
 
     debug!("Fetching models from: {}", url);
 
-    let client = create_client(provider, &api_key)?;
+    let client = create_client(provider, &api_key).map_err(failed)?;
 
     let response = client
         .get(&url)
         .send()
         .await
-        .map_err(|e| format!("Failed to fetch models: {}", e))?;
+        .map_err(|e| failed(format!("Failed to fetch models: {}", e)))?;
 
     let status = response.status();
     if !status.is_success() {
@@ -311,37 +487,130 @@ pub async fn fetch_models(
             .text()
             .await
             .unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!(
+        return Err(failed(format!(
             "Model list request failed ({}): {}",
             status, error_text
-        ));
+        )));
     }
 
     let parsed: serde_json::Value = response
         .json()
         .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+        .map_err(|e| failed(format!("Failed to parse response: {}", e)))?;
 
-    let mut models = Vec::new();
+    parse_model_list(&parsed)
+        .ok_or_else(|| failed("Model list response is not in a recognized format".to_string()))
+}
 
+/// Model ids from `{ data: [ { id } | { name } ] }` or `[ "model", ... ]`.
+/// None when the response has neither shape.
+fn parse_model_list(parsed: &serde_json::Value) -> Option<Vec<String>> {
     // Handle OpenAI format: { data: [ { id: "..." }, ... ] }
     if let Some(data) = parsed.get("data").and_then(|d| d.as_array()) {
-        for entry in data {
-            if let Some(id) = entry.get("id").and_then(|i| i.as_str()) {
-                models.push(id.to_string());
-            } else if let Some(name) = entry.get("name").and_then(|n| n.as_str()) {
-                models.push(name.to_string());
-            }
-        }
+        let models = data
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .get("id")
+                    .or_else(|| entry.get("name"))
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            })
+            .collect();
+        return Some(models);
     }
     // Handle array format: [ "model1", "model2", ... ]
-    else if let Some(array) = parsed.as_array() {
-        for entry in array {
-            if let Some(model) = entry.as_str() {
-                models.push(model.to_string());
-            }
+    let array = parsed.as_array()?;
+    Some(
+        array
+            .iter()
+            .filter_map(|entry| entry.as_str().map(str::to_string))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider(id: &str, base_url: &str) -> PostProcessProvider {
+        PostProcessProvider {
+            id: id.to_string(),
+            label: id.to_string(),
+            base_url: base_url.to_string(),
+            allow_base_url_edit: false,
+            models_endpoint: None,
+            organization: None,
+            project: None,
         }
     }
 
-    Ok(models)
+    #[test]
+    fn anthropic_uses_api_key_and_version_headers() {
+        let headers = build_headers(
+            &provider("anthropic", "https://api.anthropic.com/v1"),
+            "sk-ant",
+        )
+        .unwrap();
+        assert_eq!(headers.get("x-api-key").unwrap(), "sk-ant");
+        assert_eq!(
+            headers.get("anthropic-version").unwrap(),
+            ANTHROPIC_API_VERSION
+        );
+        assert!(headers.get(AUTHORIZATION).is_none());
+
+        // A custom provider pointed at the Anthropic API authenticates the same way
+        let custom = provider("custom", "https://api.anthropic.com/v1/");
+        assert_eq!(auth_scheme(&custom), AuthScheme::AnthropicApiKey);
+    }
+
+    #[test]
+    fn other_providers_use_bearer_auth() {
+        let headers =
+            build_headers(&provider("openai", "https://api.openai.com/v1"), "sk-oai").unwrap();
+        assert_eq!(headers.get(AUTHORIZATION).unwrap(), "Bearer sk-oai");
+        assert!(headers.get("x-api-key").is_none());
+        assert!(headers.get("anthropic-version").is_none());
+
+        // No key, no auth header (local servers)
+        let headers = build_headers(&provider("custom", "http://localhost:11434/v1"), "").unwrap();
+        assert!(headers.get(AUTHORIZATION).is_none());
+    }
+
+    #[test]
+    fn parses_openai_and_plain_model_lists() {
+        let openai = serde_json::json!({ "data": [{ "id": "gpt-4o" }, { "name": "llama3" }] });
+        assert_eq!(
+            parse_model_list(&openai),
+            Some(vec!["gpt-4o".to_string(), "llama3".to_string()])
+        );
+        let plain = serde_json::json!(["a", "b"]);
+        assert_eq!(
+            parse_model_list(&plain),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        // Non-standard shapes are reported, not treated as an empty list
+        let odd = serde_json::json!({ "models": ["a"] });
+        assert_eq!(parse_model_list(&odd), None);
+    }
+
+    #[test]
+    fn stop_param_processes_escapes_and_caps_at_four() {
+        assert_eq!(stop_param(&[]), None);
+        assert_eq!(stop_param(&[String::new()]), None);
+
+        let stop: Vec<String> = ["\\n\\n", "", "END", "a", "b", "c"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            stop_param(&stop),
+            Some(vec![
+                "\n\n".to_string(),
+                "END".to_string(),
+                "a".to_string(),
+                "b".to_string(),
+            ])
+        );
+    }
 }
@@ -0,0 +1,131 @@
+//! LLM Output Sanitizer
+//!
+//! LLMs often wrap their answer in a markdown code fence or quotes despite the prompt,
+//! and that gets pasted literally. With `strip_llm_formatting` on, post-processing and
+//! AI Replace output is unwrapped before paste. The heuristic is conservative: a fence
+//! is only removed when the entire output is one fenced block, and never when the
+//! instruction asked for code or the fence is tagged with a programming language.
+
+/// Fence info strings that mark prose rather than code
+const PROSE_FENCE_TAGS: &[&str] = &["", "text", "txt", "plaintext", "plain", "markdown", "md"];
+
+/// Words in an instruction that suggest the user wants code (and its fences) back.
+/// Matched as whole words (or their plural), so "transcript" doesn't count as "script".
+const CODE_REQUEST_WORDS: &[&str] = &["code", "snippet", "script", "function"];
+
+/// Quote pairs removed when they wrap the whole output
+const QUOTE_PAIRS: &[(char, char)] = &[('"', '"'), ('\'', '\''), ('“', '”'), ('«', '»')];
+
+/// Whether the instruction or prompt asks for code, in which case fences are kept
+pub fn asks_for_code(instruction: &str) -> bool {
+    if instruction.contains("```") {
+        return true;
+    }
+    instruction
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .any(|word| {
+            let singular = word.strip_suffix('s').unwrap_or(&word);
+            CODE_REQUEST_WORDS.contains(&word.as_str()) || CODE_REQUEST_WORDS.contains(&singular)
+        })
+}
+
+/// Strip a fence wrapping the whole output and then quotes wrapping the whole output.
+/// Returns the text unchanged when nothing wraps it.
+pub fn strip_llm_formatting(text: &str, keep_code_fences: bool) -> String {
+    let trimmed = text.trim();
+    let unfenced = if keep_code_fences {
+        None
+    } else {
+        strip_single_fence(trimmed)
+    };
+    let body = unfenced.unwrap_or(trimmed);
+    strip_wrapping_quotes(body).unwrap_or(body).to_string()
+}
+
+/// Body of the output when it is exactly one fenced block with a prose (or no) tag
+fn strip_single_fence(text: &str) -> Option<&str> {
+    let rest = text.strip_prefix("```")?;
+    let (tag, rest) = rest.split_once('\n')?;
+    if !PROSE_FENCE_TAGS.contains(&tag.trim().to_lowercase().as_str()) {
+        return None;
+    }
+    let body = rest.trim_end().strip_suffix("```")?;
+    // A fence inside means several blocks (or prose around them), not a single wrapper
+    if body.contains("```") {
+        return None;
+    }
+    Some(body.trim())
+}
+
+/// Body of the output when a matching quote pair wraps it and doesn't occur inside
+fn strip_wrapping_quotes(text: &str) -> Option<&str> {
+    QUOTE_PAIRS.iter().find_map(|&(open, close)| {
+        let body = text.strip_prefix(open)?.strip_suffix(close)?;
+        if body.is_empty() || body.contains(open) || body.contains(close) {
+            return None;
+        }
+        Some(body.trim())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_a_single_untagged_or_prose_fence() {
+        assert_eq!(
+            strip_llm_formatting("```\nHello there.\n```", false),
+            "Hello there."
+        );
+        assert_eq!(
+            strip_llm_formatting("  ```text\nLine one\nLine two\n```\n", false),
+            "Line one\nLine two"
+        );
+    }
+
+    #[test]
+    fn keeps_code_and_partial_fences() {
+        let tagged = "```rust\nfn main() {}\n```";
+        assert_eq!(strip_llm_formatting(tagged, false), tagged);
+        let untagged = "```\nlet x = 1;\n```";
+        assert_eq!(strip_llm_formatting(untagged, true), untagged);
+        let two_blocks = "```\na\n```\nand\n```\nb\n```";
+        assert_eq!(strip_llm_formatting(two_blocks, false), two_blocks);
+        let with_prose = "Here you go:\n```\nHello\n```";
+        assert_eq!(strip_llm_formatting(with_prose, false), with_prose);
+    }
+
+    #[test]
+    fn strips_wrapping_quotes_only() {
+        assert_eq!(
+            strip_llm_formatting("\"Hello, world.\"", false),
+            "Hello, world."
+        );
+        assert_eq!(strip_llm_formatting("“Hi”", false), "Hi");
+        assert_eq!(strip_llm_formatting("```\n\"Hi\"\n```", false), "Hi");
+        let inner = "\"Yes,\" she said, \"fine.\"";
+        assert_eq!(strip_llm_formatting(inner, false), inner);
+        assert_eq!(strip_llm_formatting("It's Bob's", false), "It's Bob's");
+    }
+
+    #[test]
+    fn detects_code_requests() {
+        assert!(asks_for_code("Write a Python function that sorts a list"));
+        assert!(asks_for_code("Convert this to a bash script"));
+        assert!(!asks_for_code("Fix grammar and punctuation"));
+        assert!(asks_for_code("Keep the ``` fences"));
+    }
+
+    #[test]
+    fn default_prompt_does_not_ask_for_code() {
+        // "transcript" contains "script" but isn't a request for code
+        let settings = crate::settings::get_default_settings();
+        assert!(settings
+            .post_process_prompts
+            .iter()
+            .all(|prompt| !asks_for_code(&prompt.prompt)));
+        assert!(!asks_for_code("Return the transcripts only"));
+    }
+}
@@ -1,11 +1,17 @@
 use crate::audio_toolkit::{list_input_devices, vad::SmoothedVad, AudioRecorder, SileroVad};
+use crate::commands::audio::{
+    get_available_microphones, get_available_output_devices, AudioDevice,
+};
 use crate::helpers::clamshell;
 use crate::settings::{get_settings, AppSettings};
 use crate::utils;
 use log::{debug, error, info};
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
-use tauri::Manager;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
 
 fn set_mute(mute: bool) {
     // Expected behavior:
@@ -112,8 +118,206 @@ pub enum MicrophoneMode {
     OnDemand,
 }
 
+/// Samples at or above this magnitude are counted as clipped
+const CLIPPING_THRESHOLD: f32 = 0.999;
+
+/// Result of a microphone capture test (nothing is saved or transcribed)
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct MicTestResult {
+    pub device_name: String,
+    pub duration_ms: u32,
+    pub sample_count: u32,
+    pub peak: f32,
+    pub rms: f32,
+    pub peak_dbfs: f32,
+    pub rms_dbfs: f32,
+    pub clipped_samples: u32,
+    pub clipping: bool,
+}
+
+fn to_dbfs(level: f32) -> f32 {
+    if level <= 0.0 {
+        -100.0
+    } else {
+        (20.0 * level.log10()).max(-100.0)
+    }
+}
+
+/// Compute peak / RMS / clipping stats for a capture buffer.
+pub fn compute_mic_levels(device_name: String, samples: &[f32]) -> MicTestResult {
+    let peak = samples.iter().fold(0.0f32, |acc, s| acc.max(s.abs()));
+    let rms = if samples.is_empty() {
+        0.0
+    } else {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    };
+    let clipped_samples = samples
+        .iter()
+        .filter(|s| s.abs() >= CLIPPING_THRESHOLD)
+        .count() as u32;
+
+    MicTestResult {
+        device_name,
+        duration_ms: (samples.len() as u64 * 1000 / WHISPER_SAMPLE_RATE as u64) as u32,
+        sample_count: samples.len() as u32,
+        peak,
+        rms,
+        peak_dbfs: to_dbfs(peak),
+        rms_dbfs: to_dbfs(rms),
+        clipped_samples,
+        clipping: clipped_samples > 0,
+    }
+}
+
+/// Window used by the non-speech gate (30 ms at 16 kHz)
+const SPEECH_ENERGY_WINDOW: usize = WHISPER_SAMPLE_RATE * 3 / 100;
+
+/// Bounds for `speech_energy_threshold_dbfs`
+pub const SPEECH_ENERGY_MIN_DBFS: f32 = -90.0;
+pub const SPEECH_ENERGY_MAX_DBFS: f32 = -20.0;
+
+/// Whether any 30 ms window of the (VAD-filtered) recording reaches `threshold_dbfs`.
+/// A single loud-enough window counts, so quiet-but-real speech is kept.
+pub fn has_speech_energy(samples: &[f32], threshold_dbfs: f32) -> bool {
+    samples.chunks(SPEECH_ENERGY_WINDOW).any(|window| {
+        let rms = (window.iter().map(|s| s * s).sum::<f32>() / window.len() as f32).sqrt();
+        to_dbfs(rms) >= threshold_dbfs
+    })
+}
+
+/* ──────────────────────────────────────────────────────────────── */
+
+/// Bounds for `audio_meter_hz`
+pub const AUDIO_METER_MIN_HZ: u32 = 1;
+pub const AUDIO_METER_MAX_HZ: u32 = 60;
+
+/// Time for a held meter peak to fall to half its value
+const METER_PEAK_HALF_LIFE: Duration = Duration::from_millis(150);
+
+/// Current meter emission rate, read by the level callback on every spectrum frame
+static AUDIO_METER_HZ: AtomicU32 = AtomicU32::new(20);
+
+/// Set the `mic-level` emission rate (clamped to 1-60 Hz); applies immediately
+pub fn set_audio_meter_hz(hz: u32) {
+    AUDIO_METER_HZ.store(
+        hz.clamp(AUDIO_METER_MIN_HZ, AUDIO_METER_MAX_HZ),
+        Ordering::Relaxed,
+    );
+}
+
+/// Throttles spectrum levels to the meter rate. Between emissions each bucket keeps
+/// a decaying peak, so short bursts still show up and the meter stays smooth at low rates.
+struct LevelMeter {
+    peaks: Vec<f32>,
+    last_update: Instant,
+    last_emit: Option<Instant>,
+}
+
+impl LevelMeter {
+    fn new() -> Self {
+        Self {
+            peaks: Vec::new(),
+            last_update: Instant::now(),
+            last_emit: None,
+        }
+    }
+
+    /// Feed a spectrum frame; returns the levels to emit when the interval has elapsed
+    fn push(&mut self, levels: &[f32], now: Instant, hz: u32) -> Option<Vec<f32>> {
+        let elapsed = now.saturating_duration_since(self.last_update);
+        let decay = 0.5f32.powf(elapsed.as_secs_f32() / METER_PEAK_HALF_LIFE.as_secs_f32());
+        self.last_update = now;
+
+        self.peaks.resize(levels.len(), 0.0);
+        for (peak, &level) in self.peaks.iter_mut().zip(levels) {
+            *peak = level.max(*peak * decay);
+        }
+
+        let interval = Duration::from_secs_f32(1.0 / hz.max(AUDIO_METER_MIN_HZ) as f32);
+        let due = self
+            .last_emit
+            .map_or(true, |last| now.saturating_duration_since(last) >= interval);
+        if !due {
+            return None;
+        }
+        self.last_emit = Some(now);
+        Some(self.peaks.clone())
+    }
+}
+
 /* ──────────────────────────────────────────────────────────────── */
 
+/// How often the device watcher enumerates input/output devices while the settings
+/// window is open
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Pause between re-enumerations while a changed device list settles
+const DEVICE_SETTLE_DELAY: Duration = Duration::from_millis(500);
+
+/// Payload of the `devices-changed` event: the lists the device commands would return
+#[derive(Clone, Debug, PartialEq, Serialize, Type)]
+pub struct DevicesChanged {
+    pub inputs: Vec<AudioDevice>,
+    pub outputs: Vec<AudioDevice>,
+}
+
+/// Current device lists; None if enumeration failed (no change is reported then)
+fn enumerate_devices() -> Option<DevicesChanged> {
+    Some(DevicesChanged {
+        inputs: get_available_microphones().ok()?,
+        outputs: get_available_output_devices().ok()?,
+    })
+}
+
+/// The device pickers only live in the settings window, so there is nothing to
+/// refresh while it is hidden or minimized
+fn settings_window_visible(app_handle: &tauri::AppHandle) -> bool {
+    app_handle.get_webview_window("main").is_some_and(|window| {
+        window.is_visible().unwrap_or(false) && !window.is_minimized().unwrap_or(false)
+    })
+}
+
+/// Poll the device lists while the settings window is open and emit `devices-changed`
+/// when they change (a change made while it was hidden is reported once it reappears).
+/// Plugging in a device often enumerates in bursts, so a change is only reported once
+/// two consecutive enumerations agree.
+fn start_device_watcher(app_handle: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut last = enumerate_devices();
+        'poll: loop {
+            std::thread::sleep(DEVICE_POLL_INTERVAL);
+            if !settings_window_visible(&app_handle) {
+                continue;
+            }
+            let Some(mut current) = enumerate_devices() else {
+                continue;
+            };
+            if last.as_ref() == Some(&current) {
+                continue;
+            }
+            loop {
+                std::thread::sleep(DEVICE_SETTLE_DELAY);
+                match enumerate_devices() {
+                    Some(settled) if settled == current => break,
+                    Some(settled) => current = settled,
+                    // Enumeration failed mid-settle: start over from the next poll
+                    None => continue 'poll,
+                }
+            }
+            if last.as_ref() == Some(&current) {
+                continue;
+            }
+
+            info!(
+                "Audio devices changed: {} inputs, {} outputs",
+                current.inputs.len(),
+                current.outputs.len()
+            );
+            let _ = app_handle.emit("devices-changed", &current);
+            last = Some(current);
+        }
+    });
+}
+
 fn create_audio_recorder(
     vad_path: &str,
     app_handle: &tauri::AppHandle,
@@ -130,8 +334,13 @@ fn create_audio_recorder(
         .with_vad(Box::new(smoothed_vad))
         .with_level_callback({
             let app_handle = app_handle.clone();
+            let meter = Mutex::new(LevelMeter::new());
             move |levels| {
-                utils::emit_levels(&app_handle, &levels);
+                let hz = AUDIO_METER_HZ.load(Ordering::Relaxed);
+                let emitted = meter.lock().unwrap().push(&levels, Instant::now(), hz);
+                if let Some(levels) = emitted {
+                    utils::emit_levels(&app_handle, &levels);
+                }
             }
         });
 
@@ -150,6 +359,8 @@ pub struct AudioRecordingManager {
     is_open: Arc<Mutex<bool>>,
     is_recording: Arc<Mutex<bool>>,
     did_mute: Arc<Mutex<bool>>,
+    /// Threshold the recorder uses (or will be created with); profiles may override it
+    vad_threshold: Arc<Mutex<f32>>,
 }
 
 impl AudioRecordingManager {
@@ -162,6 +373,7 @@ impl AudioRecordingManager {
         } else {
             MicrophoneMode::OnDemand
         };
+        set_audio_meter_hz(settings.audio_meter_hz);
 
         let manager = Self {
             state: Arc::new(Mutex::new(RecordingState::Idle)),
@@ -172,6 +384,7 @@ impl AudioRecordingManager {
             is_open: Arc::new(Mutex::new(false)),
             is_recording: Arc::new(Mutex::new(false)),
             did_mute: Arc::new(Mutex::new(false)),
+            vad_threshold: Arc::new(Mutex::new(settings.vad_threshold)),
         };
 
         // Always-on?  Open immediately.
@@ -179,6 +392,8 @@ impl AudioRecordingManager {
             manager.start_microphone_stream()?;
         }
 
+        start_device_watcher(app.clone());
+
         Ok(manager)
     }
 
@@ -265,7 +480,7 @@ impl AudioRecordingManager {
             *recorder_opt = Some(create_audio_recorder(
                 vad_path.to_str().unwrap(),
                 &self.app_handle,
-                settings.vad_threshold,
+                *self.vad_threshold.lock().unwrap(),
             )?);
         }
 
@@ -425,16 +640,21 @@ impl AudioRecordingManager {
     }
 
     /// Cancel any ongoing recording without returning audio samples
-    pub fn cancel_recording(&self) {
+    /// Stops an active recording without transcribing it.
+    /// Returns the captured samples so the caller can decide whether to keep them.
+    pub fn cancel_recording(&self) -> Option<Vec<f32>> {
         let mut state = self.state.lock().unwrap();
 
         if let RecordingState::Recording { .. } = *state {
             *state = RecordingState::Idle;
             drop(state);
 
-            if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
-                let _ = rec.stop(); // Discard the result
-            }
+            let samples = self
+                .recorder
+                .lock()
+                .unwrap()
+                .as_ref()
+                .and_then(|rec| rec.stop().ok());
 
             *self.is_recording.lock().unwrap() = false;
 
@@ -442,11 +662,129 @@ impl AudioRecordingManager {
             if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
                 self.stop_microphone_stream();
             }
+
+            samples
+        } else {
+            None
         }
     }
+    /// Capture from a device for `duration` and report input levels.
+    /// `device_id` matches a device index or name; None/"default" uses the effective
+    /// microphone from settings. Uses a separate recorder without VAD so every
+    /// sample is measured, and never touches the main recording state.
+    pub fn test_microphone(
+        &self,
+        device_id: Option<String>,
+        duration: Duration,
+    ) -> Result<MicTestResult, anyhow::Error> {
+        if self.is_recording() {
+            return Err(anyhow::anyhow!(
+                "Cannot test the microphone while recording"
+            ));
+        }
+
+        let settings = get_settings(&self.app_handle);
+        let device = match device_id.as_deref().filter(|id| *id != "default") {
+            Some(id) => Some(
+                list_input_devices()
+                    .map_err(|e| anyhow::anyhow!("Failed to list audio devices: {}", e))?
+                    .into_iter()
+                    .find(|d| d.index == id || d.name == id)
+                    .map(|d| d.device)
+                    .ok_or_else(|| anyhow::anyhow!("Microphone not found: {}", id))?,
+            ),
+            None => self.get_effective_microphone_device(&settings),
+        };
+        let device_name = device
+            .as_ref()
+            .and_then(|d| cpal::traits::DeviceTrait::name(d).ok())
+            .unwrap_or_else(|| "Default".to_string());
+
+        let mut recorder = AudioRecorder::new()
+            .map_err(|e| anyhow::anyhow!("Failed to create AudioRecorder: {}", e))?;
+        recorder
+            .open(device)
+            .map_err(|e| anyhow::anyhow!("Failed to open microphone: {}", e))?;
+
+        let result = recorder
+            .start()
+            .and_then(|_| {
+                std::thread::sleep(duration);
+                recorder.stop()
+            })
+            .map_err(|e| anyhow::anyhow!("Microphone capture failed: {}", e));
+        let _ = recorder.close();
+
+        let samples = result?;
+        debug!(
+            "Microphone test captured {} samples from {}",
+            samples.len(),
+            device_name
+        );
+        Ok(compute_mic_levels(device_name, &samples))
+    }
+
     pub fn update_vad_threshold(&self, threshold: f32) {
+        *self.vad_threshold.lock().unwrap() = threshold;
         if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
             rec.set_vad_threshold(threshold);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speech_energy_gate_needs_one_loud_window() {
+        assert!(!has_speech_energy(&[], -55.0));
+        // Zero padding added to short recordings is silence
+        assert!(!has_speech_energy(&vec![0.0; 20_000], -55.0));
+        // Faint hiss around -70 dBFS
+        assert!(!has_speech_energy(&vec![0.0003; 16_000], -55.0));
+
+        // Quiet speech (~-40 dBFS) in one 30 ms stretch of an otherwise silent buffer
+        let mut samples = vec![0.0; 16_000];
+        samples[8_000..8_480].fill(0.01);
+        assert!(has_speech_energy(&samples, -55.0));
+    }
+
+    #[test]
+    fn mic_levels_report_peak_rms_and_clipping() {
+        let mut samples = vec![0.5; 16_000];
+        samples[0] = 1.0;
+        samples[1] = -1.0;
+        let result = compute_mic_levels("Mic".to_string(), &samples);
+        assert_eq!(result.duration_ms, 1000);
+        assert_eq!(result.peak, 1.0);
+        assert_eq!(result.peak_dbfs, 0.0);
+        assert!((result.rms_dbfs - -6.02).abs() < 0.05);
+        assert_eq!(result.clipped_samples, 2);
+        assert!(result.clipping);
+
+        let silent = compute_mic_levels("Mic".to_string(), &[]);
+        assert_eq!(silent.rms, 0.0);
+        assert_eq!(silent.peak_dbfs, -100.0);
+        assert!(!silent.clipping);
+    }
+
+    #[test]
+    fn level_meter_throttles_and_holds_decaying_peak() {
+        let mut meter = LevelMeter::new();
+        let start = Instant::now();
+
+        assert_eq!(meter.push(&[0.8], start, 10), Some(vec![0.8]));
+        // Within the 100ms interval: nothing emitted, but the peak is kept
+        assert_eq!(
+            meter.push(&[0.1], start + Duration::from_millis(50), 10),
+            None
+        );
+
+        let levels = meter
+            .push(&[0.0], start + Duration::from_millis(150), 10)
+            .unwrap();
+        // One half-life after the burst the peak has halved
+        assert!((levels[0] - 0.4).abs() < 0.01);
+    }
+}
@@ -6,9 +6,9 @@
 //! Supports long-polling: extension can send `wait=N` query parameter to hold
 //! the connection open for up to N seconds waiting for new messages.
 
-use crate::settings::{default_connector_password, get_settings, write_settings};
+use crate::settings::{default_connector_password, get_settings, write_settings, AppSettings};
 use axum::{
-    body::Body,
+    body::{Body, Bytes},
     extract::{Path, Query, State},
     http::{header, Method, StatusCode},
     response::{IntoResponse, Response},
@@ -27,7 +27,7 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter};
 use tokio::net::TcpListener;
 use tokio::sync::{Notify, RwLock};
-use tower_http::cors::{Any, CorsLayer};
+use tower_http::cors::{AllowOrigin, CorsLayer};
 
 /// Default server port (same as test-server.ps1)
 const DEFAULT_PORT: u16 = 38243;
@@ -44,6 +44,10 @@ const BLOB_EXPIRY_MS: i64 = 300_000;
 const MAX_WAIT_SECONDS: u32 = 30;
 /// Default long-poll wait (0 = immediate response for backward compat)
 const DEFAULT_WAIT_SECONDS: u32 = 0;
+/// Ports to try (port, port+1, ...) when `connector_port_auto_fallback` is enabled
+const PORT_FALLBACK_ATTEMPTS: u16 = 10;
+/// A pending password the extension was offered this long ago without acking is stuck
+const PENDING_PASSWORD_STUCK_MS: i64 = 60_000;
 
 /// Extension connection status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
@@ -71,6 +75,65 @@ pub struct ConnectorStatus {
     pub server_error: Option<String>,
 }
 
+/// Payload for `connector-port-changed` (port auto-fallback picked a new port)
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ConnectorPortChanged {
+    pub previous_port: u16,
+    pub port: u16,
+}
+
+/// Payload for `connector-bind-failed`
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ConnectorBindFailed {
+    pub port: u16,
+    /// Number of ports tried (1 when auto-fallback is off)
+    pub attempts: u16,
+    pub error: String,
+}
+
+/// Outcome of a single connector health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosisLevel {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// One connector health check with a remediation hint when it didn't pass
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct DiagnosisItem {
+    /// Stable check id: server, port, password, extension, password_handshake
+    pub check: String,
+    pub level: DiagnosisLevel,
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+/// Result of `diagnose_connector`
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ConnectorDiagnosis {
+    pub items: Vec<DiagnosisItem>,
+    /// No check failed (warnings allowed)
+    pub ok: bool,
+}
+
+/// Connector state gathered for diagnosis
+struct DiagnosisSnapshot {
+    server_running: bool,
+    server_error: Option<String>,
+    port: u16,
+    /// Bind probe result when the server isn't running (None when it is)
+    port_probe: Option<Result<(), String>>,
+    password_empty: bool,
+    password_default: bool,
+    pending_password: bool,
+    /// When the pending password was first offered to the extension, 0 if not yet
+    password_offered_at: i64,
+    last_poll_at: i64,
+    now: i64,
+}
+
 /// A message in the queue to be sent to extension
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueuedMessage {
@@ -110,10 +173,20 @@ pub struct BundleFetch {
     pub expires_at: Option<i64>,
 }
 
+/// Where a blob's bytes come from
+#[derive(Debug, Clone)]
+pub enum BlobSource {
+    /// Bytes produced in memory (e.g. a transcoded screenshot)
+    Memory(Bytes),
+    /// A file on disk, read on demand when the extension fetches it
+    File(PathBuf),
+}
+
 /// A blob stored for serving to extension
 #[derive(Debug, Clone)]
 pub struct PendingBlob {
-    pub data: Vec<u8>,
+    pub source: BlobSource,
+    pub size: u64,
     pub mime_type: String,
     pub expires_at: i64,
 }
@@ -196,6 +269,8 @@ struct AppState {
     port: Arc<RwLock<u16>>,
     /// Notify waiters when a new message is queued
     message_notify: Arc<Notify>,
+    /// When the pending password was first offered to the extension, 0 if not yet
+    password_offered_at: Arc<AtomicI64>,
 }
 
 pub struct ConnectorManager {
@@ -214,6 +289,10 @@ pub struct ConnectorManager {
     message_notify: Arc<Notify>,
     /// Last server error (e.g., port binding failure)
     server_error: Arc<RwLock<Option<String>>>,
+    /// When the pending password was first offered to the extension, 0 if not yet
+    password_offered_at: Arc<AtomicI64>,
+    /// CORS allowlist (empty = any origin), cached so requests don't load settings
+    allowed_origins: Arc<std::sync::RwLock<Vec<String>>>,
 }
 
 impl ConnectorManager {
@@ -241,11 +320,29 @@ impl ConnectorManager {
             stop_flag: Arc::new(AtomicBool::new(false)),
             message_notify: Arc::new(Notify::new()),
             server_error: Arc::new(RwLock::new(None)),
+            password_offered_at: Arc::new(AtomicI64::new(0)),
+            allowed_origins: Arc::new(std::sync::RwLock::new(
+                settings.connector_allowed_origins.clone(),
+            )),
         };
 
         Ok(manager)
     }
 
+    /// Forget when the pending password was offered. Call whenever the pending password
+    /// changes, so the stuck-password diagnosis times the new offer.
+    pub fn reset_password_offer(&self) {
+        self.password_offered_at.store(0, Ordering::SeqCst);
+    }
+
+    /// Replace the CORS allowlist; applies to the next request
+    pub fn set_allowed_origins(&self, origins: Vec<String>) {
+        *self
+            .allowed_origins
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = origins;
+    }
+
     /// Start the HTTP server in a background task
     pub fn start_server(&self) -> Result<(), String> {
         if self.server_running.load(Ordering::SeqCst) {
@@ -274,6 +371,7 @@ impl ConnectorManager {
             last_poll_at: Arc::clone(&self.last_poll_at),
             port: self.port.clone(),
             message_notify: self.message_notify.clone(),
+            password_offered_at: self.password_offered_at.clone(),
         };
 
         let stop_flag = self.stop_flag.clone();
@@ -282,6 +380,15 @@ impl ConnectorManager {
         let last_poll_at = self.last_poll_at.clone();
         let state = self.state.clone();
         let server_error = self.server_error.clone();
+        let port_lock = self.port.clone();
+        let allowed_origins = self.allowed_origins.clone();
+        let attempts = if get_settings(&self.app_handle).connector_port_auto_fallback {
+            (u16::MAX - port)
+                .saturating_add(1)
+                .min(PORT_FALLBACK_ATTEMPTS)
+        } else {
+            1
+        };
 
         tauri::async_runtime::spawn(async move {
             info!("Connector server starting on port {}", port);
@@ -289,9 +396,21 @@ impl ConnectorManager {
             // Emit initial status
             let _ = app_handle.emit("extension-status-changed", ExtensionStatus::Unknown);
 
-            // Build router with CORS
+            // Build router with CORS. The cached allowlist is read per request so edits
+            // apply without restarting the server; an empty list allows any origin.
             let cors = CorsLayer::new()
-                .allow_origin(Any)
+                .allow_origin(AllowOrigin::predicate(move |origin, _| {
+                    let allowed = allowed_origins
+                        .read()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    let allowed_origin = origin
+                        .to_str()
+                        .is_ok_and(|origin| is_origin_allowed(&allowed, origin));
+                    if !allowed_origin {
+                        debug!("Connector CORS: rejected origin {:?}", origin);
+                    }
+                    allowed_origin
+                }))
                 .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
                 .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE]);
 
@@ -302,18 +421,42 @@ impl ConnectorManager {
                 .layer(cors)
                 .with_state(app_state.clone());
 
-            let addr = format!("127.0.0.1:{}", port);
-            let listener = match TcpListener::bind(&addr).await {
-                Ok(l) => {
+            // Try the configured port, then port+1, ... when auto-fallback is enabled
+            let mut bound = None;
+            let mut last_error = String::new();
+            for candidate in (0..attempts).map(|offset| port + offset) {
+                match TcpListener::bind(format!("127.0.0.1:{}", candidate)).await {
+                    Ok(l) => {
+                        bound = Some((l, candidate));
+                        break;
+                    }
+                    Err(e) => {
+                        debug!("Connector server: port {} unavailable: {}", candidate, e);
+                        last_error = e.to_string();
+                    }
+                }
+            }
+
+            let (listener, bound_port) = match bound {
+                Some(bound) => {
                     // Clear any previous error on successful bind
                     {
                         let mut err_guard = server_error.write().await;
                         *err_guard = None;
                     }
-                    l
+                    bound
                 }
-                Err(e) => {
-                    let error_msg = format!("Failed to bind to port {}: {}", port, e);
+                None => {
+                    let error_msg = if attempts > 1 {
+                        format!(
+                            "Failed to bind to ports {}-{}: {}",
+                            port,
+                            port + attempts - 1,
+                            last_error
+                        )
+                    } else {
+                        format!("Failed to bind to port {}: {}", port, last_error)
+                    };
                     error!("Connector server: {}", error_msg);
 
                     // Store the error for status display
@@ -322,14 +465,40 @@ impl ConnectorManager {
                         *err_guard = Some(error_msg.clone());
                     }
 
-                    // Emit error event so UI can display it
-                    let _ = app_handle.emit("connector-server-error", error_msg);
+                    // Emit error events so UI can display it
+                    let _ = app_handle.emit("connector-server-error", error_msg.clone());
+                    let _ = app_handle.emit(
+                        "connector-bind-failed",
+                        ConnectorBindFailed {
+                            port,
+                            attempts,
+                            error: error_msg,
+                        },
+                    );
 
                     server_running.store(false, Ordering::SeqCst);
                     return;
                 }
             };
 
+            if bound_port != port {
+                info!(
+                    "Connector port {} in use, falling back to {}",
+                    port, bound_port
+                );
+                // Runtime only: `connector_port` keeps the configured port for next start
+                *port_lock.write().await = bound_port;
+
+                let _ = app_handle.emit(
+                    "connector-port-changed",
+                    ConnectorPortChanged {
+                        previous_port: port,
+                        port: bound_port,
+                    },
+                );
+            }
+            let addr = format!("127.0.0.1:{}", bound_port);
+
             info!("Connector server listening on {}", addr);
 
             // Spawn status check task
@@ -499,13 +668,18 @@ impl ConnectorManager {
             return Err("Message is empty".to_string());
         }
 
+        let text = match profile_tag(&get_settings(&self.app_handle)) {
+            Some(tag) => format!("#{} {}", tag, trimmed),
+            None => trimmed.to_string(),
+        };
+
         let msg_id = uuid_simple();
         let ts = now_ms();
 
         let msg = QueuedMessage {
             id: msg_id.clone(),
             msg_type: "text".to_string(),
-            text: trimmed.to_string(),
+            text: text.clone(),
             ts,
             attachments: None,
         };
@@ -528,7 +702,7 @@ impl ConnectorManager {
             "connector-message-queued",
             MessageQueuedEvent {
                 id: msg_id.clone(),
-                text: trimmed.to_string(),
+                text,
                 timestamp: ts,
             },
         );
@@ -538,9 +712,12 @@ impl ConnectorManager {
 
     /// Queue a bundle message with an image attachment
     pub fn queue_bundle_message(&self, text: &str, image_path: &PathBuf) -> Result<String, String> {
-        // Read the image file
-        let data =
-            std::fs::read(image_path).map_err(|e| format!("Failed to read image file: {}", e))?;
+        // Only the path is kept; the file is streamed when the extension fetches it
+        let metadata = std::fs::metadata(image_path)
+            .map_err(|e| format!("Failed to read image file: {}", e))?;
+        if !metadata.is_file() {
+            return Err(format!("Not a file: {}", image_path.display()));
+        }
 
         // Determine MIME type from extension
         let extension = image_path
@@ -562,7 +739,7 @@ impl ConnectorManager {
             .and_then(|n| n.to_str())
             .map(|s| s.to_string());
 
-        let file_size = data.len() as u64;
+        let file_size = metadata.len();
         let att_id = uuid_simple();
         let msg_id = uuid_simple();
         let now = now_ms();
@@ -592,7 +769,8 @@ impl ConnectorManager {
 
         // Store the blob
         let pending_blob = PendingBlob {
-            data,
+            source: BlobSource::File(image_path.clone()),
+            size: file_size,
             mime_type: mime_type.to_string(),
             expires_at,
         };
@@ -685,7 +863,8 @@ impl ConnectorManager {
 
         // Store the blob
         let pending_blob = PendingBlob {
-            data,
+            source: BlobSource::Memory(Bytes::from(data)),
+            size: file_size,
             mime_type: mime_type.to_string(),
             expires_at,
         };
@@ -769,6 +948,31 @@ impl ConnectorManager {
         }
     }
 
+    /// Mark a message as delivered as if the extension had acked it, and drop it from the queue
+    pub fn ack_message(&self, message_id: &str) -> Result<(), String> {
+        let mut state = self.state.lock().unwrap();
+
+        let original_len = state.messages.len();
+        state.messages.retain(|m| m.id != message_id);
+        let removed = state.messages.len() < original_len;
+
+        if !removed && !state.delivered_ids.contains(message_id) {
+            return Err(format!("Message not found: {}", message_id));
+        }
+        state.delivered_ids.insert(message_id.to_string());
+        drop(state); // Release lock before emitting
+
+        let _ = self.app_handle.emit(
+            "connector-message-delivered",
+            MessageDeliveredEvent {
+                id: message_id.to_string(),
+            },
+        );
+
+        info!("Manually acked connector message: {}", message_id);
+        Ok(())
+    }
+
     /// Get current connection status
     pub fn get_status(&self) -> ConnectorStatus {
         let last_poll = self.last_poll_at.load(Ordering::SeqCst);
@@ -811,6 +1015,176 @@ impl ConnectorManager {
         }
         (now_ms() - last_poll) < POLL_TIMEOUT_MS
     }
+
+    /// Run every connector health check (server, port, password, extension polling,
+    /// password handshake) and collect the results with remediation hints.
+    pub fn diagnose(&self) -> ConnectorDiagnosis {
+        let status = self.get_status();
+        let settings = get_settings(&self.app_handle);
+        // Probe the port only when we aren't the ones holding it
+        let port_probe = (!status.server_running).then(|| {
+            std::net::TcpListener::bind(("127.0.0.1", status.port))
+                .map(drop)
+                .map_err(|e| e.to_string())
+        });
+        let snapshot = DiagnosisSnapshot {
+            server_running: status.server_running,
+            server_error: status.server_error,
+            port: status.port,
+            port_probe,
+            password_empty: settings.connector_password.is_empty(),
+            password_default: settings.connector_password == default_connector_password(),
+            pending_password: settings.connector_pending_password.is_some(),
+            password_offered_at: self.password_offered_at.load(Ordering::SeqCst),
+            last_poll_at: status.last_poll_at,
+            now: now_ms(),
+        };
+        let items = diagnosis_items(&snapshot);
+        let ok = items.iter().all(|item| item.level != DiagnosisLevel::Fail);
+        ConnectorDiagnosis { items, ok }
+    }
+}
+
+fn diagnosis_item(
+    check: &str,
+    level: DiagnosisLevel,
+    message: String,
+    hint: Option<&str>,
+) -> DiagnosisItem {
+    DiagnosisItem {
+        check: check.to_string(),
+        level,
+        message,
+        hint: hint.map(str::to_string),
+    }
+}
+
+fn diagnosis_items(s: &DiagnosisSnapshot) -> Vec<DiagnosisItem> {
+    use DiagnosisLevel::{Fail, Pass, Warn};
+    let mut items = Vec::new();
+
+    items.push(if s.server_running {
+        diagnosis_item("server", Pass, "Server is running".to_string(), None)
+    } else {
+        let message = match &s.server_error {
+            Some(error) => format!("Server is not running: {}", error),
+            None => "Server is not running".to_string(),
+        };
+        diagnosis_item(
+            "server",
+            Fail,
+            message,
+            Some("Restart AivoRelay or change the connector port in settings."),
+        )
+    });
+
+    items.push(match &s.port_probe {
+        None => diagnosis_item(
+            "port",
+            Pass,
+            format!("Listening on 127.0.0.1:{}", s.port),
+            None,
+        ),
+        Some(Ok(())) => diagnosis_item(
+            "port",
+            Pass,
+            format!("Port {} is free", s.port),
+            Some("Start the connector server."),
+        ),
+        Some(Err(e)) => diagnosis_item(
+            "port",
+            Fail,
+            format!("Port {} can't be bound: {}", s.port, e),
+            Some(
+                "Close the program using this port, pick another port, or enable port \
+                 auto-fallback. Update the port in the extension to match.",
+            ),
+        ),
+    });
+
+    items.push(if s.password_empty {
+        diagnosis_item(
+            "password",
+            Fail,
+            "Password is empty, so every extension request is rejected".to_string(),
+            Some("Set a connector password and enter the same one in the extension."),
+        )
+    } else if s.password_default && !s.pending_password {
+        diagnosis_item(
+            "password",
+            Warn,
+            "Password is still the default".to_string(),
+            Some(
+                "A secure password is generated when the extension next connects, \
+                 or set your own in settings.",
+            ),
+        )
+    } else {
+        diagnosis_item("password", Pass, "Password is set".to_string(), None)
+    });
+
+    items.push(if s.last_poll_at == 0 {
+        diagnosis_item(
+            "extension",
+            Warn,
+            "Extension has not connected since AivoRelay started".to_string(),
+            Some(
+                "Install and enable the AivoRelay Connector extension and check that its \
+                 port and password match.",
+            ),
+        )
+    } else {
+        let age_secs = (s.now - s.last_poll_at) / 1000;
+        if s.now - s.last_poll_at < POLL_TIMEOUT_MS {
+            diagnosis_item(
+                "extension",
+                Pass,
+                format!("Extension polled {}s ago", age_secs),
+                None,
+            )
+        } else {
+            diagnosis_item(
+                "extension",
+                Warn,
+                format!("Extension last polled {}s ago", age_secs),
+                Some("Open the browser and check that the extension is still enabled."),
+            )
+        }
+    });
+
+    if s.pending_password {
+        let offered_ms = s.now - s.password_offered_at;
+        items.push(if s.password_offered_at == 0 {
+            diagnosis_item(
+                "password_handshake",
+                Warn,
+                "New password is waiting for the extension to connect".to_string(),
+                Some("Connect the extension so it can pick up the new password."),
+            )
+        } else if offered_ms >= PENDING_PASSWORD_STUCK_MS {
+            diagnosis_item(
+                "password_handshake",
+                Fail,
+                format!(
+                    "Extension received the new password {}s ago but never acknowledged it",
+                    offered_ms / 1000
+                ),
+                Some(
+                    "Update the extension, or set the same password manually in the \
+                     extension and in settings.",
+                ),
+            )
+        } else {
+            diagnosis_item(
+                "password_handshake",
+                Pass,
+                "New password was offered and is awaiting acknowledgement".to_string(),
+                None,
+            )
+        });
+    }
+
+    items
 }
 
 // ============================================================================
@@ -851,6 +1225,8 @@ async fn handle_get_messages(
         .unwrap_or(DEFAULT_WAIT_SECONDS)
         .min(MAX_WAIT_SECONDS);
 
+    let ttl_ms = settings.connector_message_ttl_secs as i64 * 1000;
+
     // Try to get messages, with optional long-poll wait
     let (messages, delivered_ids) = if wait_seconds > 0 {
         // Long-poll mode: wait for messages or timeout
@@ -858,6 +1234,7 @@ async fn handle_get_messages(
 
         loop {
             // Check for messages
+            drop_expired_messages(&app_state, ttl_ms);
             let (msgs, ids) = get_pending_messages(&app_state.state, cursor);
             if !msgs.is_empty() {
                 break (msgs, ids);
@@ -883,6 +1260,7 @@ async fn handle_get_messages(
         }
     } else {
         // Immediate mode (backward compatible)
+        drop_expired_messages(&app_state, ttl_ms);
         get_pending_messages(&app_state.state, cursor)
     };
 
@@ -908,6 +1286,14 @@ async fn handle_get_messages(
 
     // Check if we need to generate a new password
     let password_update = maybe_generate_new_password(&app_state.app_handle);
+    if password_update.is_some() {
+        let _ = app_state.password_offered_at.compare_exchange(
+            0,
+            now_ms(),
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        );
+    }
 
     // Get config from settings
     let settings = get_settings(&app_state.app_handle);
@@ -957,6 +1343,7 @@ async fn handle_post_messages(
         } else if post_body.msg_type.as_deref() == Some("password_ack") {
             info!("Received password_ack from extension, committing password...");
             commit_pending_password(&app_state.app_handle);
+            app_state.password_offered_at.store(0, Ordering::SeqCst);
         } else if let Some(text) = post_body.text {
             debug!("Received message from extension: {}", text);
         }
@@ -997,26 +1384,109 @@ async fn handle_get_blob(
         state_guard.blobs.get(&att_id).cloned()
     };
 
-    match blob_data {
-        Some(blob) => {
-            debug!(
-                "Serving blob {} ({} bytes, {})",
-                att_id,
-                blob.data.len(),
-                blob.mime_type
-            );
+    let Some(blob) = blob_data else {
+        debug!("Blob not found or expired: {}", att_id);
+        return (StatusCode::NOT_FOUND, "Blob not found").into_response();
+    };
 
-            Response::builder()
-                .status(StatusCode::OK)
-                .header(header::CONTENT_TYPE, blob.mime_type)
-                .body(Body::from(blob.data))
-                .unwrap()
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| parse_byte_range(v, blob.size))
+        .unwrap_or(Ok(None));
+    let (status, start, end) = match range {
+        Ok(Some((start, end))) => (StatusCode::PARTIAL_CONTENT, start, end),
+        Ok(None) => (StatusCode::OK, 0, blob.size.saturating_sub(1)),
+        Err(()) => {
+            return Response::builder()
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .header(header::CONTENT_RANGE, format!("bytes */{}", blob.size))
+                .body(Body::empty())
+                .unwrap();
         }
-        None => {
-            debug!("Blob not found or expired: {}", att_id);
-            (StatusCode::NOT_FOUND, "Blob not found").into_response()
+    };
+    let len = if blob.size == 0 { 0 } else { end - start + 1 };
+
+    debug!(
+        "Serving blob {} (bytes {}-{} of {}, {})",
+        att_id, start, end, blob.size, blob.mime_type
+    );
+
+    let body = match blob.source {
+        BlobSource::Memory(data) => Body::from(data.slice(start as usize..(start + len) as usize)),
+        BlobSource::File(path) => match open_file_range(&path, start, len).await {
+            Ok(body) => body,
+            Err(e) => {
+                error!(
+                    "Failed to read blob {} from {}: {}",
+                    att_id,
+                    path.display(),
+                    e
+                );
+                return (StatusCode::NOT_FOUND, "Blob file is no longer available").into_response();
+            }
+        },
+    };
+
+    let mut response = Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, blob.mime_type)
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::CONTENT_LENGTH, len);
+    if status == StatusCode::PARTIAL_CONTENT {
+        response = response.header(
+            header::CONTENT_RANGE,
+            format!("bytes {}-{}/{}", start, end, blob.size),
+        );
+    }
+    response.body(body).unwrap()
+}
+
+/// Stream `len` bytes of a file starting at `start`
+async fn open_file_range(path: &std::path::Path, start: u64, len: u64) -> std::io::Result<Body> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(std::io::SeekFrom::Start(start)).await?;
+    Ok(Body::from_stream(tokio_util::io::ReaderStream::new(
+        file.take(len),
+    )))
+}
+
+/// Parse a single `Range: bytes=...` header against a blob of `size` bytes.
+/// Ok(None) means serve the whole blob (multi-range requests aren't supported);
+/// Err means the range is malformed or can't be satisfied (416).
+fn parse_byte_range(value: &str, size: u64) -> Result<Option<(u64, u64)>, ()> {
+    let spec = value.trim().strip_prefix("bytes=").ok_or(())?;
+    if spec.contains(',') {
+        return Ok(None);
+    }
+    let (start, end) = spec.trim().split_once('-').ok_or(())?;
+    let (start, end) = (start.trim(), end.trim());
+
+    let (start, end) = if start.is_empty() {
+        // Suffix range: the last N bytes
+        let suffix: u64 = end.parse().map_err(|_| ())?;
+        if suffix == 0 || size == 0 {
+            return Err(());
         }
+        (size.saturating_sub(suffix), size - 1)
+    } else {
+        let start: u64 = start.parse().map_err(|_| ())?;
+        let end = if end.is_empty() {
+            size.saturating_sub(1)
+        } else {
+            end.parse::<u64>()
+                .map_err(|_| ())?
+                .min(size.saturating_sub(1))
+        };
+        (start, end)
+    };
+
+    if start >= size || start > end {
+        return Err(());
     }
+    Ok(Some((start, end)))
 }
 
 // ============================================================================
@@ -1042,6 +1512,88 @@ fn get_pending_messages(
     (filtered, ids)
 }
 
+/// Whether a queued message is too old to deliver.
+/// Keepalives never expire; bundles also expire with their attachments.
+fn is_message_expired(msg: &QueuedMessage, now: i64, ttl_ms: i64) -> bool {
+    if msg.msg_type == "keepalive" {
+        return false;
+    }
+    if ttl_ms > 0 && now - msg.ts > ttl_ms {
+        return true;
+    }
+    msg.attachments.as_ref().is_some_and(|atts| {
+        atts.iter()
+            .any(|a| a.fetch.expires_at.is_some_and(|exp| exp <= now))
+    })
+}
+
+/// Remove expired messages from the queue and emit `connector-message-cancelled` for each
+fn drop_expired_messages(app_state: &AppState, ttl_ms: i64) {
+    let expired: Vec<String> = {
+        let mut state_guard = app_state.state.lock().unwrap();
+        let now = now_ms();
+        let expired = state_guard
+            .messages
+            .iter()
+            .filter(|m| is_message_expired(m, now, ttl_ms))
+            .map(|m| m.id.clone())
+            .collect::<Vec<_>>();
+        if !expired.is_empty() {
+            state_guard
+                .messages
+                .retain(|m| !is_message_expired(m, now, ttl_ms));
+        }
+        expired
+    };
+
+    for id in expired {
+        info!("Dropping expired connector message: {}", id);
+        let _ = app_state
+            .app_handle
+            .emit("connector-message-cancelled", MessageCancelledEvent { id });
+    }
+}
+
+/// Hashtag (without '#') for the active profile when `connector_tag_with_profile` is on.
+/// The default profile uses `connector_default_profile_tag` (empty = no tag).
+fn profile_tag(settings: &AppSettings) -> Option<String> {
+    if !settings.connector_tag_with_profile {
+        return None;
+    }
+    let name = if settings.active_profile_id == "default" {
+        settings.connector_default_profile_tag.as_str()
+    } else {
+        settings
+            .transcription_profile(&settings.active_profile_id)?
+            .name
+            .as_str()
+    };
+    let tag = slugify_tag(name);
+    (!tag.is_empty()).then_some(tag)
+}
+
+/// Lowercase, keep letters/digits, collapse everything else into single underscores
+fn slugify_tag(name: &str) -> String {
+    let mut tag = String::new();
+    for c in name.trim().trim_start_matches('#').chars() {
+        if c.is_alphanumeric() {
+            tag.extend(c.to_lowercase());
+        } else if !tag.is_empty() && !tag.ends_with('_') {
+            tag.push('_');
+        }
+    }
+    tag.trim_end_matches('_').to_string()
+}
+
+/// Whether `origin` may make cross-origin requests to the connector. An empty allowlist
+/// allows everything; otherwise the origin must match an entry, ignoring case and a
+/// trailing slash.
+fn is_origin_allowed(allowed: &[String], origin: &str) -> bool {
+    let normalize = |o: &str| o.trim().trim_end_matches('/').to_lowercase();
+    let origin = normalize(origin);
+    allowed.is_empty() || allowed.iter().any(|a| normalize(a) == origin)
+}
+
 /// Create unauthorized response
 fn unauthorized_response() -> Response {
     Response::builder()
@@ -1227,3 +1779,175 @@ fn commit_pending_password(app_handle: &AppHandle) {
         debug!("Received password_ack but no pending password to commit");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_tag_collapses_separators() {
+        assert_eq!(slugify_tag(" #Work Email! "), "work_email");
+        assert_eq!(slugify_tag("Büro -- Notizen"), "büro_notizen");
+        assert_eq!(slugify_tag("--"), "");
+    }
+
+    #[test]
+    fn profile_tag_uses_active_profile_name() {
+        let mut settings = crate::settings::get_default_settings();
+        assert_eq!(profile_tag(&settings), None);
+
+        settings.connector_tag_with_profile = true;
+        assert_eq!(profile_tag(&settings), None);
+        settings.connector_default_profile_tag = "General".to_string();
+        assert_eq!(profile_tag(&settings).as_deref(), Some("general"));
+
+        settings.transcription_profiles.push(
+            serde_json::from_value(serde_json::json!({
+                "id": "profile_meetings",
+                "name": "Team Meetings",
+                "language": "en",
+                "translate_to_english": false,
+            }))
+            .expect("profile"),
+        );
+        settings.active_profile_id = "profile_meetings".to_string();
+        assert_eq!(profile_tag(&settings).as_deref(), Some("team_meetings"));
+    }
+
+    #[test]
+    fn parse_byte_range_handles_common_forms() {
+        assert_eq!(parse_byte_range("bytes=0-99", 1000), Ok(Some((0, 99))));
+        assert_eq!(parse_byte_range("bytes=900-", 1000), Ok(Some((900, 999))));
+        assert_eq!(parse_byte_range("bytes=-100", 1000), Ok(Some((900, 999))));
+        // End past the blob is clamped
+        assert_eq!(parse_byte_range("bytes=0-5000", 1000), Ok(Some((0, 999))));
+        // Multiple ranges fall back to the whole blob
+        assert_eq!(parse_byte_range("bytes=0-1,5-6", 1000), Ok(None));
+    }
+
+    #[test]
+    fn origin_allowlist_is_permissive_only_when_empty() {
+        let extension = "chrome-extension://abcdefghijklmnop".to_string();
+        assert!(is_origin_allowed(&[], "https://evil.example"));
+        assert!(is_origin_allowed(
+            &[format!("{}/", extension)],
+            "Chrome-Extension://abcdefghijklmnop"
+        ));
+        assert!(!is_origin_allowed(&[extension], "https://evil.example"));
+    }
+
+    fn healthy_snapshot() -> DiagnosisSnapshot {
+        DiagnosisSnapshot {
+            server_running: true,
+            server_error: None,
+            port: DEFAULT_PORT,
+            port_probe: None,
+            password_empty: false,
+            password_default: false,
+            pending_password: false,
+            password_offered_at: 0,
+            last_poll_at: 1_000_000 - 5_000,
+            now: 1_000_000,
+        }
+    }
+
+    fn levels(items: &[DiagnosisItem]) -> Vec<(&str, DiagnosisLevel)> {
+        items.iter().map(|i| (i.check.as_str(), i.level)).collect()
+    }
+
+    #[test]
+    fn diagnosis_passes_healthy_setup() {
+        let items = diagnosis_items(&healthy_snapshot());
+        assert!(items.iter().all(|i| i.level == DiagnosisLevel::Pass));
+        assert!(items.iter().all(|i| i.check != "password_handshake"));
+    }
+
+    #[test]
+    fn diagnosis_flags_stopped_server_default_password_and_stuck_handshake() {
+        let snapshot = DiagnosisSnapshot {
+            server_running: false,
+            server_error: Some("Failed to bind".to_string()),
+            port_probe: Some(Err("address in use".to_string())),
+            password_default: true,
+            pending_password: true,
+            password_offered_at: 1_000_000 - PENDING_PASSWORD_STUCK_MS,
+            last_poll_at: 0,
+            ..healthy_snapshot()
+        };
+        assert_eq!(
+            levels(&diagnosis_items(&snapshot)),
+            vec![
+                ("server", DiagnosisLevel::Fail),
+                ("port", DiagnosisLevel::Fail),
+                // Default password with a pending replacement is the handshake's concern
+                ("password", DiagnosisLevel::Pass),
+                ("extension", DiagnosisLevel::Warn),
+                ("password_handshake", DiagnosisLevel::Fail),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_byte_range_rejects_malformed_and_unsatisfiable() {
+        assert_eq!(parse_byte_range("bytes=1000-", 1000), Err(()));
+        assert_eq!(parse_byte_range("bytes=50-10", 1000), Err(()));
+        assert_eq!(parse_byte_range("bytes=abc", 1000), Err(()));
+        assert_eq!(parse_byte_range("items=0-1", 1000), Err(()));
+        assert_eq!(parse_byte_range("bytes=-0", 1000), Err(()));
+    }
+
+    #[test]
+    fn message_expiry_by_ttl_and_attachment_deadline() {
+        let message = |msg_type: &str, ts: i64, expires_at: Option<i64>| QueuedMessage {
+            id: "m1".to_string(),
+            msg_type: msg_type.to_string(),
+            text: String::new(),
+            ts,
+            attachments: expires_at.map(|exp| {
+                vec![BundleAttachment {
+                    att_id: "a1".to_string(),
+                    kind: "image".to_string(),
+                    filename: None,
+                    mime: None,
+                    size: None,
+                    fetch: BundleFetch {
+                        url: "/blob/a1".to_string(),
+                        method: None,
+                        headers: None,
+                        expires_at: Some(exp),
+                    },
+                }]
+            }),
+        };
+
+        // TTL 0 never expires by age
+        assert!(!is_message_expired(&message("text", 0, None), 1_000_000, 0));
+        assert!(!is_message_expired(
+            &message("text", 1_000, None),
+            5_000,
+            5_000
+        ));
+        assert!(is_message_expired(
+            &message("text", 1_000, None),
+            7_000,
+            5_000
+        ));
+        // Keepalives never expire
+        assert!(!is_message_expired(
+            &message("keepalive", 0, Some(1)),
+            7_000,
+            5_000
+        ));
+        // An attachment past its deadline expires the message even with TTL off
+        assert!(is_message_expired(
+            &message("bundle", 6_000, Some(7_000)),
+            7_000,
+            0
+        ));
+        assert!(!is_message_expired(
+            &message("bundle", 6_000, Some(8_000)),
+            7_000,
+            0
+        ));
+    }
+}
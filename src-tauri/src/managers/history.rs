@@ -10,6 +10,7 @@ use std::path::PathBuf;
 use tauri::{AppHandle, Emitter, Manager};
 
 use crate::audio_toolkit::save_wav_file;
+use crate::subtitle::SubtitleSegment;
 
 /// Database migrations for transcription history.
 /// Each migration is applied in order. The library tracks which migrations
@@ -37,8 +38,51 @@ static MIGRATIONS: &[M] = &[
          ALTER TABLE transcription_history ADD COLUMN original_selection TEXT;
          ALTER TABLE transcription_history ADD COLUMN ai_response TEXT;",
     ),
+    // Migration 5: Segment timings (JSON) for subtitle export
+    M::up("ALTER TABLE transcription_history ADD COLUMN segments TEXT;"),
+    // Migration 6: Outcome of LLM post-processing (NULL for older entries)
+    M::up("ALTER TABLE transcription_history ADD COLUMN post_process_status TEXT;"),
 ];
 
+/// Outcome of LLM post-processing for a transcription
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PostProcessStatus {
+    /// Post-processing was turned off
+    Disabled,
+    /// Post-processing produced the stored post-processed text
+    Success,
+    /// The LLM call failed and the raw transcription was used instead
+    Failed,
+    /// Post-processing was enabled but not configured (no provider/model/prompt)
+    Skipped,
+    /// Chinese variant conversion ran instead of the LLM; the converted text is stored
+    ChineseConversion,
+}
+
+impl PostProcessStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PostProcessStatus::Disabled => "disabled",
+            PostProcessStatus::Success => "success",
+            PostProcessStatus::Failed => "failed",
+            PostProcessStatus::Skipped => "skipped",
+            PostProcessStatus::ChineseConversion => "chinese_conversion",
+        }
+    }
+
+    fn from_db(value: Option<String>) -> Option<Self> {
+        match value.as_deref() {
+            Some("disabled") => Some(PostProcessStatus::Disabled),
+            Some("success") => Some(PostProcessStatus::Success),
+            Some("failed") => Some(PostProcessStatus::Failed),
+            Some("skipped") => Some(PostProcessStatus::Skipped),
+            Some("chinese_conversion") => Some(PostProcessStatus::ChineseConversion),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
 pub struct HistoryEntry {
     pub id: i64,
@@ -55,6 +99,8 @@ pub struct HistoryEntry {
     pub original_selection: Option<String>,
     /// For AI Replace: the AI response (None if request failed/never received)
     pub ai_response: Option<String>,
+    /// Outcome of LLM post-processing (None for entries recorded before this was tracked)
+    pub post_process_status: Option<PostProcessStatus>,
 }
 
 pub struct HistoryManager {
@@ -195,6 +241,8 @@ impl HistoryManager {
         transcription_text: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
+        post_process_status: PostProcessStatus,
+        segments: Option<Vec<SubtitleSegment>>,
     ) -> Result<()> {
         let timestamp = Utc::now().timestamp();
         let file_name = format!("aivorelay-{}.wav", timestamp);
@@ -212,6 +260,9 @@ impl HistoryManager {
             transcription_text,
             post_processed_text,
             post_process_prompt,
+            post_process_status,
+            segments,
+            "transcribe",
         )?;
 
         // Clean up old entries
@@ -233,11 +284,19 @@ impl HistoryManager {
         transcription_text: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
+        post_process_status: PostProcessStatus,
+        segments: Option<Vec<SubtitleSegment>>,
+        action_type: &str,
     ) -> Result<()> {
+        let segments_json = match segments {
+            Some(segs) => Some(serde_json::to_string(&segs)?),
+            None => None,
+        };
+
         let conn = self.get_connection()?;
         conn.execute(
-            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-            params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt, "transcribe"],
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type, segments, post_process_status) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt, action_type, segments_json, post_process_status.as_str()],
         )?;
 
         debug!("Saved transcription to database");
@@ -367,7 +426,7 @@ impl HistoryManager {
     pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type, original_selection, ai_response FROM transcription_history ORDER BY timestamp DESC"
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type, original_selection, ai_response, post_process_status FROM transcription_history ORDER BY timestamp DESC"
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -385,6 +444,7 @@ impl HistoryManager {
                     .unwrap_or_else(|| "transcribe".to_string()),
                 original_selection: row.get("original_selection")?,
                 ai_response: row.get("ai_response")?,
+                post_process_status: PostProcessStatus::from_db(row.get("post_process_status")?),
             })
         })?;
 
@@ -401,13 +461,33 @@ impl HistoryManager {
         Self::get_latest_entry_with_conn(&conn)
     }
 
+    /// Latest entry that has recorded audio (transcriptions and retained cancelled
+    /// recordings; AI Replace entries have no WAV file)
+    pub fn get_latest_recording_entry(&self) -> Result<Option<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        Self::get_latest_recording_entry_with_conn(&conn)
+    }
+
     fn get_latest_entry_with_conn(conn: &Connection) -> Result<Option<HistoryEntry>> {
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type, original_selection, ai_response
+        Self::query_latest_entry(conn, "")
+    }
+
+    fn get_latest_recording_entry_with_conn(conn: &Connection) -> Result<Option<HistoryEntry>> {
+        Self::query_latest_entry(
+            conn,
+            "WHERE COALESCE(action_type, 'transcribe') IN ('transcribe', 'cancelled')",
+        )
+    }
+
+    fn query_latest_entry(conn: &Connection, filter: &str) -> Result<Option<HistoryEntry>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type, original_selection, ai_response, post_process_status
              FROM transcription_history
+             {}
              ORDER BY timestamp DESC
              LIMIT 1",
-        )?;
+            filter
+        ))?;
 
         let entry = stmt
             .query_row([], |row| {
@@ -425,6 +505,9 @@ impl HistoryManager {
                         .unwrap_or_else(|| "transcribe".to_string()),
                     original_selection: row.get("original_selection")?,
                     ai_response: row.get("ai_response")?,
+                    post_process_status: PostProcessStatus::from_db(
+                        row.get("post_process_status")?,
+                    ),
                 })
             })
             .optional()?;
@@ -466,7 +549,7 @@ impl HistoryManager {
     pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type, original_selection, ai_response
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, action_type, original_selection, ai_response, post_process_status
              FROM transcription_history WHERE id = ?1",
         )?;
 
@@ -486,6 +569,9 @@ impl HistoryManager {
                         .unwrap_or_else(|| "transcribe".to_string()),
                     original_selection: row.get("original_selection")?,
                     ai_response: row.get("ai_response")?,
+                    post_process_status: PostProcessStatus::from_db(
+                        row.get("post_process_status")?,
+                    ),
                 })
             })
             .optional()?;
@@ -493,6 +579,25 @@ impl HistoryManager {
         Ok(entry)
     }
 
+    /// Get the stored segment timings for an entry.
+    /// Returns Ok(None) if the entry exists but was recorded without segment storage.
+    pub fn get_entry_segments(&self, id: i64) -> Result<Option<Vec<SubtitleSegment>>> {
+        let conn = self.get_connection()?;
+        let segments_json: Option<Option<String>> = conn
+            .query_row(
+                "SELECT segments FROM transcription_history WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        match segments_json {
+            None => Err(anyhow::anyhow!("History entry {} not found", id)),
+            Some(None) => Ok(None),
+            Some(Some(json)) => Ok(Some(serde_json::from_str(&json)?)),
+        }
+    }
+
     pub async fn delete_entry(&self, id: i64) -> Result<()> {
         let conn = self.get_connection()?;
 
@@ -524,6 +629,41 @@ impl HistoryManager {
         Ok(())
     }
 
+    /// Save the audio of a cancelled recording (only when `discard_cancelled_recordings` is off).
+    /// Stored with action_type "cancelled" so it is never mistaken for a completed transcription.
+    pub async fn save_cancelled_recording(
+        &self,
+        audio_samples: Vec<f32>,
+        transcription_text: String,
+    ) -> Result<()> {
+        let timestamp = Utc::now().timestamp();
+        let file_name = format!("aivorelay-cancelled-{}.wav", timestamp);
+        let title = self.format_timestamp_title(timestamp);
+
+        let file_path = self.recordings_dir.join(&file_name);
+        save_wav_file(file_path, &audio_samples).await?;
+
+        self.save_to_database(
+            file_name,
+            timestamp,
+            title,
+            transcription_text,
+            None,
+            None,
+            PostProcessStatus::Skipped,
+            None,
+            "cancelled",
+        )?;
+
+        self.cleanup_old_entries()?;
+
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+
+        Ok(())
+    }
+
     /// Save an AI Replace operation to history (no audio file, just the text data)
     pub async fn save_ai_replace_entry(
         &self,
@@ -581,7 +721,11 @@ mod tests {
                 title TEXT NOT NULL,
                 transcription_text TEXT NOT NULL,
                 post_processed_text TEXT,
-                post_process_prompt TEXT
+                post_process_prompt TEXT,
+                action_type TEXT DEFAULT 'transcribe',
+                original_selection TEXT,
+                ai_response TEXT,
+                post_process_status TEXT
             );",
         )
         .expect("create transcription_history table");
@@ -626,4 +770,22 @@ mod tests {
         assert_eq!(entry.transcription_text, "second");
         assert_eq!(entry.post_processed_text.as_deref(), Some("processed"));
     }
+
+    #[test]
+    fn get_latest_recording_entry_skips_entries_without_audio() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "spoken", None);
+        conn.execute(
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, action_type)
+             VALUES ('ai-replace-200.txt', 200, 0, 'AI Replace', 'shorten', 'ai_replace')",
+            [],
+        )
+        .expect("insert ai replace entry");
+
+        let entry = HistoryManager::get_latest_recording_entry_with_conn(&conn)
+            .expect("fetch latest recording")
+            .expect("recording exists");
+
+        assert_eq!(entry.timestamp, 100);
+    }
 }
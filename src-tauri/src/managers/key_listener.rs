@@ -1,5 +1,5 @@
 use log::{debug, error, info, warn};
-use rdev::{Event, EventType, Key};
+use rdev::{Button, Event, EventType, Key};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Emitter};
@@ -34,11 +34,18 @@ impl ModifierState {
     }
 }
 
+/// Main trigger of a shortcut: a keyboard key or a mouse button (rdev only)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShortcutKey {
+    Keyboard(Key),
+    Mouse(Button),
+}
+
 /// A registered shortcut with its trigger key and required modifiers
 /// For modifier-only shortcuts (like Ctrl+Alt), key will be None
 #[derive(Debug, Clone)]
 pub struct RegisteredShortcut {
-    pub key: Option<Key>,
+    pub key: Option<ShortcutKey>,
     pub modifiers: ModifierState,
     pub original_binding: String,
 }
@@ -178,17 +185,27 @@ impl KeyListenerManager {
         shortcuts: &Arc<Mutex<HashMap<String, RegisteredShortcut>>>,
         active_shortcuts: &Arc<Mutex<HashMap<String, bool>>>,
     ) {
-        match event.event_type {
-            EventType::KeyPress(key) => {
-                // Update modifiers - non-blocking with try_lock or unwrap_or_else
-                let current_mods = {
-                    let Ok(mut mods) = modifiers.try_lock() else {
-                        return; // Skip if can't get lock immediately
-                    };
-                    mods.update(key, true);
-                    mods.clone()
-                };
+        let (trigger, pressed) = match event.event_type {
+            EventType::KeyPress(key) => (ShortcutKey::Keyboard(key), true),
+            EventType::KeyRelease(key) => (ShortcutKey::Keyboard(key), false),
+            EventType::ButtonPress(button) => (ShortcutKey::Mouse(button), true),
+            EventType::ButtonRelease(button) => (ShortcutKey::Mouse(button), false),
+            _ => return,
+        };
 
+        // Update modifiers - non-blocking with try_lock (mouse buttons leave them unchanged)
+        let current_mods = {
+            let Ok(mut mods) = modifiers.try_lock() else {
+                return; // Skip if can't get lock immediately
+            };
+            if let ShortcutKey::Keyboard(key) = trigger {
+                mods.update(key, pressed);
+            }
+            mods.clone()
+        };
+
+        match pressed {
+            true => {
                 // Check if this key press matches any registered shortcut
                 let Ok(shortcuts_guard) = shortcuts.try_lock() else {
                     return;
@@ -199,14 +216,14 @@ impl KeyListenerManager {
 
                 for (id, shortcut) in shortcuts_guard.iter() {
                     let matches = match shortcut.key {
-                        // Regular shortcut with main key
+                        // Regular shortcut with main key or mouse button
                         Some(shortcut_key) => {
-                            shortcut_key == key && current_mods.matches(&shortcut.modifiers)
+                            shortcut_key == trigger && current_mods.matches(&shortcut.modifiers)
                         }
                         // Modifier-only shortcut - fire when modifiers match exactly
                         None => {
                             current_mods.matches(&shortcut.modifiers)
-                                && Self::is_modifier_key(key)
+                                && matches!(trigger, ShortcutKey::Keyboard(key) if Self::is_modifier_key(key))
                         }
                     };
 
@@ -228,16 +245,7 @@ impl KeyListenerManager {
                     }
                 }
             }
-            EventType::KeyRelease(key) => {
-                // Update modifiers
-                let current_mods = {
-                    let Ok(mut mods) = modifiers.try_lock() else {
-                        return;
-                    };
-                    mods.update(key, false);
-                    mods.clone()
-                };
-
+            false => {
                 // Check if releasing this key deactivates any shortcuts
                 let Ok(shortcuts_guard) = shortcuts.try_lock() else {
                     return;
@@ -248,8 +256,8 @@ impl KeyListenerManager {
 
                 for (id, shortcut) in shortcuts_guard.iter() {
                     let should_release = match shortcut.key {
-                        // Release if main key is released
-                        Some(shortcut_key) => shortcut_key == key,
+                        // Release if main key or mouse button is released
+                        Some(shortcut_key) => shortcut_key == trigger,
                         // For modifier-only: release if any required modifier is released
                         None => !current_mods.matches(&shortcut.modifiers),
                     };
@@ -274,7 +282,6 @@ impl KeyListenerManager {
                     }
                 }
             }
-            _ => {}
         }
     }
 
@@ -318,14 +325,16 @@ impl KeyListenerManager {
     }
 }
 
-/// Parse a shortcut string like "ctrl+shift+a", "caps lock", or "ctrl+alt" into key and modifiers
-/// Returns (Option<Key>, ModifierState) - key is None for modifier-only shortcuts
-pub fn parse_shortcut_string(binding: &str) -> Result<(Option<Key>, ModifierState), String> {
+/// Parse a shortcut string like "ctrl+shift+a", "caps lock", "ctrl+mouse4" or "ctrl+alt" into key and modifiers
+/// Returns (Option<ShortcutKey>, ModifierState) - key is None for modifier-only shortcuts
+pub fn parse_shortcut_string(
+    binding: &str,
+) -> Result<(Option<ShortcutKey>, ModifierState), String> {
     let binding = binding.to_lowercase().trim().to_string();
     let parts: Vec<&str> = binding.split('+').map(|s| s.trim()).collect();
 
     let mut modifiers = ModifierState::default();
-    let mut main_key: Option<Key> = None;
+    let mut main_key: Option<ShortcutKey> = None;
 
     for part in parts {
         match part {
@@ -340,7 +349,10 @@ pub fn parse_shortcut_string(binding: &str) -> Result<(Option<Key>, ModifierStat
                         key_str
                     ));
                 }
-                main_key = Some(string_to_rdev_key(key_str)?);
+                main_key = Some(match string_to_mouse_button(key_str) {
+                    Some(button) => ShortcutKey::Mouse(button),
+                    None => ShortcutKey::Keyboard(string_to_rdev_key(key_str)?),
+                });
             }
         }
     }
@@ -359,6 +371,27 @@ pub fn parse_shortcut_string(binding: &str) -> Result<(Option<Key>, ModifierStat
     Ok((main_key, modifiers))
 }
 
+/// Side button numbers as reported by rdev (XBUTTON1/2 on Windows, X11 buttons 8/9 elsewhere)
+#[cfg(target_os = "windows")]
+const MOUSE_BACK_BUTTON: u8 = 1;
+#[cfg(target_os = "windows")]
+const MOUSE_FORWARD_BUTTON: u8 = 2;
+#[cfg(not(target_os = "windows"))]
+const MOUSE_BACK_BUTTON: u8 = 8;
+#[cfg(not(target_os = "windows"))]
+const MOUSE_FORWARD_BUTTON: u8 = 9;
+
+/// Convert a mouse button name ("mouse3", "mouse4", "mouse5") to an rdev::Button.
+/// Left/right buttons are not bindable to avoid breaking normal clicking.
+pub fn string_to_mouse_button(s: &str) -> Option<Button> {
+    match s.to_lowercase().trim() {
+        "mouse3" | "middlemouse" | "mousemiddle" => Some(Button::Middle),
+        "mouse4" | "mouseback" | "xbutton1" => Some(Button::Unknown(MOUSE_BACK_BUTTON)),
+        "mouse5" | "mouseforward" | "xbutton2" => Some(Button::Unknown(MOUSE_FORWARD_BUTTON)),
+        _ => None,
+    }
+}
+
 /// Convert a string to an rdev::Key
 fn string_to_rdev_key(s: &str) -> Result<Key, String> {
     let s = s.to_lowercase();
@@ -498,7 +531,14 @@ fn string_to_rdev_key(s: &str) -> Result<Key, String> {
         // International backslash (non-US keyboards, key between left shift and Z)
         "intlbackslash" | "oem102" => Ok(Key::IntlBackslash),
 
-        _ => Err(format!("Unknown key: '{}'", s)),
+        // Extra keys without a name (macro/media keys): raw platform key code, e.g. "keycode:179"
+        _ => match s
+            .strip_prefix("keycode:")
+            .map(|code| code.trim().parse::<u32>())
+        {
+            Some(Ok(code)) => Ok(Key::Unknown(code)),
+            _ => Err(format!("Unknown key: '{}'", s)),
+        },
     }
 }
 
@@ -514,3 +554,42 @@ impl KeyListenerState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mouse_buttons_with_modifiers() {
+        let (key, modifiers) = parse_shortcut_string("Ctrl + Mouse4").unwrap();
+        assert_eq!(
+            key,
+            Some(ShortcutKey::Mouse(Button::Unknown(MOUSE_BACK_BUTTON)))
+        );
+        assert!(modifiers.ctrl && !modifiers.shift);
+
+        let (key, _) = parse_shortcut_string("mouse3").unwrap();
+        assert_eq!(key, Some(ShortcutKey::Mouse(Button::Middle)));
+        assert_eq!(
+            string_to_mouse_button("xbutton2"),
+            Some(Button::Unknown(MOUSE_FORWARD_BUTTON))
+        );
+        // Left/right clicks are never bindable
+        assert!(parse_shortcut_string("mouse1").is_err());
+    }
+
+    #[test]
+    fn parses_keys_key_codes_and_modifier_only_shortcuts() {
+        let (key, _) = parse_shortcut_string("shift+caps lock").unwrap();
+        assert_eq!(key, Some(ShortcutKey::Keyboard(Key::CapsLock)));
+        let (key, _) = parse_shortcut_string("keycode: 179").unwrap();
+        assert_eq!(key, Some(ShortcutKey::Keyboard(Key::Unknown(179))));
+
+        let (key, modifiers) = parse_shortcut_string("ctrl+alt").unwrap();
+        assert_eq!(key, None);
+        assert!(modifiers.ctrl && modifiers.alt);
+
+        assert!(parse_shortcut_string("f13+mouse5").is_err());
+        assert!(parse_shortcut_string("keycode:abc").is_err());
+    }
+}
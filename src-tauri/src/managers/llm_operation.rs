@@ -2,8 +2,17 @@
 //!
 //! Provides cancellation tracking for LLM requests (AI Replace, etc.)
 //! Similar pattern to RemoteSttManager's operation tracking.
+//! Also provides the heartbeat emitted while a post-processing request is in flight.
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// Interval between `post-process-heartbeat` events
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
 
 /// Tracks LLM operations and allows cancellation.
 /// When cancel() is called, all operations started before that point are marked as cancelled.
@@ -49,3 +58,51 @@ impl Default for LlmOperationTracker {
         Self::new()
     }
 }
+
+/// Payload of the `post-process-heartbeat` event
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct PostProcessHeartbeat {
+    pub operation_id: u64,
+    /// Time since the LLM request was sent
+    pub elapsed_ms: u64,
+}
+
+/// Emits `post-process-heartbeat` every second while an LLM request is in flight,
+/// so the overlay can show an elapsed timer. Stops when dropped (completion or error)
+/// or when the operation is cancelled.
+pub struct HeartbeatGuard {
+    stopped: Arc<AtomicBool>,
+}
+
+impl HeartbeatGuard {
+    pub fn start(app: &AppHandle, tracker: Arc<LlmOperationTracker>, operation_id: u64) -> Self {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let stopped_clone = stopped.clone();
+        let app = app.clone();
+        let started = Instant::now();
+
+        tauri::async_runtime::spawn(async move {
+            loop {
+                tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+                if stopped_clone.load(Ordering::SeqCst) || tracker.is_cancelled(operation_id) {
+                    break;
+                }
+                let _ = app.emit(
+                    "post-process-heartbeat",
+                    PostProcessHeartbeat {
+                        operation_id,
+                        elapsed_ms: started.elapsed().as_millis() as u64,
+                    },
+                );
+            }
+        });
+
+        Self { stopped }
+    }
+}
+
+impl Drop for HeartbeatGuard {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
@@ -37,6 +37,9 @@ pub struct ModelInfo {
     pub engine_type: EngineType,
     pub accuracy_score: f32, // 0.0 to 1.0, higher is more accurate
     pub speed_score: f32,    // 0.0 to 1.0, higher is faster
+    /// Language codes the model can transcribe (empty = multilingual)
+    #[serde(default)]
+    pub supported_languages: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -87,6 +90,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.60,
                 speed_score: 0.85,
+                supported_languages: Vec::new(),
             },
         );
 
@@ -107,6 +111,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.75,
                 speed_score: 0.60,
+                supported_languages: Vec::new(),
             },
         );
 
@@ -126,6 +131,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.80,
                 speed_score: 0.40,
+                supported_languages: Vec::new(),
             },
         );
 
@@ -145,6 +151,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.85,
                 speed_score: 0.30,
+                supported_languages: Vec::new(),
             },
         );
 
@@ -165,6 +172,7 @@ impl ModelManager {
                 engine_type: EngineType::Parakeet,
                 accuracy_score: 0.85,
                 speed_score: 0.85,
+                supported_languages: vec!["en".to_string()],
             },
         );
 
@@ -184,6 +192,13 @@ impl ModelManager {
                 engine_type: EngineType::Parakeet,
                 accuracy_score: 0.80,
                 speed_score: 0.85,
+                supported_languages: [
+                    "bg", "cs", "da", "de", "el", "en", "es", "et", "fi", "fr", "hr", "hu", "it",
+                    "lt", "lv", "mt", "nl", "pl", "pt", "ro", "ru", "sk", "sl", "sv", "uk",
+                ]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
             },
         );
 
@@ -203,6 +218,7 @@ impl ModelManager {
                 engine_type: EngineType::Moonshine,
                 accuracy_score: 0.70,
                 speed_score: 0.90,
+                supported_languages: vec!["en".to_string()],
             },
         );
 
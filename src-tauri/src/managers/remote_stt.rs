@@ -1,7 +1,8 @@
 use crate::audio_toolkit::encode_wav_bytes;
 use crate::settings::{RemoteSttDebugMode, RemoteSttSettings};
 use anyhow::{anyhow, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use specta::Type;
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Mutex;
@@ -19,7 +20,7 @@ const REMOTE_STT_USER: &str = "remote_stt_api_key";
 /// Languages supported by Whisper models (ISO 639-1 codes)
 /// Based on OpenAI Whisper documentation and Groq's supported languages list
 /// https://github.com/openai/whisper/blob/main/whisper/tokenizer.py
-const WHISPER_SUPPORTED_LANGUAGES: &[&str] = &[
+pub(crate) const WHISPER_SUPPORTED_LANGUAGES: &[&str] = &[
     "af", // Afrikaans
     "am", // Amharic
     "ar", // Arabic
@@ -193,6 +194,67 @@ pub fn supports_translation(model_id: &str) -> bool {
     false
 }
 
+/// Response body characters kept in Normal debug mode (Verbose keeps the full body)
+const DEBUG_BODY_SNIPPET_CHARS: usize = 500;
+
+/// Headers whose values are never captured
+const REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "x-api-key",
+    "api-key",
+    "cookie",
+    "set-cookie",
+];
+
+/// The last remote transcription request/response, captured while `debug_capture` is on
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct RemoteSttDebugData {
+    /// Unix timestamp (ms) of the request
+    pub captured_at: i64,
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<(String, String)>,
+    pub audio_bytes: usize,
+    /// None when no response arrived
+    pub status: Option<u16>,
+    pub elapsed_ms: u64,
+    pub response_headers: Vec<(String, String)>,
+    /// Truncated in Normal debug mode, full in Verbose
+    pub response_body: Option<String>,
+    pub body_truncated: bool,
+    pub error: Option<String>,
+}
+
+fn redacted_headers(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(&name.as_str()) {
+                "[redacted]".to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+/// Response body for the debug capture and whether it was truncated
+fn debug_body(body: &[u8], mode: RemoteSttDebugMode) -> (String, bool) {
+    let text = String::from_utf8_lossy(body);
+    match mode {
+        RemoteSttDebugMode::Verbose => (text.into_owned(), false),
+        RemoteSttDebugMode::Normal => {
+            let truncated = text.chars().count() > DEBUG_BODY_SNIPPET_CHARS;
+            (
+                text.chars().take(DEBUG_BODY_SNIPPET_CHARS).collect(),
+                truncated,
+            )
+        }
+    }
+}
+
 #[derive(Default)]
 struct DebugBuffer {
     lines: VecDeque<String>,
@@ -225,6 +287,7 @@ impl DebugBuffer {
 pub struct RemoteSttManager {
     client: reqwest::Client,
     debug: Mutex<DebugBuffer>,
+    last_exchange: Mutex<Option<RemoteSttDebugData>>,
     app_handle: AppHandle,
     /// Monotonically increasing operation ID; when cancel() is called, all
     /// operations started before that point should abort.
@@ -244,6 +307,7 @@ impl RemoteSttManager {
         Ok(Self {
             client,
             debug: Mutex::new(DebugBuffer::new()),
+            last_exchange: Mutex::new(None),
             app_handle: app_handle.clone(),
             current_operation_id: AtomicU64::new(0),
             cancelled_before_id: AtomicU64::new(0),
@@ -279,6 +343,18 @@ impl RemoteSttManager {
     pub fn clear_debug(&self) {
         let mut buffer = self.debug.lock().unwrap();
         buffer.lines.clear();
+        *self.last_exchange.lock().unwrap() = None;
+    }
+
+    /// The last captured transcription request/response (None when nothing was captured)
+    pub fn get_last_exchange(&self) -> Option<RemoteSttDebugData> {
+        self.last_exchange.lock().unwrap().clone()
+    }
+
+    fn record_exchange(&self, settings: &RemoteSttSettings, exchange: RemoteSttDebugData) {
+        if settings.debug_capture {
+            *self.last_exchange.lock().unwrap() = Some(exchange);
+        }
     }
 
     fn record_line(&self, settings: &RemoteSttSettings, line: String, is_error: bool) {
@@ -436,27 +512,60 @@ impl RemoteSttManager {
             }
         }
 
-        let start = Instant::now();
-        let response = self
+        let request = self
             .client
             .post(url)
             .bearer_auth(api_key)
             .multipart(form)
-            .send()
-            .await
-            .map_err(|e| {
+            .build()
+            .map_err(|e| anyhow!("Failed to build Remote STT request: {}", e))?;
+        let mut exchange = RemoteSttDebugData {
+            captured_at: chrono::Utc::now().timestamp_millis(),
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            request_headers: redacted_headers(request.headers()),
+            audio_bytes: file_size,
+            status: None,
+            elapsed_ms: 0,
+            response_headers: Vec::new(),
+            response_body: None,
+            body_truncated: false,
+            error: None,
+        };
+
+        let start = Instant::now();
+        let response = match self.client.execute(request).await {
+            Ok(response) => response,
+            Err(e) => {
                 let message = format!("Remote STT request failed: {}", e);
                 self.record_error(settings, message.clone());
-                anyhow!(message)
-            })?;
+                exchange.elapsed_ms = start.elapsed().as_millis() as u64;
+                exchange.error = Some(message.clone());
+                self.record_exchange(settings, exchange);
+                return Err(anyhow!(message));
+            }
+        };
 
         let status = response.status();
-        let body = response.bytes().await.map_err(|e| {
-            let message = format!("Remote STT response read failed: {}", e);
-            self.record_error(settings, message.clone());
-            anyhow!(message)
-        })?;
+        exchange.status = Some(status.as_u16());
+        exchange.response_headers = redacted_headers(response.headers());
+        let body = response.bytes().await;
         let elapsed_ms = start.elapsed().as_millis();
+        exchange.elapsed_ms = elapsed_ms as u64;
+        let body = match body {
+            Ok(body) => body,
+            Err(e) => {
+                let message = format!("Remote STT response read failed: {}", e);
+                self.record_error(settings, message.clone());
+                exchange.error = Some(message.clone());
+                self.record_exchange(settings, exchange);
+                return Err(anyhow!(message));
+            }
+        };
+        let (debug_body, body_truncated) = debug_body(&body, settings.debug_mode);
+        exchange.response_body = Some(debug_body);
+        exchange.body_truncated = body_truncated;
+        self.record_exchange(settings, exchange);
 
         if settings.debug_mode == RemoteSttDebugMode::Verbose {
             self.record_info(
@@ -621,3 +730,45 @@ pub fn clear_remote_stt_api_key() -> Result<()> {
 pub fn has_remote_stt_api_key() -> bool {
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+
+    #[test]
+    fn redacted_headers_hide_credentials_only() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer sk-secret"));
+        headers.insert("x-api-key", HeaderValue::from_static("sk-secret"));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let mut redacted = redacted_headers(&headers);
+        redacted.sort();
+        assert_eq!(
+            redacted,
+            [
+                ("authorization".to_string(), "[redacted]".to_string()),
+                ("content-type".to_string(), "application/json".to_string()),
+                ("x-api-key".to_string(), "[redacted]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_body_truncates_only_in_normal_mode() {
+        let long = "é".repeat(DEBUG_BODY_SNIPPET_CHARS + 10);
+        let (snippet, truncated) = debug_body(long.as_bytes(), RemoteSttDebugMode::Normal);
+        assert_eq!(snippet.chars().count(), DEBUG_BODY_SNIPPET_CHARS);
+        assert!(truncated);
+
+        assert_eq!(
+            debug_body(long.as_bytes(), RemoteSttDebugMode::Verbose),
+            (long.clone(), false)
+        );
+        assert_eq!(
+            debug_body(b"{\"text\":\"hi\"}", RemoteSttDebugMode::Normal),
+            ("{\"text\":\"hi\"}".to_string(), false)
+        );
+    }
+}
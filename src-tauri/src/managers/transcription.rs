@@ -1,9 +1,14 @@
 use crate::audio_toolkit::{apply_custom_words, filter_transcription_output};
 use crate::managers::model::{EngineType, ModelManager};
-use crate::settings::{get_settings, ModelUnloadTimeout};
+use crate::settings::{
+    get_settings, AccelerationPreference, AppSettings, LocalDecodeParams, ModelUnloadTimeout,
+};
+use crate::whisper_engine::{WhisperEngine, WhisperParams};
 use anyhow::Result;
 use log::{debug, error, info, warn};
 use serde::Serialize;
+use specta::Type;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
@@ -15,7 +20,6 @@ use transcribe_rs::{
         parakeet::{
             ParakeetEngine, ParakeetInferenceParams, ParakeetModelParams, TimestampGranularity,
         },
-        whisper::{WhisperEngine, WhisperInferenceParams},
     },
     TranscriptionEngine,
 };
@@ -28,6 +32,192 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
+/// Payload for `model-load-started`
+#[derive(Clone, Debug, Serialize)]
+pub struct ModelLoadStartedEvent {
+    pub model_id: String,
+}
+
+/// Payload for `model-load-finished`
+#[derive(Clone, Debug, Serialize)]
+pub struct ModelLoadFinishedEvent {
+    pub model_id: String,
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// Approximate memory held by the loaded local model
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ModelMemoryInfo {
+    pub model_id: String,
+    /// Approximate footprint in bytes: the size of the model weights on disk.
+    /// Whisper also allocates compute buffers on top of this while transcribing.
+    pub approx_bytes: u64,
+    /// "gpu" when the weights are held in VRAM, otherwise "cpu" (system RAM)
+    pub device: String,
+    /// Backend the model runs on ("metal", "vulkan" or "cpu")
+    pub backend: String,
+}
+
+/// What a local engine reported about a transcription besides its text
+#[derive(Debug, Clone, Default)]
+pub struct TranscriptionDetails {
+    /// Segment timings
+    pub segments: Option<Vec<crate::subtitle::SubtitleSegment>>,
+    /// Whisper's mean token probability (0.0-1.0); None for other engines
+    pub confidence: Option<f32>,
+}
+
+/// Total size of a model file, or of every file under a model directory
+fn path_size(path: &Path) -> u64 {
+    if path.is_dir() {
+        std::fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| path_size(&entry.path()))
+                    .sum()
+            })
+            .unwrap_or(0)
+    } else {
+        path.metadata().map(|m| m.len()).unwrap_or(0)
+    }
+}
+
+/// Most likely candidate language according to Whisper's detection
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageDetection {
+    pub language: String,
+    /// Whisper's probability for `language` (0.0-1.0)
+    pub confidence: f32,
+}
+
+/// Whisper uses ISO 639-1 codes: zh-Hans and zh-Hant are both zh
+fn whisper_language_code(language: &str) -> &str {
+    match language {
+        "zh-Hans" | "zh-Hant" => "zh",
+        other => other,
+    }
+}
+
+/// The candidate Whisper finds most likely, from its per-language probabilities.
+/// Languages outside the candidates are ignored, however likely.
+pub fn detect_among_candidates(
+    candidates: &[String],
+    probabilities: &[(&str, f32)],
+) -> Option<LanguageDetection> {
+    candidates
+        .iter()
+        .filter_map(|candidate| {
+            let code = whisper_language_code(candidate);
+            probabilities
+                .iter()
+                .find(|(lang, _)| lang.eq_ignore_ascii_case(code))
+                .map(|(_, p)| LanguageDetection {
+                    language: candidate.clone(),
+                    confidence: *p,
+                })
+        })
+        .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+}
+
+/// Pick the language to use when the user selected "auto" but restricted
+/// detection to a set of candidate languages.
+///
+/// - No candidates: plain auto-detect (None)
+/// - One candidate: always use it
+/// - Detection confident enough: the detected candidate
+/// - Otherwise the OS input language, if it's a candidate
+/// - Otherwise the first candidate after a low-confidence detection, or plain
+///   auto-detect when detection didn't run
+pub fn pick_auto_language(
+    candidates: &[String],
+    detection: Option<&LanguageDetection>,
+    os_input_language: Option<&str>,
+    min_confidence: f32,
+) -> Option<String> {
+    let first = candidates.first()?;
+    if candidates.len() == 1 {
+        return Some(first.clone());
+    }
+
+    if let Some(detected) = detection {
+        if detected.confidence >= min_confidence {
+            return Some(detected.language.clone());
+        }
+        debug!(
+            "Detected language '{}' (confidence {:.2}) below {:.2}",
+            detected.language, detected.confidence, min_confidence
+        );
+    }
+
+    let from_os =
+        os_input_language.and_then(|lang| candidates.iter().find(|c| c.eq_ignore_ascii_case(lang)));
+    from_os.or(detection.map(|_| first)).cloned()
+}
+
+/// Resolve the language code passed to Whisper for a selected language setting.
+/// Handles "auto" (restricted to the candidate languages by running detection on
+/// `audio`), "os_input" and zh variants.
+fn resolve_whisper_language(
+    selected_language: &str,
+    settings: &AppSettings,
+    engine: &mut WhisperEngine,
+    audio: &[f32],
+) -> Option<String> {
+    let candidates = &settings.auto_language_candidates;
+    let language = match selected_language {
+        "auto" if candidates.len() > 1 => {
+            let detection = match engine.detect_language(audio) {
+                Ok(probabilities) => detect_among_candidates(candidates, &probabilities),
+                Err(e) => {
+                    warn!("Language detection failed: {}", e);
+                    None
+                }
+            };
+            debug!("Candidate language detection: {:?}", detection);
+            let os_language = crate::input_source::get_language_from_input_source();
+            pick_auto_language(
+                candidates,
+                detection.as_ref(),
+                os_language.as_deref(),
+                settings.auto_language_min_confidence,
+            )
+        }
+        "auto" => pick_auto_language(
+            candidates,
+            None,
+            None,
+            settings.auto_language_min_confidence,
+        ),
+        // Resolve OS input source to language, fall back to auto-detect
+        "os_input" => crate::input_source::get_language_from_input_source(),
+        other => Some(other.to_string()),
+    };
+
+    language.map(|lang| whisper_language_code(&lang).to_string())
+}
+
+/// Apply user decoding parameters to Whisper params.
+fn apply_whisper_decode_params(
+    mut params: WhisperParams,
+    decode: &LocalDecodeParams,
+) -> WhisperParams {
+    params.beam_size = decode.beam_size;
+    params.temperature = decode.temperature;
+    params.no_speech_threshold = decode.no_speech_threshold;
+    params.condition_on_previous = decode.condition_on_previous;
+    params
+}
+
+/// Parakeet and Moonshine have no tunable decoding parameters.
+fn log_ignored_decode_params(engine: &str, decode: &LocalDecodeParams) {
+    if *decode != LocalDecodeParams::default() {
+        debug!("{} backend ignores local decoding parameters", engine);
+    }
+}
+
 enum LoadedEngine {
     Whisper(WhisperEngine),
     Parakeet(ParakeetEngine),
@@ -40,6 +230,8 @@ pub struct TranscriptionManager {
     model_manager: Arc<ModelManager>,
     app_handle: AppHandle,
     current_model_id: Arc<Mutex<Option<String>>>,
+    /// Device preference the loaded Whisper model was loaded with (None for other engines)
+    loaded_acceleration: Arc<Mutex<Option<(AccelerationPreference, Option<u32>)>>>,
     last_activity: Arc<AtomicU64>,
     shutdown_signal: Arc<AtomicBool>,
     watcher_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
@@ -54,6 +246,7 @@ impl TranscriptionManager {
             model_manager,
             app_handle: app_handle.clone(),
             current_model_id: Arc::new(Mutex::new(None)),
+            loaded_acceleration: Arc::new(Mutex::new(None)),
             last_activity: Arc::new(AtomicU64::new(
                 SystemTime::now()
                     .duration_since(SystemTime::UNIX_EPOCH)
@@ -142,7 +335,8 @@ impl TranscriptionManager {
             let mut engine = self.engine.lock().unwrap();
             if let Some(ref mut loaded_engine) = *engine {
                 match loaded_engine {
-                    LoadedEngine::Whisper(ref mut e) => e.unload_model(),
+                    // Whisper frees its context when dropped below
+                    LoadedEngine::Whisper(_) => {}
                     LoadedEngine::Parakeet(ref mut e) => e.unload_model(),
                     LoadedEngine::Moonshine(ref mut e) => e.unload_model(),
                 }
@@ -153,6 +347,7 @@ impl TranscriptionManager {
             let mut current_model = self.current_model_id.lock().unwrap();
             *current_model = None;
         }
+        *self.loaded_acceleration.lock().unwrap() = None;
 
         // Emit unloaded event
         let _ = self.app_handle.emit(
@@ -186,7 +381,32 @@ impl TranscriptionManager {
         }
     }
 
+    /// Load a model, bracketing the load with `model-load-started` and
+    /// `model-load-finished` (success/error + duration) events.
     pub fn load_model(&self, model_id: &str) -> Result<()> {
+        let load_start = std::time::Instant::now();
+        let _ = self.app_handle.emit(
+            "model-load-started",
+            ModelLoadStartedEvent {
+                model_id: model_id.to_string(),
+            },
+        );
+
+        let result = self.load_model_inner(model_id);
+
+        let _ = self.app_handle.emit(
+            "model-load-finished",
+            ModelLoadFinishedEvent {
+                model_id: model_id.to_string(),
+                success: result.is_ok(),
+                error: result.as_ref().err().map(|e| e.to_string()),
+                duration_ms: load_start.elapsed().as_millis() as u64,
+            },
+        );
+        result
+    }
+
+    fn load_model_inner(&self, model_id: &str) -> Result<()> {
         let load_start = std::time::Instant::now();
         debug!("Starting to load model: {}", model_id);
 
@@ -223,22 +443,29 @@ impl TranscriptionManager {
         let model_path = self.model_manager.get_model_path(model_id)?;
 
         // Create appropriate engine based on model type
+        let mut acceleration = None;
         let loaded_engine = match model_info.engine_type {
             EngineType::Whisper => {
-                let mut engine = WhisperEngine::new();
-                engine.load_model(&model_path).map_err(|e| {
-                    let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
-                    let _ = self.app_handle.emit(
-                        "model-state-changed",
-                        ModelStateEvent {
-                            event_type: "loading_failed".to_string(),
-                            model_id: Some(model_id.to_string()),
-                            model_name: Some(model_info.name.clone()),
-                            error: Some(error_msg.clone()),
-                        },
-                    );
-                    anyhow::anyhow!(error_msg)
-                })?;
+                let settings = get_settings(&self.app_handle);
+                let (use_gpu, gpu_device) = crate::acceleration::whisper_device(&settings);
+                let engine =
+                    WhisperEngine::load(&model_path, use_gpu, gpu_device).map_err(|e| {
+                        let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
+                        let _ = self.app_handle.emit(
+                            "model-state-changed",
+                            ModelStateEvent {
+                                event_type: "loading_failed".to_string(),
+                                model_id: Some(model_id.to_string()),
+                                model_name: Some(model_info.name.clone()),
+                                error: Some(error_msg.clone()),
+                            },
+                        );
+                        anyhow::anyhow!(error_msg)
+                    })?;
+                acceleration = Some((
+                    settings.acceleration_preference,
+                    settings.acceleration_gpu_index,
+                ));
                 LoadedEngine::Whisper(engine)
             }
             EngineType::Parakeet => {
@@ -295,6 +522,7 @@ impl TranscriptionManager {
             let mut current_model = self.current_model_id.lock().unwrap();
             *current_model = Some(model_id.to_string());
         }
+        *self.loaded_acceleration.lock().unwrap() = acceleration;
 
         // Emit loading completed event
         let _ = self.app_handle.emit(
@@ -316,6 +544,57 @@ impl TranscriptionManager {
         Ok(())
     }
 
+    /// Kicks off loading of a specific model in a background thread,
+    /// swapping out the current model if a different one is loaded.
+    pub fn initiate_model_load_for(&self, model_id: String) {
+        let mut is_loading = self.is_loading.lock().unwrap();
+        if *is_loading
+            || (self.is_model_loaded()
+                && self.get_current_model().as_deref() == Some(model_id.as_str()))
+        {
+            return;
+        }
+
+        *is_loading = true;
+        let self_clone = self.clone();
+        thread::spawn(move || {
+            if let Err(e) = self_clone.load_model(&model_id) {
+                error!("Failed to load model {}: {}", model_id, e);
+            }
+            let mut is_loading = self_clone.is_loading.lock().unwrap();
+            *is_loading = false;
+            self_clone.loading_condvar.notify_all();
+        });
+    }
+
+    /// Blocks until the given model is loaded, swapping models if needed.
+    /// Emits the usual `model-state-changed` loading events when a swap happens.
+    pub fn ensure_model_loaded(&self, model_id: &str) -> Result<()> {
+        let mut is_loading = self.is_loading.lock().unwrap();
+        while *is_loading {
+            is_loading = self.loading_condvar.wait(is_loading).unwrap();
+        }
+
+        if self.is_model_loaded() && self.get_current_model().as_deref() == Some(model_id) {
+            return Ok(());
+        }
+
+        info!(
+            "Switching transcription model from {:?} to {}",
+            self.get_current_model(),
+            model_id
+        );
+        *is_loading = true;
+        drop(is_loading);
+
+        let result = self.load_model(model_id);
+
+        let mut is_loading = self.is_loading.lock().unwrap();
+        *is_loading = false;
+        self.loading_condvar.notify_all();
+        result
+    }
+
     /// Kicks off the model loading in a background thread if it's not already loaded
     pub fn initiate_model_load(&self) {
         let mut is_loading = self.is_loading.lock().unwrap();
@@ -341,6 +620,40 @@ impl TranscriptionManager {
         current_model.clone()
     }
 
+    /// Device preference the loaded Whisper model was loaded with.
+    /// None when no model is loaded or the model isn't Whisper.
+    pub fn loaded_acceleration(&self) -> Option<(AccelerationPreference, Option<u32>)> {
+        *self.loaded_acceleration.lock().unwrap()
+    }
+
+    /// Approximate memory footprint of the loaded model and where it lives.
+    /// None when no model is loaded.
+    pub fn memory_usage(&self) -> Option<ModelMemoryInfo> {
+        if !self.is_model_loaded() {
+            return None;
+        }
+        let model_id = self.get_current_model()?;
+        let model_info = self.model_manager.get_model_info(&model_id)?;
+        let approx_bytes = self
+            .model_manager
+            .get_model_path(&model_id)
+            .map(|path| path_size(&path))
+            .unwrap_or(model_info.size_mb * 1024 * 1024);
+
+        let preference = self
+            .loaded_acceleration()
+            .map_or(AccelerationPreference::Cpu, |(preference, _)| preference);
+        let backend = crate::acceleration::backend_for(&model_info.engine_type, preference);
+        let device = if backend == "cpu" { "cpu" } else { "gpu" };
+
+        Some(ModelMemoryInfo {
+            model_id,
+            approx_bytes,
+            device: device.to_string(),
+            backend,
+        })
+    }
+
     pub fn transcribe(&self, audio: Vec<f32>, apply_custom_words_enabled: bool) -> Result<String> {
         // Update last activity timestamp
         self.last_activity.store(
@@ -389,26 +702,14 @@ impl TranscriptionManager {
 
             match engine {
                 LoadedEngine::Whisper(whisper_engine) => {
-                    // Normalize language code for Whisper
-                    // Convert zh-Hans and zh-Hant to zh since Whisper uses ISO 639-1 codes
-                    // Handle "os_input" by resolving to current keyboard layout language
-                    let whisper_language = if settings.selected_language == "auto" {
-                        None
-                    } else if settings.selected_language == "os_input" {
-                        // Resolve OS input source to language, fall back to auto-detect
-                        crate::input_source::get_language_from_input_source()
-                    } else {
-                        let normalized = if settings.selected_language == "zh-Hans"
-                            || settings.selected_language == "zh-Hant"
-                        {
-                            "zh".to_string()
-                        } else {
-                            settings.selected_language.clone()
-                        };
-                        Some(normalized)
-                    };
+                    let whisper_language = resolve_whisper_language(
+                        &settings.selected_language,
+                        &settings,
+                        whisper_engine,
+                        &audio,
+                    );
 
-                    let params = WhisperInferenceParams {
+                    let params = WhisperParams {
                         language: whisper_language,
                         translate: settings.translate_to_english,
                         initial_prompt: {
@@ -422,12 +723,15 @@ impl TranscriptionManager {
                         },
                         ..Default::default()
                     };
+                    let params = apply_whisper_decode_params(params, &settings.local_decode_params);
 
                     whisper_engine
-                        .transcribe_samples(audio, Some(params))
+                        .transcribe(&audio, &params)
                         .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
+                        .0
                 }
                 LoadedEngine::Parakeet(parakeet_engine) => {
+                    log_ignored_decode_params("Parakeet", &settings.local_decode_params);
                     let params = ParakeetInferenceParams {
                         timestamp_granularity: TimestampGranularity::Segment,
                         ..Default::default()
@@ -436,9 +740,12 @@ impl TranscriptionManager {
                         .transcribe_samples(audio, Some(params))
                         .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
                 }
-                LoadedEngine::Moonshine(moonshine_engine) => moonshine_engine
-                    .transcribe_samples(audio, None)
-                    .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?,
+                LoadedEngine::Moonshine(moonshine_engine) => {
+                    log_ignored_decode_params("Moonshine", &settings.local_decode_params);
+                    moonshine_engine
+                        .transcribe_samples(audio, None)
+                        .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?
+                }
             }
         };
 
@@ -497,6 +804,7 @@ impl TranscriptionManager {
         translate_override: Option<bool>,
         prompt_override: Option<String>,
         apply_custom_words_enabled: bool,
+        filler_filter_enabled: bool,
     ) -> Result<String> {
         // Update last activity timestamp
         self.last_activity.store(
@@ -545,22 +853,14 @@ impl TranscriptionManager {
 
             match engine {
                 LoadedEngine::Whisper(whisper_engine) => {
-                    let whisper_language = if selected_language == "auto" {
-                        None
-                    } else if selected_language == "os_input" {
-                        // Resolve OS input source to language, fall back to auto-detect
-                        crate::input_source::get_language_from_input_source()
-                    } else {
-                        let normalized =
-                            if selected_language == "zh-Hans" || selected_language == "zh-Hant" {
-                                "zh".to_string()
-                            } else {
-                                selected_language.clone()
-                            };
-                        Some(normalized)
-                    };
+                    let whisper_language = resolve_whisper_language(
+                        &selected_language,
+                        &settings,
+                        whisper_engine,
+                        &audio,
+                    );
 
-                    let params = WhisperInferenceParams {
+                    let params = WhisperParams {
                         language: whisper_language,
                         translate: translate_to_english,
                         initial_prompt: {
@@ -578,12 +878,15 @@ impl TranscriptionManager {
                         },
                         ..Default::default()
                     };
+                    let params = apply_whisper_decode_params(params, &settings.local_decode_params);
 
                     whisper_engine
-                        .transcribe_samples(audio, Some(params))
+                        .transcribe(&audio, &params)
                         .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
+                        .0
                 }
                 LoadedEngine::Parakeet(parakeet_engine) => {
+                    log_ignored_decode_params("Parakeet", &settings.local_decode_params);
                     let params = ParakeetInferenceParams {
                         timestamp_granularity: TimestampGranularity::Segment,
                         ..Default::default()
@@ -593,9 +896,12 @@ impl TranscriptionManager {
                         .transcribe_samples(audio, Some(params))
                         .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
                 }
-                LoadedEngine::Moonshine(moonshine_engine) => moonshine_engine
-                    .transcribe_samples(audio, None)
-                    .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?,
+                LoadedEngine::Moonshine(moonshine_engine) => {
+                    log_ignored_decode_params("Moonshine", &settings.local_decode_params);
+                    moonshine_engine
+                        .transcribe_samples(audio, None)
+                        .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?
+                }
             }
         };
 
@@ -613,7 +919,7 @@ impl TranscriptionManager {
         };
 
         // Filter out filler words and hallucinations (if enabled)
-        let filtered_result = if settings.filler_word_filter_enabled {
+        let filtered_result = if filler_filter_enabled {
             filter_transcription_output(&corrected_result)
         } else {
             corrected_result
@@ -644,8 +950,9 @@ impl TranscriptionManager {
         Ok(final_result)
     }
 
-    /// Transcribe audio and return full result including segments with timestamps.
-    /// This is used for file transcription where subtitle formats (SRT/VTT) are needed.
+    /// Transcribe audio and return full result including segments with timestamps and
+    /// the engine's confidence. Used for file transcription where subtitle formats
+    /// (SRT/VTT) are needed, and by recordings.
     pub fn transcribe_with_segments(
         &self,
         audio: Vec<f32>,
@@ -653,7 +960,8 @@ impl TranscriptionManager {
         translate_override: Option<bool>,
         prompt_override: Option<String>,
         apply_custom_words_enabled: bool,
-    ) -> Result<(String, Option<Vec<crate::subtitle::SubtitleSegment>>)> {
+        filler_filter_enabled: bool,
+    ) -> Result<(String, TranscriptionDetails)> {
         // Update last activity timestamp
         self.last_activity.store(
             SystemTime::now()
@@ -669,7 +977,7 @@ impl TranscriptionManager {
 
         if audio.len() == 0 {
             debug!("Empty audio vector");
-            return Ok((String::new(), None));
+            return Ok((String::new(), TranscriptionDetails::default()));
         }
 
         // Check if model is loaded
@@ -693,7 +1001,7 @@ impl TranscriptionManager {
             .unwrap_or_else(|| settings.selected_language.clone());
         let translate_to_english = translate_override.unwrap_or(settings.translate_to_english);
 
-        let result = {
+        let (result, confidence) = {
             let mut engine_guard = self.engine.lock().unwrap();
             let engine = engine_guard.as_mut().ok_or_else(|| {
                 anyhow::anyhow!("Model failed to load. Please check your model settings.")
@@ -701,22 +1009,14 @@ impl TranscriptionManager {
 
             match engine {
                 LoadedEngine::Whisper(whisper_engine) => {
-                    let whisper_language = if selected_language == "auto" {
-                        None
-                    } else if selected_language == "os_input" {
-                        // Resolve OS input source to language, fall back to auto-detect
-                        crate::input_source::get_language_from_input_source()
-                    } else {
-                        let normalized =
-                            if selected_language == "zh-Hans" || selected_language == "zh-Hant" {
-                                "zh".to_string()
-                            } else {
-                                selected_language.clone()
-                            };
-                        Some(normalized)
-                    };
+                    let whisper_language = resolve_whisper_language(
+                        &selected_language,
+                        &settings,
+                        whisper_engine,
+                        &audio,
+                    );
 
-                    let params = WhisperInferenceParams {
+                    let params = WhisperParams {
                         language: whisper_language,
                         translate: translate_to_english,
                         initial_prompt: {
@@ -733,24 +1033,31 @@ impl TranscriptionManager {
                         },
                         ..Default::default()
                     };
+                    let params = apply_whisper_decode_params(params, &settings.local_decode_params);
 
                     whisper_engine
-                        .transcribe_samples(audio, Some(params))
+                        .transcribe(&audio, &params)
                         .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
                 }
                 LoadedEngine::Parakeet(parakeet_engine) => {
+                    log_ignored_decode_params("Parakeet", &settings.local_decode_params);
                     let params = ParakeetInferenceParams {
                         timestamp_granularity: TimestampGranularity::Segment,
                         ..Default::default()
                     };
 
-                    parakeet_engine
+                    let result = parakeet_engine
                         .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
+                        .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?;
+                    (result, None)
+                }
+                LoadedEngine::Moonshine(moonshine_engine) => {
+                    log_ignored_decode_params("Moonshine", &settings.local_decode_params);
+                    let result = moonshine_engine
+                        .transcribe_samples(audio, None)
+                        .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?;
+                    (result, None)
                 }
-                LoadedEngine::Moonshine(moonshine_engine) => moonshine_engine
-                    .transcribe_samples(audio, None)
-                    .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?,
             }
         };
 
@@ -790,7 +1097,7 @@ impl TranscriptionManager {
         };
 
         // Filter out filler words and hallucinations (if enabled)
-        let filtered_result = if settings.filler_word_filter_enabled {
+        let filtered_result = if filler_filter_enabled {
             filter_transcription_output(&corrected_result)
         } else {
             corrected_result
@@ -818,7 +1125,13 @@ impl TranscriptionManager {
             }
         }
 
-        Ok((final_result, segments))
+        Ok((
+            final_result,
+            TranscriptionDetails {
+                segments,
+                confidence,
+            },
+        ))
     }
 }
 
@@ -839,3 +1152,55 @@ impl Drop for TranscriptionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detected(language: &str, confidence: f32) -> LanguageDetection {
+        LanguageDetection {
+            language: language.to_string(),
+            confidence,
+        }
+    }
+
+    #[test]
+    fn detection_only_considers_candidates() {
+        let candidates = vec!["en".to_string(), "zh-Hans".to_string()];
+        let probabilities = [("fr", 0.6), ("zh", 0.25), ("en", 0.1)];
+        assert_eq!(
+            detect_among_candidates(&candidates, &probabilities),
+            Some(detected("zh-Hans", 0.25))
+        );
+        assert_eq!(detect_among_candidates(&candidates, &[("fr", 1.0)]), None);
+    }
+
+    #[test]
+    fn confident_detection_wins_over_a_different_keyboard_layout() {
+        let candidates = vec!["en".to_string(), "de".to_string()];
+        let pick = |detection: Option<LanguageDetection>, os| {
+            pick_auto_language(&candidates, detection.as_ref(), os, 0.5)
+        };
+        // English speech while the German layout is active
+        assert_eq!(
+            pick(Some(detected("en", 0.9)), Some("de")).as_deref(),
+            Some("en")
+        );
+        // Unsure: the layout if it's a candidate, else the first candidate
+        assert_eq!(
+            pick(Some(detected("en", 0.3)), Some("DE")).as_deref(),
+            Some("de")
+        );
+        assert_eq!(
+            pick(Some(detected("de", 0.3)), Some("fr")).as_deref(),
+            Some("en")
+        );
+        // No detection and a layout outside the candidates: Whisper detects freely
+        assert_eq!(pick(None, Some("fr")), None);
+        assert_eq!(pick(None, Some("de")).as_deref(), Some("de"));
+
+        assert_eq!(pick_auto_language(&[], None, Some("en"), 0.5), None);
+        let only_de = pick_auto_language(&candidates[1..], None, Some("en"), 0.5);
+        assert_eq!(only_de.as_deref(), Some("de"));
+    }
+}
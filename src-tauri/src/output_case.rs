@@ -0,0 +1,153 @@
+//! Output Case Transform
+//!
+//! Forces the casing of transcribed text as the last step before paste (code
+//! constants, SQL keywords). Deterministic and Unicode-aware: lower/upper use the
+//! standard Unicode case mappings (so "ß" uppercases to "SS"). Title case is a simple
+//! word-boundary heuristic: the first letter or digit after whitespace is uppercased and
+//! the rest of the word lowercased. It is not locale-aware titlecasing (no special
+//! handling of "of"/"the", Turkish dotted i, or Dutch "IJ").
+//!
+//! Sentence capitalization (`auto_capitalize_sentences`) uppercases the first letter
+//! after `.`, `?` or `!` followed by whitespace. Ellipses and a short list of
+//! abbreviations ("e.g.", "Dr.") don't end a sentence; any other abbreviation does, so
+//! "approx. ten" becomes "approx. Ten".
+
+use crate::settings::{AppSettings, OutputCaseTransform, TranscriptionProfile};
+
+const SENTENCE_ENDINGS: &[char] = &['.', '?', '!'];
+
+/// Abbreviations whose period doesn't end a sentence (compared case-insensitively).
+/// "etc." and "no." are left out since they often do end one.
+const ABBREVIATIONS: &[&str] = &[
+    "e.g.", "i.e.", "vs.", "cf.", "mr.", "mrs.", "ms.", "dr.", "prof.", "st.", "jr.", "sr.",
+];
+
+/// Quotes and brackets allowed between a sentence end and the letter to capitalize
+const OPENING_PUNCTUATION: &[char] = &['"', '\'', '(', '[', '“', '‘', '«', '¿', '¡'];
+
+/// Transform for a transcription: the profile's override, else the global setting
+pub fn transform_for_profile(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+) -> OutputCaseTransform {
+    profile
+        .and_then(|p| p.output_case_transform)
+        .unwrap_or(settings.output_case_transform)
+}
+
+/// Whether sentence capitalization is on: the profile's override, else the global setting
+pub fn capitalize_sentences_for_profile(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+) -> bool {
+    profile
+        .and_then(|p| p.auto_capitalize_sentences)
+        .unwrap_or(settings.auto_capitalize_sentences)
+}
+
+/// Uppercase the first letter of every sentence after the first
+pub fn capitalize_sentences(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    // Set after a sentence end and whitespace, cleared by the next non-opening character
+    let mut capitalize_next = false;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if capitalize_next && !c.is_whitespace() && !OPENING_PUNCTUATION.contains(&c) {
+            capitalize_next = false;
+            if c.is_lowercase() {
+                result.extend(c.to_uppercase());
+                continue;
+            }
+        }
+        result.push(c);
+        if SENTENCE_ENDINGS.contains(&c)
+            && chars.peek().is_some_and(|(_, next)| next.is_whitespace())
+            && ends_sentence(&text[..i + c.len_utf8()])
+        {
+            capitalize_next = true;
+        }
+    }
+    result
+}
+
+/// Whether the word ending `text` (which ends in sentence punctuation) closes a sentence
+fn ends_sentence(text: &str) -> bool {
+    let word = text.rsplit(char::is_whitespace).next().unwrap_or(text);
+    let word = word.trim_start_matches(OPENING_PUNCTUATION);
+    !word.ends_with("..") && !ABBREVIATIONS.iter().any(|a| word.eq_ignore_ascii_case(a))
+}
+
+pub fn apply_case_transform(text: &str, transform: OutputCaseTransform) -> String {
+    match transform {
+        OutputCaseTransform::None => text.to_string(),
+        OutputCaseTransform::Lower => text.to_lowercase(),
+        OutputCaseTransform::Upper => text.to_uppercase(),
+        OutputCaseTransform::Title => title_case(text),
+    }
+}
+
+fn title_case(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    // Each piece is a word followed by (at most) one whitespace character
+    for piece in text.split_inclusive(char::is_whitespace) {
+        match piece.char_indices().find(|(_, c)| c.is_alphanumeric()) {
+            Some((start, first)) => {
+                let rest = &piece[start + first.len_utf8()..];
+                result.push_str(&piece[..start]);
+                result.extend(first.to_uppercase());
+                // Lowercasing the whole tail keeps context-sensitive mappings (final sigma)
+                result.push_str(&rest.to_lowercase());
+            }
+            None => result.push_str(piece),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_and_upper_use_unicode_mappings() {
+        assert_eq!(
+            apply_case_transform("Straße ÉTÉ", OutputCaseTransform::Upper),
+            "STRASSE ÉTÉ"
+        );
+        assert_eq!(
+            apply_case_transform("ΟΔΟΣ Été", OutputCaseTransform::Lower),
+            "οδος été"
+        );
+    }
+
+    #[test]
+    fn title_case_capitalizes_each_word() {
+        let text = "hello WORLD,  \"quoted\" (élan)\nnext";
+        assert_eq!(
+            apply_case_transform(text, OutputCaseTransform::Title),
+            "Hello World,  \"Quoted\" (Élan)\nNext"
+        );
+        assert_eq!(apply_case_transform("", OutputCaseTransform::Title), "");
+    }
+
+    #[test]
+    fn capitalizes_after_sentence_endings() {
+        assert_eq!(
+            capitalize_sentences("hi there. how are you? fine!\n\"great\" thanks. é"),
+            "hi there. How are you? Fine!\n\"Great\" thanks. É"
+        );
+        // Punctuation not followed by whitespace, and non-letters, are left alone
+        assert_eq!(
+            capitalize_sentences("v1.2 is out. 3 fixes"),
+            "v1.2 is out. 3 fixes"
+        );
+    }
+
+    #[test]
+    fn skips_abbreviations_and_ellipses() {
+        assert_eq!(
+            capitalize_sentences("fruit, e.g. apples. see Dr. smith... or not"),
+            "fruit, e.g. apples. See Dr. smith... or not"
+        );
+    }
+}
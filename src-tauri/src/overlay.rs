@@ -1,6 +1,6 @@
 use crate::input;
 use crate::settings;
-use crate::settings::OverlayPosition;
+use crate::settings::{OverlayMonitor, OverlayPosition};
 use std::sync::atomic::{AtomicU64, Ordering};
 use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize};
 
@@ -80,19 +80,52 @@ pub fn force_overlay_topmost(overlay_window: &tauri::webview::WebviewWindow) {
 }
 
 fn get_monitor_with_cursor(app_handle: &AppHandle) -> Option<tauri::Monitor> {
-    if let Some(mouse_location) = input::get_cursor_position(app_handle) {
-        if let Ok(monitors) = app_handle.available_monitors() {
-            for monitor in monitors {
-                let is_within =
-                    is_mouse_within_monitor(mouse_location, monitor.position(), monitor.size());
-                if is_within {
-                    return Some(monitor);
-                }
-            }
-        }
+    input::get_cursor_position(app_handle)
+        .and_then(|mouse_location| get_monitor_containing(app_handle, mouse_location))
+        .or_else(|| app_handle.primary_monitor().ok().flatten())
+}
+
+fn get_monitor_containing(app_handle: &AppHandle, point: (i32, i32)) -> Option<tauri::Monitor> {
+    app_handle
+        .available_monitors()
+        .ok()?
+        .into_iter()
+        .find(|monitor| is_mouse_within_monitor(point, monitor.position(), monitor.size()))
+}
+
+/// The monitor the recording overlay appears on, per the `overlay_monitor` setting
+fn get_overlay_monitor(
+    app_handle: &AppHandle,
+    settings: &settings::AppSettings,
+) -> Option<tauri::Monitor> {
+    let primary = || app_handle.primary_monitor().ok().flatten();
+    match settings.overlay_monitor {
+        OverlayMonitor::Cursor => get_monitor_with_cursor(app_handle),
+        OverlayMonitor::ActiveWindow => crate::foreground_app::focused_window_center()
+            .and_then(|center| get_monitor_containing(app_handle, center))
+            .or_else(|| get_monitor_with_cursor(app_handle)),
+        OverlayMonitor::Primary => primary(),
+        OverlayMonitor::MonitorIndex(index) => app_handle
+            .available_monitors()
+            .ok()
+            .and_then(|monitors| monitors.into_iter().nth(index as usize))
+            .or_else(primary),
     }
+}
 
-    app_handle.primary_monitor().ok().flatten()
+/// Display names of the available monitors, in `OverlayMonitor::MonitorIndex` order
+pub fn monitor_names(app_handle: &AppHandle) -> Vec<String> {
+    app_handle
+        .available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| {
+            let size = monitor.size();
+            let name = monitor.name().cloned().unwrap_or_default();
+            format!("{}: {} ({}x{})", index + 1, name, size.width, size.height)
+        })
+        .collect()
 }
 
 fn is_mouse_within_monitor(
@@ -116,8 +149,23 @@ fn is_mouse_within_monitor(
         && mouse_y < (monitor_y + monitor_height as i32)
 }
 
+/// Overlay window size after applying the appearance scale
+fn overlay_size(settings: &settings::AppSettings) -> (f64, f64) {
+    let scale = settings.overlay_appearance.scale as f64;
+    (OVERLAY_WIDTH * scale, OVERLAY_HEIGHT * scale)
+}
+
+/// Resizes the overlay for the current scale and sends the appearance to the webview
+fn apply_overlay_appearance(app_handle: &AppHandle, overlay_window: &tauri::WebviewWindow) {
+    let settings = settings::get_settings(app_handle);
+    let (width, height) = overlay_size(&settings);
+    let _ = overlay_window.set_size(tauri::Size::Logical(tauri::LogicalSize { width, height }));
+    let _ = overlay_window.emit("overlay-appearance", &settings.overlay_appearance);
+}
+
 fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
-    if let Some(monitor) = get_monitor_with_cursor(app_handle) {
+    let settings = settings::get_settings(app_handle);
+    if let Some(monitor) = get_overlay_monitor(app_handle, &settings) {
         let work_area = monitor.work_area();
         let scale = monitor.scale_factor();
         let work_area_width = work_area.size.width as f64 / scale;
@@ -125,9 +173,9 @@ fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
         let work_area_x = work_area.position.x as f64 / scale;
         let work_area_y = work_area.position.y as f64 / scale;
 
-        let settings = settings::get_settings(app_handle);
+        let (overlay_width, _) = overlay_size(&settings);
 
-        let x = work_area_x + (work_area_width - OVERLAY_WIDTH) / 2.0;
+        let x = work_area_x + (work_area_width - overlay_width) / 2.0;
         let y = match settings.overlay_position {
             OverlayPosition::Top => work_area_y + OVERLAY_TOP_OFFSET,
             OverlayPosition::Bottom | OverlayPosition::None => {
@@ -144,6 +192,7 @@ fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
 /// Creates the recording overlay window and keeps it hidden by default
 #[cfg(not(target_os = "macos"))]
 pub fn create_recording_overlay(app_handle: &AppHandle) {
+    let (width, height) = overlay_size(&settings::get_settings(app_handle));
     if let Some((x, y)) = calculate_overlay_position(app_handle) {
         match WebviewWindowBuilder::new(
             app_handle,
@@ -153,7 +202,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
         .title("Recording")
         .position(x, y)
         .resizable(false)
-        .inner_size(OVERLAY_WIDTH, OVERLAY_HEIGHT)
+        .inner_size(width, height)
         .shadow(false)
         .maximizable(false)
         .minimizable(false)
@@ -180,6 +229,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
 /// Creates the recording overlay panel and keeps it hidden by default (macOS)
 #[cfg(target_os = "macos")]
 pub fn create_recording_overlay(app_handle: &AppHandle) {
+    let (width, height) = overlay_size(&settings::get_settings(app_handle));
     if let Some((x, y)) = calculate_overlay_position(app_handle) {
         // PanelBuilder creates a Tauri window then converts it to NSPanel.
         // The window remains registered, so get_webview_window() still works.
@@ -188,10 +238,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
             .title("Recording")
             .position(tauri::Position::Logical(tauri::LogicalPosition { x, y }))
             .level(PanelLevel::Status)
-            .size(tauri::Size::Logical(tauri::LogicalSize {
-                width: OVERLAY_WIDTH,
-                height: OVERLAY_HEIGHT,
-            }))
+            .size(tauri::Size::Logical(tauri::LogicalSize { width, height }))
             .has_shadow(false)
             .transparent(true)
             .no_activate(true)
@@ -227,6 +274,8 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
     }
 
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        apply_overlay_appearance(app_handle, &overlay_window);
+
         // Update position before showing to prevent flicker from position changes
         if let Some((x, y)) = calculate_overlay_position(app_handle) {
             let _ = overlay_window
@@ -310,9 +359,10 @@ pub fn show_thinking_overlay(app_handle: &AppHandle) {
     }
 }
 
-/// Updates the overlay window position based on current settings
+/// Updates the overlay window position and appearance based on current settings
 pub fn update_overlay_position(app_handle: &AppHandle) {
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        apply_overlay_appearance(app_handle, &overlay_window);
         if let Some((x, y)) = calculate_overlay_position(app_handle) {
             let _ = overlay_window
                 .set_position(tauri::Position::Logical(tauri::LogicalPosition { x, y }));
@@ -445,6 +495,13 @@ pub fn show_command_confirm_overlay(
         let _ = window.set_position(tauri::Position::Logical(tauri::LogicalPosition { x, y }));
     }
 
+    // A new command replaces any pending countdown; only predefined commands auto-run
+    if payload.auto_run && !payload.from_llm && payload.auto_run_seconds > 0 {
+        crate::voice_command_countdown::start(app_handle, payload.auto_run_seconds);
+    } else {
+        crate::voice_command_countdown::cancel(app_handle);
+    }
+
     // For new windows, we need to wait for the webview to load before emitting the payload.
     // For existing windows, emit the payload immediately, then show.
     if is_new_window {
@@ -491,15 +548,79 @@ pub fn show_command_confirm_overlay(
 // Profile Switch Overlay (Transcription Profiles)
 // ============================================================================
 
+/// Payload for the `show-profile-switch` overlay event
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProfileSwitchPayload {
+    pub name: String,
+    /// Language code the profile will dictate in ("os_input" resolved when possible)
+    pub language: String,
+    pub translate_to_english: bool,
+}
+
+impl ProfileSwitchPayload {
+    /// Build the payload for a profile ID ("default" uses the global language settings)
+    pub fn for_profile(settings: &settings::AppSettings, profile_id: &str) -> Self {
+        let (name, language, translate_to_english) =
+            match settings.transcription_profile(profile_id) {
+                Some(p) if profile_id != "default" => {
+                    (p.name.clone(), p.language.clone(), p.translate_to_english)
+                }
+                _ => (
+                    "Default".to_string(),
+                    settings.selected_language.clone(),
+                    settings.translate_to_english,
+                ),
+            };
+
+        let language = if language == "os_input" {
+            crate::input_source::get_language_from_input_source().unwrap_or(language)
+        } else {
+            language
+        };
+
+        Self {
+            name,
+            language,
+            translate_to_english,
+        }
+    }
+}
+
 /// Shows a brief overlay notification when switching transcription profiles.
-/// Uses the existing recording overlay to display the profile name, then auto-hides.
-pub fn show_profile_switch_overlay(app_handle: &AppHandle, profile_name: &str) {
+/// Uses the existing recording overlay to display the target language and profile name, then auto-hides.
+pub fn show_profile_switch_overlay(app_handle: &AppHandle, payload: &ProfileSwitchPayload) {
+    show_brief_overlay(app_handle, "show-profile-switch", payload);
+}
+
+/// Payload for the `show-ptt-mode` overlay event
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PttModePayload {
+    pub push_to_talk: bool,
+}
+
+/// Shows a brief overlay notification with the new recording mode (push-to-talk or toggle).
+pub fn show_ptt_mode_overlay(app_handle: &AppHandle, push_to_talk: bool) {
+    show_brief_overlay(
+        app_handle,
+        "show-ptt-mode",
+        &PttModePayload { push_to_talk },
+    );
+}
+
+/// Show the recording overlay with `event`/`payload` for a moment, then auto-hide it
+/// (unless a recording starts meanwhile).
+fn show_brief_overlay<P>(app_handle: &AppHandle, event: &str, payload: &P)
+where
+    P: serde::Serialize + Clone,
+{
     let settings = settings::get_settings(app_handle);
     if settings.overlay_position == OverlayPosition::None {
         return;
     }
 
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        apply_overlay_appearance(app_handle, &overlay_window);
+
         // Update position
         if let Some((x, y)) = calculate_overlay_position(app_handle) {
             let _ = overlay_window
@@ -511,8 +632,7 @@ pub fn show_profile_switch_overlay(app_handle: &AppHandle, profile_name: &str) {
         #[cfg(target_os = "windows")]
         force_overlay_topmost(&overlay_window);
 
-        // Emit profile name for display
-        let _ = overlay_window.emit("show-profile-switch", profile_name);
+        let _ = overlay_window.emit(event, payload);
 
         // Capture the current generation before spawning the timer thread.
         // If a recording starts before the timer fires, the generation will change
@@ -0,0 +1,215 @@
+//! Pipeline Timing
+//!
+//! Structured record of where time went in a transcription
+//! (record → stop → transcribe → post-process → paste). Each finished pipeline is
+//! logged, emitted as `pipeline-timing`, and aggregated into session stats
+//! (since app launch) for diagnosing "dictation feels slow".
+//!
+//! Timed actions: transcribe, send to extension (with and without selection) and
+//! AI Replace. Screenshot and voice command runs wait on the user and are not timed.
+
+use log::debug;
+use serde::Serialize;
+use specta::Type;
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
+
+/// Sample rate of recorded audio (used to derive the recording duration)
+const SAMPLE_RATE: u64 = 16000;
+
+/// Stage durations of a single transcription, in milliseconds
+#[derive(Debug, Clone, Default, Serialize, Type)]
+pub struct PipelineTiming {
+    pub binding_id: String,
+    /// Length of the recorded audio
+    pub record_ms: u64,
+    /// Stopping the recorder and collecting samples
+    pub stop_ms: u64,
+    /// Speech-to-text (local or remote)
+    pub transcribe_ms: u64,
+    /// Text replacements, Chinese conversion and LLM post-processing
+    /// (for AI Replace: selection capture and the LLM call)
+    pub post_process_ms: u64,
+    /// Pasting, or queuing the message for the extension
+    pub paste_ms: u64,
+    /// Time from key release until the text was pasted (excludes recording)
+    pub total_ms: u64,
+}
+
+/// Aggregated stage timings for the current app session
+#[derive(Debug, Clone, Default, Serialize, Type)]
+pub struct PipelineTimingStats {
+    pub count: u64,
+    pub avg_record_ms: u64,
+    pub avg_stop_ms: u64,
+    pub avg_transcribe_ms: u64,
+    pub avg_post_process_ms: u64,
+    pub avg_paste_ms: u64,
+    pub avg_total_ms: u64,
+    pub max_total_ms: u64,
+    pub last: Option<PipelineTiming>,
+}
+
+#[derive(Default)]
+struct Totals {
+    count: u64,
+    record_ms: u64,
+    stop_ms: u64,
+    transcribe_ms: u64,
+    post_process_ms: u64,
+    paste_ms: u64,
+    total_ms: u64,
+    max_total_ms: u64,
+    last: Option<PipelineTiming>,
+}
+
+impl Totals {
+    fn add(&mut self, timing: &PipelineTiming) {
+        self.count += 1;
+        self.record_ms += timing.record_ms;
+        self.stop_ms += timing.stop_ms;
+        self.transcribe_ms += timing.transcribe_ms;
+        self.post_process_ms += timing.post_process_ms;
+        self.paste_ms += timing.paste_ms;
+        self.total_ms += timing.total_ms;
+        self.max_total_ms = self.max_total_ms.max(timing.total_ms);
+        self.last = Some(timing.clone());
+    }
+
+    fn stats(&self) -> PipelineTimingStats {
+        if self.count == 0 {
+            return PipelineTimingStats::default();
+        }
+        let avg = |sum: u64| sum / self.count;
+        PipelineTimingStats {
+            count: self.count,
+            avg_record_ms: avg(self.record_ms),
+            avg_stop_ms: avg(self.stop_ms),
+            avg_transcribe_ms: avg(self.transcribe_ms),
+            avg_post_process_ms: avg(self.post_process_ms),
+            avg_paste_ms: avg(self.paste_ms),
+            avg_total_ms: avg(self.total_ms),
+            max_total_ms: self.max_total_ms,
+            last: self.last.clone(),
+        }
+    }
+}
+
+static SESSION_TOTALS: Mutex<Option<Totals>> = Mutex::new(None);
+
+/// Pipeline stages measured between consecutive marks
+#[derive(Debug, Clone, Copy)]
+pub enum PipelineStage {
+    Stop,
+    Transcribe,
+    PostProcess,
+    Paste,
+}
+
+/// Collects stage durations while a transcription moves through the pipeline
+#[derive(Debug)]
+pub struct PipelineTimer {
+    started: Instant,
+    last_mark: Instant,
+    timing: PipelineTiming,
+}
+
+impl PipelineTimer {
+    pub fn start(binding_id: &str) -> Self {
+        let now = Instant::now();
+        Self {
+            started: now,
+            last_mark: now,
+            timing: PipelineTiming {
+                binding_id: binding_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Record the recording length from the captured samples
+    pub fn set_recorded_samples(&mut self, sample_count: usize) {
+        self.timing.record_ms = sample_count as u64 * 1000 / SAMPLE_RATE;
+    }
+
+    /// Attribute the time since the previous mark to `stage`
+    pub fn mark(&mut self, stage: PipelineStage) {
+        let now = Instant::now();
+        let ms = now.duration_since(self.last_mark).as_millis() as u64;
+        self.last_mark = now;
+        match stage {
+            PipelineStage::Stop => self.timing.stop_ms += ms,
+            PipelineStage::Transcribe => self.timing.transcribe_ms += ms,
+            PipelineStage::PostProcess => self.timing.post_process_ms += ms,
+            PipelineStage::Paste => self.timing.paste_ms += ms,
+        }
+    }
+
+    /// Log, emit `pipeline-timing` and add to the session stats
+    pub fn finish(mut self, app: &AppHandle) {
+        self.timing.total_ms = self.started.elapsed().as_millis() as u64;
+        let timing = self.timing;
+
+        debug!(
+            "Pipeline timing [{}]: record={}ms stop={}ms transcribe={}ms post_process={}ms paste={}ms total={}ms",
+            timing.binding_id,
+            timing.record_ms,
+            timing.stop_ms,
+            timing.transcribe_ms,
+            timing.post_process_ms,
+            timing.paste_ms,
+            timing.total_ms
+        );
+
+        SESSION_TOTALS
+            .lock()
+            .unwrap()
+            .get_or_insert_with(Totals::default)
+            .add(&timing);
+
+        let _ = app.emit("pipeline-timing", &timing);
+    }
+}
+
+/// Aggregated timings since app launch
+pub fn session_stats() -> PipelineTimingStats {
+    SESSION_TOTALS
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(Totals::stats)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(transcribe_ms: u64, total_ms: u64) -> PipelineTiming {
+        PipelineTiming {
+            binding_id: "transcribe".to_string(),
+            record_ms: 2000,
+            transcribe_ms,
+            total_ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn totals_average_stages_and_track_max_and_last() {
+        let mut totals = Totals::default();
+        assert_eq!(totals.stats().count, 0);
+        assert_eq!(totals.stats().avg_total_ms, 0);
+
+        totals.add(&timing(300, 900));
+        totals.add(&timing(100, 400));
+        let stats = totals.stats();
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.avg_record_ms, 2000);
+        assert_eq!(stats.avg_transcribe_ms, 200);
+        assert_eq!(stats.avg_total_ms, 650);
+        assert_eq!(stats.max_total_ms, 900);
+        assert_eq!(stats.last.map(|t| t.total_ms), Some(400));
+    }
+}
@@ -7,13 +7,14 @@
 use crate::overlay;
 use crate::tray::{change_tray_icon, TrayIconState};
 use serde::Serialize;
+use specta::Type;
 use std::sync::atomic::{AtomicU64, Ordering};
 use tauri::{AppHandle, Emitter, Manager};
 
 static OVERLAY_GENERATION: AtomicU64 = AtomicU64::new(0);
 
 /// Error categories for overlay display
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Type)]
 #[serde(rename_all = "PascalCase")]
 pub enum OverlayErrorCategory {
     TlsCertificate,
@@ -98,7 +99,8 @@ pub fn categorize_error(err_string: &str) -> OverlayErrorCategory {
     }
 }
 
-/// Show the error overlay state with category and auto-hide after 3 seconds
+/// Show the error overlay state with category and auto-hide after `overlay_error_duration_ms`
+/// (default 3 seconds), or keep it until dismissed when `overlay_error_persist_until_dismissed` is set
 pub fn show_error_overlay(app: &AppHandle, category: OverlayErrorCategory) {
     let settings = crate::settings::get_settings(app);
     if settings.overlay_position == crate::settings::OverlayPosition::None {
@@ -127,11 +129,17 @@ pub fn show_error_overlay(app: &AppHandle, category: OverlayErrorCategory) {
         // Generation counter to prevent hiding overlay of new session
         let current_gen = OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
 
-        // Auto-hide after 3 seconds
+        // Persistent errors stay until dismiss_error_overlay (click) or the next overlay
+        if settings.overlay_error_persist_until_dismissed {
+            return;
+        }
+
+        // Auto-hide after the configured duration
+        let duration = std::time::Duration::from_millis(settings.overlay_error_duration_ms as u64);
         let window_clone = overlay_window.clone();
         let app_clone = app.clone();
         std::thread::spawn(move || {
-            std::thread::sleep(std::time::Duration::from_secs(3));
+            std::thread::sleep(duration);
             // Only hide if generation hasn't changed (no new overlay shown)
             if OVERLAY_GENERATION.load(Ordering::SeqCst) == current_gen {
                 let _ = window_clone.emit("hide-overlay", ());
@@ -146,11 +154,27 @@ pub fn show_error_overlay(app: &AppHandle, category: OverlayErrorCategory) {
     }
 }
 
+/// Hide a visible error overlay (user clicked it) and reset the tray icon.
+/// Bumps the generation so a pending auto-hide doesn't fire later.
+pub fn dismiss_error_overlay(app: &AppHandle) {
+    OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst);
+
+    if let Some(overlay_window) = app.get_webview_window("recording_overlay") {
+        let _ = overlay_window.emit("hide-overlay", ());
+        let window_clone = overlay_window.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(300));
+            let _ = window_clone.hide();
+        });
+    }
+    change_tray_icon(app, TrayIconState::Idle);
+}
+
 /// Main hook function: handle transcription errors with categorized overlay
 ///
 /// This function:
 /// 1. Categorizes the error
-/// 2. Shows error overlay for 3 seconds
+/// 2. Shows error overlay for `overlay_error_duration_ms` (or until dismissed)
 /// 3. Auto-hides overlay and resets tray icon
 ///
 /// Note: The existing toast (remote-stt-error event) should still be emitted separately
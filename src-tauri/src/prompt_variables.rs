@@ -0,0 +1,92 @@
+//! Prompt Template Variables
+//!
+//! Single-pass `${name}` substitution for LLM prompt templates (post-processing,
+//! AI Replace, and the connector "with selection" message). Supported variables:
+//!
+//! - `${output}`: the transcription (post-processing) or the selected text (AI Replace / connector)
+//! - `${instruction}`: the spoken instruction (AI Replace / connector)
+//! - `${selection}`: the selected text; empty where no selection is captured (post-processing)
+//! - `${clipboard}`: current clipboard text
+//! - `${app_name}`: the focused application (process name, else window title)
+//!
+//! Unknown tokens are left untouched, and substituted values are never expanded again,
+//! so a transcript containing "${clipboard}" stays literal.
+
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Replace every `${name}` in `template` for which `resolve` returns a value.
+pub fn substitute(template: &str, resolve: impl Fn(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match resolve(name) {
+                    Some(value) => result.push_str(&value),
+                    None => result.push_str(&rest[start..start + 2 + end + 1]),
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                // Unterminated token - keep the remainder as is
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Render a prompt template. `values` supplies caller-specific variables
+/// (`output`, `instruction`, `selection`); the app-wide ones are resolved lazily,
+/// only when the template uses them.
+pub fn render_prompt(app: &AppHandle, template: &str, values: &[(&str, &str)]) -> String {
+    substitute(template, |name| {
+        if let Some((_, value)) = values.iter().find(|(key, _)| *key == name) {
+            return Some(value.to_string());
+        }
+        match name {
+            "selection" => Some(String::new()),
+            "clipboard" => Some(app.clipboard().read_text().unwrap_or_default()),
+            "app_name" => Some(
+                crate::foreground_app::get_foreground_app()
+                    .and_then(|fg| fg.display_name())
+                    .unwrap_or_default(),
+            ),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(name: &str) -> Option<String> {
+        match name {
+            "output" => Some("hello ${clipboard}".to_string()),
+            "instruction" => Some("shorten".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn substitutes_known_and_keeps_unknown_tokens() {
+        assert_eq!(
+            substitute("${instruction}: ${output} ${unknown}", resolve),
+            "shorten: hello ${clipboard} ${unknown}"
+        );
+    }
+
+    #[test]
+    fn keeps_unterminated_token() {
+        assert_eq!(substitute("a ${output", resolve), "a ${output");
+        assert_eq!(substitute("no tokens", resolve), "no tokens");
+    }
+}
@@ -0,0 +1,74 @@
+//! Scratchpad
+//!
+//! In-app buffer that collects dictations when `scratchpad_mode` is on, so long text can
+//! be composed hands-free and pasted in one go. Runtime only; not persisted across restarts.
+//! Every change emits `scratchpad-changed` with the full contents.
+
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Managed scratchpad buffer
+#[derive(Default)]
+pub struct ScratchpadState {
+    text: Mutex<String>,
+}
+
+impl ScratchpadState {
+    /// Current contents
+    pub fn get(&self) -> String {
+        self.text.lock().unwrap().clone()
+    }
+
+    /// Append a dictation, separated from the previous one by a space.
+    /// Returns the new contents.
+    pub fn append(&self, text: &str) -> String {
+        let mut buffer = self.text.lock().unwrap();
+        append_dictation(&mut buffer, text);
+        buffer.clone()
+    }
+
+    pub fn clear(&self) {
+        self.text.lock().unwrap().clear();
+    }
+}
+
+/// Join a dictation onto the buffer, adding a space unless either side already has whitespace
+fn append_dictation(buffer: &mut String, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+    let needs_separator = !buffer.is_empty()
+        && !buffer.ends_with(char::is_whitespace)
+        && !text.starts_with(char::is_whitespace);
+    if needs_separator {
+        buffer.push(' ');
+    }
+    buffer.push_str(text);
+}
+
+/// Append to the managed scratchpad and notify the UI
+pub fn append(app: &AppHandle, text: &str) {
+    let contents = app.state::<ScratchpadState>().append(text);
+    let _ = app.emit("scratchpad-changed", contents);
+}
+
+/// Clear the managed scratchpad and notify the UI
+pub fn clear(app: &AppHandle) {
+    app.state::<ScratchpadState>().clear();
+    let _ = app.emit("scratchpad-changed", String::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_dictation_separates_with_single_space() {
+        let mut buffer = String::new();
+        append_dictation(&mut buffer, "Hello there.");
+        append_dictation(&mut buffer, "How are you?");
+        append_dictation(&mut buffer, "  ");
+        append_dictation(&mut buffer, "\nNew line");
+        assert_eq!(buffer, "Hello there. How are you?\nNew line");
+    }
+}
@@ -21,6 +21,22 @@ pub enum KeyType {
 }
 
 impl KeyType {
+    pub const ALL: [KeyType; 3] = [
+        KeyType::PostProcess,
+        KeyType::AiReplace,
+        KeyType::VoiceCommand,
+    ];
+
+    /// Parse a feature name as used by the frontend ("post_process", "ai_replace", "voice_command")
+    pub fn from_feature(feature: &str) -> Option<Self> {
+        match feature {
+            "post_process" => Some(KeyType::PostProcess),
+            "ai_replace" => Some(KeyType::AiReplace),
+            "voice_command" => Some(KeyType::VoiceCommand),
+            _ => None,
+        }
+    }
+
     fn prefix(&self) -> &'static str {
         match self {
             KeyType::PostProcess => "post_process_api_key",
@@ -98,6 +114,12 @@ pub fn get_api_key(_key_type: KeyType, _provider_id: Option<&str>) -> Result<Str
     Err(anyhow!("Secure key storage is only available on Windows"))
 }
 
+/// Delete a stored API key (succeeds if nothing was stored)
+pub fn delete_api_key(key_type: KeyType, provider_id: Option<&str>) -> Result<()> {
+    // An empty key deletes the credential
+    set_api_key(key_type, provider_id, "")
+}
+
 // ============================================================================
 // Convenience functions for specific key types
 // ============================================================================
@@ -9,12 +9,13 @@
 //! - Unregisters it exactly once on Drop
 //! - Tracks what resources were acquired to only release what was actually acquired
 
+use crate::foreground_app::FocusTarget;
 use crate::managers::audio::AudioRecordingManager;
 use crate::shortcut;
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils::hide_recording_overlay;
 use log::debug;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Manager};
 
@@ -32,10 +33,16 @@ pub enum SessionState {
         /// This is used to ensure transcription uses the correct profile
         /// even if the user switches profiles mid-recording.
         captured_profile_id: Option<String>,
+        /// Window that had focus when recording started; the paste goes back to it
+        paste_target: Option<FocusTarget>,
     },
     /// Recording finished, now processing (transcription, LLM, etc.)
     /// New recordings are blocked during this state, only cancellation is allowed.
-    Processing { binding_id: String },
+    Processing {
+        binding_id: String,
+        /// Carried over from `Recording`; None for work not tied to a recording
+        paste_target: Option<FocusTarget>,
+    },
 }
 
 impl Default for SessionState {
@@ -169,7 +176,7 @@ pub fn take_session(app: &AppHandle) -> Option<(Arc<RecordingSession>, String)>
         SessionState::Recording {
             session,
             binding_id,
-            captured_profile_id: _,
+            ..
         } => {
             debug!("take_session: Took session for {}", binding_id);
             Some((session, binding_id))
@@ -178,7 +185,7 @@ pub fn take_session(app: &AppHandle) -> Option<(Arc<RecordingSession>, String)>
             debug!("take_session: No active session to take");
             None
         }
-        SessionState::Processing { binding_id } => {
+        SessionState::Processing { binding_id, .. } => {
             debug!(
                 "take_session: Was in Processing state for {}, returning to Idle",
                 binding_id
@@ -217,7 +224,7 @@ pub fn take_session_if_matches(
                 expected_binding_id, binding_id
             );
         }
-        SessionState::Processing { binding_id } => {
+        SessionState::Processing { binding_id, .. } => {
             debug!(
                 "take_session_if_matches: In Processing state for {}",
                 binding_id
@@ -230,13 +237,64 @@ pub fn take_session_if_matches(
     None
 }
 
+/// Whether nothing is recording or processing
+pub fn is_idle(app: &AppHandle) -> bool {
+    let state = app.state::<ManagedSessionState>();
+    let state_guard = state.lock().expect("Failed to lock session state");
+    matches!(*state_guard, SessionState::Idle)
+}
+
+/// Bumped by every cancellation so in-flight processing can tell it was cancelled
+static CANCEL_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Current cancellation generation; capture it when processing starts
+pub fn cancel_generation() -> u64 {
+    CANCEL_GENERATION.load(Ordering::SeqCst)
+}
+
+/// Record a cancellation (called by `cancel_current_operation`)
+pub fn mark_cancelled() {
+    CANCEL_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Take the paste target of the recording being processed, leaving none
+/// (each recording is pasted once)
+pub fn take_paste_target(app: &AppHandle) -> Option<FocusTarget> {
+    let state = app.state::<ManagedSessionState>();
+    let mut state_guard = state.lock().expect("Failed to lock session state");
+    match &mut *state_guard {
+        SessionState::Processing { paste_target, .. } => paste_target.take(),
+        _ => None,
+    }
+}
+
+/// Whether a cancellation happened after `generation` was captured
+pub fn cancelled_since(generation: u64) -> bool {
+    cancel_generation() != generation
+}
+
+/// Enters the Processing state for work that isn't tied to a recording, blocking
+/// new recordings until `exit_processing`. Returns false unless the session was Idle.
+pub fn try_enter_processing(app: &AppHandle, binding_id: &str) -> bool {
+    let state = app.state::<ManagedSessionState>();
+    let mut state_guard = state.lock().expect("Failed to lock session state");
+    if !matches!(*state_guard, SessionState::Idle) {
+        return false;
+    }
+    *state_guard = SessionState::Processing {
+        binding_id: binding_id.to_string(),
+        paste_target: None,
+    };
+    true
+}
+
 /// Exits the Processing state, returning to Idle.
 /// Call this when async processing completes (success or error).
 pub fn exit_processing(app: &AppHandle) {
     let state = app.state::<ManagedSessionState>();
     let mut state_guard = state.lock().expect("Failed to lock session state");
 
-    if let SessionState::Processing { binding_id } = &*state_guard {
+    if let SessionState::Processing { binding_id, .. } = &*state_guard {
         debug!("exit_processing: Exiting Processing for {}", binding_id);
         *state_guard = SessionState::Idle;
     } else {
@@ -3,6 +3,7 @@ use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use specta::Type;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use tauri::AppHandle;
 use tauri_plugin_store::StoreExt;
 
@@ -83,6 +84,9 @@ pub struct ShortcutBinding {
     pub description: String,
     pub default_binding: String,
     pub current_binding: String,
+    /// Extra keys that trigger the same action (e.g., a mouse-button-mapped key)
+    #[serde(default)]
+    pub additional_bindings: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -145,6 +149,25 @@ pub struct TranscriptionProfile {
     /// If Some, uses this model instead of the global model for the current provider
     #[serde(default)]
     pub llm_model_override: Option<String>,
+    /// Spoken formatting commands ("new line", "open quote") for this profile.
+    /// None inherits the global `dictation_commands_enabled`.
+    #[serde(default)]
+    pub dictation_commands_enabled: Option<bool>,
+    /// Casing forced on this profile's output. None inherits the global `output_case_transform`.
+    #[serde(default)]
+    pub output_case_transform: Option<OutputCaseTransform>,
+    /// Capitalize sentence starts for this profile.
+    /// None inherits the global `auto_capitalize_sentences`.
+    #[serde(default)]
+    pub auto_capitalize_sentences: Option<bool>,
+    /// Filler word/hallucination filter for this profile.
+    /// None inherits the global `filler_word_filter_enabled`.
+    #[serde(default)]
+    pub filler_filter_override: Option<bool>,
+    /// VAD threshold for recordings with this profile (0.1-0.9).
+    /// None inherits the global `vad_threshold`.
+    #[serde(default)]
+    pub vad_threshold: Option<f32>,
 }
 
 impl TranscriptionProfile {
@@ -314,10 +337,49 @@ impl VoiceCommandDefaults {
     }
 }
 
+/// How a dictation command's output joins the surrounding words
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DictationAttach {
+    /// Spaced like a normal word
+    None,
+    /// No space before (punctuation, closing quote)
+    Left,
+    /// No space after (opening quote/paren)
+    Right,
+    /// No space on either side (line breaks)
+    Both,
+}
+
+/// A spoken formatting command ("new line" → "\n") for dictation command mode.
+/// Built-in commands are seeded per language; user entries override them by phrase.
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct DictationCommand {
+    /// Language code the command applies to (e.g., "en"), or "*" for all languages
+    pub language: String,
+    /// Spoken phrase, matched case-insensitively on word boundaries
+    pub phrase: String,
+    /// Replacement text (supports escape sequences: \n, \t)
+    pub output: String,
+    #[serde(default = "default_dictation_attach")]
+    pub attach: DictationAttach,
+    /// Phrase is also an ordinary word (e.g., "period"): only converted at the end
+    /// of the transcription or right before another command
+    #[serde(default)]
+    pub ambiguous: bool,
+    /// Set to false to disable a built-in command with the same phrase
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_dictation_attach() -> DictationAttach {
+    DictationAttach::None
+}
+
 /// A text replacement rule that substitutes one text pattern with another.
 /// Supports escape sequences for special characters (e.g., \n for newline).
 /// Used to automatically fix common misheard phrases or apply consistent formatting.
-#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
 pub struct TextReplacement {
     /// Unique identifier (e.g., "tr_1704067200000")
     pub id: String,
@@ -325,7 +387,8 @@ pub struct TextReplacement {
     /// If is_regex is true, this is treated as a regular expression pattern.
     pub from: String,
     /// The replacement text (supports escape sequences: \n, \r\n, \t, \\)
-    /// For regex replacements, supports $1, $2, etc. for capture groups.
+    /// For regex replacements, supports $1, $2, etc. and ${name} for capture groups, and
+    /// \U$1 / \L$1 / \u$1 to upper-case, lower-case or capitalize a group.
     pub to: String,
     /// Whether this replacement rule is enabled
     #[serde(default = "default_true")]
@@ -336,12 +399,91 @@ pub struct TextReplacement {
     /// Whether the 'from' field is a regular expression (default: false)
     #[serde(default)]
     pub is_regex: bool,
+    /// Also match words within `fuzzy_max_distance` edits of 'from', ignoring case and
+    /// spacing ("get hub" / "git hub" for "GitHub"). Ignored for regex rules.
+    #[serde(default)]
+    pub fuzzy: bool,
+    /// Maximum edit distance for fuzzy matching, capped at a third of the length of 'from'
+    #[serde(default = "default_fuzzy_max_distance")]
+    pub fuzzy_max_distance: u32,
+    /// Named group this rule belongs to; the whole group can be switched off at once
+    /// via `text_replacement_groups_enabled`. Ungrouped rules always run.
+    #[serde(default)]
+    pub group: Option<String>,
+    /// When the rule runs relative to LLM post-processing. Unset follows
+    /// `text_replacements_before_llm`.
+    #[serde(default)]
+    pub phase: Option<TextReplacementPhase>,
+}
+
+/// When a text replacement rule runs relative to LLM post-processing
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum TextReplacementPhase {
+    /// Fix the transcription before the LLM sees it
+    BeforeLlm,
+    AfterLlm,
+    Both,
+}
+
+fn default_fuzzy_max_distance() -> u32 {
+    2
+}
+
+/// Byte ranges of the words (letters, digits, apostrophes) in `text`
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        let is_word = c.is_alphanumeric() || c == '\'';
+        match (is_word, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                spans.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+    spans
+}
+
+/// Lowercased letters and digits only, so "Git Hub" and "github" compare equal
+fn fuzzy_key(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
 }
 
 impl TextReplacement {
+    /// Whether the rule runs in `phase` (`BeforeLlm` or `AfterLlm`), falling back to the
+    /// global `before_llm` setting when the rule has no phase of its own
+    pub fn runs_in(&self, phase: TextReplacementPhase, before_llm: bool) -> bool {
+        let own = self.phase.unwrap_or(if before_llm {
+            TextReplacementPhase::BeforeLlm
+        } else {
+            TextReplacementPhase::AfterLlm
+        });
+        own == TextReplacementPhase::Both || own == phase
+    }
+
+    /// Whether the rule runs: its own flag and, if it has a group, the group's flag
+    /// (groups missing from `groups_enabled` count as enabled)
+    pub fn is_active(&self, groups_enabled: &HashMap<String, bool>) -> bool {
+        self.enabled
+            && self
+                .group
+                .as_ref()
+                .map_or(true, |g| groups_enabled.get(g).copied().unwrap_or(true))
+    }
+
     /// Processes escape sequences in a string.
     /// Converts: \\n -> \n, \\r\\n -> \r\n, \\t -> \t, \\\\ -> \\
-    fn process_escapes(s: &str) -> String {
+    pub(crate) fn process_escapes(s: &str) -> String {
         let mut result = String::with_capacity(s.len());
         let mut chars = s.chars().peekable();
 
@@ -429,6 +571,33 @@ impl TextReplacement {
         result
     }
 
+    /// Processes escape sequences and compiles the regex (for regex rules) once, so the
+    /// rule can be applied repeatedly. Fails when the regex pattern is invalid.
+    pub fn compile(&self) -> Result<CompiledReplacement, String> {
+        let regex = if self.is_regex {
+            let pattern = if self.case_sensitive {
+                self.from.clone()
+            } else {
+                format!("(?i){}", self.from)
+            };
+            let re = regex::Regex::new(&pattern)
+                .map_err(|e| format!("Invalid regex pattern '{}': {}", self.from, e))?;
+            Some(re)
+        } else {
+            None
+        };
+        let template = regex
+            .as_ref()
+            .and_then(|_| parse_replacement_template(&self.to));
+        Ok(CompiledReplacement {
+            from: Self::process_escapes(&self.from),
+            to: Self::process_escapes(&self.to),
+            template,
+            regex,
+            rule: self.clone(),
+        })
+    }
+
     /// Applies this replacement rule to the given text.
     /// Returns the text with all occurrences of `from` replaced with `to`.
     pub fn apply(&self, text: &str) -> String {
@@ -436,60 +605,444 @@ impl TextReplacement {
             return text.to_string();
         }
 
-        let to_processed = Self::process_escapes(&self.to);
+        match self.compile() {
+            Ok(compiled) => compiled.apply(text),
+            Err(e) => {
+                log::warn!("{} in text replacement", e);
+                text.to_string()
+            }
+        }
+    }
 
-        if self.is_regex {
-            // Regex mode
-            let pattern = if self.case_sensitive {
-                self.from.clone()
-            } else {
-                format!("(?i){}", self.from)
-            };
+    /// Replace runs of words that are within the edit distance of `from` once joined.
+    /// A run is at most one word longer than `from` and only spans words separated by
+    /// whitespace or hyphens; the closest (then shortest) run wins at each position.
+    /// Returns the new text and the number of runs replaced.
+    fn apply_fuzzy(&self, text: &str, from: &str, to: &str) -> (String, usize) {
+        let target = fuzzy_key(from);
+        let target_len = target.chars().count();
+        if target_len == 0 {
+            return (text.to_string(), 0);
+        }
+        let max_distance = (self.fuzzy_max_distance as usize).min(target_len / 3);
+        let max_words = from.split_whitespace().count().max(1) + 1;
+
+        let words = word_spans(text);
+        let mut result = String::with_capacity(text.len());
+        let mut last_end = 0;
+        let mut count = 0;
+        let mut i = 0;
+        while i < words.len() {
+            let mut best: Option<(usize, usize)> = None;
+            for k in 1..=max_words.min(words.len() - i) {
+                if k > 1 {
+                    let gap = &text[words[i + k - 2].1..words[i + k - 1].0];
+                    if !gap.chars().all(|c| c.is_whitespace() || c == '-') {
+                        break;
+                    }
+                }
+                let candidate = fuzzy_key(&text[words[i].0..words[i + k - 1].1]);
+                if candidate.chars().count().abs_diff(target_len) > max_distance {
+                    continue;
+                }
+                let distance = strsim::levenshtein(&candidate, &target);
+                if distance <= max_distance && best.map_or(true, |(d, _)| distance < d) {
+                    best = Some((distance, k));
+                }
+            }
 
-            match regex::Regex::new(&pattern) {
-                Ok(re) => re.replace_all(text, to_processed.as_str()).to_string(),
-                Err(e) => {
-                    log::warn!(
-                        "Invalid regex pattern '{}' in text replacement: {}",
-                        self.from,
-                        e
-                    );
-                    text.to_string()
+            match best {
+                Some((_, k)) => {
+                    result.push_str(&text[last_end..words[i].0]);
+                    result.push_str(to);
+                    count += 1;
+                    last_end = words[i + k - 1].1;
+                    i += k;
                 }
+                None => i += 1,
             }
-        } else {
-            // Plain text mode
-            let from_processed = Self::process_escapes(&self.from);
+        }
+        result.push_str(&text[last_end..]);
+        (result, count)
+    }
+}
 
-            if self.case_sensitive {
-                text.replace(&from_processed, &to_processed)
-            } else {
-                // Case-insensitive plain text replacement
-                let lower_from = from_processed.to_lowercase();
-                let mut result = String::with_capacity(text.len());
-                let mut remaining = text;
-
-                while let Some(start) = remaining.to_lowercase().find(&lower_from) {
-                    result.push_str(&remaining[..start]);
-                    result.push_str(&to_processed);
-                    remaining = &remaining[start + from_processed.len()..];
+/// A `TextReplacement` with escapes processed and its regex compiled (`TextReplacement::compile`)
+#[derive(Debug, Clone)]
+pub struct CompiledReplacement {
+    rule: TextReplacement,
+    from: String,
+    to: String,
+    regex: Option<regex::Regex>,
+    /// Regex replacement split around case transforms; None when `to` has none
+    template: Option<Vec<ReplacementPart>>,
+}
+
+/// Case change applied to one capture group in a regex replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupCase {
+    /// `\U$group`
+    Upper,
+    /// `\L$group`
+    Lower,
+    /// `\u$group`: first letter upper-cased, the rest unchanged
+    Capitalize,
+}
+
+impl GroupCase {
+    fn apply(self, value: &str) -> String {
+        match self {
+            GroupCase::Upper => value.to_uppercase(),
+            GroupCase::Lower => value.to_lowercase(),
+            GroupCase::Capitalize => {
+                let mut chars = value.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
+/// A piece of a regex replacement template
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReplacementPart {
+    /// Escape-processed text, still subject to `$group` expansion
+    Literal(String),
+    /// A capture group (number or name) whose text gets a case change
+    Group(GroupCase, String),
+}
+
+/// Reads a `$name`, `$1` or `${name}` reference at the start of `s`, returning the group
+/// and the length consumed
+fn parse_group_ref(s: &str) -> Option<(String, usize)> {
+    let rest = s.strip_prefix('$')?;
+    if let Some(braced) = rest.strip_prefix('{') {
+        let end = braced.find('}')?;
+        let name = &braced[..end];
+        return (!name.is_empty()).then(|| (name.to_string(), end + 3));
+    }
+    let len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    (len > 0).then(|| (rest[..len].to_string(), len + 1))
+}
+
+/// Splits a raw regex replacement into literal parts and case-transformed groups
+/// (`\U$1`, `\L${name}`, `\u$word`). Literal parts go through `process_escapes`
+/// afterwards, so `\n`, `\t` and `\\` behave as before, and a `\U` that isn't
+/// followed by a group stays as written. Returns None when there are no transforms.
+fn parse_replacement_template(raw: &str) -> Option<Vec<ReplacementPart>> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut rest = raw;
+    while let Some(pos) = rest.find('\\') {
+        literal.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        let case = match after.chars().next() {
+            Some('U') => Some(GroupCase::Upper),
+            Some('L') => Some(GroupCase::Lower),
+            Some('u') => Some(GroupCase::Capitalize),
+            _ => None,
+        };
+        match case.and_then(|case| parse_group_ref(&after[1..]).map(|g| (case, g))) {
+            Some((case, (group, len))) => {
+                if !literal.is_empty() {
+                    let text = TextReplacement::process_escapes(&literal);
+                    parts.push(ReplacementPart::Literal(text));
+                    literal.clear();
+                }
+                parts.push(ReplacementPart::Group(case, group));
+                rest = &after[1 + len..];
+            }
+            None => {
+                // Copy the escape whole so "\\U" stays a literal backslash + U
+                let len = after.chars().next().map_or(0, char::len_utf8);
+                literal.push('\\');
+                literal.push_str(&after[..len]);
+                rest = &after[len..];
+            }
+        }
+    }
+    if parts.is_empty() {
+        return None;
+    }
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        parts.push(ReplacementPart::Literal(TextReplacement::process_escapes(
+            &literal,
+        )));
+    }
+    Some(parts)
+}
+
+/// Builds the replacement for one regex match from a parsed template
+fn expand_template(parts: &[ReplacementPart], caps: &regex::Captures) -> String {
+    let mut out = String::new();
+    for part in parts {
+        match part {
+            ReplacementPart::Literal(text) => caps.expand(text, &mut out),
+            ReplacementPart::Group(case, group) => {
+                let matched = match group.parse::<usize>() {
+                    Ok(index) => caps.get(index),
+                    Err(_) => caps.name(group),
+                };
+                out.push_str(&case.apply(matched.map_or("", |m| m.as_str())));
+            }
+        }
+    }
+    out
+}
+
+impl CompiledReplacement {
+    pub fn apply(&self, text: &str) -> String {
+        self.apply_counted(text).0
+    }
+
+    /// Applies the rule and also returns how many matches were replaced
+    pub fn apply_counted(&self, text: &str) -> (String, usize) {
+        let rule = &self.rule;
+        if !rule.enabled || rule.from.is_empty() {
+            return (text.to_string(), 0);
+        }
+
+        if let Some(re) = &self.regex {
+            let count = re.find_iter(text).count();
+            let replaced = match &self.template {
+                Some(parts) => {
+                    re.replace_all(text, |caps: &regex::Captures| expand_template(parts, caps))
                 }
-                result.push_str(remaining);
-                result
+                None => re.replace_all(text, self.to.as_str()),
+            };
+            (replaced.to_string(), count)
+        } else if rule.fuzzy {
+            rule.apply_fuzzy(text, &self.from, &self.to)
+        } else if rule.case_sensitive {
+            let count = text.matches(self.from.as_str()).count();
+            (text.replace(&self.from, &self.to), count)
+        } else {
+            // Case-insensitive plain text replacement
+            let lower_from = self.from.to_lowercase();
+            let mut result = String::with_capacity(text.len());
+            let mut remaining = text;
+            let mut count = 0;
+
+            while let Some(start) = remaining.to_lowercase().find(&lower_from) {
+                result.push_str(&remaining[..start]);
+                result.push_str(&self.to);
+                remaining = &remaining[start + self.from.len()..];
+                count += 1;
             }
+            result.push_str(remaining);
+            (result, count)
         }
     }
 }
 
-/// Applies all enabled text replacement rules to the given text.
-pub fn apply_text_replacements(text: &str, replacements: &[TextReplacement]) -> String {
-    let mut result = text.to_string();
-    for replacement in replacements {
-        if replacement.enabled {
-            result = replacement.apply(&result);
+/// The enabled rules of a rule list, compiled once and applied in order
+#[derive(Debug)]
+pub struct CompiledReplacements {
+    /// The rules and group flags this was compiled from, to detect edits
+    source: Vec<TextReplacement>,
+    groups_enabled: HashMap<String, bool>,
+    rules: Vec<CompiledReplacement>,
+}
+
+impl CompiledReplacements {
+    /// Compiles the active rules. Invalid regexes are logged and skipped; use
+    /// `validate_text_replacements` to reject them when the rules are saved.
+    pub fn new(replacements: &[TextReplacement], groups_enabled: &HashMap<String, bool>) -> Self {
+        let rules = replacements
+            .iter()
+            .filter(|r| r.is_active(groups_enabled))
+            .filter_map(|r| match r.compile() {
+                Ok(compiled) => Some(compiled),
+                Err(e) => {
+                    warn!("Skipping text replacement {}: {}", r.id, e);
+                    None
+                }
+            })
+            .collect();
+        Self {
+            source: replacements.to_vec(),
+            groups_enabled: groups_enabled.clone(),
+            rules,
         }
     }
-    result
+
+    /// Applies only the rules that run in `phase`; None when there are none
+    pub fn apply_in_phase(
+        &self,
+        text: &str,
+        phase: TextReplacementPhase,
+        before_llm: bool,
+    ) -> Option<String> {
+        let mut rules = self
+            .rules
+            .iter()
+            .filter(|r| r.rule.runs_in(phase, before_llm))
+            .peekable();
+        rules.peek()?;
+        Some(rules.fold(text.to_string(), |result, rule| rule.apply(&result)))
+    }
+}
+
+/// The last compiled rule list. Rebuilt whenever a rule's text, flags or group change.
+static COMPILED_REPLACEMENTS: Mutex<Option<Arc<CompiledReplacements>>> = Mutex::new(None);
+
+fn compiled_replacements(
+    replacements: &[TextReplacement],
+    groups_enabled: &HashMap<String, bool>,
+) -> Arc<CompiledReplacements> {
+    let mut cache = COMPILED_REPLACEMENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(compiled) = cache
+        .as_ref()
+        .filter(|c| c.source == replacements && &c.groups_enabled == groups_enabled)
+    {
+        return compiled.clone();
+    }
+    let compiled = Arc::new(CompiledReplacements::new(replacements, groups_enabled));
+    *cache = Some(compiled.clone());
+    compiled
+}
+
+/// Applies the active rules that run in `phase` (see `TextReplacement::runs_in`).
+/// Returns None when no rule runs in that phase.
+pub fn apply_text_replacements_in_phase(
+    text: &str,
+    replacements: &[TextReplacement],
+    groups_enabled: &HashMap<String, bool>,
+    phase: TextReplacementPhase,
+    before_llm: bool,
+) -> Option<String> {
+    compiled_replacements(replacements, groups_enabled).apply_in_phase(text, phase, before_llm)
+}
+
+/// Applies the before-LLM rules, then the after-LLM rules, as the pipeline does when no
+/// LLM step runs in between. Rules in both phases run twice.
+pub fn apply_text_replacements_by_phase(
+    text: &str,
+    replacements: &[TextReplacement],
+    groups_enabled: &HashMap<String, bool>,
+    before_llm: bool,
+) -> String {
+    let compiled = compiled_replacements(replacements, groups_enabled);
+    PHASES.iter().fold(text.to_string(), |text, &phase| {
+        compiled
+            .apply_in_phase(&text, phase, before_llm)
+            .unwrap_or(text)
+    })
+}
+
+/// The phases in pipeline order
+const PHASES: [TextReplacementPhase; 2] = [
+    TextReplacementPhase::BeforeLlm,
+    TextReplacementPhase::AfterLlm,
+];
+
+/// Checks that every enabled regex rule compiles, so a bad pattern is rejected when the
+/// rules are saved instead of being skipped at transcription time.
+pub fn validate_text_replacements(replacements: &[TextReplacement]) -> Result<(), String> {
+    replacements
+        .iter()
+        .filter(|r| r.enabled)
+        .try_for_each(|r| r.compile().map(drop))
+}
+
+/// One rule's effect in `preview_text_replacements`
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+pub struct ReplacementStep {
+    pub rule_id: String,
+    pub before: String,
+    pub after: String,
+    pub matches: u32,
+    /// Why the rule couldn't run (invalid regex); `after` equals `before` then
+    pub error: Option<String>,
+}
+
+/// Runs each active rule over `text` in pipeline order (before-LLM rules, then after-LLM
+/// rules), recording every step. A rule that fails to compile is reported in its step
+/// and skipped; the rest of the chain still runs.
+pub fn preview_text_replacements(
+    text: &str,
+    replacements: &[TextReplacement],
+    groups_enabled: &HashMap<String, bool>,
+    before_llm: bool,
+) -> Vec<ReplacementStep> {
+    let mut current = text.to_string();
+    PHASES
+        .iter()
+        .flat_map(|&phase| {
+            replacements
+                .iter()
+                .filter(move |r| r.runs_in(phase, before_llm))
+        })
+        .filter(|r| r.is_active(groups_enabled))
+        .map(|rule| {
+            let before = current.clone();
+            let (after, matches, error) = match rule.compile() {
+                Ok(compiled) => {
+                    let (after, matches) = compiled.apply_counted(&before);
+                    (after, matches, None)
+                }
+                Err(e) => (before.clone(), 0, Some(e)),
+            };
+            current = after.clone();
+            ReplacementStep {
+                rule_id: rule.id.clone(),
+                before,
+                after,
+                matches: matches as u32,
+                error,
+            }
+        })
+        .collect()
+}
+
+/// A representative input and the output the text replacement rules should produce
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct TextReplacementTestCase {
+    pub input: String,
+    pub expected: String,
+}
+
+/// Outcome of one `TextReplacementTestCase`
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+pub struct TextReplacementTestResult {
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+    pub passed: bool,
+}
+
+/// Runs every case through the full rule set (in pipeline order, so rules feeding each
+/// other are exercised) and compares with the expected output.
+pub fn run_text_replacement_suite(
+    cases: Vec<TextReplacementTestCase>,
+    replacements: &[TextReplacement],
+    groups_enabled: &HashMap<String, bool>,
+    before_llm: bool,
+) -> Vec<TextReplacementTestResult> {
+    cases
+        .into_iter()
+        .map(|case| {
+            let actual = apply_text_replacements_by_phase(
+                &case.input,
+                replacements,
+                groups_enabled,
+                before_llm,
+            );
+            TextReplacementTestResult {
+                passed: actual == case.expected,
+                input: case.input,
+                expected: case.expected,
+                actual,
+            }
+        })
+        .collect()
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -501,6 +1054,12 @@ pub struct PostProcessProvider {
     pub allow_base_url_edit: bool,
     #[serde(default)]
     pub models_endpoint: Option<String>,
+    /// Optional OpenAI organization ID, sent as `OpenAI-Organization`
+    #[serde(default)]
+    pub organization: Option<String>,
+    /// Optional OpenAI project ID, sent as `OpenAI-Project`
+    #[serde(default)]
+    pub project: Option<String>,
 }
 
 /// Which feature is requesting LLM access.
@@ -526,6 +1085,173 @@ pub struct LlmConfig {
     pub base_url: String,
 }
 
+/// How `llm_config_for` resolved a feature, for support and debugging.
+/// Only reports whether a key is present, never the key itself.
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct LlmConfigDebug {
+    pub feature: LlmFeature,
+    pub provider_id: String,
+    pub model: String,
+    pub base_url: String,
+    pub has_api_key: bool,
+    /// Resolution steps in order, e.g. "used ai_replace key" or "fell back to post-process key"
+    pub fallback_path: Vec<String>,
+}
+
+/// Optional decoding parameters for local transcription (None = engine default).
+/// Whisper only; Parakeet and Moonshine ignore them.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Type)]
+pub struct LocalDecodeParams {
+    /// Beam search width (1-10)
+    #[serde(default)]
+    pub beam_size: Option<u32>,
+    /// Sampling temperature (0.0-1.0)
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    /// Probability above which a segment is treated as silence (0.0-1.0)
+    #[serde(default)]
+    pub no_speech_threshold: Option<f32>,
+    /// Feed previously decoded text back as context
+    #[serde(default)]
+    pub condition_on_previous: Option<bool>,
+}
+
+impl LocalDecodeParams {
+    /// Check that every set parameter is within its supported range.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(beam_size) = self.beam_size {
+            if !(1..=10).contains(&beam_size) {
+                return Err(format!(
+                    "beam_size must be between 1 and 10, got {}",
+                    beam_size
+                ));
+            }
+        }
+        if let Some(temperature) = self.temperature {
+            if !(0.0..=1.0).contains(&temperature) {
+                return Err(format!(
+                    "temperature must be between 0.0 and 1.0, got {}",
+                    temperature
+                ));
+            }
+        }
+        if let Some(threshold) = self.no_speech_threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(format!(
+                    "no_speech_threshold must be between 0.0 and 1.0, got {}",
+                    threshold
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Recording overlay appearance. Defaults match the built-in look.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct OverlayAppearance {
+    /// Background opacity (0.0-1.0)
+    #[serde(default = "default_overlay_background_opacity")]
+    pub background_opacity: f32,
+    /// Accent color for the level bars, as "#rrggbb"
+    #[serde(default = "default_overlay_accent_color")]
+    pub accent_color: String,
+    /// Size multiplier (0.5-2.0)
+    #[serde(default = "default_overlay_scale")]
+    pub scale: f32,
+}
+
+impl Default for OverlayAppearance {
+    fn default() -> Self {
+        Self {
+            background_opacity: default_overlay_background_opacity(),
+            accent_color: default_overlay_accent_color(),
+            scale: default_overlay_scale(),
+        }
+    }
+}
+
+impl OverlayAppearance {
+    /// Check ranges and that the accent color is a "#rrggbb" / "#rgb" hex color.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.background_opacity) {
+            return Err(format!(
+                "background_opacity must be between 0.0 and 1.0, got {}",
+                self.background_opacity
+            ));
+        }
+        if !(0.5..=2.0).contains(&self.scale) {
+            return Err(format!(
+                "scale must be between 0.5 and 2.0, got {}",
+                self.scale
+            ));
+        }
+        let hex = self.accent_color.strip_prefix('#').unwrap_or("");
+        if !matches!(hex.len(), 3 | 6) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!(
+                "accent_color must be a hex color like #ffe5ee, got \"{}\"",
+                self.accent_color
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn default_overlay_background_opacity() -> f32 {
+    0.8
+}
+
+fn default_overlay_accent_color() -> String {
+    "#ffe5ee".to_string()
+}
+
+fn default_overlay_scale() -> f32 {
+    1.0
+}
+
+/// A custom word for fuzzy correction, with an optional per-word match threshold.
+/// Older settings stored plain strings; those load with `threshold: None`.
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+pub struct CustomWord {
+    pub word: String,
+    /// Overrides `word_correction_threshold` for this word (None = use the global value)
+    #[serde(default)]
+    pub threshold: Option<f64>,
+}
+
+impl CustomWord {
+    pub fn new(word: impl Into<String>) -> Self {
+        Self {
+            word: word.into(),
+            threshold: None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomWord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Accept both the legacy plain-string form and the structured form
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Plain(String),
+            Full {
+                word: String,
+                #[serde(default)]
+                threshold: Option<f64>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Plain(word) => CustomWord::new(word),
+            Repr::Full { word, threshold } => CustomWord { word, threshold },
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum TranscriptionProvider {
@@ -581,6 +1307,31 @@ pub enum OverlayPosition {
     Bottom,
 }
 
+/// What switching the active profile does while a recording is in flight
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileSwitchDuringRecording {
+    /// Switch now; the in-flight recording keeps the profile it started with
+    #[default]
+    NextRecording,
+    /// Refuse the switch and tell the user why
+    Reject,
+}
+
+/// Which display the recording overlay appears on
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayMonitor {
+    /// The monitor under the mouse cursor
+    #[default]
+    Cursor,
+    /// The monitor containing the focused window (falls back to the cursor's monitor)
+    ActiveWindow,
+    Primary,
+    /// Index into the system monitor list (falls back to the primary monitor)
+    MonitorIndex(u32),
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum ScreenshotCaptureMethod {
@@ -597,6 +1348,19 @@ pub enum NativeRegionCaptureMode {
     ScreenshotBackground,
 }
 
+/// Which device local (Whisper) transcription should run on
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AccelerationPreference {
+    /// Let the engine pick (GPU when available)
+    #[default]
+    Auto,
+    /// Force CPU inference
+    Cpu,
+    /// Prefer a GPU, optionally a specific index (`acceleration_gpu_index`)
+    Gpu,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum ModelUnloadTimeout {
@@ -629,6 +1393,43 @@ pub enum ClipboardHandling {
     RestoreAdvanced,
 }
 
+/// Entry format of the transcription log file
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionLogFormat {
+    /// Readable blocks separated by a blank line
+    #[default]
+    Text,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// Casing forced on transcription output as the last step before paste
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputCaseTransform {
+    /// Keep the text as transcribed
+    #[default]
+    None,
+    Lower,
+    Upper,
+    /// Simple word-boundary heuristic, not locale-aware titlecasing
+    Title,
+}
+
+/// Where the AI Replace result goes
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AiReplaceOutputMode {
+    /// Paste over the selection
+    #[default]
+    Replace,
+    /// Copy to the clipboard only (paste manually elsewhere)
+    Clipboard,
+    /// Paste over the selection and keep a copy on the clipboard
+    Both,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum RecordingRetentionPeriod {
@@ -729,6 +1530,10 @@ pub struct AppSettings {
     pub update_checks_enabled: bool,
     #[serde(default = "default_model")]
     pub selected_model: String,
+    /// Optional per-language local model (language code -> model ID).
+    /// Falls back to `selected_model` when the resolved language has no entry.
+    #[serde(default)]
+    pub models_by_language: HashMap<String, String>,
     #[serde(default = "default_transcription_provider")]
     pub transcription_provider: TranscriptionProvider,
     #[serde(default = "default_remote_stt_settings")]
@@ -741,18 +1546,53 @@ pub struct AppSettings {
     pub clamshell_microphone: Option<String>,
     #[serde(default)]
     pub selected_output_device: Option<String>,
+    /// Output devices to try, in order, when `selected_output_device` can't be opened
+    /// (e.g. headphones disconnected). The default device is always tried last.
+    #[serde(default)]
+    pub output_device_fallbacks: Vec<String>,
     #[serde(default = "default_translate_to_english")]
     pub translate_to_english: bool,
     #[serde(default = "default_selected_language")]
     pub selected_language: String,
+    /// When `selected_language` is "auto", restrict detection to these languages (empty = any)
+    #[serde(default)]
+    pub auto_language_candidates: Vec<String>,
+    /// Minimum detection confidence (0.0-1.0) among the candidates; below it the OS input
+    /// language is used if it's a candidate, else the first candidate. Whisper only.
+    #[serde(default = "default_auto_language_min_confidence")]
+    pub auto_language_min_confidence: f32,
+    /// Languages listed first in the language selector
+    #[serde(default)]
+    pub preferred_languages: Vec<String>,
+    /// Show only `preferred_languages` (plus "auto") in the language selector
+    #[serde(default)]
+    pub preferred_languages_only: bool,
     #[serde(default = "default_overlay_position")]
     pub overlay_position: OverlayPosition,
+    #[serde(default)]
+    pub overlay_monitor: OverlayMonitor,
+    #[serde(default)]
+    pub overlay_appearance: OverlayAppearance,
+    /// How long error overlays stay visible before auto-hiding
+    #[serde(default = "default_overlay_error_duration_ms")]
+    pub overlay_error_duration_ms: u32,
+    /// Keep error overlays visible until clicked
+    #[serde(default)]
+    pub overlay_error_persist_until_dismissed: bool,
     #[serde(default = "default_debug_mode")]
     pub debug_mode: bool,
     #[serde(default = "default_log_level")]
     pub log_level: LogLevel,
     #[serde(default)]
-    pub custom_words: Vec<String>,
+    pub custom_words: Vec<CustomWord>,
+    /// Decoding parameters for local models (defaults keep engine behavior)
+    #[serde(default)]
+    pub local_decode_params: LocalDecodeParams,
+    #[serde(default)]
+    pub acceleration_preference: AccelerationPreference,
+    /// GPU index used when `acceleration_preference` is Gpu (None = default GPU)
+    #[serde(default)]
+    pub acceleration_gpu_index: Option<u32>,
     #[serde(default = "default_custom_words_enabled")]
     pub custom_words_enabled: bool,
     #[serde(default)]
@@ -763,6 +1603,19 @@ pub struct AppSettings {
     pub history_limit: usize,
     #[serde(default = "default_recording_retention_period")]
     pub recording_retention_period: RecordingRetentionPeriod,
+    /// Store segment timings with history entries so they can be exported as SRT/VTT.
+    /// Only applies to local models; must be enabled before recording.
+    #[serde(default)]
+    pub history_store_segments: bool,
+    /// Drop the audio of cancelled recordings instead of keeping it in history (for debugging)
+    #[serde(default = "default_true")]
+    pub discard_cancelled_recordings: bool,
+    /// Append every completed transcription (timestamp, profile, raw, processed) to this
+    /// file. Separate from history and never pruned. None disables the log.
+    #[serde(default)]
+    pub transcription_log_path: Option<String>,
+    #[serde(default)]
+    pub transcription_log_format: TranscriptionLogFormat,
     #[serde(default)]
     pub paste_method: PasteMethod,
     /// Convert LF to CRLF before clipboard paste (fixes newlines on Windows)
@@ -770,12 +1623,30 @@ pub struct AppSettings {
     pub convert_lf_to_crlf: bool,
     #[serde(default)]
     pub clipboard_handling: ClipboardHandling,
+    /// Always leave the final text on the clipboard after pasting, regardless of paste method
+    #[serde(default)]
+    pub always_copy_result: bool,
+    /// Wait before pasting so the target app regains focus after the shortcut release
+    /// (fast pastes otherwise lose their first characters in some apps)
+    #[serde(default = "default_paste_delay_ms")]
+    pub paste_delay_ms: u64,
+    /// Extra clipboard reads when selection capture comes back empty (the copy may not
+    /// have landed yet). 0 disables retries.
+    #[serde(default = "default_selection_capture_retries")]
+    pub selection_capture_retries: u32,
+    /// Delay before each selection capture retry
+    #[serde(default = "default_selection_capture_retry_delay_ms")]
+    pub selection_capture_retry_delay_ms: u64,
     #[serde(default = "default_post_process_enabled")]
     pub post_process_enabled: bool,
     #[serde(default = "default_post_process_provider_id")]
     pub post_process_provider_id: String,
     #[serde(default = "default_post_process_providers")]
     pub post_process_providers: Vec<PostProcessProvider>,
+    /// Allow editing the base URL of built-in providers (e.g. an internal OpenAI gateway).
+    /// Locked by default: only providers with `allow_base_url_edit` ("custom") are editable.
+    #[serde(default)]
+    pub unlock_builtin_provider_base_urls: bool,
     #[serde(default = "default_post_process_api_keys")]
     pub post_process_api_keys: HashMap<String, String>,
     #[serde(default = "default_post_process_models")]
@@ -800,6 +1671,11 @@ pub struct AppSettings {
     pub ai_replace_quick_tap_threshold_ms: u32,
     #[serde(default = "default_ai_replace_quick_tap_system_prompt")]
     pub ai_replace_quick_tap_system_prompt: String,
+    #[serde(default)]
+    pub ai_replace_output_mode: AiReplaceOutputMode,
+    /// Stop sequences sent with AI Replace requests (empty = none)
+    #[serde(default)]
+    pub ai_replace_stop_sequences: Vec<String>,
     /// AI Replace LLM provider ID (separate from post-processing)
     #[serde(default)]
     pub ai_replace_provider_id: Option<String>,
@@ -835,8 +1711,27 @@ pub struct AppSettings {
     pub mute_while_recording: bool,
     #[serde(default)]
     pub append_trailing_space: bool,
+    /// Collect transcriptions in the scratchpad instead of pasting each one
+    #[serde(default)]
+    pub scratchpad_mode: bool,
+    /// Per-app output suffix: foreground app pattern (process name or window title,
+    /// case-insensitive substring) -> suffix appended when pasting. Supports escapes like \\n.
+    /// Takes precedence over `append_trailing_space` when a pattern matches.
+    #[serde(default)]
+    pub paste_suffix_by_app: HashMap<String, String>,
+    /// Foreground app patterns (same matching as `paste_suffix_by_app`) for which
+    /// LLM post-processing is skipped and the raw transcription is pasted. Empty = no gate.
+    #[serde(default)]
+    pub post_process_disabled_apps: Vec<String>,
+    /// Reset a stuck "Transcribing" overlay/tray to Idle after this many seconds (0 = disabled)
+    #[serde(default = "default_transcribing_watchdog_secs")]
+    pub transcribing_watchdog_secs: u32,
     #[serde(default = "default_connector_port")]
     pub connector_port: u16,
+    /// If the connector port is taken, try the next ports and listen on the first that binds.
+    /// The bound port is runtime state; `connector_port` is never rewritten.
+    #[serde(default)]
+    pub connector_port_auto_fallback: bool,
     #[serde(default = "default_connector_auto_open_enabled")]
     pub connector_auto_open_enabled: bool,
     #[serde(default = "default_connector_auto_open_url")]
@@ -876,6 +1771,22 @@ pub struct AppSettings {
     /// Pending password awaiting acknowledgement from extension (two-phase commit)
     #[serde(default)]
     pub connector_pending_password: Option<String>,
+    /// Apply text replacements to messages sent to the extension (same ordering as paste)
+    #[serde(default = "default_true")]
+    pub connector_apply_text_replacements: bool,
+    /// Drop queued connector messages older than this instead of delivering them (0 = never)
+    #[serde(default = "default_connector_message_ttl_secs")]
+    pub connector_message_ttl_secs: u32,
+    /// Prepend "#<profile name>" to connector messages
+    #[serde(default)]
+    pub connector_tag_with_profile: bool,
+    /// Tag used for the default profile when tagging is on (empty = no tag)
+    #[serde(default)]
+    pub connector_default_profile_tag: String,
+    /// Origins allowed to call the connector server (e.g. "chrome-extension://<id>").
+    /// Empty allows any origin.
+    #[serde(default)]
+    pub connector_allowed_origins: Vec<String>,
     /// Per-model transcription prompts (model_id -> prompt text)
     /// For Whisper: context/terms prompt. For Parakeet: comma-separated boost words.
     #[serde(default)]
@@ -891,6 +1802,8 @@ pub struct AppSettings {
     /// Whether to show an overlay notification when switching profiles
     #[serde(default = "default_true")]
     pub profile_switch_overlay_enabled: bool,
+    #[serde(default)]
+    pub profile_switch_during_recording: ProfileSwitchDuringRecording,
     // ==================== Voice Command Center ====================
     /// Whether the Voice Command feature is enabled
     #[serde(default)]
@@ -907,6 +1820,13 @@ pub struct AppSettings {
     /// Whether to use LLM fallback when no predefined command matches
     #[serde(default = "default_true")]
     pub voice_command_llm_fallback: bool,
+    /// Minimum number of spoken words before the LLM fallback is attempted (0 = no minimum)
+    #[serde(default)]
+    pub voice_command_llm_min_words: usize,
+    /// Match built-in "switch to <profile>" and "use <prompt>" phrases alongside the
+    /// predefined commands
+    #[serde(default)]
+    pub voice_command_meta_commands_enabled: bool,
     /// System prompt for LLM command generation
     #[serde(default = "default_voice_command_system_prompt")]
     pub voice_command_system_prompt: String,
@@ -932,6 +1852,34 @@ pub struct AppSettings {
     /// Token budget for post-processing extended thinking (min: 1024, default: 2048)
     #[serde(default = "default_reasoning_budget")]
     pub post_process_reasoning_budget: u32,
+    /// When set, parse the post-process response as JSON and paste only this field
+    /// (dotted path for nested fields). Falls back to the raw response.
+    #[serde(default)]
+    pub post_process_extract_json_field: Option<String>,
+    /// Prepend a correction hint to the post-process prompt when the transcription
+    /// confidence is below this (0.0-1.0, None = off). Only Whisper reports confidence.
+    #[serde(default)]
+    pub post_process_low_confidence_threshold: Option<f32>,
+    /// Custom low-confidence hint (None = built-in)
+    #[serde(default)]
+    pub post_process_low_confidence_hint: Option<String>,
+    /// Stop sequences sent with post-processing requests (empty = none). Escapes like
+    /// `\n` are processed; providers that reject `stop` are retried without it.
+    #[serde(default)]
+    pub post_process_stop_sequences: Vec<String>,
+    /// Paste the raw transcription immediately, then select it back and replace it with
+    /// the post-processed text once that differs. Best-effort: if the caret moves in
+    /// between, the wrong range gets replaced.
+    #[serde(default)]
+    pub post_process_paste_then_replace: bool,
+    /// LLM requests allowed in flight at once per provider (post-processing, AI Replace,
+    /// voice commands); extra requests queue instead of tripping rate limits
+    #[serde(default = "default_llm_max_concurrent_requests")]
+    pub llm_max_concurrent_requests: usize,
+    /// Unwrap post-processing and AI Replace output that is entirely one code fence or
+    /// quoted string (kept when the prompt/instruction asks for code)
+    #[serde(default = "default_true")]
+    pub strip_llm_formatting: bool,
     /// Whether to enable extended thinking for AI Replace LLM calls
     #[serde(default)]
     pub ai_replace_reasoning_enabled: bool,
@@ -954,6 +1902,10 @@ pub struct AppSettings {
     /// Token budget for Voice Command extended thinking (min: 1024, default: 2048)
     #[serde(default = "default_reasoning_budget")]
     pub voice_command_reasoning_budget: u32,
+    /// Ask the Voice Command LLM for JSON (`{command, safe, explanation}`) via `response_format`.
+    /// Plain-text replies (providers without JSON mode) are still accepted.
+    #[serde(default)]
+    pub voice_command_structured_output: bool,
     // ==================== Voice Command Fuzzy Matching ====================
     /// Whether to use Levenshtein distance for character-level matching
     #[serde(default = "default_true")]
@@ -981,11 +1933,26 @@ pub struct AppSettings {
     /// List of text replacement rules
     #[serde(default)]
     pub text_replacements: Vec<TextReplacement>,
+    /// On/off state of named text replacement groups; groups not listed are enabled
+    #[serde(default)]
+    pub text_replacement_groups_enabled: HashMap<String, bool>,
     /// Whether to apply text replacements BEFORE LLM post-processing (default: after)
     /// When true: STT → Text Replacement → LLM → Output
     /// When false (default): STT → LLM → Text Replacement → Output
     #[serde(default)]
     pub text_replacements_before_llm: bool,
+    /// Convert spoken formatting commands ("new line", "open quote") before other text processing
+    #[serde(default)]
+    pub dictation_commands_enabled: bool,
+    /// User additions/overrides for the built-in dictation commands
+    #[serde(default)]
+    pub dictation_commands: Vec<DictationCommand>,
+    /// Casing forced on transcription output after all other text processing
+    #[serde(default)]
+    pub output_case_transform: OutputCaseTransform,
+    /// Capitalize the first letter after `.`, `?` or `!` and whitespace (rule-based, no LLM)
+    #[serde(default)]
+    pub auto_capitalize_sentences: bool,
     // ==================== Audio Processing ====================
     /// Whether to filter filler words (uh, um, hmm, etc.) from transcriptions
     #[serde(default)]
@@ -995,6 +1962,18 @@ pub struct AppSettings {
     /// Higher = less sensitive (cleaner input but may cut off quiet speech)
     #[serde(default = "default_vad_threshold")]
     pub vad_threshold: f32,
+    /// How often `mic-level` meter updates are emitted (1-60 Hz).
+    /// Lower rates reduce overhead on weaker hardware.
+    #[serde(default = "default_audio_meter_hz")]
+    pub audio_meter_hz: u32,
+    /// Skip transcription when the recording has no speech: VAD kept nothing, or no
+    /// 30 ms window reaches `speech_energy_threshold_dbfs`. Emits `transcription-empty`.
+    #[serde(default = "default_true")]
+    pub skip_non_speech_recordings: bool,
+    /// RMS level (dBFS) a window must reach to count as speech for the non-speech gate.
+    /// Kept well below normal speech so quiet-but-real speech isn't dropped.
+    #[serde(default = "default_speech_energy_threshold_dbfs")]
+    pub speech_energy_threshold_dbfs: f32,
     // ==================== Shortcut Engine (Windows only) ====================
     /// Which shortcut engine to use for global hotkeys (Windows only)
     /// - "tauri": High performance, but doesn't support Caps Lock, Num Lock, modifier-only shortcuts
@@ -1039,10 +2018,38 @@ fn default_remote_stt_settings() -> RemoteSttSettings {
     }
 }
 
+/// Documented range of `vad_threshold` (global and per profile)
+pub const VAD_THRESHOLD_MIN: f32 = 0.1;
+pub const VAD_THRESHOLD_MAX: f32 = 0.9;
+
 fn default_vad_threshold() -> f32 {
     0.3 // Original Handy default - more sensitive
 }
 
+fn default_audio_meter_hz() -> u32 {
+    20
+}
+
+fn default_speech_energy_threshold_dbfs() -> f32 {
+    -55.0
+}
+
+fn default_paste_delay_ms() -> u64 {
+    50
+}
+
+fn default_llm_max_concurrent_requests() -> usize {
+    2
+}
+
+fn default_selection_capture_retries() -> u32 {
+    3
+}
+
+fn default_selection_capture_retry_delay_ms() -> u64 {
+    50
+}
+
 fn default_always_on_microphone() -> bool {
     false
 }
@@ -1067,6 +2074,18 @@ fn default_selected_language() -> String {
     "auto".to_string()
 }
 
+fn default_transcribing_watchdog_secs() -> u32 {
+    180
+}
+
+fn default_auto_language_min_confidence() -> f32 {
+    0.5
+}
+
+fn default_overlay_error_duration_ms() -> u32 {
+    3000
+}
+
 fn default_overlay_position() -> OverlayPosition {
     #[cfg(target_os = "linux")]
     return OverlayPosition::None;
@@ -1120,6 +2139,10 @@ fn default_connector_port() -> u16 {
     38243
 }
 
+fn default_connector_message_ttl_secs() -> u32 {
+    0 // Off: deliver queued messages whenever the extension comes back
+}
+
 fn default_connector_auto_open_enabled() -> bool {
     false
 }
@@ -1262,7 +2285,7 @@ fn default_send_to_extension_with_selection_user_prompt() -> String {
     default_ai_replace_user_prompt()
 }
 
-fn default_post_process_providers() -> Vec<PostProcessProvider> {
+pub fn default_post_process_providers() -> Vec<PostProcessProvider> {
     // mut is required on macOS where we push Apple Intelligence provider
     #[allow(unused_mut)]
     let mut providers = vec![
@@ -1272,6 +2295,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.openai.com/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            organization: None,
+            project: None,
         },
         PostProcessProvider {
             id: "openrouter".to_string(),
@@ -1279,6 +2304,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://openrouter.ai/api/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            organization: None,
+            project: None,
         },
         PostProcessProvider {
             id: "anthropic".to_string(),
@@ -1286,6 +2313,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.anthropic.com/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            organization: None,
+            project: None,
         },
         PostProcessProvider {
             id: "groq".to_string(),
@@ -1293,6 +2322,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.groq.com/openai/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            organization: None,
+            project: None,
         },
         PostProcessProvider {
             id: "cerebras".to_string(),
@@ -1300,6 +2331,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.cerebras.ai/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            organization: None,
+            project: None,
         },
     ];
 
@@ -1315,6 +2348,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "apple-intelligence://local".to_string(),
             allow_base_url_edit: false,
             models_endpoint: None,
+            organization: None,
+            project: None,
         });
     }
 
@@ -1325,6 +2360,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
         base_url: "http://localhost:11434/v1".to_string(),
         allow_base_url_edit: true,
         models_endpoint: Some("/models".to_string()),
+        organization: None,
+        project: None,
     });
 
     providers
@@ -1405,6 +2442,9 @@ fn ensure_post_process_defaults(settings: &mut AppSettings) -> bool {
 
 pub const SETTINGS_STORE_PATH: &str = "settings_store.json";
 
+/// Binding that always transcribes with the global settings, ignoring the active profile
+pub const DEFAULT_PROFILE_BINDING_ID: &str = "transcribe_default";
+
 pub fn get_default_settings() -> AppSettings {
     #[cfg(target_os = "windows")]
     let default_shortcut = "ctrl+space";
@@ -1442,6 +2482,7 @@ pub fn get_default_settings() -> AppSettings {
             description: "Converts your speech into text.".to_string(),
             default_binding: default_shortcut.to_string(),
             current_binding: default_shortcut.to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     bindings.insert(
@@ -1452,6 +2493,7 @@ pub fn get_default_settings() -> AppSettings {
             description: "Send transcription to AivoRelay Connector.".to_string(),
             default_binding: default_send_shortcut.to_string(),
             current_binding: default_send_shortcut.to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     bindings.insert(
@@ -1463,6 +2505,7 @@ pub fn get_default_settings() -> AppSettings {
                 .to_string(),
             default_binding: default_send_selection_shortcut.to_string(),
             current_binding: default_send_selection_shortcut.to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     #[cfg(target_os = "windows")]
@@ -1476,6 +2519,7 @@ pub fn get_default_settings() -> AppSettings {
                     .to_string(),
             default_binding: "ctrl+shift+space".to_string(),
             current_binding: "ctrl+shift+space".to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     #[cfg(target_os = "windows")]
@@ -1489,6 +2533,7 @@ pub fn get_default_settings() -> AppSettings {
                     .to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     bindings.insert(
@@ -1499,6 +2544,7 @@ pub fn get_default_settings() -> AppSettings {
             description: "Cancels the current recording.".to_string(),
             default_binding: "escape".to_string(),
             current_binding: "escape".to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     bindings.insert(
@@ -1509,6 +2555,7 @@ pub fn get_default_settings() -> AppSettings {
             description: "Paste the most recent transcription or AI response again.".to_string(),
             default_binding: "ctrl+shift+z".to_string(),
             current_binding: "ctrl+shift+z".to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     #[cfg(target_os = "windows")]
@@ -1521,18 +2568,33 @@ pub fn get_default_settings() -> AppSettings {
                 .to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            additional_bindings: Vec::new(),
+        },
+    );
+    #[cfg(target_os = "windows")]
+    bindings.insert(
+        "cancel_voice_command_countdown".to_string(),
+        ShortcutBinding {
+            id: "cancel_voice_command_countdown".to_string(),
+            name: "Cancel Voice Command Auto-Run".to_string(),
+            description: "Abort the countdown before a predefined voice command auto-runs."
+                .to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     // Default profile shortcut (optional - uses global settings when active)
     bindings.insert(
-        "transcribe_default".to_string(),
+        DEFAULT_PROFILE_BINDING_ID.to_string(),
         ShortcutBinding {
-            id: "transcribe_default".to_string(),
+            id: DEFAULT_PROFILE_BINDING_ID.to_string(),
             name: "Transcribe (Default Profile)".to_string(),
             description: "Transcribe using global language settings, regardless of active profile."
                 .to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            additional_bindings: Vec::new(),
         },
     );
     // Cycle through transcription profiles
@@ -1544,6 +2606,19 @@ pub fn get_default_settings() -> AppSettings {
             description: "Switch to the next transcription profile in the rotation.".to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            additional_bindings: Vec::new(),
+        },
+    );
+    // Flip between push-to-talk and toggle recording
+    bindings.insert(
+        "toggle_ptt_mode".to_string(),
+        ShortcutBinding {
+            id: "toggle_ptt_mode".to_string(),
+            name: "Toggle Push-to-Talk".to_string(),
+            description: "Switch between push-to-talk and toggle recording.".to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            additional_bindings: Vec::new(),
         },
     );
 
@@ -1557,29 +2632,51 @@ pub fn get_default_settings() -> AppSettings {
         autostart_enabled: default_autostart_enabled(),
         update_checks_enabled: default_update_checks_enabled(),
         selected_model: "".to_string(),
+        models_by_language: HashMap::new(),
         transcription_provider: default_transcription_provider(),
         remote_stt: default_remote_stt_settings(),
         always_on_microphone: false,
         selected_microphone: None,
         clamshell_microphone: None,
         selected_output_device: None,
+        output_device_fallbacks: Vec::new(),
         translate_to_english: false,
         selected_language: "auto".to_string(),
+        auto_language_candidates: Vec::new(),
+        preferred_languages: Vec::new(),
+        preferred_languages_only: false,
+        auto_language_min_confidence: default_auto_language_min_confidence(),
         overlay_position: default_overlay_position(),
+        overlay_monitor: OverlayMonitor::default(),
+        overlay_appearance: OverlayAppearance::default(),
+        overlay_error_duration_ms: default_overlay_error_duration_ms(),
+        overlay_error_persist_until_dismissed: false,
         debug_mode: false,
         log_level: default_log_level(),
         custom_words: Vec::new(),
+        local_decode_params: LocalDecodeParams::default(),
+        acceleration_preference: AccelerationPreference::Auto,
+        acceleration_gpu_index: None,
         custom_words_enabled: default_custom_words_enabled(),
         model_unload_timeout: ModelUnloadTimeout::Never,
         word_correction_threshold: default_word_correction_threshold(),
         history_limit: default_history_limit(),
         recording_retention_period: default_recording_retention_period(),
+        history_store_segments: false,
+        discard_cancelled_recordings: true,
+        transcription_log_path: None,
+        transcription_log_format: TranscriptionLogFormat::default(),
         paste_method: PasteMethod::default(),
         convert_lf_to_crlf: true,
         clipboard_handling: ClipboardHandling::default(),
+        always_copy_result: false,
+        selection_capture_retries: default_selection_capture_retries(),
+        selection_capture_retry_delay_ms: default_selection_capture_retry_delay_ms(),
+        paste_delay_ms: default_paste_delay_ms(),
         post_process_enabled: default_post_process_enabled(),
         post_process_provider_id: default_post_process_provider_id(),
         post_process_providers: default_post_process_providers(),
+        unlock_builtin_provider_base_urls: false,
         post_process_api_keys: default_post_process_api_keys(),
         post_process_models: default_post_process_models(),
         post_process_prompts: default_post_process_prompts(),
@@ -1592,6 +2689,8 @@ pub fn get_default_settings() -> AppSettings {
         ai_replace_allow_quick_tap: default_ai_replace_allow_quick_tap(),
         ai_replace_quick_tap_threshold_ms: default_ai_replace_quick_tap_threshold_ms(),
         ai_replace_quick_tap_system_prompt: default_ai_replace_quick_tap_system_prompt(),
+        ai_replace_output_mode: AiReplaceOutputMode::default(),
+        ai_replace_stop_sequences: Vec::new(),
         ai_replace_provider_id: None,
         ai_replace_api_keys: HashMap::new(),
         ai_replace_models: HashMap::new(),
@@ -1609,7 +2708,12 @@ pub fn get_default_settings() -> AppSettings {
         ai_replace_selection_push_to_talk: true,
         mute_while_recording: false,
         append_trailing_space: false,
+        scratchpad_mode: false,
+        paste_suffix_by_app: HashMap::new(),
+        post_process_disabled_apps: Vec::new(),
+        transcribing_watchdog_secs: default_transcribing_watchdog_secs(),
         connector_port: default_connector_port(),
+        connector_port_auto_fallback: false,
         connector_auto_open_enabled: default_connector_auto_open_enabled(),
         connector_auto_open_url: default_connector_auto_open_url(),
         screenshot_capture_method: default_screenshot_capture_method(),
@@ -1628,16 +2732,24 @@ pub fn get_default_settings() -> AppSettings {
         connector_password: default_connector_password(),
         connector_password_user_set: false,
         connector_pending_password: None,
+        connector_apply_text_replacements: true,
+        connector_message_ttl_secs: default_connector_message_ttl_secs(),
+        connector_tag_with_profile: false,
+        connector_default_profile_tag: String::new(),
+        connector_allowed_origins: Vec::new(),
         transcription_prompts: HashMap::new(),
         transcription_profiles: Vec::new(),
         active_profile_id: default_active_profile_id(),
         profile_switch_overlay_enabled: true,
+        profile_switch_during_recording: ProfileSwitchDuringRecording::default(),
         // Voice Command Center
         voice_command_enabled: false,
         voice_command_push_to_talk: true,
         voice_commands: Vec::new(),
         voice_command_default_threshold: default_voice_command_threshold(),
         voice_command_llm_fallback: true,
+        voice_command_llm_min_words: 0,
+        voice_command_meta_commands_enabled: false,
         voice_command_system_prompt: default_voice_command_system_prompt(),
         voice_command_defaults: VoiceCommandDefaults::default(),
         voice_command_template: String::new(), // Deprecated, kept for migration
@@ -1647,6 +2759,13 @@ pub fn get_default_settings() -> AppSettings {
         // Extended Thinking / Reasoning
         post_process_reasoning_enabled: false,
         post_process_reasoning_budget: default_reasoning_budget(),
+        post_process_extract_json_field: None,
+        post_process_low_confidence_threshold: None,
+        post_process_low_confidence_hint: None,
+        post_process_stop_sequences: Vec::new(),
+        post_process_paste_then_replace: false,
+        llm_max_concurrent_requests: default_llm_max_concurrent_requests(),
+        strip_llm_formatting: true,
         ai_replace_reasoning_enabled: false,
         ai_replace_reasoning_budget: default_reasoning_budget(),
         // Voice Command LLM Settings
@@ -1654,6 +2773,7 @@ pub fn get_default_settings() -> AppSettings {
         voice_command_api_keys: HashMap::new(),
         voice_command_models: HashMap::new(),
         voice_command_reasoning_enabled: false,
+        voice_command_structured_output: false,
         voice_command_reasoning_budget: default_reasoning_budget(),
         // Voice Command Fuzzy Matching
         voice_command_use_levenshtein: true,
@@ -1666,10 +2786,18 @@ pub fn get_default_settings() -> AppSettings {
         // Text Replacement
         text_replacements_enabled: false,
         text_replacements: Vec::new(),
+        text_replacement_groups_enabled: HashMap::new(),
         text_replacements_before_llm: false,
+        dictation_commands_enabled: false,
+        output_case_transform: OutputCaseTransform::default(),
+        auto_capitalize_sentences: false,
+        dictation_commands: Vec::new(),
         // Audio Processing
         filler_word_filter_enabled: false,
         vad_threshold: default_vad_threshold(),
+        audio_meter_hz: default_audio_meter_hz(),
+        skip_non_speech_recordings: true,
+        speech_energy_threshold_dbfs: default_speech_energy_threshold_dbfs(),
         // Shortcut Engine (Windows only)
         shortcut_engine: ShortcutEngine::default(),
         // UI State
@@ -1685,6 +2813,18 @@ impl AppSettings {
             .find(|provider| provider.id == self.post_process_provider_id)
     }
 
+    /// Switch the active post-processing provider.
+    /// Each provider keeps its own entry in `post_process_models`, so switching
+    /// away and back restores the model that was last selected for it.
+    pub fn set_active_post_process_provider(&mut self, provider_id: &str) {
+        self.post_process_provider_id = provider_id.to_string();
+    }
+
+    /// Model stored for the active post-processing provider (empty if none).
+    pub fn active_post_process_model(&self) -> String {
+        self.post_process_model(&self.post_process_provider_id)
+    }
+
     /// Get the active LLM provider for Voice Commands.
     /// If voice_command_provider_id is set, uses that; otherwise falls back to post-processing provider.
     pub fn active_voice_command_provider(&self) -> Option<&PostProcessProvider> {
@@ -1698,6 +2838,21 @@ impl AppSettings {
         }
     }
 
+    /// Whether the filler word filter runs for `profile` (its override, else the global flag).
+    pub fn filler_filter_enabled_for(&self, profile: Option<&TranscriptionProfile>) -> bool {
+        profile
+            .and_then(|p| p.filler_filter_override)
+            .unwrap_or(self.filler_word_filter_enabled)
+    }
+
+    /// VAD threshold for recordings with `profile` (its override, else the global value)
+    pub fn vad_threshold_for(&self, profile: Option<&TranscriptionProfile>) -> f32 {
+        profile
+            .and_then(|p| p.vad_threshold)
+            .map(|t| t.clamp(VAD_THRESHOLD_MIN, VAD_THRESHOLD_MAX))
+            .unwrap_or(self.vad_threshold)
+    }
+
     /// Get a transcription profile by its ID.
     pub fn transcription_profile(&self, profile_id: &str) -> Option<&TranscriptionProfile> {
         self.transcription_profiles
@@ -1705,12 +2860,36 @@ impl AppSettings {
             .find(|p| p.id == profile_id)
     }
 
+    /// Local model to use for a language, via `models_by_language`, else `selected_model`.
+    /// "os_input" is resolved to the current keyboard layout language first.
+    pub fn model_for_language(&self, language: &str) -> String {
+        if self.models_by_language.is_empty() {
+            return self.selected_model.clone();
+        }
+
+        let resolved = if language == "os_input" {
+            crate::input_source::get_language_from_input_source()
+        } else {
+            Some(language.to_string())
+        };
+
+        resolved
+            .and_then(|lang| self.models_by_language.get(&lang))
+            .filter(|model_id| !model_id.is_empty())
+            .cloned()
+            .unwrap_or_else(|| self.selected_model.clone())
+    }
+
     /// Get a transcription profile by its binding ID (e.g., "transcribe_profile_abc123").
-    /// Returns None if binding_id doesn't match the expected pattern.
+    /// Returns None if binding_id doesn't match the expected pattern, and always for
+    /// `transcribe_default` (it is not a profile lookup).
     pub fn transcription_profile_by_binding(
         &self,
         binding_id: &str,
     ) -> Option<&TranscriptionProfile> {
+        if binding_id == DEFAULT_PROFILE_BINDING_ID {
+            return None;
+        }
         if let Some(profile_id) = binding_id.strip_prefix("transcribe_") {
             self.transcription_profile(profile_id)
         } else {
@@ -1718,6 +2897,25 @@ impl AppSettings {
         }
     }
 
+    /// Profile a transcribe binding records with:
+    /// - `transcribe`: the active profile (None when "default")
+    /// - `transcribe_default`: None, global settings regardless of the active profile
+    /// - `transcribe_<profile_id>`: that profile
+    pub fn profile_for_binding(&self, binding_id: &str) -> Option<&TranscriptionProfile> {
+        if binding_id == "transcribe" {
+            self.transcription_profile(&self.active_profile_id)
+        } else {
+            self.transcription_profile_by_binding(binding_id)
+        }
+    }
+
+    /// Push-to-talk mode of a transcribe binding: its profile's, else the global setting
+    pub fn transcribe_push_to_talk(&self, binding_id: &str) -> bool {
+        self.profile_for_binding(binding_id)
+            .map(|p| p.push_to_talk)
+            .unwrap_or(self.push_to_talk)
+    }
+
     pub fn post_process_provider(&self, provider_id: &str) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
@@ -1745,47 +2943,47 @@ impl AppSettings {
         }
     }
 
-    /// Get AI Replace API key for a provider.
-    /// On Windows, fetches from secure storage. Falls back to post-processing API key if not set.
-    pub fn ai_replace_api_key(&self, provider_id: &str) -> String {
-        // On Windows, use secure key storage
+    /// Post-processing API key for a provider. Only the post-processing key store is
+    /// consulted, so AI Replace and Voice Command keys never leak into post-processing.
+    /// On Windows, fetches from secure storage.
+    pub fn post_process_api_key(&self, provider_id: &str) -> String {
         #[cfg(target_os = "windows")]
         {
-            // If AI Replace is configured to use the same provider as post-processing,
-            // use the post-processing API key (ignore any AI Replace overrides).
-            if self.ai_replace_provider_id.as_deref() != Some(provider_id) {
-                return crate::secure_keys::get_post_process_api_key(provider_id);
-            }
-
-            // Try AI Replace specific key first, then fall back to post-processing key
-            let ai_key = crate::secure_keys::get_ai_replace_api_key(provider_id);
-            if !ai_key.is_empty() {
-                return ai_key;
-            }
-            return crate::secure_keys::get_post_process_api_key(provider_id);
+            crate::secure_keys::get_post_process_api_key(provider_id)
         }
 
-        // On non-Windows, use JSON settings (original behavior)
         #[cfg(not(target_os = "windows"))]
         {
-            if self.ai_replace_provider_id.as_deref() != Some(provider_id) {
-                return self
-                    .post_process_api_keys
-                    .get(provider_id)
-                    .cloned()
-                    .unwrap_or_default();
-            }
-
-            self.ai_replace_api_keys
+            self.post_process_api_keys
                 .get(provider_id)
-                .filter(|k| !k.is_empty())
                 .cloned()
-                .unwrap_or_else(|| {
-                    self.post_process_api_keys
-                        .get(provider_id)
-                        .cloned()
-                        .unwrap_or_default()
-                })
+                .unwrap_or_default()
+        }
+    }
+
+    /// Post-processing model for a provider (empty if none).
+    pub fn post_process_model(&self, provider_id: &str) -> String {
+        self.post_process_models
+            .get(provider_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Get AI Replace API key for a provider.
+    /// On Windows, fetches from secure storage. Falls back to post-processing API key if not set.
+    pub fn ai_replace_api_key(&self, provider_id: &str) -> String {
+        // If AI Replace is configured to use the same provider as post-processing,
+        // use the post-processing API key (ignore any AI Replace overrides).
+        if self.ai_replace_provider_id.as_deref() != Some(provider_id) {
+            return self.post_process_api_key(provider_id);
+        }
+
+        // Try AI Replace specific key first, then fall back to post-processing key
+        let ai_key = self.ai_replace_own_api_key(provider_id);
+        if ai_key.is_empty() {
+            self.post_process_api_key(provider_id)
+        } else {
+            ai_key
         }
     }
 
@@ -1795,105 +2993,210 @@ impl AppSettings {
         // If AI Replace is configured to use the same provider as post-processing,
         // use the post-processing model (ignore any AI Replace overrides).
         if self.ai_replace_provider_id.as_deref() != Some(provider_id) {
-            return self
-                .post_process_models
+            return self.post_process_model(provider_id);
+        }
+
+        self.ai_replace_models
+            .get(provider_id)
+            .filter(|m| !m.is_empty())
+            .cloned()
+            .unwrap_or_else(|| self.post_process_model(provider_id))
+    }
+
+    /// Get Voice Command API key for a provider.
+    /// Unlike AI Replace, the Voice Command key wins whenever it is set, even when Voice
+    /// Commands share the post-processing provider; otherwise the post-processing key.
+    pub fn voice_command_api_key(&self, provider_id: &str) -> String {
+        let vc_key = self.voice_command_own_api_key(provider_id);
+        if vc_key.is_empty() {
+            self.post_process_api_key(provider_id)
+        } else {
+            vc_key
+        }
+    }
+
+    /// AI Replace's own key for a provider, without any fallback (empty if not set)
+    fn ai_replace_own_api_key(&self, provider_id: &str) -> String {
+        #[cfg(target_os = "windows")]
+        {
+            crate::secure_keys::get_ai_replace_api_key(provider_id)
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            self.ai_replace_api_keys
                 .get(provider_id)
                 .cloned()
-                .unwrap_or_default();
+                .unwrap_or_default()
         }
+    }
 
-        self.ai_replace_models
+    /// Voice Command's own key for a provider, without any fallback (empty if not set)
+    fn voice_command_own_api_key(&self, provider_id: &str) -> String {
+        #[cfg(target_os = "windows")]
+        {
+            crate::secure_keys::get_voice_command_api_key(provider_id).unwrap_or_default()
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            self.voice_command_api_keys
+                .get(provider_id)
+                .cloned()
+                .unwrap_or_default()
+        }
+    }
+
+    /// Get Voice Command model for a provider.
+    /// Like the key, wins whenever set; falls back to post-processing model if not set.
+    pub fn voice_command_model(&self, provider_id: &str) -> String {
+        self.voice_command_models
             .get(provider_id)
             .filter(|m| !m.is_empty())
             .cloned()
-            .unwrap_or_else(|| {
-                self.post_process_models
-                    .get(provider_id)
-                    .cloned()
-                    .unwrap_or_default()
-            })
+            .unwrap_or_else(|| self.post_process_model(provider_id))
+    }
+
+    /// Store a model id typed by the user for the feature's active provider, in the map
+    /// `llm_config_for` reads it from. Not checked against a fetched model list, so a
+    /// provider whose `/models` is broken can still be used with a known model id.
+    /// AI Replace sharing the post-processing provider shares its model; voice commands
+    /// always keep their own, since their model wins whenever set.
+    pub fn set_model_for(&mut self, feature: LlmFeature, model: &str) -> Result<(), String> {
+        let model = model.trim();
+        if model.is_empty() {
+            return Err("Model id cannot be empty".to_string());
+        }
+        let own = match feature {
+            LlmFeature::PostProcessing => None,
+            LlmFeature::AiReplace => self
+                .ai_replace_provider_id
+                .clone()
+                .map(|id| (id, &mut self.ai_replace_models)),
+            LlmFeature::VoiceCommand => self
+                .active_voice_command_provider()
+                .map(|p| p.id.clone())
+                .map(|id| (id, &mut self.voice_command_models)),
+        };
+        if let Some((provider_id, models)) = own {
+            models.insert(provider_id, model.to_string());
+            return Ok(());
+        }
+        let provider_id = self
+            .active_post_process_provider()
+            .map(|p| p.id.clone())
+            .ok_or_else(|| "No post-processing provider selected".to_string())?;
+        self.post_process_models
+            .insert(provider_id, model.to_string());
+        Ok(())
     }
 
     /// Get the fully resolved LLM configuration for a specific feature.
     /// This is the primary entry point for getting LLM settings with proper fallback chains.
     /// On Windows, API keys are fetched from secure storage.
+    ///
+    /// Fallback order per feature:
+    /// - PostProcessing: `post_process_provider_id` with its own key/model; never falls back
+    /// - AiReplace / VoiceCommand: own provider if set (else the post-processing provider)
+    /// - AiReplace: own key/model only for its own provider, else the post-processing key/model
+    /// - VoiceCommand: own key/model whenever set, else the post-processing key/model
     pub fn llm_config_for(&self, feature: LlmFeature) -> Option<LlmConfig> {
-        match feature {
+        let (provider, api_key, model) = match feature {
             LlmFeature::PostProcessing => {
                 let provider = self.active_post_process_provider()?;
-
-                // On Windows, use secure key storage
-                #[cfg(target_os = "windows")]
-                let api_key = crate::secure_keys::get_post_process_api_key(&provider.id);
-
-                // On non-Windows, use JSON settings
-                #[cfg(not(target_os = "windows"))]
-                let api_key = self
-                    .post_process_api_keys
-                    .get(&provider.id)
-                    .cloned()
-                    .unwrap_or_default();
-
-                let model = self
-                    .post_process_models
-                    .get(&provider.id)
-                    .cloned()
-                    .unwrap_or_default();
-
-                Some(LlmConfig {
-                    provider_id: provider.id.clone(),
-                    api_key,
-                    model,
-                    base_url: provider.base_url.clone(),
-                })
+                let api_key = self.post_process_api_key(&provider.id);
+                (provider, api_key, self.post_process_model(&provider.id))
             }
             LlmFeature::AiReplace => {
                 let provider = self.active_ai_replace_provider()?;
                 let api_key = self.ai_replace_api_key(&provider.id);
-                let model = self.ai_replace_model(&provider.id);
-
-                Some(LlmConfig {
-                    provider_id: provider.id.clone(),
-                    api_key,
-                    model,
-                    base_url: provider.base_url.clone(),
-                })
+                (provider, api_key, self.ai_replace_model(&provider.id))
             }
             LlmFeature::VoiceCommand => {
                 let provider = self.active_voice_command_provider()?;
+                let api_key = self.voice_command_api_key(&provider.id);
+                (provider, api_key, self.voice_command_model(&provider.id))
+            }
+        };
 
-                // On Windows, use secure key storage with fallback to post-processing key
-                #[cfg(target_os = "windows")]
-                let api_key = crate::secure_keys::get_voice_command_api_key(&provider.id)
-                    .unwrap_or_else(|| crate::secure_keys::get_post_process_api_key(&provider.id));
-
-                // On non-Windows, use JSON settings with fallback
-                #[cfg(not(target_os = "windows"))]
-                let api_key = self
-                    .voice_command_api_keys
-                    .get(&provider.id)
-                    .cloned()
-                    .filter(|k| !k.is_empty())
-                    .or_else(|| self.post_process_api_keys.get(&provider.id).cloned())
-                    .unwrap_or_default();
-
-                // Use voice command model with fallback to post-processing model
-                let model = self
-                    .voice_command_models
-                    .get(&provider.id)
-                    .cloned()
-                    .filter(|m| !m.is_empty())
-                    .or_else(|| self.post_process_models.get(&provider.id).cloned())
-                    .unwrap_or_default();
-
-                Some(LlmConfig {
-                    provider_id: provider.id.clone(),
-                    api_key,
-                    model,
-                    base_url: provider.base_url.clone(),
-                })
+        Some(LlmConfig {
+            provider_id: provider.id.clone(),
+            api_key,
+            model,
+            base_url: provider.base_url.clone(),
+        })
+    }
+
+    /// Describe how `llm_config_for` resolves a feature, following the same fallback order.
+    pub fn llm_config_debug(&self, feature: LlmFeature) -> Option<LlmConfigDebug> {
+        let config = self.llm_config_for(feature)?;
+        let provider_id = config.provider_id.as_str();
+
+        let own = match feature {
+            LlmFeature::PostProcessing => None,
+            // (name, own provider set, own key/model apply, own key, own model)
+            LlmFeature::AiReplace => Some((
+                "ai_replace",
+                self.ai_replace_provider_id.is_some(),
+                self.ai_replace_provider_id.is_some(),
+                self.ai_replace_own_api_key(provider_id),
+                self.ai_replace_models.get(provider_id),
+            )),
+            LlmFeature::VoiceCommand => Some((
+                "voice_command",
+                self.voice_command_provider_id.is_some(),
+                true,
+                self.voice_command_own_api_key(provider_id),
+                self.voice_command_models.get(provider_id),
+            )),
+        };
+
+        let mut fallback_path = Vec::new();
+        match own {
+            Some((name, own_provider, overrides_apply, own_key, own_model)) => {
+                fallback_path.push(if own_provider {
+                    format!("used {} provider '{}'", name, provider_id)
+                } else {
+                    format!(
+                        "no {} provider set, fell back to post-process provider '{}'",
+                        name, provider_id
+                    )
+                });
+                if overrides_apply {
+                    fallback_path.push(if own_key.is_empty() {
+                        format!("no {} key set, fell back to post-process key", name)
+                    } else {
+                        format!("used {} key", name)
+                    });
+                    fallback_path.push(if own_model.filter(|m| !m.is_empty()).is_none() {
+                        format!("no {} model set, fell back to post-process model", name)
+                    } else {
+                        format!("used {} model", name)
+                    });
+                } else {
+                    fallback_path.push(format!(
+                        "used post-process key and model ({} overrides ignored)",
+                        name
+                    ));
+                }
+            }
+            None => {
+                fallback_path.push(format!("used post-process provider '{}'", provider_id));
+                fallback_path.push("used post-process key and model".to_string());
             }
         }
+        if config.api_key.trim().is_empty() {
+            fallback_path.push("no API key found".to_string());
+        }
+
+        Some(LlmConfigDebug {
+            feature,
+            provider_id: config.provider_id,
+            model: config.model,
+            base_url: config.base_url,
+            has_api_key: !config.api_key.trim().is_empty(),
+            fallback_path,
+        })
     }
 }
 
@@ -2071,3 +3374,500 @@ pub fn get_recording_retention_period(app: &AppHandle) -> RecordingRetentionPeri
     let settings = get_settings(app);
     settings.recording_retention_period
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An enabled, case-sensitive literal rule; tests override the rest with `..rule(..)`
+    fn rule(id: &str, from: &str, to: &str) -> TextReplacement {
+        TextReplacement {
+            id: id.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            enabled: true,
+            case_sensitive: true,
+            is_regex: false,
+            fuzzy: false,
+            fuzzy_max_distance: 2,
+            group: None,
+            phase: None,
+        }
+    }
+
+    #[test]
+    fn fuzzy_replacement_matches_spelling_and_spacing_variants() {
+        let rule = TextReplacement {
+            fuzzy: true,
+            ..rule("tr_1", "GitHub", "GitHub")
+        };
+        assert_eq!(
+            rule.apply("pushed to get hub, then git hub is up and github too"),
+            "pushed to GitHub, then GitHub is up and GitHub too"
+        );
+        // Unrelated words and words across punctuation are left alone
+        assert_eq!(rule.apply("the hub has a gift"), "the hub has a gift");
+        assert_eq!(rule.apply("git. hub"), "git. hub");
+
+        let exact = TextReplacement {
+            fuzzy: false,
+            ..rule
+        };
+        assert_eq!(exact.apply("git hub"), "git hub");
+    }
+
+    #[test]
+    fn compiled_replacements_follow_rule_edits() {
+        let mut rule = TextReplacement {
+            case_sensitive: false,
+            is_regex: true,
+            ..rule("tr_1", r"(\d+) percent", "$1%")
+        };
+        let groups = HashMap::new();
+        let rules = vec![rule.clone()];
+        assert_eq!(
+            apply_text_replacements_by_phase("50 Percent", &rules, &groups, false),
+            "50%"
+        );
+
+        // Editing the pattern recompiles instead of reusing the cached regex
+        rule.from = r"(\d+) pct".to_string();
+        assert_eq!(
+            apply_text_replacements_by_phase("50 pct", &[rule.clone()], &groups, false),
+            "50%"
+        );
+
+        rule.from = "(unclosed".to_string();
+        assert!(rule.compile().is_err());
+        assert!(validate_text_replacements(&[rule.clone()]).is_err());
+        assert_eq!(
+            apply_text_replacements_by_phase("(unclosed", &[rule.clone()], &groups, false),
+            "(unclosed"
+        );
+        rule.enabled = false;
+        assert!(validate_text_replacements(&[rule]).is_ok());
+    }
+
+    #[test]
+    fn regex_replacement_supports_named_groups_and_case_transforms() {
+        let rule = |from: &str, to: &str| TextReplacement {
+            is_regex: true,
+            ..rule("tr_1", from, to)
+        };
+        let swap = rule(r"(?P<first>\w+) (?P<last>\w+)", "${last}, ${first}");
+        assert_eq!(swap.apply("ada lovelace"), "lovelace, ada");
+
+        let shout = rule(r"todo (\w+)", r"\U$1:\n\u${1} \L$1");
+        assert_eq!(shout.apply("todo fixMe"), "FIXME:\nFixMe fixme");
+
+        // A transform without a group, and an escaped backslash, stay literal
+        assert_eq!(rule("x", r"\Uy").apply("x"), r"\Uy");
+        assert_eq!(rule("(x)", r"\\U$1").apply("x"), r"\Ux");
+    }
+
+    #[test]
+    fn preview_reports_each_step_and_continues_past_bad_regex() {
+        let rule = |id: &str, from: &str, to: &str, is_regex: bool| TextReplacement {
+            case_sensitive: false,
+            is_regex,
+            ..rule(id, from, to)
+        };
+        let rules = [
+            rule("tr_1", "um ", "", false),
+            rule("tr_2", "(broken", "", true),
+            rule("tr_3", "Hello", "Hi", false),
+        ];
+        let steps = preview_text_replacements("um hello, um HELLO", &rules, &HashMap::new(), false);
+        assert_eq!(steps.len(), 3);
+        assert_eq!(
+            (steps[0].after.as_str(), steps[0].matches),
+            ("hello, HELLO", 2)
+        );
+        assert!(steps[1].error.is_some());
+        assert_eq!(steps[1].after, steps[1].before);
+        assert_eq!((steps[2].after.as_str(), steps[2].matches), ("Hi, Hi", 2));
+    }
+
+    #[test]
+    fn preview_runs_rules_in_pipeline_phase_order() {
+        let rule = |id: &str, from: &str, to: &str, phase| TextReplacement {
+            phase,
+            ..rule(id, from, to)
+        };
+        // Listed after-first; the before-LLM rule still runs first, "both" runs twice
+        let rules = [
+            rule("tr_after", "b", "c", Some(TextReplacementPhase::AfterLlm)),
+            rule("tr_before", "a", "b", Some(TextReplacementPhase::BeforeLlm)),
+            rule("tr_both", "c", "d", Some(TextReplacementPhase::Both)),
+        ];
+        let steps = preview_text_replacements("a", &rules, &HashMap::new(), false);
+        let order: Vec<&str> = steps.iter().map(|s| s.rule_id.as_str()).collect();
+        assert_eq!(order, ["tr_before", "tr_both", "tr_after", "tr_both"]);
+        assert_eq!(steps.last().unwrap().after, "d");
+        assert_eq!(
+            apply_text_replacements_by_phase("a", &rules, &HashMap::new(), false),
+            "d"
+        );
+    }
+
+    #[test]
+    fn replacement_suite_reports_chained_rule_results() {
+        // The second rule rewrites the first rule's output
+        let rules = [
+            rule("tr_1", "get hub", "github"),
+            rule("tr_2", "github", "GitHub"),
+        ];
+        let case = |input: &str, expected: &str| TextReplacementTestCase {
+            input: input.to_string(),
+            expected: expected.to_string(),
+        };
+
+        let results = run_text_replacement_suite(
+            vec![case("on get hub", "on GitHub"), case("get hub", "github")],
+            &rules,
+            &HashMap::new(),
+            false,
+        );
+        assert!(results[0].passed);
+        assert_eq!(results[1].actual, "GitHub");
+        assert!(!results[1].passed);
+    }
+
+    #[test]
+    fn disabled_group_skips_its_rules_but_not_ungrouped_ones() {
+        let rule = |id: &str, from: &str, group: Option<&str>| TextReplacement {
+            group: group.map(str::to_string),
+            ..rule(id, from, &from.to_uppercase())
+        };
+        let rules = [
+            rule("tr_1", "api", Some("coding")),
+            rule("tr_2", "ecg", Some("medical")),
+            rule("tr_3", "ok", None),
+        ];
+        let mut groups = HashMap::new();
+        assert_eq!(
+            apply_text_replacements_by_phase("api ecg ok", &rules, &groups, false),
+            "API ECG OK"
+        );
+
+        groups.insert("medical".to_string(), false);
+        assert_eq!(
+            apply_text_replacements_by_phase("api ecg ok", &rules, &groups, false),
+            "API ecg OK"
+        );
+
+        // The rule's own flag still applies inside an enabled group
+        groups.insert("coding".to_string(), true);
+        let mut disabled = rules.clone();
+        disabled[0].enabled = false;
+        assert_eq!(
+            apply_text_replacements_by_phase("api ecg ok", &disabled, &groups, false),
+            "api ecg OK"
+        );
+    }
+
+    /// (resolution reads keys from JSON storage, so non-Windows only)
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn switching_providers_restores_each_providers_model() {
+        let mut settings = get_default_settings();
+        let providers: Vec<String> = settings
+            .post_process_providers
+            .iter()
+            .map(|p| p.id.clone())
+            .take(2)
+            .collect();
+        let (provider_a, provider_b) = (&providers[0], &providers[1]);
+        let resolved = |settings: &AppSettings| {
+            let config = settings
+                .llm_config_for(LlmFeature::PostProcessing)
+                .expect("provider configured");
+            (config.provider_id, config.api_key, config.model)
+        };
+        let expect = |id: &str, key: &str, model: &str| -> (String, String, String) {
+            (id.into(), key.into(), model.into())
+        };
+        for (id, key) in [(provider_a, "key-a"), (provider_b, "key-b")] {
+            settings
+                .post_process_api_keys
+                .insert(id.clone(), key.into());
+        }
+        settings
+            .post_process_models
+            .insert(provider_b.clone(), "stored-b".into());
+
+        settings.set_active_post_process_provider(provider_a);
+        settings
+            .set_model_for(LlmFeature::PostProcessing, "model-a")
+            .unwrap();
+
+        // B resolves its own key and stored model; A's choice stays with A
+        settings.set_active_post_process_provider(provider_b);
+        assert_eq!(resolved(&settings), expect(provider_b, "key-b", "stored-b"));
+        assert_eq!(
+            settings.post_process_models.get(provider_a.as_str()),
+            Some(&"model-a".to_string())
+        );
+        settings
+            .set_model_for(LlmFeature::PostProcessing, "model-b")
+            .unwrap();
+        assert_eq!(
+            settings.post_process_models.get(provider_a.as_str()),
+            Some(&"model-a".to_string())
+        );
+
+        // Switching back resolves the model last chosen for that provider
+        settings.set_active_post_process_provider(provider_a);
+        assert_eq!(resolved(&settings), expect(provider_a, "key-a", "model-a"));
+        settings.set_active_post_process_provider(provider_b);
+        assert_eq!(resolved(&settings), expect(provider_b, "key-b", "model-b"));
+    }
+
+    /// Each feature resolves its own key/model; post-processing never sees the others'
+    /// (JSON key storage, so non-Windows only)
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn llm_config_resolves_each_features_own_key_and_model() {
+        let mut settings = get_default_settings();
+        settings.set_active_post_process_provider("openai");
+        for (id, key, model) in [
+            ("openai", "pp-openai", "gpt-pp"),
+            ("anthropic", "pp-anth", ""),
+        ] {
+            settings.post_process_api_keys.insert(id.into(), key.into());
+            settings.post_process_models.insert(id.into(), model.into());
+        }
+        settings
+            .ai_replace_api_keys
+            .insert("openai".into(), "ai-openai".into());
+        settings
+            .ai_replace_models
+            .insert("openai".into(), "gpt-ai".into());
+        settings
+            .voice_command_api_keys
+            .insert("openai".into(), "vc-openai".into());
+        settings
+            .voice_command_models
+            .insert("openai".into(), "gpt-vc".into());
+
+        let resolve = |settings: &AppSettings, feature| {
+            let config = settings
+                .llm_config_for(feature)
+                .expect("provider configured");
+            (config.provider_id, config.api_key, config.model)
+        };
+        let expect = |id: &str, key: &str, model: &str| -> (String, String, String) {
+            (id.into(), key.into(), model.into())
+        };
+
+        // No feature provider set: post-processing's provider; AI Replace overrides are
+        // ignored, Voice Command's own key/model still win when set
+        for feature in [LlmFeature::PostProcessing, LlmFeature::AiReplace] {
+            assert_eq!(
+                resolve(&settings, feature),
+                expect("openai", "pp-openai", "gpt-pp")
+            );
+        }
+        assert_eq!(
+            resolve(&settings, LlmFeature::VoiceCommand),
+            expect("openai", "vc-openai", "gpt-vc")
+        );
+
+        // Own provider selected: own key/model, post-processing unaffected
+        settings.ai_replace_provider_id = Some("openai".into());
+        settings.voice_command_provider_id = Some("openai".into());
+        assert_eq!(
+            resolve(&settings, LlmFeature::PostProcessing),
+            expect("openai", "pp-openai", "gpt-pp")
+        );
+        assert_eq!(
+            resolve(&settings, LlmFeature::AiReplace),
+            expect("openai", "ai-openai", "gpt-ai")
+        );
+        assert_eq!(
+            resolve(&settings, LlmFeature::VoiceCommand),
+            expect("openai", "vc-openai", "gpt-vc")
+        );
+
+        // Own provider without its own key/model: falls back to post-processing's for it
+        settings.ai_replace_provider_id = Some("anthropic".into());
+        settings.voice_command_provider_id = Some("anthropic".into());
+        assert_eq!(
+            resolve(&settings, LlmFeature::AiReplace),
+            expect("anthropic", "pp-anth", "")
+        );
+        assert_eq!(
+            resolve(&settings, LlmFeature::VoiceCommand),
+            expect("anthropic", "pp-anth", "")
+        );
+        assert_eq!(
+            resolve(&settings, LlmFeature::PostProcessing),
+            expect("openai", "pp-openai", "gpt-pp")
+        );
+    }
+
+    /// A manually entered model lands in the map the feature resolves it from
+    /// (resolution reads keys from JSON storage, so non-Windows only)
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn manual_model_goes_where_the_feature_reads_it() {
+        let mut settings = get_default_settings();
+        settings.set_active_post_process_provider("openai");
+        let model = |s: &AppSettings, feature| s.llm_config_for(feature).unwrap().model;
+
+        settings
+            .set_model_for(LlmFeature::AiReplace, " my-model ")
+            .unwrap();
+        // Sharing the post-processing provider means sharing its model
+        assert_eq!(model(&settings, LlmFeature::PostProcessing), "my-model");
+        assert_eq!(model(&settings, LlmFeature::AiReplace), "my-model");
+
+        settings.voice_command_provider_id = Some("anthropic".to_string());
+        settings
+            .set_model_for(LlmFeature::VoiceCommand, "claude-x")
+            .unwrap();
+        assert_eq!(model(&settings, LlmFeature::VoiceCommand), "claude-x");
+        assert_eq!(model(&settings, LlmFeature::PostProcessing), "my-model");
+        assert!(settings
+            .set_model_for(LlmFeature::VoiceCommand, "  ")
+            .is_err());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn llm_config_debug_reports_fallbacks_without_the_key() {
+        let mut settings = get_default_settings();
+        settings.set_active_post_process_provider("openai");
+        settings
+            .post_process_api_keys
+            .insert("openai".to_string(), "sk-secret".to_string());
+        settings.ai_replace_provider_id = Some("openai".to_string());
+
+        let debug = settings
+            .llm_config_debug(LlmFeature::AiReplace)
+            .expect("provider configured");
+        assert!(debug.has_api_key);
+        assert!(debug
+            .fallback_path
+            .contains(&"no ai_replace key set, fell back to post-process key".to_string()));
+        let serialized = serde_json::to_string(&debug).expect("serialize");
+        assert!(!serialized.contains("sk-secret"));
+    }
+
+    #[test]
+    fn default_binding_uses_global_settings_with_active_profile() {
+        let mut settings = get_default_settings();
+        settings.selected_language = "en".to_string();
+        settings.selected_model = "global-model".to_string();
+        settings.push_to_talk = true;
+        settings
+            .models_by_language
+            .insert("fr".to_string(), "french-model".to_string());
+        settings.transcription_profiles.push(TranscriptionProfile {
+            id: "profile_fr".to_string(),
+            name: "French".to_string(),
+            language: "fr".to_string(),
+            translate_to_english: false,
+            description: String::new(),
+            system_prompt: String::new(),
+            stt_prompt_override_enabled: false,
+            include_in_cycle: true,
+            push_to_talk: false,
+            llm_post_process_enabled: false,
+            llm_prompt_override: None,
+            llm_model_override: None,
+            dictation_commands_enabled: None,
+            output_case_transform: None,
+            auto_capitalize_sentences: None,
+            filler_filter_override: None,
+            vad_threshold: None,
+        });
+        settings.active_profile_id = "profile_fr".to_string();
+
+        let language = |binding_id: &str| {
+            settings
+                .profile_for_binding(binding_id)
+                .map(|p| p.language.clone())
+                .unwrap_or_else(|| settings.selected_language.clone())
+        };
+
+        // The main shortcut follows the active profile
+        assert_eq!(language("transcribe"), "fr");
+        assert_eq!(
+            settings.model_for_language(&language("transcribe")),
+            "french-model"
+        );
+        assert!(!settings.transcribe_push_to_talk("transcribe"));
+
+        // transcribe_default ignores it: global language, model and PTT
+        assert!(settings
+            .profile_for_binding(DEFAULT_PROFILE_BINDING_ID)
+            .is_none());
+        assert_eq!(language(DEFAULT_PROFILE_BINDING_ID), "en");
+        assert_eq!(
+            settings.model_for_language(&language(DEFAULT_PROFILE_BINDING_ID)),
+            "global-model"
+        );
+        assert!(settings.transcribe_push_to_talk(DEFAULT_PROFILE_BINDING_ID));
+    }
+
+    #[test]
+    fn profile_vad_threshold_overrides_global_within_range() {
+        let settings = get_default_settings();
+        let mut profile: TranscriptionProfile = serde_json::from_value(serde_json::json!({
+            "id": "profile_noisy",
+            "name": "Noisy office",
+            "language": "en",
+            "translate_to_english": false,
+        }))
+        .expect("profile");
+
+        // Unset inherits the global threshold
+        assert_eq!(settings.vad_threshold_for(None), settings.vad_threshold);
+        assert_eq!(
+            settings.vad_threshold_for(Some(&profile)),
+            settings.vad_threshold
+        );
+
+        profile.vad_threshold = Some(0.6);
+        assert_eq!(settings.vad_threshold_for(Some(&profile)), 0.6);
+        profile.vad_threshold = Some(0.95);
+        assert_eq!(
+            settings.vad_threshold_for(Some(&profile)),
+            VAD_THRESHOLD_MAX
+        );
+        profile.vad_threshold = Some(0.0);
+        assert_eq!(
+            settings.vad_threshold_for(Some(&profile)),
+            VAD_THRESHOLD_MIN
+        );
+    }
+
+    #[test]
+    fn overlay_appearance_rejects_bad_hex() {
+        assert!(OverlayAppearance::default().validate().is_ok());
+        let mut appearance = OverlayAppearance::default();
+        appearance.accent_color = "#12345g".to_string();
+        assert!(appearance.validate().is_err());
+        appearance.accent_color = "ffe5ee".to_string();
+        assert!(appearance.validate().is_err());
+        appearance.accent_color = "#abc".to_string();
+        assert!(appearance.validate().is_ok());
+    }
+
+    #[test]
+    fn local_decode_params_reject_out_of_range_values() {
+        let mut params = LocalDecodeParams {
+            beam_size: Some(5),
+            temperature: Some(0.2),
+            no_speech_threshold: Some(0.6),
+            condition_on_previous: Some(false),
+        };
+        assert!(params.validate().is_ok());
+        params.beam_size = Some(0);
+        assert!(params.validate().is_err());
+        params.beam_size = None;
+        params.temperature = Some(1.5);
+        assert!(params.validate().is_err());
+    }
+}
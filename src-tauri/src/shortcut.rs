@@ -1,7 +1,8 @@
 use log::{error, info, warn};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Listener, Manager, State};
 use tauri_plugin_autostart::ManagerExt;
@@ -15,8 +16,9 @@ use crate::settings::ShortcutBinding;
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::settings::APPLE_INTELLIGENCE_DEFAULT_MODEL_ID;
 use crate::settings::{
-    self, get_settings, ClipboardHandling, LLMPrompt, OverlayPosition, PasteMethod,
-    RemoteSttDebugMode, ShortcutEngine, SoundTheme, TranscriptionProvider,
+    self, get_settings, AiReplaceOutputMode, ClipboardHandling, LLMPrompt, OutputCaseTransform,
+    OverlayMonitor, OverlayPosition, PasteMethod, ProfileSwitchDuringRecording, RemoteSttDebugMode,
+    ShortcutEngine, SoundTheme, TranscriptionLogFormat, TranscriptionProvider,
     APPLE_INTELLIGENCE_PROVIDER_ID,
 };
 use crate::tray;
@@ -28,6 +30,9 @@ pub type RdevShortcutsSet = std::sync::Mutex<HashSet<String>>;
 /// Track which shortcut engine is actually running (set at startup, doesn't change until restart)
 pub type ActiveShortcutEngine = std::sync::Mutex<ShortcutEngine>;
 
+/// Binding ID prefix for shortcuts that run a specific voice command directly
+pub const RUN_VOICE_COMMAND_PREFIX: &str = "run_voice_command_";
+
 pub fn init_shortcuts(app: &AppHandle) {
     let default_bindings = settings::get_default_settings().bindings;
     let user_settings = settings::load_or_create_app_settings(app);
@@ -73,7 +78,7 @@ pub fn init_shortcuts(app: &AppHandle) {
             .unwrap_or(default_binding);
 
         // Skip empty bindings (intentionally unbound shortcuts like voice_command, cycle_profile)
-        if !binding.current_binding.is_empty() {
+        if !key_variants(&binding).is_empty() {
             if let Err(e) = register_shortcut(app, binding) {
                 error!("Failed to register shortcut {} during init: {}", id, e);
             }
@@ -85,7 +90,7 @@ pub fn init_shortcuts(app: &AppHandle) {
         let binding_id = format!("transcribe_{}", profile.id);
         if let Some(binding) = user_settings.bindings.get(&binding_id) {
             // Only register if the binding has a key assigned
-            if !binding.current_binding.is_empty() {
+            if !key_variants(binding).is_empty() {
                 if let Err(e) = register_shortcut(app, binding.clone()) {
                     error!(
                         "Failed to register transcription profile shortcut {} during init: {}",
@@ -95,6 +100,18 @@ pub fn init_shortcuts(app: &AppHandle) {
             }
         }
     }
+
+    // Register direct-run voice command shortcuts
+    for (binding_id, binding) in &user_settings.bindings {
+        if binding_id.starts_with(RUN_VOICE_COMMAND_PREFIX) && !key_variants(binding).is_empty() {
+            if let Err(e) = register_shortcut(app, binding.clone()) {
+                error!(
+                    "Failed to register voice command shortcut {} during init: {}",
+                    binding_id, e
+                );
+            }
+        }
+    }
 }
 
 /// Start the rdev key listener
@@ -123,118 +140,298 @@ fn setup_rdev_shortcut_handler(app: &AppHandle) {
     });
 }
 
-/// Handle a shortcut event from rdev (mirrors the tauri-plugin-global-shortcut handler logic)
+/// Risky extension actions and voice commands have their own enable flags;
+/// other actions are always enabled.
+fn is_action_enabled(settings: &settings::AppSettings, binding_id: &str) -> bool {
+    match binding_id {
+        "send_to_extension" => settings.send_to_extension_enabled,
+        "send_to_extension_with_selection" => settings.send_to_extension_with_selection_enabled,
+        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_enabled,
+        "voice_command" => settings.voice_command_enabled,
+        id if id.starts_with(RUN_VOICE_COMMAND_PREFIX) => settings.voice_command_enabled,
+        _ => true,
+    }
+}
+
+/// ACTION_MAP key for a binding: its own ID, or the shared action behind dynamic
+/// `transcribe_<profile_id>` and `run_voice_command_<command_id>` bindings.
+fn action_key_for_binding(binding_id: &str) -> Option<&str> {
+    if ACTION_MAP.contains_key(binding_id) {
+        Some(binding_id)
+    } else if binding_id.starts_with("transcribe_") {
+        Some("transcribe")
+    } else if binding_id.starts_with(RUN_VOICE_COMMAND_PREFIX) {
+        Some("run_voice_command")
+    } else {
+        None
+    }
+}
+
+/// Resolve whether a binding runs in push-to-talk or toggle mode.
+/// Fork actions have individual PTT settings; `transcribe` follows the active profile,
+/// and `transcribe_<profile_id>` bindings follow their own profile.
+fn resolve_push_to_talk(settings: &settings::AppSettings, binding_id: &str) -> bool {
+    match binding_id {
+        "send_to_extension" => settings.send_to_extension_push_to_talk,
+        "send_to_extension_with_selection" => {
+            settings.send_to_extension_with_selection_push_to_talk
+        }
+        "ai_replace_selection" => settings.ai_replace_selection_push_to_talk,
+        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_push_to_talk,
+        "voice_command" => settings.voice_command_push_to_talk,
+        // Active profile for `transcribe`, global for `transcribe_default`, own profile otherwise
+        id if id == "transcribe" || id.starts_with("transcribe_") => {
+            settings.transcribe_push_to_talk(id)
+        }
+        _ => settings.push_to_talk,
+    }
+}
+
+/// Handle a shortcut event from rdev (same dispatch as the tauri-plugin-global-shortcut handler)
 fn handle_rdev_shortcut_event(app: &AppHandle, event: ShortcutEvent) {
-    let binding_id = event.id;
-    let shortcut_string = event.binding;
-    let pressed = event.pressed;
+    let binding_id = base_binding_id(&event.id);
+    if let Err(e) = dispatch_binding_event(app, binding_id, &event.binding, event.pressed) {
+        log::debug!(
+            "Ignoring rdev shortcut: {}. Binding: '{}'",
+            e,
+            event.binding
+        );
+    }
+}
 
+/// Phase of a programmatically triggered action (key press / key release)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionPhase {
+    Start,
+    Stop,
+}
+
+/// Run a binding's action for a press/release, applying the same cancel, enabled,
+/// instant and push-to-talk/toggle gating as a physical shortcut.
+fn dispatch_binding_event(
+    app: &AppHandle,
+    binding_id: &str,
+    shortcut_string: &str,
+    pressed: bool,
+) -> Result<(), String> {
     let settings = get_settings(app);
 
-    // Look up action - for profile-based bindings, fall back to "transcribe" action
-    let action = ACTION_MAP.get(&binding_id).or_else(|| {
-        if binding_id.starts_with("transcribe_") {
-            ACTION_MAP.get("transcribe")
-        } else {
-            None
-        }
-    });
+    // Look up action - dynamic profile/voice-command bindings share one action
+    let action = action_key_for_binding(binding_id).and_then(|key| ACTION_MAP.get(key));
 
     let Some(action) = action else {
-        warn!(
-            "No action defined for rdev shortcut ID '{}'. Binding: '{}'",
-            binding_id, shortcut_string
-        );
-        return;
+        return Err(format!(
+            "No action defined for shortcut ID '{}'",
+            binding_id
+        ));
     };
 
     // Handle cancel action
     if binding_id == "cancel" {
         let audio_manager = app.state::<Arc<AudioRecordingManager>>();
         if audio_manager.is_recording() && pressed {
-            action.start(app, &binding_id, &shortcut_string);
+            action.start(app, binding_id, shortcut_string);
+        } else if pressed {
+            // Not recording: the shortcut may be held for a voice command auto-run countdown
+            crate::voice_command_countdown::cancel(app);
         }
-        return;
+        return Ok(());
     }
 
     // Check if action is enabled
-    let action_enabled = match binding_id.as_str() {
-        "send_to_extension" => settings.send_to_extension_enabled,
-        "send_to_extension_with_selection" => settings.send_to_extension_with_selection_enabled,
-        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_enabled,
-        "voice_command" => settings.voice_command_enabled,
-        _ => true,
-    };
-    if !action_enabled {
-        return;
+    if !is_action_enabled(&settings, binding_id) {
+        return Err(format!("Action '{}' is disabled", binding_id));
     }
 
-    // Determine push-to-talk setting
-    let use_push_to_talk = match binding_id.as_str() {
-        "send_to_extension" => settings.send_to_extension_push_to_talk,
-        "send_to_extension_with_selection" => {
-            settings.send_to_extension_with_selection_push_to_talk
-        }
-        "ai_replace_selection" => settings.ai_replace_selection_push_to_talk,
-        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_push_to_talk,
-        "voice_command" => settings.voice_command_push_to_talk,
-        "transcribe" => {
-            if settings.active_profile_id == "default" {
-                settings.push_to_talk
-            } else {
-                settings
-                    .transcription_profile(&settings.active_profile_id)
-                    .map(|p| p.push_to_talk)
-                    .unwrap_or(settings.push_to_talk)
-            }
-        }
-        id if id.starts_with("transcribe_") => settings
-            .transcription_profile_by_binding(id)
-            .map(|p| p.push_to_talk)
-            .unwrap_or(settings.push_to_talk),
-        _ => settings.push_to_talk,
+    let use_push_to_talk = resolve_push_to_talk(&settings, binding_id);
+
+    // Decide while holding the toggle lock, but RELEASE it before calling the action
+    // (actions may need it themselves, e.g. cancel_current_operation)
+    let phase = if action.is_instant() || use_push_to_talk {
+        binding_phase(action.is_instant(), use_push_to_talk, pressed, &mut false)
+    } else {
+        let toggle_state_manager = app.state::<ManagedToggleState>();
+        let mut states = toggle_state_manager
+            .lock()
+            .expect("Failed to lock toggle state manager");
+        let is_currently_active = states
+            .active_toggles
+            .entry(binding_id.to_string())
+            .or_insert(false);
+        binding_phase(false, false, pressed, is_currently_active)
     };
 
-    // Handle instant actions
-    if action.is_instant() {
-        if pressed {
-            action.start(app, &binding_id, &shortcut_string);
-        }
-        return;
+    match phase {
+        Some(ActionPhase::Start) => action.start(app, binding_id, shortcut_string),
+        Some(ActionPhase::Stop) => action.stop(app, binding_id, shortcut_string),
+        None => {}
     }
 
-    if use_push_to_talk {
-        if pressed {
-            action.start(app, &binding_id, &shortcut_string);
+    Ok(())
+}
+
+/// What a press/release does for an enabled, non-cancel binding. Instant actions fire on
+/// every press, push-to-talk starts on press and stops on release, and toggle mode flips
+/// `toggle_active` on press and ignores releases.
+fn binding_phase(
+    instant: bool,
+    push_to_talk: bool,
+    pressed: bool,
+    toggle_active: &mut bool,
+) -> Option<ActionPhase> {
+    if instant {
+        return pressed.then_some(ActionPhase::Start);
+    }
+    if push_to_talk {
+        return Some(if pressed {
+            ActionPhase::Start
         } else {
-            action.stop(app, &binding_id, &shortcut_string);
-        }
+            ActionPhase::Stop
+        });
+    }
+    if !pressed {
+        return None;
+    }
+    *toggle_active = !*toggle_active;
+    Some(if *toggle_active {
+        ActionPhase::Start
     } else {
-        // Toggle mode
-        if pressed {
-            let should_start: bool;
-            {
-                let toggle_state_manager = app.state::<ManagedToggleState>();
-                let mut states = toggle_state_manager
-                    .lock()
-                    .expect("Failed to lock toggle state manager");
-
-                let is_currently_active = states
-                    .active_toggles
-                    .entry(binding_id.clone())
-                    .or_insert(false);
-
-                should_start = !*is_currently_active;
-                *is_currently_active = should_start;
-            }
+        ActionPhase::Stop
+    })
+}
+
+/// Trigger a binding's action without a key press (automation / self-test).
+/// `start` acts as a key press and `stop` as a release, so toggle-mode bindings
+/// toggle on `start` and ignore `stop`, exactly like a physical shortcut.
+#[tauri::command]
+#[specta::specta]
+pub fn trigger_action(
+    app: AppHandle,
+    binding_id: String,
+    phase: ActionPhase,
+) -> Result<(), String> {
+    let settings = get_settings(&app);
+    if !settings.bindings.contains_key(&binding_id) {
+        return Err(format!("Unknown binding '{}'", binding_id));
+    }
+
+    dispatch_binding_event(
+        &app,
+        &binding_id,
+        "trigger_action",
+        phase == ActionPhase::Start,
+    )
+}
+
+/// One line of the effective keymap
+#[derive(Serialize)]
+struct KeymapEntry {
+    binding_id: String,
+    name: String,
+    key: String,
+    additional_keys: Vec<KeymapKey>,
+    /// ACTION_MAP key that runs (None = no action)
+    action: Option<String>,
+    /// "instant", "push_to_talk" or "toggle"
+    mode: &'static str,
+    enabled: bool,
+    /// "native", "rdev", "dynamic" (registered while recording), "unbound" or "unregistered"
+    registration: &'static str,
+    profile_id: Option<String>,
+}
+
+/// An additional key and how its "#alt" variant is registered
+#[derive(Serialize)]
+struct KeymapKey {
+    key: String,
+    registration: &'static str,
+}
+
+/// How one key variant (primary or "#alt") is registered. `rdev_shortcuts` holds the
+/// variant IDs registered with rdev; `is_native` checks the global shortcut plugin.
+fn keymap_registration(
+    variant: &ShortcutBinding,
+    rdev_shortcuts: &HashSet<String>,
+    is_native: impl Fn(&str) -> bool,
+) -> &'static str {
+    if variant.current_binding.is_empty() {
+        "unbound"
+    } else if rdev_shortcuts.contains(&variant.id) {
+        "rdev"
+    } else if is_native(&variant.current_binding) {
+        "native"
+    } else if base_binding_id(&variant.id) == "cancel" {
+        "dynamic"
+    } else {
+        "unregistered"
+    }
+}
+
+/// Export the effective keymap (every binding, its key, action, mode, enabled state and
+/// how it is actually registered) as pretty-printed JSON for bug reports.
+#[tauri::command]
+#[specta::specta]
+pub fn export_keymap(app: AppHandle) -> Result<String, String> {
+    let settings = get_settings(&app);
+    let rdev_shortcuts: HashSet<String> = app
+        .try_state::<RdevShortcutsSet>()
+        .and_then(|set| set.lock().ok().map(|s| s.clone()))
+        .unwrap_or_default();
 
-            if should_start {
-                action.start(app, &binding_id, &shortcut_string);
+    let is_native = |key: &str| {
+        key.parse::<Shortcut>()
+            .is_ok_and(|s| app.global_shortcut().is_registered(s))
+    };
+
+    let mut ids: Vec<&String> = settings.bindings.keys().collect();
+    ids.sort();
+
+    let entries: Vec<KeymapEntry> = ids
+        .into_iter()
+        .map(|id| {
+            let binding = &settings.bindings[id];
+            let action_key = action_key_for_binding(id).map(String::from);
+            let instant = action_key
+                .as_ref()
+                .and_then(|a| ACTION_MAP.get(a))
+                .is_some_and(|a| a.is_instant());
+
+            let mode = if instant {
+                "instant"
+            } else if resolve_push_to_talk(&settings, id) {
+                "push_to_talk"
             } else {
-                action.stop(app, &binding_id, &shortcut_string);
+                "toggle"
+            };
+
+            let additional_keys = key_variants(binding)
+                .iter()
+                .filter(|variant| variant.id != binding.id)
+                .map(|variant| KeymapKey {
+                    key: variant.current_binding.clone(),
+                    registration: keymap_registration(variant, &rdev_shortcuts, is_native),
+                })
+                .collect();
+
+            KeymapEntry {
+                binding_id: id.clone(),
+                name: binding.name.clone(),
+                key: binding.current_binding.clone(),
+                additional_keys,
+                action: action_key,
+                mode,
+                enabled: is_action_enabled(&settings, id),
+                registration: keymap_registration(binding, &rdev_shortcuts, is_native),
+                profile_id: id
+                    .strip_prefix("transcribe_")
+                    .filter(|p| settings.transcription_profile(p).is_some())
+                    .map(String::from),
             }
-        }
-    }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&entries).map_err(|e| format!("Failed to serialize keymap: {}", e))
 }
 
 #[derive(Serialize, Type)]
@@ -244,6 +441,72 @@ pub struct BindingResponse {
     error: Option<String>,
 }
 
+/// Effective configuration a binding will use when triggered
+#[derive(Serialize, Type)]
+pub struct BindingTarget {
+    pub binding_id: String,
+    /// Action key that will run (e.g., "transcribe"), None if the binding has no action
+    pub action: Option<String>,
+    /// Whether this binding drives the transcribe action
+    pub drives_transcribe: bool,
+    /// Profile used for transcription (None = global "default" settings)
+    pub profile_id: Option<String>,
+    pub profile_name: Option<String>,
+    pub language: String,
+    pub translate_to_english: bool,
+    pub push_to_talk: bool,
+    /// Transcription provider that will be used
+    pub provider: TranscriptionProvider,
+    /// Local model ID or Remote STT model ID, depending on provider
+    pub model: String,
+}
+
+/// Preview which action/profile a binding ID maps to and the settings it will use.
+#[tauri::command]
+#[specta::specta]
+pub fn resolve_binding_target(app: AppHandle, binding_id: String) -> Result<BindingTarget, String> {
+    binding_target(&settings::get_settings(&app), binding_id)
+}
+
+fn binding_target(
+    settings: &settings::AppSettings,
+    binding_id: String,
+) -> Result<BindingTarget, String> {
+    let action = action_key_for_binding(&binding_id).map(String::from);
+
+    if action.is_none() && !settings.bindings.contains_key(&binding_id) {
+        return Err(format!("Unknown binding '{}'", binding_id));
+    }
+
+    let drives_transcribe = action.as_deref() == Some("transcribe");
+
+    // Mirrors the profile capture in start_recording_with_feedback
+    let profile = settings.profile_for_binding(&binding_id);
+    let language = profile
+        .map(|p| p.language.clone())
+        .unwrap_or_else(|| settings.selected_language.clone());
+
+    let model = match settings.transcription_provider {
+        TranscriptionProvider::Local => settings.model_for_language(&language),
+        TranscriptionProvider::RemoteOpenAiCompatible => settings.remote_stt.model_id.clone(),
+    };
+
+    Ok(BindingTarget {
+        push_to_talk: resolve_push_to_talk(settings, &binding_id),
+        action,
+        drives_transcribe,
+        profile_id: profile.map(|p| p.id.clone()),
+        profile_name: profile.map(|p| p.name.clone()),
+        language,
+        translate_to_english: profile
+            .map(|p| p.translate_to_english)
+            .unwrap_or(settings.translate_to_english),
+        provider: settings.transcription_provider,
+        model,
+        binding_id,
+    })
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_binding(
@@ -280,6 +543,12 @@ pub fn change_binding(
         warn!("change_binding validation error: {}", e);
         return Err(e);
     }
+    if let Some(other) = find_key_conflict(&settings, &id, &binding, false) {
+        return Err(format!(
+            "Shortcut '{}' is already used as an additional key for '{}'",
+            binding, other
+        ));
+    }
 
     // 2. Create the updated binding
     let mut updated_binding = binding_to_modify.clone();
@@ -337,6 +606,103 @@ pub fn reset_binding(app: AppHandle, id: String) -> Result<BindingResponse, Stri
     return change_binding(app, id, binding.default_binding);
 }
 
+/// Find another binding that already uses `key`. Always checks additional keys
+/// (including this binding's own); primary keys of other bindings only when
+/// `include_primaries` is set, since those conflicts are caught at registration.
+fn find_key_conflict(
+    settings: &settings::AppSettings,
+    id: &str,
+    key: &str,
+    include_primaries: bool,
+) -> Option<String> {
+    let key = key.trim().to_lowercase();
+    let same = |other: &String| other.trim().to_lowercase() == key;
+    settings
+        .bindings
+        .iter()
+        .find(|(other_id, b)| {
+            b.additional_bindings.iter().any(same)
+                || (include_primaries && other_id.as_str() != id && same(&b.current_binding))
+        })
+        .map(|(other_id, _)| other_id.clone())
+}
+
+/// Set the extra keys that trigger a binding's action alongside its primary key.
+#[tauri::command]
+#[specta::specta]
+pub fn change_additional_bindings(
+    app: AppHandle,
+    id: String,
+    bindings: Vec<String>,
+) -> Result<BindingResponse, String> {
+    let mut settings = settings::get_settings(&app);
+
+    let binding_to_modify = settings
+        .bindings
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| format!("Binding with id '{}' not found", id))?;
+
+    // Validate, drop duplicates and the primary key
+    let mut keys: Vec<String> = Vec::new();
+    for key in bindings {
+        let key = key.trim().to_string();
+        if key.is_empty()
+            || key.eq_ignore_ascii_case(&binding_to_modify.current_binding)
+            || keys.iter().any(|k| k.eq_ignore_ascii_case(&key))
+        {
+            continue;
+        }
+        validate_shortcut_string(&key)?;
+        keys.push(key);
+    }
+
+    // Check conflicts against every other binding (own additional keys are being replaced)
+    let mut others = settings.clone();
+    others.bindings.remove(&id);
+    for key in &keys {
+        if let Some(other) = find_key_conflict(&others, &id, key, true) {
+            return Err(format!("Shortcut '{}' is already used by '{}'", key, other));
+        }
+    }
+
+    let mut updated_binding = binding_to_modify.clone();
+    updated_binding.additional_bindings = keys;
+
+    // The cancel binding is registered dynamically while recording
+    if id != "cancel" {
+        if let Err(e) = unregister_shortcut(&app, binding_to_modify.clone()) {
+            warn!(
+                "change_additional_bindings: failed to unregister old shortcuts (proceeding anyway): {}",
+                e
+            );
+        }
+
+        if let Err(e) = register_shortcut(&app, updated_binding.clone()) {
+            error!(
+                "change_additional_bindings: failed to register shortcuts: {}",
+                e
+            );
+            if let Err(rollback_err) = register_shortcut(&app, binding_to_modify) {
+                error!(
+                    "change_additional_bindings: failed to restore previous shortcuts: {}",
+                    rollback_err
+                );
+            }
+            return Err(format!("Failed to register shortcut: {}", e));
+        }
+    }
+
+    settings.bindings.insert(id, updated_binding.clone());
+    settings::write_settings(&app, settings);
+
+    Ok(BindingResponse {
+        success: true,
+        binding: Some(updated_binding),
+        error: None,
+    })
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_ptt_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -350,6 +716,47 @@ pub fn change_ptt_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
     Ok(())
 }
 
+/// Flip between push-to-talk and toggle recording for the `transcribe` binding, briefly
+/// showing the new mode in the overlay. Flips the active profile's mode when a profile is
+/// active, else the global setting. Returns the new push-to-talk value.
+#[tauri::command]
+#[specta::specta]
+pub fn toggle_ptt_mode(app: AppHandle) -> Result<bool, String> {
+    let mut settings = settings::get_settings(&app);
+    let active_profile_id = settings.active_profile_id.clone();
+    let (enabled, setting) = match settings
+        .transcription_profiles
+        .iter_mut()
+        .find(|p| p.id == active_profile_id)
+    {
+        Some(profile) => {
+            profile.push_to_talk = !profile.push_to_talk;
+            (profile.push_to_talk, "transcription_profiles")
+        }
+        None => {
+            settings.push_to_talk = !settings.push_to_talk;
+            (settings.push_to_talk, "push_to_talk")
+        }
+    };
+    settings::write_settings(&app, settings);
+    info!(
+        "Recording mode switched to {} ({})",
+        if enabled { "push-to-talk" } else { "toggle" },
+        setting
+    );
+
+    crate::overlay::show_ptt_mode_overlay(&app, enabled);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": setting,
+            "value": enabled
+        }),
+    );
+
+    Ok(enabled)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_audio_feedback_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -404,6 +811,64 @@ pub fn change_selected_language_setting(app: AppHandle, language: String) -> Res
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_auto_language_candidates_setting(
+    app: AppHandle,
+    candidates: Vec<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let mut cleaned: Vec<String> = Vec::new();
+    for candidate in candidates {
+        let candidate = candidate.trim().to_string();
+        if candidate.is_empty() || candidate == "auto" || candidate == "os_input" {
+            continue;
+        }
+        if !cleaned.contains(&candidate) {
+            cleaned.push(candidate);
+        }
+    }
+    settings.auto_language_candidates = cleaned;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_preferred_languages_setting(
+    app: AppHandle,
+    languages: Vec<String>,
+    preferred_only: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let mut cleaned: Vec<String> = Vec::new();
+    for language in languages {
+        let language = language.trim().to_string();
+        if language.is_empty() || language == "auto" {
+            continue;
+        }
+        if !cleaned.contains(&language) {
+            cleaned.push(language);
+        }
+    }
+    settings.preferred_languages = cleaned;
+    settings.preferred_languages_only = preferred_only;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_auto_language_min_confidence_setting(
+    app: AppHandle,
+    confidence: f32,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.auto_language_min_confidence = confidence.clamp(0.0, 1.0);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_transcription_provider_setting(
@@ -457,6 +922,51 @@ pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Resu
     Ok(())
 }
 
+/// Choose the display the recording overlay appears on
+#[tauri::command]
+#[specta::specta]
+pub fn change_overlay_monitor_setting(
+    app: AppHandle,
+    monitor: OverlayMonitor,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.overlay_monitor = monitor;
+    settings::write_settings(&app, settings);
+    crate::utils::update_overlay_position(&app);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_overlay_appearance_setting(
+    app: AppHandle,
+    appearance: settings::OverlayAppearance,
+) -> Result<(), String> {
+    appearance.validate()?;
+    let mut settings = settings::get_settings(&app);
+    settings.overlay_appearance = appearance;
+    settings::write_settings(&app, settings);
+
+    // Resize and restyle the overlay without recreating window
+    crate::utils::update_overlay_position(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_overlay_error_duration_setting(
+    app: AppHandle,
+    duration_ms: u32,
+    persist_until_dismissed: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.overlay_error_duration_ms = duration_ms.clamp(500, 60_000);
+    settings.overlay_error_persist_until_dismissed = persist_until_dismissed;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_debug_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -563,18 +1073,92 @@ pub fn change_beta_voice_commands_enabled_setting(
 
 #[tauri::command]
 #[specta::specta]
-pub fn update_custom_words(app: AppHandle, words: Vec<String>) -> Result<(), String> {
+pub fn update_custom_words(app: AppHandle, words: Vec<settings::CustomWord>) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.custom_words = words;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set (or clear with None) the match threshold for a single custom word.
+#[tauri::command]
+#[specta::specta]
+pub fn set_custom_word_threshold(
+    app: AppHandle,
+    word: String,
+    threshold: Option<f64>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let entry = settings
+        .custom_words
+        .iter_mut()
+        .find(|w| w.word == word)
+        .ok_or_else(|| format!("Custom word '{}' not found", word))?;
+    entry.threshold = threshold.map(|t| t.clamp(0.0, 1.0));
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_custom_words_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.custom_words_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_local_decode_params_setting(
+    app: AppHandle,
+    params: settings::LocalDecodeParams,
+) -> Result<(), String> {
+    params.validate()?;
+    let mut settings = settings::get_settings(&app);
+    settings.local_decode_params = params;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_history_store_segments_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.history_store_segments = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the append-only transcription log file (None or empty disables it) and its format
+#[tauri::command]
+#[specta::specta]
+pub fn change_transcription_log_setting(
+    app: AppHandle,
+    path: Option<String>,
+    format: TranscriptionLogFormat,
+) -> Result<(), String> {
+    let path = path.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+    if let Some(path) = &path {
+        if !std::path::Path::new(path).is_absolute() {
+            return Err(format!("Transcription log path must be absolute: {}", path));
+        }
+    }
     let mut settings = settings::get_settings(&app);
-    settings.custom_words = words;
+    settings.transcription_log_path = path;
+    settings.transcription_log_format = format;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_custom_words_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_discard_cancelled_recordings_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.custom_words_enabled = enabled;
+    settings.discard_cancelled_recordings = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
@@ -632,6 +1216,48 @@ pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Re
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_always_copy_result_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.always_copy_result = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_paste_delay_ms_setting(app: AppHandle, delay_ms: u64) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.paste_delay_ms = delay_ms.min(1000);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_selection_capture_retries_setting(
+    app: AppHandle,
+    retries: u32,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.selection_capture_retries = retries.min(20);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_selection_capture_retry_delay_ms_setting(
+    app: AppHandle,
+    delay_ms: u64,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.selection_capture_retry_delay_ms = delay_ms.min(1000);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_convert_lf_to_crlf_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -721,6 +1347,16 @@ pub fn change_post_process_enabled_setting(app: AppHandle, enabled: bool) -> Res
     let mut settings = settings::get_settings(&app);
     settings.post_process_enabled = enabled;
     settings::write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "post_process_enabled",
+            "value": enabled
+        }),
+    );
+
     Ok(())
 }
 
@@ -753,6 +1389,118 @@ pub fn change_post_process_reasoning_budget_setting(
     Ok(())
 }
 
+/// Set the JSON field to extract from post-process responses (None or empty = paste raw response)
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_extract_json_field_setting(
+    app: AppHandle,
+    field: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_extract_json_field = field
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty());
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the confidence below which the low-confidence hint is added (None = off)
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_low_confidence_threshold_setting(
+    app: AppHandle,
+    threshold: Option<f32>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_low_confidence_threshold = threshold.map(|t| t.clamp(0.0, 1.0));
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the low-confidence hint (None or empty = built-in hint)
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_low_confidence_hint_setting(
+    app: AppHandle,
+    hint: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_low_confidence_hint =
+        hint.map(|h| h.trim().to_string()).filter(|h| !h.is_empty());
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Drop empty stop sequences. Others are kept verbatim: whitespace such as "\n\n" or
+/// " ###" is often the point of a stop sequence.
+fn clean_stop_sequences(sequences: Vec<String>) -> Vec<String> {
+    sequences.into_iter().filter(|s| !s.is_empty()).collect()
+}
+
+/// Set the stop sequences sent with post-processing requests (empty = none)
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_stop_sequences_setting(
+    app: AppHandle,
+    sequences: Vec<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_stop_sequences = clean_stop_sequences(sequences);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the stop sequences sent with AI Replace requests (empty = none)
+#[tauri::command]
+#[specta::specta]
+pub fn change_ai_replace_stop_sequences_setting(
+    app: AppHandle,
+    sequences: Vec<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.ai_replace_stop_sequences = clean_stop_sequences(sequences);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Paste the raw transcription first and replace it once post-processing finishes
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_paste_then_replace_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_paste_then_replace = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Per-provider limit on concurrent LLM requests (1-8). Takes effect for new requests.
+#[tauri::command]
+#[specta::specta]
+pub fn change_llm_max_concurrent_requests_setting(
+    app: AppHandle,
+    limit: usize,
+) -> Result<(), String> {
+    let limit = limit.clamp(1, 8);
+    let mut settings = settings::get_settings(&app);
+    settings.llm_max_concurrent_requests = limit;
+    settings::write_settings(&app, settings);
+    crate::llm_client::set_max_concurrent_requests(limit);
+    Ok(())
+}
+
+/// Unwrap LLM output that is entirely one code fence or quoted string
+#[tauri::command]
+#[specta::specta]
+pub fn change_strip_llm_formatting_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.strip_llm_formatting = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_ai_replace_reasoning_enabled_setting(
@@ -789,6 +1537,18 @@ pub fn change_voice_command_reasoning_enabled_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_command_structured_output_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_command_structured_output = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_voice_command_reasoning_budget_setting(
@@ -826,6 +1586,30 @@ pub fn change_voice_command_llm_fallback_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_command_llm_min_words_setting(
+    app: AppHandle,
+    min_words: usize,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_command_llm_min_words = min_words.min(20);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_command_meta_commands_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_command_meta_commands_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_voice_command_system_prompt_setting(
@@ -903,7 +1687,74 @@ pub fn change_voice_commands_setting(
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
     settings.voice_commands = commands;
+
+    // Drop direct-run shortcuts whose voice command was deleted
+    let orphaned: Vec<String> = settings
+        .bindings
+        .keys()
+        .filter(|id| {
+            id.strip_prefix(RUN_VOICE_COMMAND_PREFIX)
+                .is_some_and(|cmd_id| !settings.voice_commands.iter().any(|c| c.id == cmd_id))
+        })
+        .cloned()
+        .collect();
+    for id in orphaned {
+        if let Some(binding) = settings.bindings.remove(&id) {
+            if !key_variants(&binding).is_empty() {
+                let _ = unregister_shortcut(&app, binding);
+            }
+        }
+    }
+
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Create a shortcut binding that runs a voice command directly (no recording or matching).
+/// The binding starts unassigned; set its key with `change_binding`.
+#[tauri::command]
+#[specta::specta]
+pub fn add_voice_command_binding(
+    app: AppHandle,
+    command_id: String,
+) -> Result<ShortcutBinding, String> {
+    let mut settings = settings::get_settings(&app);
+    let command = settings
+        .voice_commands
+        .iter()
+        .find(|c| c.id == command_id)
+        .ok_or_else(|| format!("Voice command '{}' not found", command_id))?;
+
+    let binding_id = format!("{}{}", RUN_VOICE_COMMAND_PREFIX, command_id);
+    if let Some(existing) = settings.bindings.get(&binding_id) {
+        return Ok(existing.clone());
+    }
+
+    let binding = ShortcutBinding {
+        id: binding_id.clone(),
+        name: format!("Run: {}", command.name),
+        description: format!("Runs the voice command \"{}\" directly.", command.name),
+        default_binding: String::new(),
+        current_binding: String::new(),
+        additional_bindings: Vec::new(),
+    };
+    settings.bindings.insert(binding_id, binding.clone());
     settings::write_settings(&app, settings);
+    Ok(binding)
+}
+
+/// Remove a voice command's direct-run shortcut binding.
+#[tauri::command]
+#[specta::specta]
+pub fn remove_voice_command_binding(app: AppHandle, command_id: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let binding_id = format!("{}{}", RUN_VOICE_COMMAND_PREFIX, command_id);
+    if let Some(binding) = settings.bindings.remove(&binding_id) {
+        if !key_variants(&binding).is_empty() {
+            let _ = unregister_shortcut(&app, binding);
+        }
+        settings::write_settings(&app, settings);
+    }
     Ok(())
 }
 
@@ -973,41 +1824,119 @@ pub fn change_voice_command_word_similarity_threshold_setting(
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_profile_switch_overlay_enabled_setting(
-    app: AppHandle,
-    enabled: bool,
-) -> Result<(), String> {
+pub fn change_profile_switch_overlay_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.profile_switch_overlay_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Whether switching profiles mid-recording applies to the next recording or is rejected
+#[tauri::command]
+#[specta::specta]
+pub fn change_profile_switch_during_recording_setting(
+    app: AppHandle,
+    behavior: ProfileSwitchDuringRecording,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.profile_switch_during_recording = behavior;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_base_url_setting(
+    app: AppHandle,
+    provider_id: String,
+    base_url: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let label = settings
+        .post_process_provider(&provider_id)
+        .map(|provider| provider.label.clone())
+        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
+
+    let unlocked = settings.unlock_builtin_provider_base_urls;
+    let provider = settings
+        .post_process_provider_mut(&provider_id)
+        .expect("Provider looked up above must exist");
+
+    // Apple Intelligence has no HTTP endpoint, so it stays locked even when unlocked
+    let builtin_editable = unlocked && provider.id != APPLE_INTELLIGENCE_PROVIDER_ID;
+    if !provider.allow_base_url_edit && !builtin_editable {
+        return Err(format!(
+            "Provider '{}' does not allow editing the base URL",
+            label
+        ));
+    }
+
+    if !provider.allow_base_url_edit {
+        warn!(
+            "Base URL of built-in provider '{}' changed to '{}'; the endpoint must accept the same auth as the provider",
+            label, base_url
+        );
+    }
+
+    provider.base_url = base_url;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_unlock_builtin_provider_base_urls_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.unlock_builtin_provider_base_urls = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Restores a provider's base URL to its built-in default
+#[tauri::command]
+#[specta::specta]
+pub fn reset_post_process_base_url(app: AppHandle, provider_id: String) -> Result<(), String> {
+    let default_url = settings::default_post_process_providers()
+        .into_iter()
+        .find(|p| p.id == provider_id)
+        .map(|p| p.base_url)
+        .ok_or_else(|| format!("Provider '{}' has no default base URL", provider_id))?;
+
     let mut settings = settings::get_settings(&app);
-    settings.profile_switch_overlay_enabled = enabled;
+    let provider = settings
+        .post_process_provider_mut(&provider_id)
+        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
+    provider.base_url = default_url;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_post_process_base_url_setting(
+pub fn change_post_process_organization_setting(
     app: AppHandle,
     provider_id: String,
-    base_url: String,
+    organization: Option<String>,
+    project: Option<String>,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    let label = settings
-        .post_process_provider(&provider_id)
-        .map(|provider| provider.label.clone())
-        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
-
     let provider = settings
         .post_process_provider_mut(&provider_id)
-        .expect("Provider looked up above must exist");
-
-    if provider.id != "custom" {
-        return Err(format!(
-            "Provider '{}' does not allow editing the base URL",
-            label
-        ));
-    }
+        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
 
-    provider.base_url = base_url;
+    // Treat blank input as "not set" so no empty header is sent
+    provider.organization = organization
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    provider.project = project
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
     settings::write_settings(&app, settings);
     Ok(())
 }
@@ -1066,6 +1995,7 @@ pub fn change_post_process_model_setting(
     validate_provider_exists(&settings, &provider_id)?;
     settings.post_process_models.insert(provider_id, model);
     settings::write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
     Ok(())
 }
 
@@ -1074,8 +2004,10 @@ pub fn change_post_process_model_setting(
 pub fn set_post_process_provider(app: AppHandle, provider_id: String) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
     validate_provider_exists(&settings, &provider_id)?;
-    settings.post_process_provider_id = provider_id;
+    // Leaves `post_process_models` untouched so each provider keeps its last model
+    settings.set_active_post_process_provider(&provider_id);
     settings::write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
     Ok(())
 }
 
@@ -1099,6 +2031,7 @@ pub fn add_post_process_prompt(
 
     settings.post_process_prompts.push(new_prompt.clone());
     settings::write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
 
     Ok(new_prompt)
 }
@@ -1121,6 +2054,7 @@ pub fn update_post_process_prompt(
         existing_prompt.name = name;
         existing_prompt.prompt = prompt;
         settings::write_settings(&app, settings);
+        tray::refresh_tray_menu(&app);
         Ok(())
     } else {
         Err(format!("Prompt with id '{}' not found", id))
@@ -1152,6 +2086,7 @@ pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), Stri
     }
 
     settings::write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
     Ok(())
 }
 
@@ -1172,6 +2107,7 @@ pub fn add_transcription_profile(
     push_to_talk: bool,
     include_in_cycle: Option<bool>,
     llm_settings: Option<settings::ProfileLlmSettings>,
+    vad_threshold: Option<f32>,
 ) -> Result<settings::TranscriptionProfile, String> {
     let mut settings = settings::get_settings(&app);
 
@@ -1207,6 +2143,11 @@ pub fn add_transcription_profile(
         llm_post_process_enabled,
         llm_prompt_override,
         llm_model_override,
+        dictation_commands_enabled: None, // Inherit the global setting
+        output_case_transform: None,      // Inherit the global setting
+        auto_capitalize_sentences: None,  // Inherit the global setting
+        filler_filter_override: None,     // Inherit the global setting
+        vad_threshold: clamp_profile_vad_threshold(vad_threshold),
     };
 
     // Create a corresponding shortcut binding (no default key assigned)
@@ -1216,8 +2157,17 @@ pub fn add_transcription_profile(
         description,
         default_binding: String::new(), // User will set the shortcut
         current_binding: String::new(),
+        additional_bindings: Vec::new(),
     };
 
+    // Advisory only: warn if the active model can't handle this language
+    crate::language_compat::warn_for_profile(
+        &app,
+        &settings,
+        Some(&profile_id),
+        &new_profile.language,
+    );
+
     // Add to settings
     settings.transcription_profiles.push(new_profile.clone());
     settings.bindings.insert(binding_id, binding);
@@ -1227,6 +2177,7 @@ pub fn add_transcription_profile(
 }
 
 /// Updates an existing transcription profile.
+/// `vad_threshold` None makes the profile use the global threshold again.
 #[tauri::command]
 #[specta::specta]
 pub fn update_transcription_profile(
@@ -1240,6 +2191,7 @@ pub fn update_transcription_profile(
     include_in_cycle: bool,
     push_to_talk: bool,
     llm_settings: settings::ProfileLlmSettings,
+    vad_threshold: Option<f32>,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
 
@@ -1257,7 +2209,7 @@ pub fn update_transcription_profile(
     };
 
     profile.name = name.clone();
-    profile.language = language;
+    profile.language = language.clone();
     profile.translate_to_english = translate_to_english;
     profile.description = description.clone();
     profile.system_prompt = system_prompt;
@@ -1267,6 +2219,7 @@ pub fn update_transcription_profile(
     profile.llm_post_process_enabled = llm_settings.enabled;
     profile.llm_prompt_override = llm_settings.prompt_override;
     profile.llm_model_override = llm_settings.model_override;
+    profile.vad_threshold = clamp_profile_vad_threshold(vad_threshold);
 
     // Update the binding name/description as well
     let binding_id = format!("transcribe_{}", id);
@@ -1275,6 +2228,9 @@ pub fn update_transcription_profile(
         binding.description = description;
     }
 
+    // Advisory only: warn if the active model can't handle this language
+    crate::language_compat::warn_for_profile(&app, &settings, Some(&id), &language);
+
     settings::write_settings(&app, settings);
     Ok(())
 }
@@ -1328,7 +2284,7 @@ pub fn delete_transcription_profile(app: AppHandle, id: String) -> Result<(), St
     let binding_id = format!("transcribe_{}", id);
     if let Some(binding) = settings.bindings.remove(&binding_id) {
         // Only try to unregister if there was an actual shortcut set
-        if !binding.current_binding.is_empty() {
+        if !key_variants(&binding).is_empty() {
             let _ = unregister_shortcut(&app, binding);
         }
     }
@@ -1357,31 +2313,24 @@ pub fn set_active_profile(app: AppHandle, id: String) -> Result<(), String> {
         return Err(format!("Profile '{}' not found", id));
     }
 
+    // A recording in flight always keeps the profile captured at its start; the
+    // setting decides whether the switch applies to the next recording or is refused
+    let idle = crate::session_manager::is_idle(&app);
+    let reject = settings.profile_switch_during_recording == ProfileSwitchDuringRecording::Reject;
+    if !idle && reject {
+        let message = "Can't switch profiles while recording or processing".to_string();
+        let _ = app.emit("profile-switch-rejected", &message);
+        return Err(message);
+    }
+
     settings.active_profile_id = id.clone();
     settings::write_settings(&app, settings.clone());
 
     // Show overlay notification if enabled
     // Skip overlay if recording/processing is active to avoid hiding the recording overlay
-    if settings.profile_switch_overlay_enabled {
-        let show_overlay = {
-            let state = app.state::<crate::session_manager::ManagedSessionState>();
-            let state_guard = state.lock().expect("Failed to lock session state");
-            matches!(*state_guard, crate::session_manager::SessionState::Idle)
-        };
-
-        if show_overlay {
-            let profile_name = if id == "default" {
-                "Default".to_string()
-            } else {
-                settings
-                    .transcription_profiles
-                    .iter()
-                    .find(|p| p.id == id)
-                    .map(|p| p.name.clone())
-                    .unwrap_or_else(|| id.clone())
-            };
-            crate::overlay::show_profile_switch_overlay(&app, &profile_name);
-        }
+    if settings.profile_switch_overlay_enabled && idle {
+        let payload = crate::overlay::ProfileSwitchPayload::for_profile(&settings, &id);
+        crate::overlay::show_profile_switch_overlay(&app, &payload);
     }
 
     // Emit event for UI sync
@@ -1431,6 +2380,8 @@ pub fn cycle_to_next_profile(app: AppHandle) -> Result<String, String> {
     Ok(next_id)
 }
 
+/// Fetch the model list for a provider.
+/// Read-only: never modifies the stored model selection in `post_process_models`.
 #[tauri::command]
 #[specta::specta]
 pub async fn fetch_post_process_models(
@@ -1459,15 +2410,7 @@ pub async fn fetch_post_process_models(
     }
 
     // Get API key - on Windows, use secure storage
-    #[cfg(target_os = "windows")]
-    let api_key = crate::secure_keys::get_post_process_api_key(&provider_id);
-
-    #[cfg(not(target_os = "windows"))]
-    let api_key = settings
-        .post_process_api_keys
-        .get(&provider_id)
-        .cloned()
-        .unwrap_or_default();
+    let api_key = settings.post_process_api_key(&provider_id);
 
     // Skip fetching if no API key for providers that typically need one
     if api_key.trim().is_empty() && provider.id != "custom" {
@@ -1477,7 +2420,28 @@ pub async fn fetch_post_process_models(
         ));
     }
 
-    crate::llm_client::fetch_models(provider, api_key).await
+    Ok(fetch_models_or_empty(&app, provider, api_key).await)
+}
+
+/// Fetch a provider's models, treating a missing or broken models endpoint (common on
+/// self-hosted servers) as an empty list so the model id can still be typed. The UI is
+/// told which case it was via `llm-models-unavailable`.
+async fn fetch_models_or_empty(
+    app: &AppHandle,
+    provider: &settings::PostProcessProvider,
+    api_key: String,
+) -> Vec<String> {
+    match crate::llm_client::fetch_models(provider, api_key).await {
+        Ok(models) => models,
+        Err(unavailable) => {
+            warn!(
+                "Model list unavailable for {}: {}",
+                unavailable.provider_id, unavailable.message
+            );
+            let _ = app.emit("llm-models-unavailable", unavailable);
+            Vec::new()
+        }
+    }
 }
 
 /// Fetch models for a specific LLM feature.
@@ -1522,7 +2486,7 @@ pub async fn fetch_llm_models(
         ));
     }
 
-    crate::llm_client::fetch_models(provider, config.api_key).await
+    Ok(fetch_models_or_empty(&app, provider, config.api_key).await)
 }
 
 #[tauri::command]
@@ -1535,11 +2499,100 @@ pub fn set_post_process_selected_prompt(app: AppHandle, id: String) -> Result<()
         return Err(format!("Prompt with id '{}' not found", id));
     }
 
-    settings.post_process_selected_prompt_id = Some(id);
+    settings.post_process_selected_prompt_id = Some(id.clone());
+    settings::write_settings(&app, settings);
+    emit_selected_prompt_changed(&app, &id);
+    Ok(())
+}
+
+fn emit_selected_prompt_changed(app: &AppHandle, id: &str) {
+    tray::refresh_tray_menu(app);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "post_process_selected_prompt_id",
+            "value": id
+        }),
+    );
+}
+
+/// Up to three prompt names close to `wanted` (lowercased), best first.
+/// Empty names never match, since they are substrings of everything.
+fn close_prompt_names(prompts: &[settings::LLMPrompt], wanted: &str) -> Vec<String> {
+    if wanted.is_empty() {
+        return Vec::new();
+    }
+    let mut scored: Vec<(f64, &str)> = prompts
+        .iter()
+        .filter_map(|p| {
+            let name = p.name.trim().to_lowercase();
+            if name.is_empty() {
+                return None;
+            }
+            let score = if name.contains(wanted) || wanted.contains(name.as_str()) {
+                1.0
+            } else {
+                strsim::normalized_levenshtein(&name, wanted)
+            };
+            (score >= 0.5).then_some((score, p.name.as_str()))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored
+        .into_iter()
+        .take(3)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Select the first post-processing prompt with this name (case-insensitive).
+/// When none matches, the error lists close names.
+#[tauri::command]
+#[specta::specta]
+pub fn set_post_process_prompt_by_name(app: AppHandle, name: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    let wanted = name.trim().to_lowercase();
+    if wanted.is_empty() {
+        return Err("Prompt name must not be empty".to_string());
+    }
+    let found = settings
+        .post_process_prompts
+        .iter()
+        .find(|p| p.name.trim().to_lowercase() == wanted);
+    let Some(prompt) = found else {
+        let close = close_prompt_names(&settings.post_process_prompts, &wanted);
+        return Err(if close.is_empty() {
+            format!("Prompt named '{}' not found", name)
+        } else {
+            format!(
+                "Prompt named '{}' not found. Did you mean: {}?",
+                name,
+                close.join(", ")
+            )
+        });
+    };
+    let id = prompt.id.clone();
+
+    settings.post_process_selected_prompt_id = Some(id.clone());
     settings::write_settings(&app, settings);
+    emit_selected_prompt_changed(&app, &id);
     Ok(())
 }
 
+/// The currently selected post-processing prompt, if any
+#[tauri::command]
+#[specta::specta]
+pub fn get_active_prompt(app: AppHandle) -> Option<settings::LLMPrompt> {
+    let settings = settings::get_settings(&app);
+    let id = settings.post_process_selected_prompt_id.as_ref()?;
+    settings
+        .post_process_prompts
+        .iter()
+        .find(|p| &p.id == id)
+        .cloned()
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -1560,6 +2613,84 @@ pub fn change_append_trailing_space_setting(app: AppHandle, enabled: bool) -> Re
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_scratchpad_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.scratchpad_mode = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_transcribing_watchdog_secs_setting(app: AppHandle, secs: u32) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    // Keep a sane lower bound so long LLM calls aren't cut off (0 disables)
+    settings.transcribing_watchdog_secs = if secs == 0 { 0 } else { secs.clamp(30, 3600) };
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_paste_suffix_for_app(
+    app: AppHandle,
+    pattern: String,
+    suffix: String,
+) -> Result<(), String> {
+    let pattern = pattern.trim().to_string();
+    if pattern.is_empty() {
+        return Err("App pattern cannot be empty".to_string());
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.paste_suffix_by_app.insert(pattern, suffix);
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn remove_paste_suffix_for_app(app: AppHandle, pattern: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    if settings
+        .paste_suffix_by_app
+        .remove(pattern.trim())
+        .is_none()
+    {
+        return Err(format!(
+            "No suffix configured for app pattern '{}'",
+            pattern
+        ));
+    }
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_disabled_apps_setting(
+    app: AppHandle,
+    patterns: Vec<String>,
+) -> Result<(), String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for pattern in patterns {
+        let pattern = pattern.trim().to_string();
+        if !pattern.is_empty() && !cleaned.iter().any(|p| p.eq_ignore_ascii_case(&pattern)) {
+            cleaned.push(pattern);
+        }
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_disabled_apps = cleaned;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_ai_replace_system_prompt_setting(
@@ -1626,6 +2757,18 @@ pub fn change_ai_replace_allow_quick_tap_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_ai_replace_output_mode_setting(
+    app: AppHandle,
+    mode: AiReplaceOutputMode,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.ai_replace_output_mode = mode;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_ai_replace_quick_tap_threshold_ms_setting(
@@ -1704,6 +2847,22 @@ pub fn change_ai_replace_model_setting(
     Ok(())
 }
 
+/// Set a feature's model to an id typed by the user, without checking it against the
+/// fetched model list (which may be unavailable).
+#[tauri::command]
+#[specta::specta]
+pub fn set_model_manual(
+    app: AppHandle,
+    feature: settings::LlmFeature,
+    model: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.set_model_for(feature, &model)?;
+    settings::write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
+    Ok(())
+}
+
 // ============================================================================
 // Voice Command LLM Settings
 // ============================================================================
@@ -1751,6 +2910,111 @@ pub fn change_voice_command_api_key_setting(
     Ok(())
 }
 
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct ApiKeysCleared {
+    /// None when every feature was cleared
+    pub feature: Option<String>,
+    /// None when every provider was cleared
+    pub provider_id: Option<String>,
+}
+
+/// Remove an API key from the JSON settings fallback
+fn clear_json_api_key(
+    settings: &mut settings::AppSettings,
+    key_type: crate::secure_keys::KeyType,
+    provider_id: &str,
+) {
+    use crate::secure_keys::KeyType;
+    match key_type {
+        // Post-process keys keep an entry per provider (see ensure_post_process_defaults)
+        KeyType::PostProcess => {
+            if let Some(key) = settings.post_process_api_keys.get_mut(provider_id) {
+                key.clear();
+            }
+        }
+        KeyType::AiReplace => {
+            settings.ai_replace_api_keys.remove(provider_id);
+        }
+        KeyType::VoiceCommand => {
+            settings.voice_command_api_keys.remove(provider_id);
+        }
+    }
+}
+
+/// Remove one feature's API key for a provider (secure storage on Windows, JSON elsewhere).
+/// `feature` is "post_process", "ai_replace" or "voice_command".
+#[tauri::command]
+#[specta::specta]
+pub fn clear_api_key(app: AppHandle, feature: String, provider_id: String) -> Result<(), String> {
+    let key_type = crate::secure_keys::KeyType::from_feature(&feature)
+        .ok_or_else(|| format!("Unknown API key feature '{}'", feature))?;
+
+    #[cfg(target_os = "windows")]
+    crate::secure_keys::delete_api_key(key_type, Some(&provider_id))
+        .map_err(|e| format!("Failed to clear API key: {}", e))?;
+
+    let mut settings = settings::get_settings(&app);
+    clear_json_api_key(&mut settings, key_type, &provider_id);
+    settings::write_settings(&app, settings);
+
+    info!("Cleared {} API key for provider '{}'", feature, provider_id);
+    let _ = app.emit(
+        "api-keys-cleared",
+        ApiKeysCleared {
+            feature: Some(feature),
+            provider_id: Some(provider_id),
+        },
+    );
+    Ok(())
+}
+
+/// Remove all post-processing, AI Replace and voice command API keys
+/// (e.g., before handing off a machine).
+#[tauri::command]
+#[specta::specta]
+pub fn clear_all_api_keys(app: AppHandle) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    // Credential Manager can't be enumerated, so clear every provider we know about
+    let mut provider_ids: HashSet<String> = settings
+        .post_process_providers
+        .iter()
+        .map(|p| p.id.clone())
+        .collect();
+    provider_ids.extend(settings.post_process_api_keys.keys().cloned());
+    provider_ids.extend(settings.ai_replace_api_keys.keys().cloned());
+    provider_ids.extend(settings.voice_command_api_keys.keys().cloned());
+
+    #[allow(unused_mut)]
+    let mut failures: Vec<String> = Vec::new();
+    for key_type in crate::secure_keys::KeyType::ALL {
+        for provider_id in &provider_ids {
+            #[cfg(target_os = "windows")]
+            if let Err(e) = crate::secure_keys::delete_api_key(key_type, Some(provider_id)) {
+                failures.push(format!("{:?}/{}: {}", key_type, provider_id, e));
+            }
+            clear_json_api_key(&mut settings, key_type, provider_id);
+        }
+    }
+    settings::write_settings(&app, settings);
+
+    if !failures.is_empty() {
+        let error_msg = format!("Failed to clear some API keys: {}", failures.join("; "));
+        error!("{}", error_msg);
+        return Err(error_msg);
+    }
+
+    info!("Cleared all API keys for {} providers", provider_ids.len());
+    let _ = app.emit(
+        "api-keys-cleared",
+        ApiKeysCleared {
+            feature: None,
+            provider_id: None,
+        },
+    );
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_voice_command_model_setting(
@@ -1863,45 +3127,124 @@ pub fn change_send_to_extension_with_selection_quick_tap_threshold_ms_setting(
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_send_to_extension_with_selection_no_voice_system_prompt_setting(
-    app: AppHandle,
-    prompt: String,
-) -> Result<(), String> {
+pub fn change_send_to_extension_with_selection_no_voice_system_prompt_setting(
+    app: AppHandle,
+    prompt: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.send_to_extension_with_selection_no_voice_system_prompt = prompt;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_ai_replace_selection_push_to_talk_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.ai_replace_selection_push_to_talk = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_connector_auto_open_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.connector_auto_open_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_connector_auto_open_url_setting(app: AppHandle, url: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.connector_auto_open_url = url;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_connector_apply_text_replacements_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.connector_apply_text_replacements = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the connector message TTL in seconds (0 disables expiry)
+#[tauri::command]
+#[specta::specta]
+pub fn change_connector_message_ttl_setting(app: AppHandle, ttl_secs: u32) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.send_to_extension_with_selection_no_voice_system_prompt = prompt;
+    settings.connector_message_ttl_secs = ttl_secs;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+/// Tag connector messages with the active profile name; `default_tag` is used for the default profile
 #[tauri::command]
 #[specta::specta]
-pub fn change_ai_replace_selection_push_to_talk_setting(
+pub fn change_connector_tag_with_profile_setting(
     app: AppHandle,
     enabled: bool,
+    default_tag: String,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.ai_replace_selection_push_to_talk = enabled;
+    settings.connector_tag_with_profile = enabled;
+    settings.connector_default_profile_tag = default_tag.trim().to_string();
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+/// Restrict connector CORS to these origins (empty = allow any). Takes effect on the
+/// next request; no server restart needed.
 #[tauri::command]
 #[specta::specta]
-pub fn change_connector_auto_open_enabled_setting(
+pub fn change_connector_allowed_origins_setting(
     app: AppHandle,
-    enabled: bool,
+    origins: Vec<String>,
+    connector_manager: State<'_, Arc<crate::managers::connector::ConnectorManager>>,
 ) -> Result<(), String> {
+    let mut allowed: Vec<String> = Vec::new();
+    for origin in origins {
+        let origin = origin.trim().trim_end_matches('/').to_string();
+        if origin.is_empty() || allowed.contains(&origin) {
+            continue;
+        }
+        if !origin.contains("://") {
+            return Err(format!(
+                "Invalid origin '{}': expected scheme://host, e.g. chrome-extension://<id>",
+                origin
+            ));
+        }
+        allowed.push(origin);
+    }
     let mut settings = settings::get_settings(&app);
-    settings.connector_auto_open_enabled = enabled;
+    settings.connector_allowed_origins = allowed.clone();
     settings::write_settings(&app, settings);
+    connector_manager.set_allowed_origins(allowed);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_connector_auto_open_url_setting(app: AppHandle, url: String) -> Result<(), String> {
+pub fn change_connector_port_auto_fallback_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.connector_auto_open_url = url;
+    settings.connector_port_auto_fallback = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
@@ -1925,7 +3268,11 @@ pub fn change_connector_port_setting(
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_connector_password_setting(app: AppHandle, password: String) -> Result<(), String> {
+pub fn change_connector_password_setting(
+    app: AppHandle,
+    password: String,
+    connector_manager: State<'_, Arc<crate::managers::connector::ConnectorManager>>,
+) -> Result<(), String> {
     let trimmed = password.trim().to_string();
     if trimmed.is_empty() {
         return Err("Connector password cannot be empty".to_string());
@@ -1946,6 +3293,7 @@ pub fn change_connector_password_setting(app: AppHandle, password: String) -> Re
     settings.connector_password_user_set = true;
     // Note: connector_password stays as OLD password until extension acks
     settings::write_settings(&app, settings);
+    connector_manager.reset_password_offer();
     Ok(())
 }
 
@@ -2278,7 +3626,17 @@ pub fn resume_binding(app: AppHandle, id: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Holders of the Cancel shortcut (a recording session, a voice command countdown). It is
+/// registered by the first holder and unregistered when the last one releases it, so a
+/// recording ending mid-countdown doesn't take Cancel away from the countdown.
+static CANCEL_SHORTCUT_HOLDERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Take a hold on the Cancel shortcut; pair with `unregister_cancel_shortcut`
 pub fn register_cancel_shortcut(app: &AppHandle) {
+    if CANCEL_SHORTCUT_HOLDERS.fetch_add(1, Ordering::SeqCst) > 0 {
+        return;
+    }
+
     // Cancel shortcut is disabled on Linux due to instability with dynamic shortcut registration
     #[cfg(target_os = "linux")]
     {
@@ -2299,7 +3657,15 @@ pub fn register_cancel_shortcut(app: &AppHandle) {
     }
 }
 
+/// Release a hold on the Cancel shortcut, unregistering it when no holder is left
 pub fn unregister_cancel_shortcut(app: &AppHandle) {
+    let previous = CANCEL_SHORTCUT_HOLDERS
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+        .unwrap_or(0);
+    if previous != 1 {
+        return;
+    }
+
     // Cancel shortcut is disabled on Linux due to instability with dynamic shortcut registration
     #[cfg(target_os = "linux")]
     {
@@ -2319,7 +3685,96 @@ pub fn unregister_cancel_shortcut(app: &AppHandle) {
     }
 }
 
+/// Separator between a binding ID and the index of one of its additional keys
+/// (e.g., "transcribe#alt1"). Events from those registrations map back to the base ID.
+const ALT_BINDING_SEPARATOR: &str = "#alt";
+
+/// Binding ID without the additional-key suffix
+fn base_binding_id(id: &str) -> &str {
+    id.split_once(ALT_BINDING_SEPARATOR)
+        .map(|(base, _)| base)
+        .unwrap_or(id)
+}
+
+/// One registration per key: the primary binding plus a copy for each additional key.
+/// Empty keys are skipped.
+fn key_variants(binding: &ShortcutBinding) -> Vec<ShortcutBinding> {
+    let mut variants = Vec::new();
+    if !binding.current_binding.is_empty() {
+        variants.push(binding.clone());
+    }
+    for (i, key) in binding.additional_bindings.iter().enumerate() {
+        if key.trim().is_empty() {
+            continue;
+        }
+        let mut alt = binding.clone();
+        alt.id = format!("{}{}{}", binding.id, ALT_BINDING_SEPARATOR, i + 1);
+        alt.current_binding = key.clone();
+        alt.additional_bindings = Vec::new();
+        variants.push(alt);
+    }
+    variants
+}
+
+/// Swaps the registered shortcuts after the bindings were replaced wholesale (e.g. when a
+/// workspace is loaded): unregisters `previous` and registers the current settings' bindings.
+/// The cancel shortcut stays dynamic and is skipped.
+pub fn reload_bindings(
+    app: &AppHandle,
+    previous: &std::collections::HashMap<String, ShortcutBinding>,
+) {
+    for (id, binding) in previous {
+        if id != "cancel" && !key_variants(binding).is_empty() {
+            let _ = unregister_shortcut(app, binding.clone());
+        }
+    }
+
+    for (id, binding) in get_settings(app).bindings {
+        if id == "cancel" || key_variants(&binding).is_empty() {
+            continue;
+        }
+        if let Err(e) = register_shortcut(app, binding) {
+            error!("Failed to register shortcut {} after reload: {}", id, e);
+        }
+    }
+}
+
+/// Register every key of a binding (primary + additional) to the same action.
+/// If any key fails, the keys registered so far are rolled back.
 pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let variants = key_variants(&binding);
+    if variants.is_empty() {
+        return register_single_shortcut(app, binding);
+    }
+
+    for (i, variant) in variants.iter().enumerate() {
+        if let Err(e) = register_single_shortcut(app, variant.clone()) {
+            for registered in &variants[..i] {
+                let _ = unregister_single_shortcut(app, registered.clone());
+            }
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Unregister every key of a binding. Keeps going on failure and returns the first error.
+pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let variants = key_variants(&binding);
+    if variants.is_empty() {
+        return unregister_single_shortcut(app, binding);
+    }
+
+    let mut first_error = None;
+    for variant in variants {
+        if let Err(e) = unregister_single_shortcut(app, variant) {
+            first_error.get_or_insert(e);
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+fn register_single_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
     let settings = get_settings(app);
 
     // On Windows, check the shortcut_engine setting to decide which engine to use
@@ -2327,6 +3782,15 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
     {
         match settings.shortcut_engine {
             ShortcutEngine::Tauri => {
+                // Mouse buttons can only be captured by the rdev hook
+                if shortcut_uses_mouse_button(&binding.current_binding) {
+                    let error_msg = format!(
+                        "Shortcut '{}' uses a mouse button, which the Tauri engine cannot capture. Switch to the rdev engine in Settings → Debug → Experimental Features to bind mouse buttons.",
+                        binding.current_binding
+                    );
+                    warn!("{}", error_msg);
+                    return Err(error_msg);
+                }
                 // Check if the shortcut is compatible with Tauri engine
                 if !is_shortcut_tauri_compatible(&binding.current_binding) {
                     // Return error - incompatible shortcuts are not allowed in Tauri mode
@@ -2351,6 +3815,13 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
     }
 }
 
+/// Check if a shortcut string binds a mouse button (e.g. "mouse4", "ctrl+mouse5")
+pub fn shortcut_uses_mouse_button(shortcut: &str) -> bool {
+    shortcut
+        .split('+')
+        .any(|part| crate::managers::key_listener::string_to_mouse_button(part).is_some())
+}
+
 /// Check if a shortcut string is compatible with tauri-plugin-global-shortcut.
 /// Returns false for keys that only rdev supports (Caps Lock, Num Lock, modifier-only, etc.)
 pub fn is_shortcut_tauri_compatible(shortcut: &str) -> bool {
@@ -2369,13 +3840,18 @@ pub fn is_shortcut_tauri_compatible(shortcut: &str) -> bool {
         "pause",
     ];
 
-    // Check if any part is an rdev-only key
+    // Check if any part is an rdev-only key (including raw key codes)
     for part in &parts {
-        if rdev_only_keys.contains(part) {
+        if rdev_only_keys.contains(part) || part.starts_with("keycode:") {
             return false;
         }
     }
 
+    // Mouse buttons are rdev-only
+    if shortcut_uses_mouse_button(&lower) {
+        return false;
+    }
+
     // Check for modifier-only shortcuts (no main key)
     let modifiers = [
         "ctrl", "control", "shift", "alt", "option", "meta", "command", "cmd", "super", "win",
@@ -2425,129 +3901,18 @@ fn register_shortcut_tauri(app: &AppHandle, binding: ShortcutBinding) -> Result<
         return Err(error_msg);
     }
 
-    // Clone binding.id for use in the closure
-    let binding_id_for_closure = binding.id.clone();
+    // Base binding ID for use in the closure (additional keys run the same action)
+    let binding_id_for_closure = base_binding_id(&binding.id).to_string();
 
     app.global_shortcut()
         .on_shortcut(shortcut, move |ah, scut, event| {
             if scut == &shortcut {
                 let shortcut_string = scut.into_string();
-                let settings = get_settings(ah);
-
-                // Look up action - for profile-based bindings (transcribe_profile_xxx),
-                // fall back to the "transcribe" action
-                let action = ACTION_MAP.get(&binding_id_for_closure).or_else(|| {
-                    if binding_id_for_closure.starts_with("transcribe_") {
-                        ACTION_MAP.get("transcribe")
-                    } else {
-                        None
-                    }
-                });
-
-                if let Some(action) = action {
-                    if binding_id_for_closure == "cancel" {
-                        let audio_manager = ah.state::<Arc<AudioRecordingManager>>();
-                        if audio_manager.is_recording() && event.state == ShortcutState::Pressed {
-                            action.start(ah, &binding_id_for_closure, &shortcut_string);
-                        }
-                        return;
-                    }
-
-                    // Check if risky extension actions or voice commands are enabled before executing
-                    let action_enabled = match binding_id_for_closure.as_str() {
-                        "send_to_extension" => settings.send_to_extension_enabled,
-                        "send_to_extension_with_selection" => settings.send_to_extension_with_selection_enabled,
-                        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_enabled,
-                        "voice_command" => settings.voice_command_enabled,
-                        _ => true, // Other actions are always enabled
-                    };
-                    if !action_enabled {
-                        log::debug!(
-                            "Action '{}' is disabled, ignoring shortcut press",
-                            binding_id_for_closure
-                        );
-                        return;
-                    }
-
-                    // Determine push-to-talk setting based on binding
-                    let use_push_to_talk = match binding_id_for_closure.as_str() {
-                        "send_to_extension" => settings.send_to_extension_push_to_talk,
-                        "send_to_extension_with_selection" => settings.send_to_extension_with_selection_push_to_talk,
-                        "ai_replace_selection" => settings.ai_replace_selection_push_to_talk,
-                        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_push_to_talk,
-                        "voice_command" => settings.voice_command_push_to_talk,
-                        "transcribe" => {
-                            // Use active profile's PTT setting, or global if "default"
-                            if settings.active_profile_id == "default" {
-                                settings.push_to_talk
-                            } else {
-                                settings
-                                    .transcription_profile(&settings.active_profile_id)
-                                    .map(|p| p.push_to_talk)
-                                    .unwrap_or(settings.push_to_talk)
-                            }
-                        }
-                        id if id.starts_with("transcribe_") => {
-                            // Profile-specific shortcut: use that profile's PTT
-                            settings
-                                .transcription_profile_by_binding(id)
-                                .map(|p| p.push_to_talk)
-                                .unwrap_or(settings.push_to_talk)
-                        }
-                        _ => settings.push_to_talk,
-                    };
-
-                    // Handle instant actions first - they fire on every press
-                    // without any toggle state management
-                    if action.is_instant() {
-                        if event.state == ShortcutState::Pressed {
-                            action.start(ah, &binding_id_for_closure, &shortcut_string);
-                        }
-                        // Instant actions don't need stop() on release
-                        return;
-                    }
-
-                    if use_push_to_talk {
-                        if event.state == ShortcutState::Pressed {
-                            action.start(ah, &binding_id_for_closure, &shortcut_string);
-                        } else if event.state == ShortcutState::Released {
-                            action.stop(ah, &binding_id_for_closure, &shortcut_string);
-                        }
-                    } else {
-                        // Toggle mode: toggle on press only
-                        if event.state == ShortcutState::Pressed {
-                            // Determine action and update state while holding the lock,
-                            // but RELEASE the lock before calling the action to avoid deadlocks.
-                            // (Actions may need to acquire the lock themselves, e.g., cancel_current_operation)
-                            let should_start: bool;
-                            {
-                                let toggle_state_manager = ah.state::<ManagedToggleState>();
-                                let mut states = toggle_state_manager
-                                    .lock()
-                                    .expect("Failed to lock toggle state manager");
-
-                                let is_currently_active = states
-                                    .active_toggles
-                                    .entry(binding_id_for_closure.clone())
-                                    .or_insert(false);
-
-                                should_start = !*is_currently_active;
-                                *is_currently_active = should_start;
-                            } // Lock released here
-
-                            // Now call the action without holding the lock
-                            if should_start {
-                                action.start(ah, &binding_id_for_closure, &shortcut_string);
-                            } else {
-                                action.stop(ah, &binding_id_for_closure, &shortcut_string);
-                            }
-                        }
-                    }
-                } else {
-                    warn!(
-                        "No action defined in ACTION_MAP for shortcut ID '{}'. Shortcut: '{}', State: {:?}",
-                        binding_id_for_closure, shortcut_string, event.state
-                    );
+                let pressed = event.state == ShortcutState::Pressed;
+                if let Err(e) =
+                    dispatch_binding_event(ah, &binding_id_for_closure, &shortcut_string, pressed)
+                {
+                    log::debug!("Ignoring shortcut: {}. Binding: '{}'", e, shortcut_string);
                 }
             }
         })
@@ -2560,7 +3925,7 @@ fn register_shortcut_tauri(app: &AppHandle, binding: ShortcutBinding) -> Result<
     Ok(())
 }
 
-pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+fn unregister_single_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
     // Check if this is an rdev shortcut first
     if let Some(rdev_set) = app.try_state::<RdevShortcutsSet>() {
         let mut rdev_shortcuts = rdev_set.lock().expect("Failed to lock rdev shortcuts");
@@ -2685,12 +4050,71 @@ pub fn change_text_replacements_setting(
     app: AppHandle,
     replacements: Vec<settings::TextReplacement>,
 ) -> Result<(), String> {
+    settings::validate_text_replacements(&replacements)?;
     let mut settings = settings::get_settings(&app);
     settings.text_replacements = replacements;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+/// Turn every text replacement rule in `group` on or off at once
+#[tauri::command]
+#[specta::specta]
+pub fn change_text_replacement_group_enabled_setting(
+    app: AppHandle,
+    group: String,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings
+        .text_replacement_groups_enabled
+        .insert(group, enabled);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Run input/expected cases through the text replacement rules (the saved rules unless
+/// `replacements` is given) and report each actual output and pass/fail
+#[tauri::command]
+#[specta::specta]
+pub fn test_text_replacement_suite(
+    app: AppHandle,
+    cases: Vec<settings::TextReplacementTestCase>,
+    replacements: Option<Vec<settings::TextReplacement>>,
+) -> Vec<settings::TextReplacementTestResult> {
+    let settings = settings::get_settings(&app);
+    let replacements = replacements.unwrap_or_else(|| settings.text_replacements.clone());
+    settings::run_text_replacement_suite(
+        cases,
+        &replacements,
+        &settings.text_replacement_groups_enabled,
+        settings.text_replacements_before_llm,
+    )
+}
+
+/// Step through the text replacement rules (the saved rules unless `replacements` is
+/// given) on sample text without saving anything. As in the pipeline, the sample first
+/// goes through dictation commands, then the before-LLM rules and the after-LLM rules;
+/// no LLM runs in between.
+#[tauri::command]
+#[specta::specta]
+pub fn preview_text_replacements(
+    app: AppHandle,
+    text: String,
+    replacements: Option<Vec<settings::TextReplacement>>,
+) -> Vec<settings::ReplacementStep> {
+    let settings = settings::get_settings(&app);
+    let profile = settings.profile_for_binding("transcribe");
+    let text = crate::dictation_commands::apply_for_profile(&settings, profile, &text);
+    let replacements = replacements.unwrap_or_else(|| settings.text_replacements.clone());
+    settings::preview_text_replacements(
+        &text,
+        &replacements,
+        &settings.text_replacement_groups_enabled,
+        settings.text_replacements_before_llm,
+    )
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_text_replacements_before_llm_setting(
@@ -2703,6 +4127,152 @@ pub fn change_text_replacements_before_llm_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_dictation_commands_setting(
+    app: AppHandle,
+    enabled: bool,
+    commands: Vec<settings::DictationCommand>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.dictation_commands_enabled = enabled;
+    settings.dictation_commands = commands;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Per-profile dictation command toggle. None inherits the global setting.
+#[tauri::command]
+#[specta::specta]
+pub fn change_profile_dictation_commands_setting(
+    app: AppHandle,
+    id: String,
+    enabled: Option<bool>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let profile = settings
+        .transcription_profiles
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Profile with id '{}' not found", id))?;
+    profile.dictation_commands_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Per-profile filler word filter. None inherits the global setting.
+#[tauri::command]
+#[specta::specta]
+pub fn change_profile_filler_filter_override_setting(
+    app: AppHandle,
+    id: String,
+    enabled: Option<bool>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let profile = settings
+        .transcription_profiles
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Profile with id '{}' not found", id))?;
+    profile.filler_filter_override = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Per-profile VAD threshold (clamped to 0.1-0.9). None inherits the global setting.
+/// Applied when the next recording with this profile starts.
+#[tauri::command]
+#[specta::specta]
+pub fn change_profile_vad_threshold_setting(
+    app: AppHandle,
+    id: String,
+    threshold: Option<f32>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let profile = settings
+        .transcription_profiles
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Profile with id '{}' not found", id))?;
+    profile.vad_threshold = clamp_profile_vad_threshold(threshold);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Profile VAD thresholds are kept in the same 0.1-0.9 range as the global setting
+fn clamp_profile_vad_threshold(threshold: Option<f32>) -> Option<f32> {
+    threshold.map(|t| t.clamp(settings::VAD_THRESHOLD_MIN, settings::VAD_THRESHOLD_MAX))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_output_case_transform_setting(
+    app: AppHandle,
+    mode: OutputCaseTransform,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.output_case_transform = mode;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Per-profile output casing. None inherits the global setting.
+#[tauri::command]
+#[specta::specta]
+pub fn change_profile_output_case_transform_setting(
+    app: AppHandle,
+    id: String,
+    mode: Option<OutputCaseTransform>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let profile = settings
+        .transcription_profiles
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Profile with id '{}' not found", id))?;
+    profile.output_case_transform = mode;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_auto_capitalize_sentences_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.auto_capitalize_sentences = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Per-profile sentence capitalization. None inherits the global setting.
+#[tauri::command]
+#[specta::specta]
+pub fn change_profile_auto_capitalize_sentences_setting(
+    app: AppHandle,
+    id: String,
+    enabled: Option<bool>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let profile = settings
+        .transcription_profiles
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Profile with id '{}' not found", id))?;
+    profile.auto_capitalize_sentences = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Built-in dictation commands for a language code (empty if none are curated)
+#[tauri::command]
+#[specta::specta]
+pub fn get_builtin_dictation_commands(language: String) -> Vec<settings::DictationCommand> {
+    crate::dictation_commands::builtin_commands(&language)
+}
+
 // ============================================================================
 // UI State Settings
 // ============================================================================
@@ -2736,3 +4306,211 @@ pub fn change_sidebar_width_setting(app: AppHandle, width: u32) -> Result<(), St
 pub fn get_language_from_os_input() -> Option<String> {
     crate::input_source::get_language_from_input_source()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    fn prompt(name: &str) -> settings::LLMPrompt {
+        settings::LLMPrompt {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            prompt: String::new(),
+        }
+    }
+
+    fn binding(id: &str, key: &str, additional: &[&str]) -> ShortcutBinding {
+        ShortcutBinding {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            default_binding: key.to_string(),
+            current_binding: key.to_string(),
+            additional_bindings: additional.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn binding_target_uses_the_profile_languages_model() {
+        let mut settings = get_default_settings();
+        settings.transcription_provider = TranscriptionProvider::Local;
+        settings.selected_model = "small".to_string();
+        settings.selected_language = "en".to_string();
+        settings
+            .models_by_language
+            .insert("de".to_string(), "parakeet-de".to_string());
+        let profile: settings::TranscriptionProfile = serde_json::from_value(serde_json::json!({
+            "id": "profile_de",
+            "name": "German",
+            "language": "de",
+            "translate_to_english": false
+        }))
+        .unwrap();
+        settings.transcription_profiles.push(profile);
+
+        settings.active_profile_id = "profile_de".to_string();
+        let target = binding_target(&settings, "transcribe".to_string()).unwrap();
+        assert_eq!(target.language, "de");
+        assert_eq!(target.model, "parakeet-de");
+
+        // No mapping for the global language: the selected model
+        settings.active_profile_id = "default".to_string();
+        let target = binding_target(&settings, "transcribe".to_string()).unwrap();
+        assert_eq!(target.language, "en");
+        assert_eq!(target.model, "small");
+    }
+
+    #[test]
+    fn keymap_reports_each_alt_key_registration() {
+        let transcribe = binding("transcribe", "ctrl+space", &["mouse4", "f13"]);
+        let rdev: HashSet<String> = ["transcribe#alt1".to_string()].into_iter().collect();
+        let is_native = |key: &str| key == "ctrl+space";
+
+        let registrations: Vec<&str> = key_variants(&transcribe)
+            .iter()
+            .map(|variant| keymap_registration(variant, &rdev, is_native))
+            .collect();
+        assert_eq!(registrations, ["native", "rdev", "unregistered"]);
+
+        let cancel = binding("cancel", "escape", &["f14"]);
+        let registrations: Vec<&str> = key_variants(&cancel)
+            .iter()
+            .map(|variant| keymap_registration(variant, &rdev, is_native))
+            .collect();
+        assert_eq!(registrations, ["dynamic", "dynamic"]);
+        assert_eq!(
+            keymap_registration(&binding("paste", "", &[]), &rdev, is_native),
+            "unbound"
+        );
+    }
+
+    #[test]
+    fn key_variants_register_each_additional_key_under_an_alt_id() {
+        let variants = key_variants(&binding(
+            "transcribe",
+            "ctrl+space",
+            &["mouse4", " ", "f13"],
+        ));
+        let ids: Vec<&str> = variants.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, ["transcribe", "transcribe#alt1", "transcribe#alt3"]);
+        assert_eq!(variants[2].current_binding, "f13");
+        assert!(variants[1].additional_bindings.is_empty());
+        assert!(ids.iter().all(|id| base_binding_id(id) == "transcribe"));
+
+        // An unbound primary still registers its additional keys
+        let variants = key_variants(&binding("paste", "", &["f14"]));
+        let ids: Vec<&str> = variants.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, ["paste#alt1"]);
+    }
+
+    #[test]
+    fn key_conflicts_check_additional_keys_and_optionally_primaries() {
+        let mut settings = get_default_settings();
+        settings.bindings.clear();
+        for b in [
+            binding("transcribe", "ctrl+space", &["mouse4"]),
+            binding("paste", "ctrl+shift+v", &[]),
+        ] {
+            settings.bindings.insert(b.id.clone(), b);
+        }
+
+        assert_eq!(
+            find_key_conflict(&settings, "paste", " Mouse4 ", false).as_deref(),
+            Some("transcribe")
+        );
+        assert_eq!(
+            find_key_conflict(&settings, "transcribe", "ctrl+shift+v", false),
+            None
+        );
+        assert_eq!(
+            find_key_conflict(&settings, "transcribe", "ctrl+shift+v", true).as_deref(),
+            Some("paste")
+        );
+        // A binding's own primary key is not a conflict
+        assert_eq!(
+            find_key_conflict(&settings, "paste", "ctrl+shift+v", true),
+            None
+        );
+    }
+
+    #[test]
+    fn close_prompt_names_ranks_substrings_and_near_misses() {
+        let prompts = [prompt("Summarize"), prompt("Translate"), prompt("Email")];
+        assert_eq!(close_prompt_names(&prompts, "summ"), vec!["Summarize"]);
+        assert_eq!(close_prompt_names(&prompts, "emial"), vec!["Email"]);
+        assert!(close_prompt_names(&prompts, "xyz").is_empty());
+    }
+
+    #[test]
+    fn close_prompt_names_ignores_empty_names() {
+        let prompts = [prompt(""), prompt("  "), prompt("Email")];
+        assert!(close_prompt_names(&prompts, "").is_empty());
+        assert_eq!(close_prompt_names(&prompts, "email me"), vec!["Email"]);
+    }
+
+    #[test]
+    fn instant_actions_fire_on_press_only() {
+        let mut active = false;
+        assert_eq!(
+            binding_phase(true, false, true, &mut active),
+            Some(ActionPhase::Start)
+        );
+        assert_eq!(binding_phase(true, true, false, &mut active), None);
+        assert!(!active);
+    }
+
+    #[test]
+    fn push_to_talk_starts_on_press_and_stops_on_release() {
+        let mut active = false;
+        assert_eq!(
+            binding_phase(false, true, true, &mut active),
+            Some(ActionPhase::Start)
+        );
+        assert_eq!(
+            binding_phase(false, true, false, &mut active),
+            Some(ActionPhase::Stop)
+        );
+        assert!(!active);
+    }
+
+    #[test]
+    fn toggle_flips_on_press_and_ignores_release() {
+        let mut active = false;
+        assert_eq!(
+            binding_phase(false, false, true, &mut active),
+            Some(ActionPhase::Start)
+        );
+        assert!(active);
+        assert_eq!(binding_phase(false, false, false, &mut active), None);
+        assert!(active);
+        assert_eq!(
+            binding_phase(false, false, true, &mut active),
+            Some(ActionPhase::Stop)
+        );
+        assert!(!active);
+    }
+
+    #[test]
+    fn stop_sequences_keep_whitespace_and_drop_empty_entries() {
+        let cleaned = clean_stop_sequences(vec![
+            String::new(),
+            "\n\n".to_string(),
+            " ###".to_string(),
+            " ".to_string(),
+        ]);
+        assert_eq!(cleaned, vec!["\n\n", " ###", " "]);
+    }
+
+    #[test]
+    fn disabled_actions_are_gated() {
+        let mut settings = get_default_settings();
+        settings.voice_command_enabled = false;
+        assert!(!is_action_enabled(&settings, "voice_command"));
+        assert!(!is_action_enabled(
+            &settings,
+            &format!("{}abc", RUN_VOICE_COMMAND_PREFIX)
+        ));
+        assert!(is_action_enabled(&settings, "transcribe"));
+    }
+}
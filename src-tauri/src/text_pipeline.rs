@@ -0,0 +1,400 @@
+//! Text Pipeline
+//!
+//! The order in which a finished transcription is transformed before it is pasted or sent.
+//! Custom words are applied by the STT engine itself, so the pipeline starts after them:
+//!
+//! 1. Dictation commands ("new line", "open quote")
+//! 2. Text replacements whose phase is before the LLM (or both)
+//! 3. Chinese variant conversion (zh-Hans/zh-Hant), which replaces LLM post-processing
+//! 4. LLM post-processing
+//! 5. Text replacements whose phase is after the LLM (or both)
+//! 6. Sentence capitalization
+//! 7. Output case transform
+//!
+//! Rules without a phase of their own follow `text_replacements_before_llm`.
+//!
+//! The LLM step is passed in so the ordering can be tested without a provider.
+
+use crate::managers::history::PostProcessStatus;
+use crate::settings::{AppSettings, TextReplacementPhase, TranscriptionProfile};
+use ferrous_opencc::{config::BuiltinConfig, OpenCC};
+use log::{debug, error};
+use std::future::Future;
+
+pub enum PostProcessTranscriptionOutcome {
+    /// Post-processing is turned off (globally, for the profile, or for the foreground app)
+    Disabled,
+    /// Enabled but not configured (no provider/model/prompt)
+    Skipped,
+    /// The LLM call failed or returned nothing; caller falls back to the raw text
+    Failed,
+    Cancelled,
+    Processed {
+        text: String,
+        prompt_template: String,
+    },
+}
+
+/// A pipeline stage that ran
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextStage {
+    DictationCommands,
+    ReplacementsBeforeLlm,
+    ChineseConversion,
+    PostProcess,
+    ReplacementsAfterLlm,
+    CapitalizeSentences,
+    CaseTransform,
+}
+
+pub struct PipelineOutput {
+    /// Text to paste or send
+    pub text: String,
+    /// Stages that ran, in order
+    pub stages: Vec<TextStage>,
+    /// Text produced by Chinese conversion or the LLM, for history
+    pub post_processed_text: Option<String>,
+    /// Prompt template the LLM was given, for history
+    pub post_process_prompt: Option<String>,
+    pub post_process_status: PostProcessStatus,
+}
+
+/// Runs every text stage in the documented order. `post_process` is the LLM step; it gets
+/// the text as prepared by the earlier stages. Returns None when post-processing was
+/// cancelled.
+///
+/// `allow_text_replacements` lets callers opt out (e.g., the connector path when
+/// `connector_apply_text_replacements` is off).
+pub async fn run_text_pipeline<F, Fut>(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    raw: &str,
+    allow_text_replacements: bool,
+    post_process: F,
+) -> Option<PipelineOutput>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = PostProcessTranscriptionOutcome>,
+{
+    let mut stages = Vec::new();
+    let mut post_processed_text = None;
+    let mut post_process_prompt = None;
+    let post_process_status;
+
+    let dictation_enabled = profile
+        .and_then(|p| p.dictation_commands_enabled)
+        .unwrap_or(settings.dictation_commands_enabled);
+    let mut text = if dictation_enabled {
+        stages.push(TextStage::DictationCommands);
+        crate::dictation_commands::apply_for_profile(settings, profile, raw)
+    } else {
+        raw.to_string()
+    };
+
+    let replacements_enabled = allow_text_replacements
+        && settings.text_replacements_enabled
+        && !settings.text_replacements.is_empty();
+    let apply_replacements = |text: &str, phase: TextReplacementPhase| -> Option<String> {
+        if !replacements_enabled {
+            return None;
+        }
+        let result = crate::settings::apply_text_replacements_in_phase(
+            text,
+            &settings.text_replacements,
+            &settings.text_replacement_groups_enabled,
+            phase,
+            settings.text_replacements_before_llm,
+        )?;
+        if result.len() != text.len() {
+            debug!(
+                "Text replacements ({:?}) applied: {} chars -> {} chars",
+                phase,
+                text.len(),
+                result.len()
+            );
+        }
+        Some(result)
+    };
+
+    if let Some(replaced) = apply_replacements(&text, TextReplacementPhase::BeforeLlm) {
+        stages.push(TextStage::ReplacementsBeforeLlm);
+        text = replaced;
+    }
+
+    if let Some(converted) = maybe_convert_chinese_variant(settings, &text).await {
+        stages.push(TextStage::ChineseConversion);
+        text = converted.clone();
+        post_processed_text = Some(converted);
+        post_process_status = PostProcessStatus::ChineseConversion;
+    } else {
+        post_process_status = match post_process(text.clone()).await {
+            PostProcessTranscriptionOutcome::Disabled => PostProcessStatus::Disabled,
+            PostProcessTranscriptionOutcome::Skipped => PostProcessStatus::Skipped,
+            // Fall back to the unprocessed text, but record the failure
+            PostProcessTranscriptionOutcome::Failed => PostProcessStatus::Failed,
+            PostProcessTranscriptionOutcome::Cancelled => return None,
+            PostProcessTranscriptionOutcome::Processed {
+                text: processed,
+                prompt_template,
+            } => {
+                stages.push(TextStage::PostProcess);
+                text = processed.clone();
+                post_processed_text = Some(processed);
+                post_process_prompt = Some(prompt_template);
+                PostProcessStatus::Success
+            }
+        };
+    }
+
+    if let Some(replaced) = apply_replacements(&text, TextReplacementPhase::AfterLlm) {
+        stages.push(TextStage::ReplacementsAfterLlm);
+        text = replaced;
+    }
+
+    if crate::output_case::capitalize_sentences_for_profile(settings, profile) {
+        stages.push(TextStage::CapitalizeSentences);
+        text = crate::output_case::capitalize_sentences(&text);
+    }
+
+    // Forced casing is the last transform before paste
+    let case_transform = crate::output_case::transform_for_profile(settings, profile);
+    if case_transform != crate::settings::OutputCaseTransform::None {
+        stages.push(TextStage::CaseTransform);
+        text = crate::output_case::apply_case_transform(&text, case_transform);
+    }
+
+    Some(PipelineOutput {
+        text,
+        stages,
+        post_processed_text,
+        post_process_prompt,
+        post_process_status,
+    })
+}
+
+pub async fn maybe_convert_chinese_variant(
+    settings: &AppSettings,
+    transcription: &str,
+) -> Option<String> {
+    // Check if language is set to Simplified or Traditional Chinese
+    let is_simplified = settings.selected_language == "zh-Hans";
+    let is_traditional = settings.selected_language == "zh-Hant";
+
+    if !is_simplified && !is_traditional {
+        debug!("selected_language is not Simplified or Traditional Chinese; skipping translation");
+        return None;
+    }
+
+    debug!(
+        "Starting Chinese translation using OpenCC for language: {}",
+        settings.selected_language
+    );
+
+    // Use OpenCC to convert based on selected language
+    let config = if is_simplified {
+        // Convert Traditional Chinese to Simplified Chinese
+        BuiltinConfig::Tw2sp
+    } else {
+        // Convert Simplified Chinese to Traditional Chinese
+        BuiltinConfig::S2twp
+    };
+
+    match OpenCC::from_config(config) {
+        Ok(converter) => {
+            let converted = converter.convert(transcription);
+            debug!(
+                "OpenCC translation completed. Input length: {}, Output length: {}",
+                transcription.len(),
+                converted.len()
+            );
+            Some(converted)
+        }
+        Err(e) => {
+            error!("Failed to initialize OpenCC converter: {}. Falling back to original transcription.", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{get_default_settings, OutputCaseTransform, TextReplacement};
+    use std::cell::RefCell;
+
+    fn settings_with_replacement(before_llm: bool) -> AppSettings {
+        let mut settings = get_default_settings();
+        settings.dictation_commands_enabled = false;
+        settings.text_replacements_enabled = true;
+        settings.text_replacements_before_llm = before_llm;
+        settings.text_replacements = vec![TextReplacement {
+            id: "tr_1".to_string(),
+            from: "world".to_string(),
+            to: "earth".to_string(),
+            enabled: true,
+            case_sensitive: true,
+            is_regex: false,
+            fuzzy: false,
+            fuzzy_max_distance: 2,
+            group: None,
+            phase: None,
+        }];
+        settings
+    }
+
+    /// Runs the pipeline with a fake LLM that appends " world", returning the output
+    /// and the text the LLM was given
+    fn run(
+        settings: &AppSettings,
+        raw: &str,
+        allow_replacements: bool,
+        llm_enabled: bool,
+    ) -> (PipelineOutput, Option<String>) {
+        let llm_input = RefCell::new(None);
+        let output = futures::executor::block_on(run_text_pipeline(
+            settings,
+            None,
+            raw,
+            allow_replacements,
+            |text| {
+                *llm_input.borrow_mut() = Some(text.clone());
+                async move {
+                    if llm_enabled {
+                        PostProcessTranscriptionOutcome::Processed {
+                            text: format!("{} world", text),
+                            prompt_template: "${output}".to_string(),
+                        }
+                    } else {
+                        PostProcessTranscriptionOutcome::Disabled
+                    }
+                }
+            },
+        ))
+        .expect("pipeline was not cancelled");
+        (output, llm_input.into_inner())
+    }
+
+    #[test]
+    fn replacements_after_llm_see_llm_output() {
+        let settings = settings_with_replacement(false);
+        let (output, llm_input) = run(&settings, "hello world", true, true);
+        assert_eq!(llm_input.as_deref(), Some("hello world"));
+        assert_eq!(output.text, "hello earth earth");
+        assert_eq!(
+            output.stages,
+            vec![TextStage::PostProcess, TextStage::ReplacementsAfterLlm]
+        );
+        assert_eq!(output.post_process_status, PostProcessStatus::Success);
+    }
+
+    #[test]
+    fn replacements_before_llm_feed_the_llm() {
+        let settings = settings_with_replacement(true);
+        let (output, llm_input) = run(&settings, "hello world", true, true);
+        assert_eq!(llm_input.as_deref(), Some("hello earth"));
+        assert_eq!(output.text, "hello earth world");
+        assert_eq!(
+            output.stages,
+            vec![TextStage::ReplacementsBeforeLlm, TextStage::PostProcess]
+        );
+    }
+
+    #[test]
+    fn replacements_apply_without_llm_in_either_position() {
+        for before_llm in [true, false] {
+            let settings = settings_with_replacement(before_llm);
+            let (output, _) = run(&settings, "hello world", true, false);
+            assert_eq!(output.text, "hello earth");
+            assert_eq!(output.post_process_status, PostProcessStatus::Disabled);
+            assert!(output.post_processed_text.is_none());
+        }
+    }
+
+    #[test]
+    fn rule_phases_split_replacements_around_the_llm() {
+        // The global flag says "before", but only rules without a phase follow it
+        let mut settings = settings_with_replacement(true);
+        let base = settings.text_replacements[0].clone();
+        settings.text_replacements = vec![
+            TextReplacement {
+                id: "tr_2".to_string(),
+                from: "hello".to_string(),
+                to: "hi".to_string(),
+                ..base.clone()
+            },
+            TextReplacement {
+                phase: Some(TextReplacementPhase::Both),
+                ..base
+            },
+        ];
+        settings.text_replacements[0].phase = Some(TextReplacementPhase::AfterLlm);
+
+        let (output, llm_input) = run(&settings, "hello world", true, true);
+        assert_eq!(llm_input.as_deref(), Some("hello earth"));
+        assert_eq!(output.text, "hi earth earth");
+        assert_eq!(
+            output.stages,
+            vec![
+                TextStage::ReplacementsBeforeLlm,
+                TextStage::PostProcess,
+                TextStage::ReplacementsAfterLlm,
+            ]
+        );
+    }
+
+    #[test]
+    fn callers_can_opt_out_of_replacements() {
+        for before_llm in [true, false] {
+            let settings = settings_with_replacement(before_llm);
+            let (output, _) = run(&settings, "hello world", false, true);
+            assert_eq!(output.text, "hello world world");
+            assert_eq!(output.stages, vec![TextStage::PostProcess]);
+        }
+    }
+
+    #[test]
+    fn dictation_commands_run_first_and_case_transform_last() {
+        let mut settings = settings_with_replacement(false);
+        settings.dictation_commands_enabled = true;
+        settings.output_case_transform = OutputCaseTransform::Upper;
+        let (output, llm_input) = run(&settings, "hello new line there", true, true);
+        assert_eq!(llm_input.as_deref(), Some("hello\nthere"));
+        assert_eq!(output.text, "HELLO\nTHERE EARTH");
+        assert_eq!(
+            output.stages,
+            vec![
+                TextStage::DictationCommands,
+                TextStage::PostProcess,
+                TextStage::ReplacementsAfterLlm,
+                TextStage::CaseTransform,
+            ]
+        );
+    }
+
+    #[test]
+    fn chinese_conversion_replaces_llm_post_processing() {
+        let mut settings = settings_with_replacement(false);
+        settings.selected_language = "zh-Hans".to_string();
+        let (output, llm_input) = run(&settings, "漢字", true, true);
+        assert!(llm_input.is_none());
+        assert_eq!(output.text, "汉字");
+        assert_eq!(output.stages[0], TextStage::ChineseConversion);
+        assert_eq!(
+            output.post_process_status,
+            PostProcessStatus::ChineseConversion
+        );
+    }
+
+    #[test]
+    fn cancelled_post_processing_stops_the_pipeline() {
+        let settings = settings_with_replacement(false);
+        let output = futures::executor::block_on(run_text_pipeline(
+            &settings,
+            None,
+            "hello",
+            true,
+            |_| async { PostProcessTranscriptionOutcome::Cancelled },
+        ));
+        assert!(output.is_none());
+    }
+}
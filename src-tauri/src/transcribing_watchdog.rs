@@ -0,0 +1,96 @@
+//! Transcribing Watchdog
+//!
+//! Recovers from a stuck "Transcribing" UI state. If an async processing task panics
+//! or loses its result, the overlay and tray can stay in Transcribing forever.
+//! The watchdog resets to Idle after `transcribing_watchdog_secs` when nothing is recording
+//! and no processing task is still running. Slow work (a long local transcription, a slow
+//! remote STT or LLM call) holds an `InFlightGuard` and is never cancelled by the watchdog.
+
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::get_settings;
+use crate::tray::TrayIconState;
+use log::{debug, warn};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// When the tray entered the Transcribing state (None when in any other state)
+static TRANSCRIBING_SINCE: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Number of processing tasks still running
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+/// Marks processing work as live until dropped. Dropped on panic too, which is exactly
+/// the case where the Transcribing state is left behind for the watchdog to clear.
+pub struct InFlightGuard(());
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Call at the start of a processing task and hold the guard until it ends
+pub fn track_processing() -> InFlightGuard {
+    IN_FLIGHT.fetch_add(1, Ordering::SeqCst);
+    InFlightGuard(())
+}
+
+/// Record a tray state change. Called from `change_tray_icon`.
+pub fn note_tray_state(state: &TrayIconState) {
+    let mut since = TRANSCRIBING_SINCE.lock().unwrap();
+    if *state == TrayIconState::Transcribing {
+        // Keep the original start time if we're already transcribing
+        since.get_or_insert_with(Instant::now);
+    } else {
+        *since = None;
+    }
+}
+
+/// Start the background watchdog thread.
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(1));
+
+        let limit_secs = get_settings(&app).transcribing_watchdog_secs;
+        if limit_secs == 0 {
+            continue;
+        }
+
+        let elapsed = match *TRANSCRIBING_SINCE.lock().unwrap() {
+            Some(since) => since.elapsed(),
+            None => continue,
+        };
+        if elapsed < Duration::from_secs(limit_secs as u64) {
+            continue;
+        }
+
+        // An active recording means the UI state is legitimate
+        let rm = app.state::<Arc<AudioRecordingManager>>();
+        if rm.is_recording() {
+            debug!("Transcribing watchdog: recording is active, skipping reset");
+            continue;
+        }
+
+        // Work still in flight is slow, not stuck; cancelling would discard its result
+        if IN_FLIGHT.load(Ordering::SeqCst) > 0 {
+            debug!("Transcribing watchdog: processing still running, skipping reset");
+            continue;
+        }
+
+        warn!(
+            "Transcribing state stuck for {}s (limit {}s), resetting to idle",
+            elapsed.as_secs(),
+            limit_secs
+        );
+
+        // Nothing is in flight, so this only clears the stale Processing state, overlay
+        // and tray
+        crate::utils::cancel_current_operation(&app);
+
+        let _ = app.emit("transcribing-watchdog-reset", elapsed.as_secs());
+    });
+}
@@ -0,0 +1,98 @@
+//! Transcription Log File
+//!
+//! Optional append-only log of every completed transcription, written to a file the
+//! user chooses (`transcription_log_path`) for their own tooling. Each entry holds the
+//! timestamp, profile, raw and processed text, as readable text blocks or JSON lines
+//! (`transcription_log_format`). Independent of the history database and its retention:
+//! entries are never pruned. Write failures are logged and otherwise ignored.
+
+use crate::settings::TranscriptionLogFormat;
+use log::{debug, warn};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+/// One completed transcription
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    /// Local time, RFC 3339 with offset
+    pub timestamp: String,
+    pub profile: String,
+    pub raw: String,
+    pub processed: String,
+}
+
+impl LogEntry {
+    pub fn now(profile: &str, raw: &str, processed: &str) -> Self {
+        Self {
+            timestamp: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            profile: profile.to_string(),
+            raw: raw.to_string(),
+            processed: processed.to_string(),
+        }
+    }
+}
+
+/// Serialize an entry, including its trailing newline(s)
+pub fn format_entry(entry: &LogEntry, format: TranscriptionLogFormat) -> String {
+    match format {
+        TranscriptionLogFormat::Text => format!(
+            "[{}] {}\nraw: {}\nprocessed: {}\n\n",
+            entry.timestamp, entry.profile, entry.raw, entry.processed
+        ),
+        // serde_json escapes newlines, so every entry stays on one line
+        TranscriptionLogFormat::Jsonl => match serde_json::to_string(entry) {
+            Ok(line) => format!("{}\n", line),
+            Err(e) => {
+                warn!("Failed to serialize transcription log entry: {}", e);
+                String::new()
+            }
+        },
+    }
+}
+
+/// Append `entry` to the log file at `path`. Never fails the transcription.
+pub fn append(path: &str, format: TranscriptionLogFormat, entry: &LogEntry) {
+    let line = format_entry(entry, format);
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()));
+    match result {
+        Ok(()) => debug!("Appended transcription to log file {}", path),
+        Err(e) => warn!("Failed to append to transcription log file {}: {}", path, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry() -> LogEntry {
+        LogEntry {
+            timestamp: "2026-01-02T03:04:05+01:00".to_string(),
+            profile: "Default".to_string(),
+            raw: "hello world".to_string(),
+            processed: "Hello, world!\nSecond line".to_string(),
+        }
+    }
+
+    #[test]
+    fn jsonl_entries_stay_on_one_line() {
+        let line = format_entry(&entry(), TranscriptionLogFormat::Jsonl);
+        assert_eq!(line.matches('\n').count(), 1);
+        let value: serde_json::Value = serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(value["processed"], "Hello, world!\nSecond line");
+        assert_eq!(value["profile"], "Default");
+    }
+
+    #[test]
+    fn text_entries_are_blank_line_separated_blocks() {
+        assert_eq!(
+            format_entry(&entry(), TranscriptionLogFormat::Text),
+            "[2026-01-02T03:04:05+01:00] Default\nraw: hello world\n\
+             processed: Hello, world!\nSecond line\n\n"
+        );
+    }
+}
@@ -2,9 +2,9 @@ use crate::managers::history::{HistoryEntry, HistoryManager};
 use crate::settings;
 use crate::tray_i18n::get_tray_translations;
 use log::{error, info, warn};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tauri::image::Image;
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tauri::tray::TrayIcon;
 use tauri::{AppHandle, Manager, Theme};
 use tauri_plugin_clipboard_manager::ClipboardExt;
@@ -16,6 +16,14 @@ pub enum TrayIconState {
     Transcribing,
 }
 
+/// Menu ID of the post-processing enable toggle
+const POST_PROCESS_TOGGLE_MENU_ID: &str = "post_process_toggle";
+/// Menu ID prefix of the prompt entries (followed by the prompt ID)
+const POST_PROCESS_PROMPT_MENU_PREFIX: &str = "post_process_prompt:";
+
+/// Last state the menu was built for, so it can be rebuilt when settings change
+static MENU_STATE: Mutex<TrayIconState> = Mutex::new(TrayIconState::Idle);
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum AppTheme {
     Dark,
@@ -77,9 +85,12 @@ pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
 
     // Update menu based on state
     update_tray_menu(app, &icon, None);
+
+    crate::transcribing_watchdog::note_tray_state(&icon);
 }
 
 pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&str>) {
+    *MENU_STATE.lock().unwrap() = state.clone();
     let settings = settings::get_settings(app);
 
     let locale = locale.unwrap_or(&settings.app_language);
@@ -164,11 +175,94 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
         .expect("failed to create menu"),
     };
 
+    // Post-processing quick switch goes right above "Settings..."
+    if let Some(post_process_menu) = post_process_submenu(app, &settings, &strings) {
+        let position = menu
+            .items()
+            .ok()
+            .and_then(|items| {
+                items
+                    .iter()
+                    .position(|item| item.id().as_ref() == "settings")
+            })
+            .unwrap_or(0);
+        let _ = menu.insert_items(&[&post_process_menu, &separator()], position);
+    }
+
     let tray = app.state::<TrayIcon>();
     let _ = tray.set_menu(Some(menu));
     let _ = tray.set_icon_as_template(true);
 }
 
+/// Rebuild the tray menu for its current state (e.g., after prompts or
+/// post-processing settings change).
+pub fn refresh_tray_menu(app: &AppHandle) {
+    let state = MENU_STATE.lock().unwrap().clone();
+    update_tray_menu(app, &state, None);
+}
+
+/// Submenu with the post-processing enable toggle and the prompt list.
+/// Only shown once a provider and model are configured.
+fn post_process_submenu(
+    app: &AppHandle,
+    settings: &settings::AppSettings,
+    strings: &crate::tray_i18n::TrayStrings,
+) -> Option<Submenu<tauri::Wry>> {
+    settings.active_post_process_provider()?;
+    if settings.active_post_process_model().trim().is_empty() {
+        return None;
+    }
+
+    let submenu = Submenu::with_id(app, "post_process", &strings.post_processing, true).ok()?;
+    let toggle_i = CheckMenuItem::with_id(
+        app,
+        POST_PROCESS_TOGGLE_MENU_ID,
+        &strings.post_process_enabled,
+        true,
+        settings.post_process_enabled,
+        None::<&str>,
+    )
+    .ok()?;
+    let _ = submenu.append(&toggle_i);
+
+    if !settings.post_process_prompts.is_empty() {
+        let _ = submenu.append(&PredefinedMenuItem::separator(app).ok()?);
+    }
+    for prompt in &settings.post_process_prompts {
+        let selected = settings.post_process_selected_prompt_id.as_deref() == Some(&prompt.id);
+        let prompt_i = CheckMenuItem::with_id(
+            app,
+            format!("{}{}", POST_PROCESS_PROMPT_MENU_PREFIX, prompt.id),
+            &prompt.name,
+            settings.post_process_enabled,
+            selected,
+            None::<&str>,
+        )
+        .ok()?;
+        let _ = submenu.append(&prompt_i);
+    }
+
+    Some(submenu)
+}
+
+/// Handle clicks on the post-processing submenu. Other menu IDs are ignored.
+pub fn handle_post_process_menu_event(app: &AppHandle, id: &str) {
+    let result = if id == POST_PROCESS_TOGGLE_MENU_ID {
+        let enabled = settings::get_settings(app).post_process_enabled;
+        crate::shortcut::change_post_process_enabled_setting(app.clone(), !enabled)
+    } else if let Some(prompt_id) = id.strip_prefix(POST_PROCESS_PROMPT_MENU_PREFIX) {
+        crate::shortcut::set_post_process_selected_prompt(app.clone(), prompt_id.to_string())
+    } else {
+        return;
+    };
+
+    if let Err(e) = result {
+        warn!("Tray post-processing action '{}' failed: {}", id, e);
+    }
+    // Commands refresh the menu on success; rebuild anyway so check marks never drift
+    refresh_tray_menu(app);
+}
+
 fn last_transcript_text(entry: &HistoryEntry) -> &str {
     entry
         .post_processed_text
@@ -216,6 +310,7 @@ mod tests {
             action_type: "transcribe".to_string(),
             original_selection: None,
             ai_response: None,
+            post_process_status: None,
         }
     }
 
@@ -1,12 +1,14 @@
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::HistoryManager;
 use crate::managers::llm_operation::LlmOperationTracker;
 use crate::managers::remote_stt::RemoteSttManager;
 use crate::managers::transcription::TranscriptionManager;
 use crate::session_manager;
 use crate::ManagedToggleState;
-use log::{debug, info, warn};
+use log::{debug, error, info, warn};
+use serde::Serialize;
 use std::sync::Arc;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 
 // Re-export all utility modules for easy access
 // pub use crate::audio_feedback::*;
@@ -14,31 +16,60 @@ pub use crate::clipboard::*;
 pub use crate::overlay::*;
 pub use crate::tray::*;
 
+/// Payload of the `recording-cancelled` event
+#[derive(Serialize, Clone, Debug)]
+pub struct RecordingCancelled {
+    /// Binding of the recording that was cancelled (None if only processing was cancelled)
+    pub binding_id: Option<String>,
+    /// False when `discard_cancelled_recordings` is off and the audio is kept in history
+    pub discarded: bool,
+}
+
+/// Keep the audio of a cancelled recording in history when `discard_cancelled_recordings`
+/// is off. Cancelled recordings are otherwise never saved.
+pub fn retain_cancelled_recording(app: &AppHandle, samples: Vec<f32>, transcription: String) {
+    if crate::settings::get_settings(app).discard_cancelled_recordings || samples.is_empty() {
+        return;
+    }
+    let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = hm.save_cancelled_recording(samples, transcription).await {
+            error!("Failed to save cancelled recording to history: {}", e);
+        }
+    });
+}
+
 /// Centralized cancellation function that can be called from anywhere in the app.
 /// Handles cancelling both recording and transcription operations and updates UI state.
 /// This also cancels any ongoing Processing work (transcription, LLM, etc.).
 pub fn cancel_current_operation(app: &AppHandle) {
     info!("Initiating operation cancellation...");
 
+    // Let in-flight processing know its result must not be pasted or saved
+    session_manager::mark_cancelled();
+
     // Take the active session if any - its Drop will handle cleanup
     // (unregistering cancel shortcut, removing mute, etc.)
-    if let Some((session, binding_id)) = session_manager::take_session(app) {
-        debug!(
-            "Cancellation: took active session for binding '{}'",
-            binding_id
-        );
-        // Session's Drop will handle:
-        // - Unregistering cancel shortcut
-        // - Removing mute
-        // - Hiding overlay
-        // - Resetting tray icon
-        drop(session);
-    } else {
-        // No Recording session - maybe we're in Processing state
-        // exit_processing will set state to Idle if we were in Processing
-        session_manager::exit_processing(app);
-        debug!("Cancellation: no active recording session, checked for Processing state");
-    }
+    let cancelled_binding_id =
+        if let Some((session, binding_id)) = session_manager::take_session(app) {
+            debug!(
+                "Cancellation: took active session for binding '{}'",
+                binding_id
+            );
+            // Session's Drop will handle:
+            // - Unregistering cancel shortcut
+            // - Removing mute
+            // - Hiding overlay
+            // - Resetting tray icon
+            drop(session);
+            Some(binding_id)
+        } else {
+            // No Recording session - maybe we're in Processing state
+            // exit_processing will set state to Idle if we were in Processing
+            session_manager::exit_processing(app);
+            debug!("Cancellation: no active recording session, checked for Processing state");
+            None
+        };
 
     // Reset all shortcut toggle states.
     // This is critical for non-push-to-talk mode where shortcuts toggle on/off
@@ -50,8 +81,11 @@ pub fn cancel_current_operation(app: &AppHandle) {
     }
 
     // Cancel any ongoing recording (belt-and-suspenders, session should have done this)
+    // The audio is dropped unless the user keeps cancelled recordings for debugging
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
-    audio_manager.cancel_recording();
+    if let Some(samples) = audio_manager.cancel_recording() {
+        retain_cancelled_recording(app, samples, String::new());
+    }
 
     // Cancel any in-flight Remote STT requests
     let remote_stt_manager = app.state::<Arc<RemoteSttManager>>();
@@ -61,6 +95,9 @@ pub fn cancel_current_operation(app: &AppHandle) {
     let llm_tracker = app.state::<Arc<LlmOperationTracker>>();
     llm_tracker.cancel();
 
+    // Don't auto-run a voice command the user just tried to cancel
+    crate::voice_command_countdown::cancel(app);
+
     // Ensure UI is in idle state (redundant if session Drop ran, but safe)
     change_tray_icon(app, crate::tray::TrayIconState::Idle);
     hide_recording_overlay(app);
@@ -69,6 +106,14 @@ pub fn cancel_current_operation(app: &AppHandle) {
     let tm = app.state::<Arc<TranscriptionManager>>();
     tm.maybe_unload_immediately("cancellation");
 
+    let _ = app.emit(
+        "recording-cancelled",
+        RecordingCancelled {
+            binding_id: cancelled_binding_id,
+            discarded: crate::settings::get_settings(app).discard_cancelled_recordings,
+        },
+    );
+
     info!("Operation cancellation completed - returned to idle state");
 }
 
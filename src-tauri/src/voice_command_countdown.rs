@@ -0,0 +1,144 @@
+//! Voice Command Auto-Run Countdown
+//!
+//! Runtime state for the countdown before a predefined voice command auto-runs.
+//! The backend owns the countdown so it can be cancelled from anywhere: the
+//! `cancel_voice_command_countdown` command/binding, the Cancel shortcut, or the
+//! confirmation overlay. A `voice-command-countdown` event is emitted every second;
+//! the overlay runs the command when it receives the `finished` tick.
+
+use log::debug;
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// Generation of the pending countdown (None when nothing is counting down).
+/// Each start bumps the generation so a stale timer thread stops on its own.
+static PENDING: Mutex<Option<u64>> = Mutex::new(None);
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+/// Paused countdowns keep their remaining time until resumed or cancelled
+static PAUSED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum CountdownState {
+    Running,
+    Paused,
+    Finished,
+    Cancelled,
+}
+
+/// Payload of the `voice-command-countdown` event
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct VoiceCommandCountdown {
+    pub remaining_seconds: u32,
+    pub state: CountdownState,
+}
+
+fn emit(app: &AppHandle, remaining_seconds: u32, state: CountdownState) {
+    let _ = app.emit(
+        "voice-command-countdown",
+        VoiceCommandCountdown {
+            remaining_seconds,
+            state,
+        },
+    );
+}
+
+/// Whether `generation` is still the pending countdown
+fn is_current(generation: u64) -> bool {
+    *PENDING.lock().unwrap() == Some(generation)
+}
+
+/// Clear the pending countdown if it is `generation`. Returns whether it was.
+fn clear_if_current(generation: u64) -> bool {
+    let mut pending = PENDING.lock().unwrap();
+    if *pending == Some(generation) {
+        *pending = None;
+        true
+    } else {
+        false
+    }
+}
+
+/// Hold the Cancel shortcut for the countdown. The countdown starts while the session
+/// is still Processing, after the recording released its own hold; holds are counted,
+/// so each side only releases what it took.
+fn set_cancel_shortcut(app: &AppHandle, registered: bool) {
+    if registered {
+        crate::shortcut::register_cancel_shortcut(app);
+    } else {
+        crate::shortcut::unregister_cancel_shortcut(app);
+    }
+}
+
+/// Start the auto-run countdown, replacing any pending one.
+// Only the Windows confirmation overlay auto-runs commands
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub fn start(app: &AppHandle, seconds: u32) {
+    let generation = NEXT_GENERATION.fetch_add(1, Ordering::SeqCst);
+    let replaced = PENDING.lock().unwrap().replace(generation).is_some();
+    PAUSED.store(false, Ordering::SeqCst);
+    if !replaced {
+        set_cancel_shortcut(app, true);
+    }
+    debug!("Voice command countdown started ({}s)", seconds);
+
+    let app = app.clone();
+    thread::spawn(move || {
+        let mut remaining = seconds;
+        while remaining > 0 {
+            if !is_current(generation) {
+                return;
+            }
+            let paused = PAUSED.load(Ordering::SeqCst);
+            let state = if paused {
+                CountdownState::Paused
+            } else {
+                CountdownState::Running
+            };
+            emit(&app, remaining, state);
+            thread::sleep(Duration::from_secs(1));
+            if !paused && !PAUSED.load(Ordering::SeqCst) {
+                remaining -= 1;
+            }
+        }
+
+        if clear_if_current(generation) {
+            debug!("Voice command countdown finished");
+            set_cancel_shortcut(&app, false);
+            emit(&app, 0, CountdownState::Finished);
+        }
+    });
+}
+
+/// Pause or resume the pending countdown. Returns whether one is pending.
+pub fn set_paused(paused: bool) -> bool {
+    if PENDING.lock().unwrap().is_none() {
+        return false;
+    }
+    if PAUSED.swap(paused, Ordering::SeqCst) != paused {
+        debug!(
+            "Voice command countdown {}",
+            if paused { "paused" } else { "resumed" }
+        );
+    }
+    true
+}
+
+/// Abort the pending countdown so the command never auto-runs.
+/// Returns whether a countdown was pending.
+pub fn cancel(app: &AppHandle) -> bool {
+    if PENDING.lock().unwrap().take().is_none() {
+        return false;
+    }
+    PAUSED.store(false, Ordering::SeqCst);
+    debug!("Voice command countdown cancelled");
+    set_cancel_shortcut(app, false);
+    emit(app, 0, CountdownState::Cancelled);
+    true
+}
@@ -0,0 +1,151 @@
+//! Whisper engine
+//!
+//! Runs Whisper models through whisper-rs directly rather than transcribe-rs, which
+//! hides the language detection probabilities needed to restrict "auto" to a set of
+//! candidate languages.
+
+use anyhow::{anyhow, Result};
+use std::path::Path;
+use transcribe_rs::{TranscriptionResult, TranscriptionSegment};
+use whisper_rs::{
+    FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperState,
+};
+
+/// Beam width transcribe-rs used for Whisper; kept as the default so results don't change
+const DEFAULT_BEAM_SIZE: i32 = 3;
+
+/// Whisper's default: segments more likely than this to be silence are dropped
+const DEFAULT_NO_SPEECH_THRESHOLD: f32 = 0.2;
+
+/// Per-transcription Whisper options
+#[derive(Debug, Clone, Default)]
+pub struct WhisperParams {
+    /// ISO 639-1 code; None lets Whisper detect the language
+    pub language: Option<String>,
+    pub translate: bool,
+    pub initial_prompt: Option<String>,
+    /// Decoding overrides from `LocalDecodeParams` (None = default)
+    pub beam_size: Option<u32>,
+    pub temperature: Option<f32>,
+    pub no_speech_threshold: Option<f32>,
+    pub condition_on_previous: Option<bool>,
+}
+
+pub struct WhisperEngine {
+    state: WhisperState,
+    /// Token ids from end-of-text up are special (timestamps, language, task)
+    token_eot: i32,
+}
+
+fn thread_count() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get().min(4))
+        .unwrap_or(4)
+}
+
+impl WhisperEngine {
+    /// Load a model on the GPU (`gpu_device` picks one, None = default) or the CPU
+    pub fn load(model_path: &Path, use_gpu: bool, gpu_device: Option<u32>) -> Result<Self> {
+        let path = model_path
+            .to_str()
+            .ok_or_else(|| anyhow!("Model path is not valid UTF-8: {:?}", model_path))?;
+        let mut context_params = WhisperContextParameters::default();
+        context_params.use_gpu = use_gpu;
+        if let Some(device) = gpu_device {
+            context_params.gpu_device = device as i32;
+        }
+        let context = WhisperContext::new_with_params(path, context_params)?;
+        let state = context.create_state()?;
+        Ok(Self {
+            state,
+            token_eot: context.token_eot(),
+        })
+    }
+
+    /// Probability of every language Whisper knows for the start of `audio`,
+    /// as (ISO 639-1 code, probability) pairs.
+    pub fn detect_language(&mut self, audio: &[f32]) -> Result<Vec<(&'static str, f32)>> {
+        let threads = thread_count();
+        self.state.pcm_to_mel(audio, threads)?;
+        let (_, probabilities) = self.state.lang_detect(0, threads)?;
+        Ok(probabilities
+            .into_iter()
+            .enumerate()
+            .filter_map(|(id, p)| whisper_rs::get_lang_str(id as i32).map(|lang| (lang, p)))
+            .collect())
+    }
+
+    /// Transcribe `audio`, also returning the confidence: the mean probability of the
+    /// text tokens (None when nothing was transcribed).
+    pub fn transcribe(
+        &mut self,
+        audio: &[f32],
+        params: &WhisperParams,
+    ) -> Result<(TranscriptionResult, Option<f32>)> {
+        let mut full_params = FullParams::new(SamplingStrategy::BeamSearch {
+            beam_size: params
+                .beam_size
+                .map_or(DEFAULT_BEAM_SIZE, |size| size as i32),
+            patience: -1.0,
+        });
+        full_params.set_n_threads(thread_count() as i32);
+        full_params.set_language(params.language.as_deref());
+        full_params.set_translate(params.translate);
+        full_params.set_print_special(false);
+        full_params.set_print_progress(false);
+        full_params.set_print_realtime(false);
+        full_params.set_print_timestamps(false);
+        full_params.set_suppress_blank(true);
+        full_params.set_suppress_non_speech_tokens(true);
+        full_params.set_no_speech_thold(
+            params
+                .no_speech_threshold
+                .unwrap_or(DEFAULT_NO_SPEECH_THRESHOLD),
+        );
+        if let Some(temperature) = params.temperature {
+            full_params.set_temperature(temperature);
+        }
+        if let Some(condition) = params.condition_on_previous {
+            full_params.set_no_context(!condition);
+        }
+        if let Some(prompt) = &params.initial_prompt {
+            full_params.set_initial_prompt(prompt);
+        }
+
+        self.state.full(full_params, audio)?;
+
+        let segment_count = self.state.full_n_segments()?;
+        let mut segments = Vec::with_capacity(segment_count.max(0) as usize);
+        let (mut probability_sum, mut token_count) = (0.0f32, 0u32);
+        for i in 0..segment_count {
+            for j in 0..self.state.full_n_tokens(i)? {
+                let token = self.state.full_get_token_data(i, j)?;
+                if token.id < self.token_eot {
+                    probability_sum += token.p;
+                    token_count += 1;
+                }
+            }
+            // Segment timestamps are in centiseconds
+            segments.push(TranscriptionSegment {
+                start: self.state.full_get_segment_t0(i)? as f32 / 100.0,
+                end: self.state.full_get_segment_t1(i)? as f32 / 100.0,
+                text: self.state.full_get_segment_text(i)?,
+            });
+        }
+
+        let text = segments
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect::<String>()
+            .trim()
+            .to_string();
+        let confidence = (token_count > 0).then(|| probability_sum / token_count as f32);
+        Ok((
+            TranscriptionResult {
+                text,
+                segments: Some(segments),
+            },
+            confidence,
+        ))
+    }
+}
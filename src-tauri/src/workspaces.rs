@@ -0,0 +1,257 @@
+//! Settings Workspaces
+//!
+//! Named snapshots of `AppSettings` (e.g. "work" with remote STT, "personal" with a
+//! local model) stored in the settings store under `workspaces`. Snapshots never contain
+//! API keys or connector passwords: those are stripped on save and the current values
+//! are kept on load.
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
+use crate::managers::connector::ConnectorManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::{self, AppSettings, SETTINGS_STORE_PATH};
+
+const WORKSPACES_KEY: &str = "workspaces";
+
+/// Summary of a saved workspace
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct WorkspaceInfo {
+    pub name: String,
+    /// Unix timestamp (seconds) of the last save
+    pub saved_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Workspace {
+    saved_at: i64,
+    /// Stored as raw JSON so snapshots from older versions still load
+    /// (missing fields fall back to their serde defaults)
+    settings: serde_json::Value,
+}
+
+fn read_workspaces(app: &AppHandle) -> HashMap<String, Workspace> {
+    let Ok(store) = app.store(SETTINGS_STORE_PATH) else {
+        return HashMap::new();
+    };
+    store
+        .get(WORKSPACES_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn write_workspaces(
+    app: &AppHandle,
+    workspaces: &HashMap<String, Workspace>,
+) -> Result<(), String> {
+    let store = app
+        .store(SETTINGS_STORE_PATH)
+        .map_err(|e| format!("Failed to open settings store: {}", e))?;
+    let value = serde_json::to_value(workspaces).map_err(|e| e.to_string())?;
+    store.set(WORKSPACES_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save workspaces: {}", e))
+}
+
+/// Case-insensitive lookup of an existing workspace name
+fn find_name(workspaces: &HashMap<String, Workspace>, name: &str) -> Option<String> {
+    workspaces
+        .keys()
+        .find(|existing| existing.eq_ignore_ascii_case(name))
+        .cloned()
+}
+
+fn strip_secrets(settings: &mut AppSettings) {
+    settings.post_process_api_keys.clear();
+    settings.ai_replace_api_keys.clear();
+    settings.voice_command_api_keys.clear();
+    settings.connector_password.clear();
+    settings.connector_password_user_set = false;
+    settings.connector_pending_password = None;
+}
+
+fn keep_secrets(target: &mut AppSettings, current: &AppSettings) {
+    target.post_process_api_keys = current.post_process_api_keys.clone();
+    target.ai_replace_api_keys = current.ai_replace_api_keys.clone();
+    target.voice_command_api_keys = current.voice_command_api_keys.clone();
+    target.connector_password = current.connector_password.clone();
+    target.connector_password_user_set = current.connector_password_user_set;
+    target.connector_pending_password = current.connector_pending_password.clone();
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn list_workspaces(app: AppHandle) -> Vec<WorkspaceInfo> {
+    let mut list: Vec<WorkspaceInfo> = read_workspaces(&app)
+        .into_iter()
+        .map(|(name, workspace)| WorkspaceInfo {
+            name,
+            saved_at: workspace.saved_at,
+        })
+        .collect();
+    list.sort_by_key(|w| w.name.to_lowercase());
+    list
+}
+
+/// Saves the current settings (minus API keys and connector passwords) as `name`,
+/// overwriting a workspace with the same name.
+#[tauri::command]
+#[specta::specta]
+pub fn save_workspace(app: AppHandle, name: String) -> Result<WorkspaceInfo, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Workspace name cannot be empty".to_string());
+    }
+
+    let mut snapshot = settings::get_settings(&app);
+    strip_secrets(&mut snapshot);
+
+    let mut workspaces = read_workspaces(&app);
+    let name = find_name(&workspaces, &name).unwrap_or(name);
+    let saved_at = chrono::Utc::now().timestamp();
+    workspaces.insert(
+        name.clone(),
+        Workspace {
+            saved_at,
+            settings: serde_json::to_value(&snapshot).map_err(|e| e.to_string())?,
+        },
+    );
+    write_workspaces(&app, &workspaces)?;
+
+    info!("Saved settings workspace '{}'", name);
+    Ok(WorkspaceInfo { name, saved_at })
+}
+
+/// Applies runtime state that the individual setters update besides the settings file
+fn apply_runtime_state(app: &AppHandle, loaded: &AppSettings, current: &AppSettings) {
+    crate::managers::audio::set_audio_meter_hz(loaded.audio_meter_hz);
+    crate::llm_client::set_max_concurrent_requests(loaded.llm_max_concurrent_requests);
+    let connector = app.state::<Arc<ConnectorManager>>();
+    connector.set_allowed_origins(loaded.connector_allowed_origins.clone());
+    // The restarted server offers the pending password afresh
+    connector.reset_password_offer();
+
+    if loaded.always_on_microphone != current.always_on_microphone {
+        let mode = if loaded.always_on_microphone {
+            MicrophoneMode::AlwaysOn
+        } else {
+            MicrophoneMode::OnDemand
+        };
+        if let Err(e) = app.state::<Arc<AudioRecordingManager>>().update_mode(mode) {
+            warn!(
+                "Failed to update microphone mode after loading workspace: {}",
+                e
+            );
+        }
+    }
+
+    // Swap the model only if one is loaded; otherwise the next recording loads it
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    if loaded.selected_model != current.selected_model && tm.is_model_loaded() {
+        tm.initiate_model_load_for(loaded.selected_model.clone());
+    }
+}
+
+/// Applies a saved workspace: writes its settings (keeping the current API keys and
+/// connector password), re-registers shortcuts, re-applies runtime state (microphone
+/// mode, model, meter rate, LLM concurrency) and restarts the connector server.
+#[tauri::command]
+#[specta::specta]
+pub fn load_workspace(app: AppHandle, name: String) -> Result<(), String> {
+    let workspaces = read_workspaces(&app);
+    let name = find_name(&workspaces, name.trim())
+        .ok_or_else(|| format!("Workspace '{}' not found", name.trim()))?;
+    let mut loaded: AppSettings = serde_json::from_value(workspaces[&name].settings.clone())
+        .map_err(|e| format!("Workspace '{}' is invalid: {}", name, e))?;
+
+    let current = settings::get_settings(&app);
+    keep_secrets(&mut loaded, &current);
+    let port = loaded.connector_port;
+    settings::write_settings(&app, loaded.clone());
+
+    crate::shortcut::reload_bindings(&app, &current.bindings);
+    apply_runtime_state(&app, &loaded, &current);
+
+    let connector = app.state::<Arc<ConnectorManager>>();
+    if let Err(e) = connector.restart_on_port(port) {
+        warn!("Failed to restart connector after loading workspace: {}", e);
+    }
+
+    crate::tray::refresh_tray_menu(&app);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "workspace", "value": name }),
+    );
+
+    info!("Loaded settings workspace '{}'", name);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_workspace(app: AppHandle, name: String) -> Result<(), String> {
+    let mut workspaces = read_workspaces(&app);
+    let name = find_name(&workspaces, name.trim())
+        .ok_or_else(|| format!("Workspace '{}' not found", name.trim()))?;
+    workspaces.remove(&name);
+    write_workspaces(&app, &workspaces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    fn with_secrets() -> AppSettings {
+        let mut settings = get_default_settings();
+        settings
+            .post_process_api_keys
+            .insert("openai".to_string(), "sk-post".to_string());
+        settings
+            .ai_replace_api_keys
+            .insert("openai".to_string(), "sk-replace".to_string());
+        settings
+            .voice_command_api_keys
+            .insert("openai".to_string(), "sk-voice".to_string());
+        settings.connector_password = "secret".to_string();
+        settings.connector_password_user_set = true;
+        settings.connector_pending_password = Some("next".to_string());
+        settings
+    }
+
+    #[test]
+    fn strip_secrets_removes_keys_and_connector_passwords() {
+        let mut snapshot = with_secrets();
+        strip_secrets(&mut snapshot);
+        assert!(snapshot.post_process_api_keys.is_empty());
+        assert!(snapshot.ai_replace_api_keys.is_empty());
+        assert!(snapshot.voice_command_api_keys.is_empty());
+        assert!(snapshot.connector_password.is_empty());
+        assert!(!snapshot.connector_password_user_set);
+        assert_eq!(snapshot.connector_pending_password, None);
+    }
+
+    #[test]
+    fn keep_secrets_restores_current_values_over_a_stripped_snapshot() {
+        let current = with_secrets();
+        let mut loaded = current.clone();
+        strip_secrets(&mut loaded);
+        keep_secrets(&mut loaded, &current);
+        assert_eq!(loaded.post_process_api_keys, current.post_process_api_keys);
+        assert_eq!(loaded.ai_replace_api_keys, current.ai_replace_api_keys);
+        assert_eq!(
+            loaded.voice_command_api_keys,
+            current.voice_command_api_keys
+        );
+        assert_eq!(loaded.connector_password, "secret");
+        assert!(loaded.connector_password_user_set);
+        assert_eq!(loaded.connector_pending_password.as_deref(), Some("next"));
+    }
+}
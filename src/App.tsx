@@ -9,7 +9,10 @@ import { HotkeySidebar } from "./components/hotkey-sidebar";
 import { useSettings } from "./hooks/useSettings";
 import { commands } from "@/bindings";
 import { listen } from "@tauri-apps/api/event";
+import { useTranslation } from "react-i18next";
 import { useNavigationStore } from "./stores/navigationStore";
+import { useSettingsStore } from "./stores/settingsStore";
+import type { AudioDevice } from "@/bindings";
 
 const renderSettingsContent = (section: SidebarSection) => {
   const ActiveComponent =
@@ -21,6 +24,7 @@ function App() {
   const [showOnboarding, setShowOnboarding] = useState<boolean | null>(null);
   const { currentSection, setSection: setCurrentSection } = useNavigationStore();
   const { settings, updateSetting, refreshSettings } = useSettings();
+  const { t } = useTranslation();
 
   useEffect(() => {
     checkOnboardingStatus();
@@ -39,12 +43,46 @@ function App() {
     const unlistenVoiceCommand = listen<string>("voice-command-error", (event) => {
       toast.error(event.payload, { duration: 4000 });
     });
+    const unlistenProfileSwitch = listen<string>("profile-switch-rejected", (event) => {
+      toast.error(event.payload);
+    });
+    // The provider's model list couldn't be fetched; the model id can still be typed
+    const unlistenModels = listen<{
+      provider_id: string;
+      reason: "no_endpoint" | "endpoint_failed";
+      message: string;
+    }>("llm-models-unavailable", (event) => {
+      const { reason, message } = event.payload;
+      toast.warning(
+        reason === "no_endpoint"
+          ? t("settings.postProcessing.modelList.noEndpoint")
+          : t("settings.postProcessing.modelList.endpointFailed"),
+        { description: message },
+      );
+    });
+    // Settings changed from the backend (e.g. the push-to-talk toggle shortcut)
+    const unlistenSettings = listen("settings-changed", () => {
+      refreshSettings();
+    });
+    // A microphone or output device was plugged in or removed
+    const unlistenDevices = listen<{ inputs: AudioDevice[]; outputs: AudioDevice[] }>(
+      "devices-changed",
+      (event) => {
+        const { setAudioDevices, setOutputDevices } = useSettingsStore.getState();
+        setAudioDevices(event.payload.inputs);
+        setOutputDevices(event.payload.outputs);
+      },
+    );
 
     return () => {
       unlistenRemote.then((unlisten) => unlisten());
       unlistenAiReplace.then((unlisten) => unlisten());
       unlistenScreenshot.then((unlisten) => unlisten());
       unlistenVoiceCommand.then((unlisten) => unlisten());
+      unlistenProfileSwitch.then((unlisten) => unlisten());
+      unlistenModels.then((unlisten) => unlisten());
+      unlistenSettings.then((unlisten) => unlisten());
+      unlistenDevices.then((unlisten) => unlisten());
     };
   }, []);
 
@@ -13,6 +13,42 @@ async changeBinding(id: string, binding: string) : Promise<Result<BindingRespons
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Preview which action/profile a binding ID maps to and the settings it will use.
+ */
+async resolveBindingTarget(bindingId: string) : Promise<Result<BindingTarget, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("resolve_binding_target", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Trigger a binding's action without a key press (automation / self-test).
+ * `start` acts as a key press and `stop` as a release, so toggle-mode bindings
+ * toggle on `start` and ignore `stop`, exactly like a physical shortcut.
+ */
+async triggerAction(bindingId: string, phase: ActionPhase) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("trigger_action", { bindingId, phase }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Export the effective keymap (every binding, its key, action, mode, enabled state and
+ * how it is actually registered) as pretty-printed JSON for bug reports.
+ */
+async exportKeymap() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_keymap") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async resetBinding(id: string) : Promise<Result<BindingResponse, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("reset_binding", { id }) };
@@ -21,6 +57,17 @@ async resetBinding(id: string) : Promise<Result<BindingResponse, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set the extra keys that trigger a binding's action alongside its primary key.
+ */
+async changeAdditionalBindings(id: string, bindings: string[]) : Promise<Result<BindingResponse, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_additional_bindings", { id, bindings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePttSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ptt_setting", { enabled }) };
@@ -29,6 +76,19 @@ async changePttSetting(enabled: boolean) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Flip between push-to-talk and toggle recording for the `transcribe` binding, briefly
+ * showing the new mode in the overlay. Flips the active profile's mode when a profile is
+ * active, else the global setting. Returns the new push-to-talk value.
+ */
+async togglePttMode() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("toggle_ptt_mode") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeAudioFeedbackSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_audio_feedback_setting", { enabled }) };
@@ -85,6 +145,30 @@ async changeSelectedLanguageSetting(language: string) : Promise<Result<null, str
     else return { status: "error", error: e  as any };
 }
 },
+async changeAutoLanguageCandidatesSetting(candidates: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_auto_language_candidates_setting", { candidates }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePreferredLanguagesSetting(languages: string[], preferredOnly: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_preferred_languages_setting", { languages, preferredOnly }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeAutoLanguageMinConfidenceSetting(confidence: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_auto_language_min_confidence_setting", { confidence }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeTranscriptionProviderSetting(provider: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_transcription_provider_setting", { provider }) };
@@ -109,6 +193,33 @@ async changeOverlayPositionSetting(position: string) : Promise<Result<null, stri
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Choose the display the recording overlay appears on
+ */
+async changeOverlayMonitorSetting(monitor: OverlayMonitor) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_overlay_monitor_setting", { monitor }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeOverlayAppearanceSetting(appearance: OverlayAppearance) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_overlay_appearance_setting", { appearance }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeOverlayErrorDurationSetting(durationMs: number, persistUntilDismissed: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_overlay_error_duration_setting", { durationMs, persistUntilDismissed }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeDebugModeSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_debug_mode_setting", { enabled }) };
@@ -125,6 +236,14 @@ async changeWordCorrectionThresholdSetting(threshold: number) : Promise<Result<n
     else return { status: "error", error: e  as any };
 }
 },
+async changeLocalDecodeParamsSetting(params: LocalDecodeParams) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_local_decode_params_setting", { params }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePasteMethodSetting(method: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_paste_method_setting", { method }) };
@@ -141,6 +260,38 @@ async changeClipboardHandlingSetting(handling: string) : Promise<Result<null, st
     else return { status: "error", error: e  as any };
 }
 },
+async changeAlwaysCopyResultSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_always_copy_result_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePasteDelayMsSetting(delayMs: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_paste_delay_ms_setting", { delayMs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeSelectionCaptureRetriesSetting(retries: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_selection_capture_retries_setting", { retries }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeSelectionCaptureRetryDelayMsSetting(delayMs: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_selection_capture_retry_delay_ms_setting", { delayMs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeConvertLfToCrlfSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_convert_lf_to_crlf_setting", { enabled }) };
@@ -205,6 +356,94 @@ async changePostProcessReasoningBudgetSetting(budget: number) : Promise<Result<n
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set the JSON field to extract from post-process responses (None or empty = paste raw response)
+ */
+async changePostProcessExtractJsonFieldSetting(field: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_extract_json_field_setting", { field }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the confidence below which the low-confidence hint is added (None = off)
+ */
+async changePostProcessLowConfidenceThresholdSetting(threshold: number | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_low_confidence_threshold_setting", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the low-confidence hint (None or empty = built-in hint)
+ */
+async changePostProcessLowConfidenceHintSetting(hint: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_low_confidence_hint_setting", { hint }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the stop sequences sent with post-processing requests (empty = none)
+ */
+async changePostProcessStopSequencesSetting(sequences: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_stop_sequences_setting", { sequences }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Paste the raw transcription first and replace it once post-processing finishes
+ */
+async changePostProcessPasteThenReplaceSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_paste_then_replace_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Per-provider limit on concurrent LLM requests (1-8). Takes effect for new requests.
+ */
+async changeLlmMaxConcurrentRequestsSetting(limit: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_llm_max_concurrent_requests_setting", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the stop sequences sent with AI Replace requests (empty = none)
+ */
+async changeAiReplaceStopSequencesSetting(sequences: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_ai_replace_stop_sequences_setting", { sequences }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Unwrap LLM output that is entirely one code fence or quoted string
+ */
+async changeStripLlmFormattingSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_strip_llm_formatting_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeAiReplaceReasoningEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ai_replace_reasoning_enabled_setting", { enabled }) };
@@ -229,6 +468,14 @@ async changeVoiceCommandReasoningEnabledSetting(enabled: boolean) : Promise<Resu
     else return { status: "error", error: e  as any };
 }
 },
+async changeVoiceCommandStructuredOutputSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_command_structured_output_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeVoiceCommandReasoningBudgetSetting(budget: number) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_voice_command_reasoning_budget_setting", { budget }) };
@@ -253,6 +500,22 @@ async changeVoiceCommandLlmFallbackSetting(enabled: boolean) : Promise<Result<nu
     else return { status: "error", error: e  as any };
 }
 },
+async changeVoiceCommandLlmMinWordsSetting(minWords: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_command_llm_min_words_setting", { minWords }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeVoiceCommandMetaCommandsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_command_meta_commands_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeVoiceCommandSystemPromptSetting(prompt: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_voice_command_system_prompt_setting", { prompt }) };
@@ -309,6 +572,29 @@ async changeVoiceCommandsSetting(commands: VoiceCommand[]) : Promise<Result<null
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Create a shortcut binding that runs a voice command directly (no recording or matching).
+ * The binding starts unassigned; set its key with `change_binding`.
+ */
+async addVoiceCommandBinding(commandId: string) : Promise<Result<ShortcutBinding, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_voice_command_binding", { commandId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Remove a voice command's direct-run shortcut binding.
+ */
+async removeVoiceCommandBinding(commandId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_voice_command_binding", { commandId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeVoiceCommandUseLevenshteinSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_voice_command_use_levenshtein_setting", { enabled }) };
@@ -357,6 +643,33 @@ async changePostProcessBaseUrlSetting(providerId: string, baseUrl: string) : Pro
     else return { status: "error", error: e  as any };
 }
 },
+async changeUnlockBuiltinProviderBaseUrlsSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_unlock_builtin_provider_base_urls_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restores a provider's base URL to its built-in default
+ */
+async resetPostProcessBaseUrl(providerId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reset_post_process_base_url", { providerId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePostProcessOrganizationSetting(providerId: string, organization: string | null, project: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_organization_setting", { providerId, organization, project }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePostProcessApiKeySetting(providerId: string, apiKey: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_api_key_setting", { providerId, apiKey }) };
@@ -381,6 +694,10 @@ async setPostProcessProvider(providerId: string) : Promise<Result<null, string>>
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Fetch the model list for a provider.
+ * Read-only: never modifies the stored model selection in `post_process_models`.
+ */
 async fetchPostProcessModels(providerId: string) : Promise<Result<string[], string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("fetch_post_process_models", { providerId }) };
@@ -433,13 +750,31 @@ async setPostProcessSelectedPrompt(id: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Select the first post-processing prompt with this name (case-insensitive).
+ * When none matches, the error lists close names.
+ */
+async setPostProcessPromptByName(name: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_post_process_prompt_by_name", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * The currently selected post-processing prompt, if any
+ */
+async getActivePrompt() : Promise<LLMPrompt | null> {
+    return await TAURI_INVOKE("get_active_prompt");
+},
 /**
  * Creates a new transcription profile with its own language/translation settings.
  * This also creates a corresponding shortcut binding and registers it.
  */
-async addTranscriptionProfile(name: string, language: string, translateToEnglish: boolean, systemPrompt: string, pushToTalk: boolean, includeInCycle: boolean | null, llmSettings: ProfileLlmSettings | null) : Promise<Result<TranscriptionProfile, string>> {
+async addTranscriptionProfile(name: string, language: string, translateToEnglish: boolean, systemPrompt: string, pushToTalk: boolean, includeInCycle: boolean | null, llmSettings: ProfileLlmSettings | null, vadThreshold: number | null) : Promise<Result<TranscriptionProfile, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_transcription_profile", { name, language, translateToEnglish, systemPrompt, pushToTalk, includeInCycle, llmSettings }) };
+    return { status: "ok", data: await TAURI_INVOKE("add_transcription_profile", { name, language, translateToEnglish, systemPrompt, pushToTalk, includeInCycle, llmSettings, vadThreshold }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -447,10 +782,11 @@ async addTranscriptionProfile(name: string, language: string, translateToEnglish
 },
 /**
  * Updates an existing transcription profile.
+ * `vad_threshold` None makes the profile use the global threshold again.
  */
-async updateTranscriptionProfile(id: string, name: string, language: string, translateToEnglish: boolean, systemPrompt: string, sttPromptOverrideEnabled: boolean, includeInCycle: boolean, pushToTalk: boolean, llmSettings: ProfileLlmSettings) : Promise<Result<null, string>> {
+async updateTranscriptionProfile(id: string, name: string, language: string, translateToEnglish: boolean, systemPrompt: string, sttPromptOverrideEnabled: boolean, includeInCycle: boolean, pushToTalk: boolean, llmSettings: ProfileLlmSettings, vadThreshold: number | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_transcription_profile", { id, name, language, translateToEnglish, systemPrompt, sttPromptOverrideEnabled, includeInCycle, pushToTalk, llmSettings }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_transcription_profile", { id, name, language, translateToEnglish, systemPrompt, sttPromptOverrideEnabled, includeInCycle, pushToTalk, llmSettings, vadThreshold }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -506,7 +842,18 @@ async changeProfileSwitchOverlayEnabledSetting(enabled: boolean) : Promise<Resul
     else return { status: "error", error: e  as any };
 }
 },
-async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
+/**
+ * Whether switching profiles mid-recording applies to the next recording or is rejected
+ */
+async changeProfileSwitchDuringRecordingSetting(behavior: ProfileSwitchDuringRecording) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_profile_switch_during_recording_setting", { behavior }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateCustomWords(words: CustomWord[]) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_custom_words", { words }) };
 } catch (e) {
@@ -514,6 +861,17 @@ async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set (or clear with None) the match threshold for a single custom word.
+ */
+async setCustomWordThreshold(word: string, threshold: number | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_custom_word_threshold", { word, threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeCustomWordsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_custom_words_enabled_setting", { enabled }) };
@@ -561,7 +919,47 @@ async changeAppendTrailingSpaceSetting(enabled: boolean) : Promise<Result<null,
     else return { status: "error", error: e  as any };
 }
 },
-async changeAiReplaceSystemPromptSetting(prompt: string) : Promise<Result<null, string>> {
+async changeScratchpadModeSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_scratchpad_mode_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setPasteSuffixForApp(pattern: string, suffix: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_paste_suffix_for_app", { pattern, suffix }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removePasteSuffixForApp(pattern: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_paste_suffix_for_app", { pattern }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePostProcessDisabledAppsSetting(patterns: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_disabled_apps_setting", { patterns }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeTranscribingWatchdogSecsSetting(secs: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_transcribing_watchdog_secs_setting", { secs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeAiReplaceSystemPromptSetting(prompt: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ai_replace_system_prompt_setting", { prompt }) };
 } catch (e) {
@@ -609,6 +1007,14 @@ async changeAiReplaceAllowQuickTapSetting(allowed: boolean) : Promise<Result<nul
     else return { status: "error", error: e  as any };
 }
 },
+async changeAiReplaceOutputModeSetting(mode: AiReplaceOutputMode) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_ai_replace_output_mode_setting", { mode }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeAiReplaceQuickTapThresholdMsSetting(thresholdMs: number) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ai_replace_quick_tap_threshold_ms_setting", { thresholdMs }) };
@@ -649,6 +1055,18 @@ async changeAiReplaceModelSetting(providerId: string, model: string) : Promise<R
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set a feature's model to an id typed by the user, without checking it against the
+ * fetched model list (which may be unavailable).
+ */
+async setModelManual(feature: LlmFeature, model: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_model_manual", { feature, model }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async setVoiceCommandProvider(providerId: string | null) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("set_voice_command_provider", { providerId }) };
@@ -665,6 +1083,30 @@ async changeVoiceCommandApiKeySetting(providerId: string, apiKey: string) : Prom
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Remove one feature's API key for a provider (secure storage on Windows, JSON elsewhere).
+ * `feature` is "post_process", "ai_replace" or "voice_command".
+ */
+async clearApiKey(feature: string, providerId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_api_key", { feature, providerId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Remove all post-processing, AI Replace and voice command API keys
+ * (e.g., before handing off a machine).
+ */
+async clearAllApiKeys() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_all_api_keys") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeVoiceCommandModelSetting(providerId: string, model: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_voice_command_model_setting", { providerId, model }) };
@@ -769,6 +1211,48 @@ async changeConnectorAutoOpenUrlSetting(url: string) : Promise<Result<null, stri
     else return { status: "error", error: e  as any };
 }
 },
+async changeConnectorApplyTextReplacementsSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_connector_apply_text_replacements_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the connector message TTL in seconds (0 disables expiry)
+ */
+async changeConnectorMessageTtlSetting(ttlSecs: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_connector_message_ttl_setting", { ttlSecs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tag connector messages with the active profile name; `default_tag` is used for the default profile
+ */
+async changeConnectorTagWithProfileSetting(enabled: boolean, defaultTag: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_connector_tag_with_profile_setting", { enabled, defaultTag }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restrict connector CORS to these origins (empty = allow any). Takes effect on the
+ * next request; no server restart needed.
+ */
+async changeConnectorAllowedOriginsSetting(origins: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_connector_allowed_origins_setting", { origins }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeConnectorPortSetting(port: number) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_connector_port_setting", { port }) };
@@ -777,6 +1261,14 @@ async changeConnectorPortSetting(port: number) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async changeConnectorPortAutoFallbackSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_connector_port_auto_fallback_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeConnectorPasswordSetting(password: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_connector_password_setting", { password }) };
@@ -921,6 +1413,33 @@ async changeTextReplacementsSetting(replacements: TextReplacement[]) : Promise<R
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Turn every text replacement rule in `group` on or off at once
+ */
+async changeTextReplacementGroupEnabledSetting(group: string, enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_text_replacement_group_enabled_setting", { group, enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Run input/expected cases through the text replacement rules (the saved rules unless
+ * `replacements` is given) and report each actual output and pass/fail
+ */
+async testTextReplacementSuite(cases: TextReplacementTestCase[], replacements: TextReplacement[] | null) : Promise<TextReplacementTestResult[]> {
+    return await TAURI_INVOKE("test_text_replacement_suite", { cases, replacements });
+},
+/**
+ * Step through the text replacement rules (the saved rules unless `replacements` is
+ * given) on sample text without saving anything. As in the pipeline, the sample first
+ * goes through dictation commands, then the before-LLM rules and the after-LLM rules;
+ * no LLM runs in between.
+ */
+async previewTextReplacements(text: string, replacements: TextReplacement[] | null) : Promise<ReplacementStep[]> {
+    return await TAURI_INVOKE("preview_text_replacements", { text, replacements });
+},
 async changeTextReplacementsBeforeLlmSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_text_replacements_before_llm_setting", { enabled }) };
@@ -929,6 +1448,92 @@ async changeTextReplacementsBeforeLlmSetting(enabled: boolean) : Promise<Result<
     else return { status: "error", error: e  as any };
 }
 },
+async changeDictationCommandsSetting(enabled: boolean, commands: DictationCommand[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_dictation_commands_setting", { enabled, commands }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Per-profile dictation command toggle. None inherits the global setting.
+ */
+async changeProfileDictationCommandsSetting(id: string, enabled: boolean | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_profile_dictation_commands_setting", { id, enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Per-profile filler word filter. None inherits the global setting.
+ */
+async changeProfileFillerFilterOverrideSetting(id: string, enabled: boolean | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_profile_filler_filter_override_setting", { id, enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Per-profile VAD threshold (clamped to 0.1-0.9). None inherits the global setting.
+ * Applied when the next recording with this profile starts.
+ */
+async changeProfileVadThresholdSetting(id: string, threshold: number | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_profile_vad_threshold_setting", { id, threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Built-in dictation commands for a language code (empty if none are curated)
+ */
+async getBuiltinDictationCommands(language: string) : Promise<DictationCommand[]> {
+    return await TAURI_INVOKE("get_builtin_dictation_commands", { language });
+},
+async changeOutputCaseTransformSetting(mode: OutputCaseTransform) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_output_case_transform_setting", { mode }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Per-profile output casing. None inherits the global setting.
+ */
+async changeProfileOutputCaseTransformSetting(id: string, mode: OutputCaseTransform | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_profile_output_case_transform_setting", { id, mode }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeAutoCapitalizeSentencesSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_auto_capitalize_sentences_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Per-profile sentence capitalization. None inherits the global setting.
+ */
+async changeProfileAutoCapitalizeSentencesSetting(id: string, enabled: boolean | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_profile_auto_capitalize_sentences_setting", { id, enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeSidebarPinnedSetting(pinned: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_sidebar_pinned_setting", { pinned }) };
@@ -1087,6 +1692,13 @@ async remoteSttGetDebugDump() : Promise<Result<string[], string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * The last captured remote transcription request/response (headers redacted, body
+ * truncated in Normal debug mode). None when debug capture recorded nothing.
+ */
+async getRemoteSttDebug() : Promise<RemoteSttDebugData | null> {
+    return await TAURI_INVOKE("get_remote_stt_debug");
+},
 async remoteSttClearDebug() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("remote_stt_clear_debug") };
@@ -1125,127 +1737,288 @@ async remoteSttSupportsTranslation() : Promise<boolean> {
 async checkAppleIntelligenceAvailable() : Promise<boolean> {
     return await TAURI_INVOKE("check_apple_intelligence_available");
 },
-async getAvailableModels() : Promise<Result<ModelInfo[], string>> {
+/**
+ * Apple Intelligence availability with a reason, for the settings UI.
+ * Only queries the system model (never runs it), so it is safe to call any time
+ * after startup; post-processing keeps its own lazy check.
+ */
+async checkAppleIntelligence() : Promise<AppleIntelligenceStatus> {
+    return await TAURI_INVOKE("check_apple_intelligence");
+},
+/**
+ * Language codes for the language selector, preferred languages first.
+ * "auto" is always the first entry.
+ */
+async getLanguageOptions() : Promise<Result<string[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_models") };
+    return { status: "ok", data: await TAURI_INVOKE("get_language_options") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getModelInfo(modelId: string) : Promise<Result<ModelInfo | null, string>> {
+/**
+ * Monitor names for the overlay monitor selector, in `MonitorIndex` order
+ */
+async getOverlayMonitors() : Promise<string[]> {
+    return await TAURI_INVOKE("get_overlay_monitors");
+},
+/**
+ * Dismiss the error overlay (clicked by the user)
+ */
+async dismissErrorOverlay() : Promise<void> {
+    await TAURI_INVOKE("dismiss_error_overlay");
+},
+/**
+ * Cleanly restart the app (for settings that can't be applied without a relaunch).
+ * Cancels any active session, unmutes, hides overlays, stops the connector server
+ * and flushes settings before relaunching.
+ */
+async restartApp() : Promise<void> {
+    await TAURI_INVOKE("restart_app");
+},
+/**
+ * Aggregated transcription pipeline timings (record → stop → transcribe → post-process → paste)
+ * since app launch
+ */
+async getPipelineTimingStats() : Promise<PipelineTimingStats> {
+    return await TAURI_INVOKE("get_pipeline_timing_stats");
+},
+/**
+ * Most recent failure per feature (transcription, post_process, ai_replace, connector,
+ * screenshot, voice_command). Entries are cleared when the feature next succeeds.
+ */
+async getLastErrors() : Promise<Partial<{ [key in string]: LastError }>> {
+    return await TAURI_INVOKE("get_last_errors");
+},
+/**
+ * The application that currently has focus (process name, window title, executable
+ * path), or None where the platform can't tell. Called from the settings window this
+ * is usually AivoRelay itself; it is meant for polling while dictating.
+ */
+async getForegroundApp() : Promise<ForegroundApp | null> {
+    return await TAURI_INVOKE("get_foreground_app");
+},
+/**
+ * Which provider, model and base URL an LLM feature will use, whether a key is present,
+ * and the fallback path taken. The API key itself is never returned.
+ */
+async resolveLlmConfig(feature: LlmFeature) : Promise<Result<LlmConfigDebug, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_model_info", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("resolve_llm_config", { feature }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async downloadModel(modelId: string) : Promise<Result<null, string>> {
+/**
+ * Runs the AI Replace LLM request with the given selection and instruction and returns
+ * the output. Skips selection capture and pasting, so prompts and provider config can be
+ * verified in isolation.
+ */
+async testAiReplace(selection: string, instruction: string) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("download_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("test_ai_replace", { selection, instruction }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteModel(modelId: string) : Promise<Result<null, string>> {
+/**
+ * Saves the current settings (minus API keys and connector passwords) as `name`,
+ * overwriting a workspace with the same name.
+ */
+async saveWorkspace(name: string) : Promise<Result<WorkspaceInfo, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("save_workspace", { name }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async cancelDownload(modelId: string) : Promise<Result<null, string>> {
+async listWorkspaces() : Promise<WorkspaceInfo[]> {
+    return await TAURI_INVOKE("list_workspaces");
+},
+/**
+ * Applies a saved workspace: writes its settings (keeping the current API keys and
+ * connector password), re-registers shortcuts, re-applies runtime state (microphone
+ * mode, model, meter rate, LLM concurrency) and restarts the connector server.
+ */
+async loadWorkspace(name: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("cancel_download", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("load_workspace", { name }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setActiveModel(modelId: string) : Promise<Result<null, string>> {
+async deleteWorkspace(name: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_active_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_workspace", { name }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getCurrentModel() : Promise<Result<string, string>> {
+async getAvailableModels() : Promise<Result<ModelInfo[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_current_model") };
+    return { status: "ok", data: await TAURI_INVOKE("get_available_models") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getTranscriptionModelStatus() : Promise<Result<string | null, string>> {
+async getModelInfo(modelId: string) : Promise<Result<ModelInfo | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_transcription_model_status") };
+    return { status: "ok", data: await TAURI_INVOKE("get_model_info", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async isModelLoading() : Promise<Result<boolean, string>> {
+async downloadModel(modelId: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("is_model_loading") };
+    return { status: "ok", data: await TAURI_INVOKE("download_model", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async hasAnyModelsAvailable() : Promise<Result<boolean, string>> {
+async deleteModel(modelId: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("has_any_models_available") };
+    return { status: "ok", data: await TAURI_INVOKE("delete_model", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async hasAnyModelsOrDownloads() : Promise<Result<boolean, string>> {
+async cancelDownload(modelId: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("has_any_models_or_downloads") };
+    return { status: "ok", data: await TAURI_INVOKE("cancel_download", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getRecommendedFirstModel() : Promise<Result<string, string>> {
+async setActiveModel(modelId: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_recommended_first_model") };
+    return { status: "ok", data: await TAURI_INVOKE("set_active_model", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateMicrophoneMode(alwaysOn: boolean) : Promise<Result<null, string>> {
+/**
+ * Map a language to a specific local model. Pass `None` to remove the mapping
+ * so the language falls back to the selected model.
+ */
+async setModelForLanguage(language: string, modelId: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_microphone_mode", { alwaysOn }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_model_for_language", { language, modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getMicrophoneMode() : Promise<Result<boolean, string>> {
+/**
+ * Advisory check: returns a warning when the model is known not to support the language.
+ * Remote models are judged by name hints; local models by their declared languages.
+ */
+async checkLanguageModelCompatibility(language: string, provider: TranscriptionProvider, model: string) : Promise<Result<string | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_microphone_mode") };
+    return { status: "ok", data: await TAURI_INVOKE("check_language_model_compatibility", { language, provider, model }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAvailableMicrophones() : Promise<Result<AudioDevice[], string>> {
+/**
+ * Measure load time, inference time and real-time factor for a local model.
+ * The clip is the bundled benchmark sample (looped/trimmed to `sample_secs`,
+ * clamped to 5-120s). Refused while recording or transcribing; new recordings are
+ * blocked until it finishes and the previously active model has been restored.
+ */
+async benchmarkModel(modelId: string, sampleSecs: number, refresh: boolean | null) : Promise<Result<BenchmarkResult, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_microphones") };
+    return { status: "ok", data: await TAURI_INVOKE("benchmark_model", { modelId, sampleSecs, refresh }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setSelectedMicrophone(deviceName: string) : Promise<Result<null, string>> {
+async getCurrentModel() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_current_model") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getTranscriptionModelStatus() : Promise<Result<string | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_transcription_model_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async isModelLoading() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_model_loading") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async hasAnyModelsAvailable() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_available") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async hasAnyModelsOrDownloads() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_or_downloads") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getRecommendedFirstModel() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_recommended_first_model") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateMicrophoneMode(alwaysOn: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_microphone_mode", { alwaysOn }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getMicrophoneMode() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_microphone_mode") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAvailableMicrophones() : Promise<Result<AudioDevice[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_microphones") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setSelectedMicrophone(deviceName: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("set_selected_microphone", { deviceName }) };
 } catch (e) {
@@ -1261,6 +2034,18 @@ async getSelectedMicrophone() : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Capture from a microphone for `duration_ms` (clamped to 100-10000 ms) and report
+ * peak/RMS levels and clipping. Nothing is saved or transcribed.
+ */
+async testMicrophone(deviceId: string | null, durationMs: number) : Promise<Result<MicTestResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("test_microphone", { deviceId, durationMs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getAvailableOutputDevices() : Promise<Result<AudioDevice[], string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_available_output_devices") };
@@ -1285,6 +2070,17 @@ async getSelectedOutputDevice() : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set the ordered output devices tried when the selected one can't be opened
+ */
+async setOutputDeviceFallbacks(deviceNames: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_output_device_fallbacks", { deviceNames }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async playTestSound(soundType: string) : Promise<void> {
     await TAURI_INVOKE("play_test_sound", { soundType });
 },
@@ -1318,6 +2114,26 @@ async changeVadThresholdSetting(threshold: number) : Promise<Result<null, string
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Skip transcription for recordings without speech, with the energy threshold (dBFS)
+ * a 30 ms window must reach to count as speech
+ */
+async changeNonSpeechGateSetting(enabled: boolean, thresholdDbfs: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_non_speech_gate_setting", { enabled, thresholdDbfs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeAudioMeterHzSetting(hz: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_audio_meter_hz_setting", { hz }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async setModelUnloadTimeout(timeout: ModelUnloadTimeout) : Promise<void> {
     await TAURI_INVOKE("set_model_unload_timeout", { timeout });
 },
@@ -1329,6 +2145,12 @@ async getModelLoadStatus() : Promise<Result<ModelLoadStatus, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Approximate memory used by the loaded local model (None when no model is loaded).
+ */
+async getModelMemoryUsage() : Promise<ModelMemoryInfo | null> {
+    return await TAURI_INVOKE("get_model_memory_usage");
+},
 async unloadModelManually() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
@@ -1337,6 +2159,86 @@ async unloadModelManually() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Report which backend (CPU/Metal/Vulkan) local transcription is using.
+ */
+async getAccelerationStatus() : Promise<AccelInfo> {
+    return await TAURI_INVOKE("get_acceleration_status");
+},
+/**
+ * Set the preferred device for local transcription. A loaded model is unloaded when
+ * nothing is recording so the next transcription loads it on the new device.
+ */
+async changeAccelerationSetting(preference: AccelerationPreference, gpuIndex: number | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_acceleration_setting", { preference, gpuIndex }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Transcribe raw 16kHz samples with a specific provider without changing settings.
+ */
+async transcribeSamplesWithProvider(provider: TranscriptionProvider, samples: number[]) : Promise<Result<ProviderTranscriptionResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_samples_with_provider", { provider, samples }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Re-run a history entry's recording through a specific provider, so the same
+ * clip can be compared across local and remote transcription.
+ */
+async transcribeHistoryEntryWithProvider(entryId: number, provider: TranscriptionProvider) : Promise<Result<ProviderTranscriptionResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_history_entry_with_provider", { entryId, provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Re-run STT on the most recent recording (e.g. after changing the model or prompt)
+ * and paste the new result. Needs that recording's audio to still be on disk.
+ */
+async retranscribeLast() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("retranscribe_last") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Append text to the scratchpad
+ */
+async scratchpadAppend(text: string) : Promise<void> {
+    await TAURI_INVOKE("scratchpad_append", { text });
+},
+/**
+ * Current scratchpad contents
+ */
+async scratchpadGet() : Promise<string> {
+    return await TAURI_INVOKE("scratchpad_get");
+},
+async scratchpadClear() : Promise<void> {
+    await TAURI_INVOKE("scratchpad_clear");
+},
+/**
+ * Paste the whole scratchpad into the focused app. The contents are kept;
+ * call `scratchpad_clear` to start over.
+ */
+async scratchpadPaste() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("scratchpad_paste") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getHistoryEntries() : Promise<Result<HistoryEntry[], string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
@@ -1393,12 +2295,70 @@ async getLatestHistoryEntry() : Promise<Result<HistoryEntry | null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Run the current custom words and text replacements over the raw text of the most
+ * recent `limit` transcriptions (max 500) and return the entries they would change.
+ * Rules apply even while their global toggle is off, so they can be checked before
+ * enabling. Read-only: history is not modified.
+ */
+async simulateRulesOnHistory(limit: number) : Promise<Result<RuleDiff[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("simulate_rules_on_history", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Export a history entry's stored segment timings as SRT or VTT subtitle text.
+ */
+async exportTranscriptionSubtitles(entryId: number, format: OutputFormat) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_transcription_subtitles", { entryId, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeHistoryStoreSegmentsSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_history_store_segments_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeDiscardCancelledRecordingsSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_discard_cancelled_recordings_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the append-only transcription log file (None or empty disables it) and its format
+ */
+async changeTranscriptionLogSetting(path: string | null, format: TranscriptionLogFormat) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_transcription_log_setting", { path, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Get current connector/extension status
  */
 async connectorGetStatus() : Promise<ConnectorStatus> {
     return await TAURI_INVOKE("connector_get_status");
 },
+/**
+ * Run the connector health checks (server, port, password, extension, handshake)
+ */
+async diagnoseConnector() : Promise<ConnectorDiagnosis> {
+    return await TAURI_INVOKE("diagnose_connector");
+},
 /**
  * Check if extension is currently online
  */
@@ -1446,6 +2406,17 @@ async connectorCancelMessage(messageId: string) : Promise<Result<boolean, string
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Ack a message as if the extension had received it (testing / lost-ack recovery)
+ */
+async ackConnectorMessage(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("ack_connector_message", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Called from the overlay to get screenshot data when ready.
  */
@@ -1486,6 +2457,20 @@ async executeVoiceCommand(script: string, silent: boolean, noProfile: boolean, u
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Aborts a pending auto-run countdown so the command doesn't run.
+ * Returns whether a countdown was pending.
+ */
+async cancelVoiceCommandCountdown() : Promise<boolean> {
+    return await TAURI_INVOKE("cancel_voice_command_countdown");
+},
+/**
+ * Pauses or resumes a pending auto-run countdown.
+ * Returns whether a countdown was pending.
+ */
+async pauseVoiceCommandCountdown(paused: boolean) : Promise<boolean> {
+    return await TAURI_INVOKE("pause_voice_command_countdown", { paused });
+},
 /**
  * Tests voice command matching with mock text (simulates STT output).
  * Runs the same matching logic as if the text was spoken.
@@ -1498,6 +2483,13 @@ async testVoiceCommandMock(mockText: string) : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Scores each test phrase against every enabled voice command (including the built-in
+ * meta commands when enabled) using the configured fuzzy-match weights. Useful for spotting phrases that match the wrong command.
+ */
+async voiceCommandScoreMatrix(phrases: string[]) : Promise<VoiceCommandScore[][]> {
+    return await TAURI_INVOKE("voice_command_score_matrix", { phrases });
+},
 /**
  * Get the list of supported audio file extensions
  */
@@ -1638,11 +2630,143 @@ async isLaptop() : Promise<Result<boolean, string>> {
 
 /** user-defined types **/
 
-export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; transcription_provider?: TranscriptionProvider; remote_stt?: RemoteSttSettings; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; custom_words_enabled?: boolean; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; paste_method?: PasteMethod; 
+/**
+ * Acceleration status for local transcription
+ */
+export type AccelInfo = { 
+/**
+ * "metal", "vulkan" or "cpu"
+ */
+backend: string; preference: AccelerationPreference; gpu_index: number | null; 
+/**
+ * Model currently loaded (None when no model is loaded)
+ */
+model_id: string | null; note: string | null }
+/**
+ * Which device local (Whisper) transcription should run on
+ */
+export type AccelerationPreference = 
+/**
+ * Let the engine pick (GPU when available)
+ */
+"auto" | 
+/**
+ * Force CPU inference
+ */
+"cpu" | 
+/**
+ * Prefer a GPU, optionally a specific index (`acceleration_gpu_index`)
+ */
+"gpu"
+/**
+ * Phase of a programmatically triggered action (key press / key release)
+ */
+export type ActionPhase = "start" | "stop"
+/**
+ * Where the AI Replace result goes
+ */
+export type AiReplaceOutputMode = 
+/**
+ * Paste over the selection
+ */
+"replace" | 
+/**
+ * Copy to the clipboard only (paste manually elsewhere)
+ */
+"clipboard" | 
+/**
+ * Paste over the selection and keep a copy on the clipboard
+ */
+"both"
+export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; 
+/**
+ * Optional per-language local model (language code -> model ID).
+ * Falls back to `selected_model` when the resolved language has no entry.
+ */
+models_by_language?: Partial<{ [key in string]: string }>; transcription_provider?: TranscriptionProvider; remote_stt?: RemoteSttSettings; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; 
+/**
+ * Output devices to try, in order, when `selected_output_device` can't be opened
+ * (e.g. headphones disconnected). The default device is always tried last.
+ */
+output_device_fallbacks?: string[]; translate_to_english?: boolean; selected_language?: string; 
+/**
+ * When `selected_language` is "auto", restrict detection to these languages (empty = any)
+ */
+auto_language_candidates?: string[]; 
+/**
+ * Minimum detection confidence (0.0-1.0) among the candidates; below it the OS input
+ * language is used if it's a candidate, else the first candidate. Whisper only.
+ */
+auto_language_min_confidence?: number; 
+/**
+ * Languages listed first in the language selector
+ */
+preferred_languages?: string[]; 
+/**
+ * Show only `preferred_languages` (plus "auto") in the language selector
+ */
+preferred_languages_only?: boolean; overlay_position?: OverlayPosition; overlay_monitor?: OverlayMonitor; overlay_appearance?: OverlayAppearance; 
+/**
+ * How long error overlays stay visible before auto-hiding
+ */
+overlay_error_duration_ms?: number; 
+/**
+ * Keep error overlays visible until clicked
+ */
+overlay_error_persist_until_dismissed?: boolean; debug_mode?: boolean; log_level?: LogLevel; custom_words?: CustomWord[]; 
+/**
+ * Decoding parameters for local models (defaults keep engine behavior)
+ */
+local_decode_params?: LocalDecodeParams; acceleration_preference?: AccelerationPreference; 
+/**
+ * GPU index used when `acceleration_preference` is Gpu (None = default GPU)
+ */
+acceleration_gpu_index?: number | null; custom_words_enabled?: boolean; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; 
+/**
+ * Store segment timings with history entries so they can be exported as SRT/VTT.
+ * Only applies to local models; must be enabled before recording.
+ */
+history_store_segments?: boolean; 
+/**
+ * Drop the audio of cancelled recordings instead of keeping it in history (for debugging)
+ */
+discard_cancelled_recordings?: boolean; 
+/**
+ * Append every completed transcription (timestamp, profile, raw, processed) to this
+ * file. Separate from history and never pruned. None disables the log.
+ */
+transcription_log_path?: string | null; transcription_log_format?: TranscriptionLogFormat; paste_method?: PasteMethod; 
 /**
  * Convert LF to CRLF before clipboard paste (fixes newlines on Windows)
  */
-convert_lf_to_crlf?: boolean; clipboard_handling?: ClipboardHandling; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; ai_replace_system_prompt?: string; ai_replace_user_prompt?: string; ai_replace_max_chars?: number; ai_replace_allow_no_selection?: boolean; ai_replace_no_selection_system_prompt?: string; ai_replace_allow_quick_tap?: boolean; ai_replace_quick_tap_threshold_ms?: number; ai_replace_quick_tap_system_prompt?: string; 
+convert_lf_to_crlf?: boolean; clipboard_handling?: ClipboardHandling; 
+/**
+ * Always leave the final text on the clipboard after pasting, regardless of paste method
+ */
+always_copy_result?: boolean; 
+/**
+ * Wait before pasting so the target app regains focus after the shortcut release
+ * (fast pastes otherwise lose their first characters in some apps)
+ */
+paste_delay_ms?: number; 
+/**
+ * Extra clipboard reads when selection capture comes back empty (the copy may not
+ * have landed yet). 0 disables retries.
+ */
+selection_capture_retries?: number; 
+/**
+ * Delay before each selection capture retry
+ */
+selection_capture_retry_delay_ms?: number; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; 
+/**
+ * Allow editing the base URL of built-in providers (e.g. an internal OpenAI gateway).
+ * Locked by default: only providers with `allow_base_url_edit` ("custom") are editable.
+ */
+unlock_builtin_provider_base_urls?: boolean; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; ai_replace_system_prompt?: string; ai_replace_user_prompt?: string; ai_replace_max_chars?: number; ai_replace_allow_no_selection?: boolean; ai_replace_no_selection_system_prompt?: string; ai_replace_allow_quick_tap?: boolean; ai_replace_quick_tap_threshold_ms?: number; ai_replace_quick_tap_system_prompt?: string; ai_replace_output_mode?: AiReplaceOutputMode; 
+/**
+ * Stop sequences sent with AI Replace requests (empty = none)
+ */
+ai_replace_stop_sequences?: string[]; 
 /**
  * AI Replace LLM provider ID (separate from post-processing)
  */
@@ -1662,7 +2786,31 @@ send_to_extension_enabled?: boolean; send_to_extension_push_to_talk?: boolean;
 /**
  * Whether the "Send Transcription + Selection to Extension" action is enabled (risky feature)
  */
-send_to_extension_with_selection_enabled?: boolean; send_to_extension_with_selection_push_to_talk?: boolean; send_to_extension_with_selection_allow_no_voice?: boolean; send_to_extension_with_selection_quick_tap_threshold_ms?: number; send_to_extension_with_selection_no_voice_system_prompt?: string; ai_replace_selection_push_to_talk?: boolean; mute_while_recording?: boolean; append_trailing_space?: boolean; connector_port?: number; connector_auto_open_enabled?: boolean; connector_auto_open_url?: string; screenshot_capture_method?: ScreenshotCaptureMethod; native_region_capture_mode?: NativeRegionCaptureMode; screenshot_capture_command?: string; screenshot_folder?: string; screenshot_require_recent?: boolean; screenshot_timeout_seconds?: number; screenshot_include_subfolders?: boolean; screenshot_allow_no_voice?: boolean; screenshot_quick_tap_threshold_ms?: number; screenshot_no_voice_default_prompt?: string; 
+send_to_extension_with_selection_enabled?: boolean; send_to_extension_with_selection_push_to_talk?: boolean; send_to_extension_with_selection_allow_no_voice?: boolean; send_to_extension_with_selection_quick_tap_threshold_ms?: number; send_to_extension_with_selection_no_voice_system_prompt?: string; ai_replace_selection_push_to_talk?: boolean; mute_while_recording?: boolean; append_trailing_space?: boolean; 
+/**
+ * Collect transcriptions in the scratchpad instead of pasting each one
+ */
+scratchpad_mode?: boolean; 
+/**
+ * Per-app output suffix: foreground app pattern (process name or window title,
+ * case-insensitive substring) -> suffix appended when pasting. Supports escapes like \\n.
+ * Takes precedence over `append_trailing_space` when a pattern matches.
+ */
+paste_suffix_by_app?: Partial<{ [key in string]: string }>; 
+/**
+ * Foreground app patterns (same matching as `paste_suffix_by_app`) for which
+ * LLM post-processing is skipped and the raw transcription is pasted. Empty = no gate.
+ */
+post_process_disabled_apps?: string[]; 
+/**
+ * Reset a stuck "Transcribing" overlay/tray to Idle after this many seconds (0 = disabled)
+ */
+transcribing_watchdog_secs?: number; connector_port?: number; 
+/**
+ * If the connector port is taken, try the next ports and listen on the first that binds.
+ * The bound port is runtime state; `connector_port` is never rewritten.
+ */
+connector_port_auto_fallback?: boolean; connector_auto_open_enabled?: boolean; connector_auto_open_url?: string; screenshot_capture_method?: ScreenshotCaptureMethod; native_region_capture_mode?: NativeRegionCaptureMode; screenshot_capture_command?: string; screenshot_folder?: string; screenshot_require_recent?: boolean; screenshot_timeout_seconds?: number; screenshot_include_subfolders?: boolean; screenshot_allow_no_voice?: boolean; screenshot_quick_tap_threshold_ms?: number; screenshot_no_voice_default_prompt?: string; 
 /**
  * Whether the "Send Transcription + Screenshot to Extension" action is enabled (risky feature)
  */
@@ -1675,6 +2823,27 @@ connector_password_user_set?: boolean;
  * Pending password awaiting acknowledgement from extension (two-phase commit)
  */
 connector_pending_password?: string | null; 
+/**
+ * Apply text replacements to messages sent to the extension (same ordering as paste)
+ */
+connector_apply_text_replacements?: boolean; 
+/**
+ * Drop queued connector messages older than this instead of delivering them (0 = never)
+ */
+connector_message_ttl_secs?: number; 
+/**
+ * Prepend "#<profile name>" to connector messages
+ */
+connector_tag_with_profile?: boolean; 
+/**
+ * Tag used for the default profile when tagging is on (empty = no tag)
+ */
+connector_default_profile_tag?: string; 
+/**
+ * Origins allowed to call the connector server (e.g. "chrome-extension://<id>").
+ * Empty allows any origin.
+ */
+connector_allowed_origins?: string[]; 
 /**
  * Per-model transcription prompts (model_id -> prompt text)
  * For Whisper: context/terms prompt. For Parakeet: comma-separated boost words.
@@ -1693,7 +2862,7 @@ active_profile_id?: string;
 /**
  * Whether to show an overlay notification when switching profiles
  */
-profile_switch_overlay_enabled?: boolean; 
+profile_switch_overlay_enabled?: boolean; profile_switch_during_recording?: ProfileSwitchDuringRecording; 
 /**
  * Whether the Voice Command feature is enabled
  */
@@ -1714,6 +2883,15 @@ voice_command_default_threshold?: number;
  * Whether to use LLM fallback when no predefined command matches
  */
 voice_command_llm_fallback?: boolean; 
+/**
+ * Minimum number of spoken words before the LLM fallback is attempted (0 = no minimum)
+ */
+voice_command_llm_min_words?: number; 
+/**
+ * Match built-in "switch to <profile>" and "use <prompt>" phrases alongside the
+ * predefined commands
+ */
+voice_command_meta_commands_enabled?: boolean; 
 /**
  * System prompt for LLM command generation
  */
@@ -1738,6 +2916,41 @@ post_process_reasoning_enabled?: boolean;
  * Token budget for post-processing extended thinking (min: 1024, default: 2048)
  */
 post_process_reasoning_budget?: number; 
+/**
+ * When set, parse the post-process response as JSON and paste only this field
+ * (dotted path for nested fields). Falls back to the raw response.
+ */
+post_process_extract_json_field?: string | null; 
+/**
+ * Prepend a correction hint to the post-process prompt when the transcription
+ * confidence is below this (0.0-1.0, None = off). Only Whisper reports confidence.
+ */
+post_process_low_confidence_threshold?: number | null; 
+/**
+ * Custom low-confidence hint (None = built-in)
+ */
+post_process_low_confidence_hint?: string | null; 
+/**
+ * Stop sequences sent with post-processing requests (empty = none). Escapes like
+ * `\n` are processed; providers that reject `stop` are retried without it.
+ */
+post_process_stop_sequences?: string[]; 
+/**
+ * Paste the raw transcription immediately, then select it back and replace it with
+ * the post-processed text once that differs. Best-effort: if the caret moves in
+ * between, the wrong range gets replaced.
+ */
+post_process_paste_then_replace?: boolean; 
+/**
+ * LLM requests allowed in flight at once per provider (post-processing, AI Replace,
+ * voice commands); extra requests queue instead of tripping rate limits
+ */
+llm_max_concurrent_requests?: number; 
+/**
+ * Unwrap post-processing and AI Replace output that is entirely one code fence or
+ * quoted string (kept when the prompt/instruction asks for code)
+ */
+strip_llm_formatting?: boolean; 
 /**
  * Whether to enable extended thinking for AI Replace LLM calls
  */
@@ -1766,6 +2979,11 @@ voice_command_reasoning_enabled?: boolean;
  * Token budget for Voice Command extended thinking (min: 1024, default: 2048)
  */
 voice_command_reasoning_budget?: number; 
+/**
+ * Ask the Voice Command LLM for JSON (`{command, safe, explanation}`) via `response_format`.
+ * Plain-text replies (providers without JSON mode) are still accepted.
+ */
+voice_command_structured_output?: boolean; 
 /**
  * Whether to use Levenshtein distance for character-level matching
  */
@@ -1798,12 +3016,32 @@ text_replacements_enabled?: boolean;
  * List of text replacement rules
  */
 text_replacements?: TextReplacement[]; 
+/**
+ * On/off state of named text replacement groups; groups not listed are enabled
+ */
+text_replacement_groups_enabled?: Partial<{ [key in string]: boolean }>; 
 /**
  * Whether to apply text replacements BEFORE LLM post-processing (default: after)
  * When true: STT → Text Replacement → LLM → Output
  * When false (default): STT → LLM → Text Replacement → Output
  */
 text_replacements_before_llm?: boolean; 
+/**
+ * Convert spoken formatting commands ("new line", "open quote") before other text processing
+ */
+dictation_commands_enabled?: boolean; 
+/**
+ * User additions/overrides for the built-in dictation commands
+ */
+dictation_commands?: DictationCommand[]; 
+/**
+ * Casing forced on transcription output after all other text processing
+ */
+output_case_transform?: OutputCaseTransform; 
+/**
+ * Capitalize the first letter after `.`, `?` or `!` and whitespace (rule-based, no LLM)
+ */
+auto_capitalize_sentences?: boolean; 
 /**
  * Whether to filter filler words (uh, um, hmm, etc.) from transcriptions
  */
@@ -1814,6 +3052,21 @@ filler_word_filter_enabled?: boolean;
  * Higher = less sensitive (cleaner input but may cut off quiet speech)
  */
 vad_threshold?: number; 
+/**
+ * How often `mic-level` meter updates are emitted (1-60 Hz).
+ * Lower rates reduce overhead on weaker hardware.
+ */
+audio_meter_hz?: number; 
+/**
+ * Skip transcription when the recording has no speech: VAD kept nothing, or no
+ * 30 ms window reaches `speech_energy_threshold_dbfs`. Emits `transcription-empty`.
+ */
+skip_non_speech_recordings?: boolean; 
+/**
+ * RMS level (dBFS) a window must reach to count as speech for the non-speech gate.
+ * Kept well below normal speech so quiet-but-real speech isn't dropped.
+ */
+speech_energy_threshold_dbfs?: number; 
 /**
  * Which shortcut engine to use for global hotkeys (Windows only)
  * - "tauri": High performance, but doesn't support Caps Lock, Num Lock, modifier-only shortcuts
@@ -1828,13 +3081,76 @@ sidebar_pinned?: boolean;
  * Width of the hotkey sidebar in pixels
  */
 sidebar_width?: number }
+export type AppleIntelligenceState = 
+/**
+ * Usable for post-processing
+ */
+"available" | 
+/**
+ * Supported hardware, but the model isn't ready (disabled, downloading, or OS too old)
+ */
+"unavailable" | 
+/**
+ * Not an Apple Silicon Mac
+ */
+"unsupported"
+export type AppleIntelligenceStatus = { state: AppleIntelligenceState; 
+/**
+ * Why Apple Intelligence can't be used (None when available)
+ */
+reason: string | null }
 export type AudioDevice = { index: string; name: string; is_default: boolean }
+export type BenchmarkResult = { model_id: string; audio_secs: number; 
+/**
+ * 0 when the model was already loaded
+ */
+load_ms: number; inference_ms: number; 
+/**
+ * Inference time / audio duration (lower is faster; < 1.0 is faster than real time)
+ */
+real_time_factor: number; 
+/**
+ * Expected backend (see `get_acceleration_status`)
+ */
+accelerator: string; was_loaded: boolean; cached: boolean }
 export type BindingResponse = { success: boolean; binding: ShortcutBinding | null; error: string | null }
+/**
+ * Effective configuration a binding will use when triggered
+ */
+export type BindingTarget = { binding_id: string; 
+/**
+ * Action key that will run (e.g., "transcribe"), None if the binding has no action
+ */
+action: string | null; 
+/**
+ * Whether this binding drives the transcribe action
+ */
+drives_transcribe: boolean; 
+/**
+ * Profile used for transcription (None = global "default" settings)
+ */
+profile_id: string | null; profile_name: string | null; language: string; translate_to_english: boolean; push_to_talk: boolean; 
+/**
+ * Transcription provider that will be used
+ */
+provider: TranscriptionProvider; 
+/**
+ * Local model ID or Remote STT model ID, depending on provider
+ */
+model: string }
 export type ClipboardHandling = "dont_modify" | "copy_to_clipboard" | 
 /**
  * Experimental: Try to restore all clipboard formats including images, HTML, files (Windows-only)
  */
 "restore_advanced"
+/**
+ * Result of `diagnose_connector`
+ */
+export type ConnectorDiagnosis = { items: DiagnosisItem[]; 
+/**
+ * No check failed (warnings allowed)
+ */
+ok: boolean }
 /**
  * Status info returned to frontend
  */
@@ -1856,6 +3172,73 @@ port: number;
  */
 server_error: string | null }
 export type CustomSounds = { start: boolean; stop: boolean }
+/**
+ * A custom word for fuzzy correction, with an optional per-word match threshold.
+ * Older settings stored plain strings; those load with `threshold: None`.
+ */
+export type CustomWord = { word: string; 
+/**
+ * Overrides `word_correction_threshold` for this word (None = use the global value)
+ */
+threshold?: number | null }
+/**
+ * One connector health check with a remediation hint when it didn't pass
+ */
+export type DiagnosisItem = { 
+/**
+ * Stable check id: server, port, password, extension, password_handshake
+ */
+check: string; level: DiagnosisLevel; message: string; hint: string | null }
+/**
+ * Outcome of a single connector health check
+ */
+export type DiagnosisLevel = "pass" | "warn" | "fail"
+/**
+ * How a dictation command's output joins the surrounding words
+ */
+export type DictationAttach = 
+/**
+ * Spaced like a normal word
+ */
+"none" | 
+/**
+ * No space before (punctuation, closing quote)
+ */
+"left" | 
+/**
+ * No space after (opening quote/paren)
+ */
+"right" | 
+/**
+ * No space on either side (line breaks)
+ */
+"both"
+/**
+ * A spoken formatting command ("new line" → "\n") for dictation command mode.
+ * Built-in commands are seeded per language; user entries override them by phrase.
+ */
+export type DictationCommand = { 
+/**
+ * Language code the command applies to (e.g., "en"), or "*" for all languages
+ */
+language: string; 
+/**
+ * Spoken phrase, matched case-insensitively on word boundaries
+ */
+phrase: string; 
+/**
+ * Replacement text (supports escape sequences: \n, \t)
+ */
+output: string; attach?: DictationAttach; 
+/**
+ * Phrase is also an ordinary word (e.g., "period"): only converted at the end
+ * of the transcription or right before another command
+ */
+ambiguous?: boolean; 
+/**
+ * Set to false to disable a built-in command with the same phrase
+ */
+enabled?: boolean }
 export type EngineType = "Whisper" | "Parakeet" | "Moonshine"
 /**
  * PowerShell execution policy for voice commands.
@@ -1910,6 +3293,22 @@ saved_file_path: string | null;
  * The segments with timestamps (only populated for SRT/VTT formats)
  */
 segments: SubtitleSegment[] | null }
+/**
+ * The application that currently owns the foreground window
+ */
+export type ForegroundApp = { 
+/**
+ * Executable / process name (e.g., "slack.exe", "Slack")
+ */
+process_name: string | null; 
+/**
+ * Title of the focused window
+ */
+window_title: string | null; 
+/**
+ * Full path of the executable, where the platform exposes it
+ */
+executable_path: string | null }
 export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; 
 /**
  * Type of action: "transcribe", "ai_replace", etc.
@@ -1922,8 +3321,26 @@ original_selection: string | null;
 /**
  * For AI Replace: the AI response (None if request failed/never received)
  */
-ai_response: string | null }
+ai_response: string | null; 
+/**
+ * Outcome of LLM post-processing (None for entries recorded before this was tracked)
+ */
+post_process_status: PostProcessStatus | null }
 export type LLMPrompt = { id: string; name: string; prompt: string }
+export type LastError = { message: string; category: OverlayErrorCategory; 
+/**
+ * Unix timestamp (milliseconds)
+ */
+timestamp: number }
+/**
+ * How `llm_config_for` resolved a feature, for support and debugging.
+ * Only reports whether a key is present, never the key itself.
+ */
+export type LlmConfigDebug = { feature: LlmFeature; provider_id: string; model: string; base_url: string; has_api_key: boolean; 
+/**
+ * Resolution steps in order, e.g. "used ai_replace key" or "fell back to post-process key"
+ */
+fallback_path: string[] }
 /**
  * Which feature is requesting LLM access.
  * Used to resolve the correct provider/key/model configuration.
@@ -1941,9 +3358,55 @@ export type LlmFeature =
  * Voice Command LLM fallback
  */
 "voice_command"
+/**
+ * Optional decoding parameters for local transcription (None = engine default).
+ * Whisper only; Parakeet and Moonshine ignore them.
+ */
+export type LocalDecodeParams = { 
+/**
+ * Beam search width (1-10)
+ */
+beam_size?: number | null; 
+/**
+ * Sampling temperature (0.0-1.0)
+ */
+temperature?: number | null; 
+/**
+ * Probability above which a segment is treated as silence (0.0-1.0)
+ */
+no_speech_threshold?: number | null; 
+/**
+ * Feed previously decoded text back as context
+ */
+condition_on_previous?: boolean | null }
 export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
-export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number }
+/**
+ * Result of a microphone capture test (nothing is saved or transcribed)
+ */
+export type MicTestResult = { device_name: string; duration_ms: number; sample_count: number; peak: number; rms: number; peak_dbfs: number; rms_dbfs: number; clipped_samples: number; clipping: boolean }
+export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number; 
+/**
+ * Language codes the model can transcribe (empty = multilingual)
+ */
+supported_languages?: string[] }
 export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
+/**
+ * Approximate memory held by the loaded local model
+ */
+export type ModelMemoryInfo = { model_id: string; 
+/**
+ * Approximate footprint in bytes: the size of the model weights on disk.
+ * Whisper also allocates compute buffers on top of this while transcribing.
+ */
+approx_bytes: number; 
+/**
+ * "gpu" when the weights are held in VRAM, otherwise "cpu" (system RAM)
+ */
+device: string; 
+/**
+ * Backend the model runs on ("metal", "vulkan" or "cpu")
+ */
+backend: string }
 export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
 /**
  * State for tracking active key modifiers (Ctrl, Shift, Alt, Win)
@@ -1958,6 +3421,18 @@ export type NativeRegionCaptureMode =
  * Legacy: capture a full screenshot first and use it as the picker background.
  */
 "screenshot_background"
+/**
+ * Casing forced on transcription output as the last step before paste
+ */
+export type OutputCaseTransform = 
+/**
+ * Keep the text as transcribed
+ */
+"none" | "lower" | "upper" | 
+/**
+ * Simple word-boundary heuristic, not locale-aware titlecasing
+ */
+"title"
 /**
  * Output format for transcription
  */
@@ -1974,21 +3449,166 @@ export type OutputFormat =
  * WebVTT subtitle format
  */
 "vtt"
+/**
+ * Recording overlay appearance. Defaults match the built-in look.
+ */
+export type OverlayAppearance = { 
+/**
+ * Background opacity (0.0-1.0)
+ */
+background_opacity?: number; 
+/**
+ * Accent color for the level bars, as "#rrggbb"
+ */
+accent_color?: string; 
+/**
+ * Size multiplier (0.5-2.0)
+ */
+scale?: number }
+/**
+ * Error categories for overlay display
+ */
+export type OverlayErrorCategory = "TlsCertificate" | "TlsHandshake" | "Timeout" | "NetworkError" | "ServerError" | "ParseError" | "ExtensionOffline" | "MicrophoneUnavailable" | "Unknown"
+/**
+ * Which display the recording overlay appears on
+ */
+export type OverlayMonitor = 
+/**
+ * The monitor under the mouse cursor
+ */
+"cursor" | 
+/**
+ * The monitor containing the focused window (falls back to the cursor's monitor)
+ */
+"active_window" | "primary" | 
+/**
+ * Index into the system monitor list (falls back to the primary monitor)
+ */
+{ monitor_index: number }
 export type OverlayPosition = "none" | "top" | "bottom"
 export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
-export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null }
+/**
+ * Stage durations of a single transcription, in milliseconds
+ */
+export type PipelineTiming = { binding_id: string; 
+/**
+ * Length of the recorded audio
+ */
+record_ms: number; 
+/**
+ * Stopping the recorder and collecting samples
+ */
+stop_ms: number; 
+/**
+ * Speech-to-text (local or remote)
+ */
+transcribe_ms: number; 
+/**
+ * Text replacements, Chinese conversion and LLM post-processing
+ * (for AI Replace: selection capture and the LLM call)
+ */
+post_process_ms: number; 
+/**
+ * Pasting, or queuing the message for the extension
+ */
+paste_ms: number; 
+/**
+ * Time from key release until the text was pasted (excludes recording)
+ */
+total_ms: number }
+/**
+ * Aggregated stage timings for the current app session
+ */
+export type PipelineTimingStats = { count: number; avg_record_ms: number; avg_stop_ms: number; avg_transcribe_ms: number; avg_post_process_ms: number; avg_paste_ms: number; avg_total_ms: number; max_total_ms: number; last: PipelineTiming | null }
+export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null; 
+/**
+ * Optional OpenAI organization ID, sent as `OpenAI-Organization`
+ */
+organization?: string | null; 
+/**
+ * Optional OpenAI project ID, sent as `OpenAI-Project`
+ */
+project?: string | null }
+/**
+ * Outcome of LLM post-processing for a transcription
+ */
+export type PostProcessStatus = 
+/**
+ * Post-processing was turned off
+ */
+"disabled" | 
+/**
+ * Post-processing produced the stored post-processed text
+ */
+"success" | 
+/**
+ * The LLM call failed and the raw transcription was used instead
+ */
+"failed" | 
+/**
+ * Post-processing was enabled but not configured (no provider/model/prompt)
+ */
+"skipped" | 
+/**
+ * Chinese variant conversion ran instead of the LLM; the converted text is stored
+ */
+"chinese_conversion"
 /**
  * Per-profile LLM post-processing settings.
  * Used as a parameter struct for update_transcription_profile to reduce argument count.
  */
 export type ProfileLlmSettings = { enabled: boolean; promptOverride: string | null; modelOverride: string | null }
+/**
+ * What switching the active profile does while a recording is in flight
+ */
+export type ProfileSwitchDuringRecording = 
+/**
+ * Switch now; the in-flight recording keeps the profile it started with
+ */
+"next_recording" | 
+/**
+ * Refuse the switch and tell the user why
+ */
+"reject"
+/**
+ * Result of a one-off transcription through an explicit provider (A/B testing)
+ */
+export type ProviderTranscriptionResult = { provider: TranscriptionProvider; text: string; duration_ms: number }
 export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
 /**
  * Response for get_data command
  */
 export type RegionCaptureData = { screenshot: string | null; virtual_screen: VirtualScreenInfo }
+/**
+ * The last remote transcription request/response, captured while `debug_capture` is on
+ */
+export type RemoteSttDebugData = { 
+/**
+ * Unix timestamp (ms) of the request
+ */
+captured_at: number; method: string; url: string; request_headers: [string, string][]; audio_bytes: number; 
+/**
+ * None when no response arrived
+ */
+status: number | null; elapsed_ms: number; response_headers: [string, string][]; 
+/**
+ * Truncated in Normal debug mode, full in Verbose
+ */
+response_body: string | null; body_truncated: boolean; error: string | null }
 export type RemoteSttDebugMode = "normal" | "verbose"
 export type RemoteSttSettings = { base_url: string; model_id: string; debug_capture?: boolean; debug_mode?: RemoteSttDebugMode }
+/**
+ * One rule's effect in `preview_text_replacements`
+ */
+export type ReplacementStep = { rule_id: string; before: string; after: string; matches: number; 
+/**
+ * Why the rule couldn't run (invalid regex); `after` equals `before` then
+ */
+error: string | null }
+/**
+ * How the current rules would change one history entry
+ */
+export type RuleDiff = { id: number; timestamp: number; before: string; after: string }
 export type ScreenshotCaptureMethod = "external_program" | "native"
 /**
  * Region selected by the user (in screen coordinates).
@@ -2010,7 +3630,11 @@ width: number;
  * Height in pixels
  */
 height: number }
-export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string }
+export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string; 
+/**
+ * Extra keys that trigger the same action (e.g., a mouse-button-mapped key)
+ */
+additional_bindings?: string[] }
 /**
  * Shortcut engine selection for Windows.
  * Controls which mechanism is used to listen for global hotkeys.
@@ -2060,7 +3684,8 @@ id: string;
 from: string; 
 /**
  * The replacement text (supports escape sequences: \n, \r\n, \t, \\)
- * For regex replacements, supports $1, $2, etc. for capture groups.
+ * For regex replacements, supports $1, $2, etc. and ${name} for capture groups, and
+ * \U$1 / \L$1 / \u$1 to upper-case, lower-case or capitalize a group.
  */
 to: string; 
 /**
@@ -2074,7 +3699,54 @@ case_sensitive?: boolean;
 /**
  * Whether the 'from' field is a regular expression (default: false)
  */
-is_regex?: boolean }
+is_regex?: boolean; 
+/**
+ * Also match words within `fuzzy_max_distance` edits of 'from', ignoring case and
+ * spacing ("get hub" / "git hub" for "GitHub"). Ignored for regex rules.
+ */
+fuzzy?: boolean; 
+/**
+ * Maximum edit distance for fuzzy matching, capped at a third of the length of 'from'
+ */
+fuzzy_max_distance?: number; 
+/**
+ * Named group this rule belongs to; the whole group can be switched off at once
+ * via `text_replacement_groups_enabled`. Ungrouped rules always run.
+ */
+group?: string | null; 
+/**
+ * When the rule runs relative to LLM post-processing. Unset follows
+ * `text_replacements_before_llm`.
+ */
+phase?: TextReplacementPhase | null }
+/**
+ * When a text replacement rule runs relative to LLM post-processing
+ */
+export type TextReplacementPhase = 
+/**
+ * Fix the transcription before the LLM sees it
+ */
+"before_llm" | "after_llm" | "both"
+/**
+ * A representative input and the output the text replacement rules should produce
+ */
+export type TextReplacementTestCase = { input: string; expected: string }
+/**
+ * Outcome of one `TextReplacementTestCase`
+ */
+export type TextReplacementTestResult = { input: string; expected: string; actual: string; passed: boolean }
+/**
+ * Entry format of the transcription log file
+ */
+export type TranscriptionLogFormat = 
+/**
+ * Readable blocks separated by a blank line
+ */
+"text" | 
+/**
+ * One JSON object per line
+ */
+"jsonl"
 /**
  * A custom transcription profile with its own language and translation settings.
  * Each profile creates a separate shortcut binding (e.g., "transcribe_profile_abc123").
@@ -2133,7 +3805,31 @@ llm_prompt_override?: string | null;
  * Override the global LLM model for this profile
  * If Some, uses this model instead of the global model for the current provider
  */
-llm_model_override?: string | null }
+llm_model_override?: string | null; 
+/**
+ * Spoken formatting commands ("new line", "open quote") for this profile.
+ * None inherits the global `dictation_commands_enabled`.
+ */
+dictation_commands_enabled?: boolean | null; 
+/**
+ * Casing forced on this profile's output. None inherits the global `output_case_transform`.
+ */
+output_case_transform?: OutputCaseTransform | null; 
+/**
+ * Capitalize sentence starts for this profile.
+ * None inherits the global `auto_capitalize_sentences`.
+ */
+auto_capitalize_sentences?: boolean | null; 
+/**
+ * Filler word/hallucination filter for this profile.
+ * None inherits the global `filler_word_filter_enabled`.
+ */
+filler_filter_override?: boolean | null; 
+/**
+ * VAD threshold for recordings with this profile (0.1-0.9).
+ * None inherits the global `vad_threshold`.
+ */
+vad_threshold?: number | null }
 export type TranscriptionProvider = "local" | "remote_openai_compatible"
 /**
  * Information about the virtual screen (all monitors combined).
@@ -2229,6 +3925,26 @@ use_pwsh?: boolean;
  * Execution policy for scripts
  */
 execution_policy?: ExecutionPolicy }
+/**
+ * Score of a phrase against a single voice command (for tuning the matcher)
+ */
+export type VoiceCommandScore = { command_id: string; command_name: string; trigger_phrase: string; score: number; 
+/**
+ * Effective threshold (per-command, or the default when unset)
+ */
+threshold: number; 
+/**
+ * True if score >= threshold
+ */
+matched: boolean }
+/**
+ * Summary of a saved workspace
+ */
+export type WorkspaceInfo = { name: string; 
+/**
+ * Unix timestamp (seconds) of the last save
+ */
+saved_at: number }
 
 /** tauri-specta globals **/
 
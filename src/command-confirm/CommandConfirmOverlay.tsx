@@ -1,5 +1,6 @@
 import { useEffect, useState, useRef } from "react";
 import { listen, emit } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { LogicalSize } from "@tauri-apps/api/dpi";
 import { commands } from "@/bindings";
@@ -15,6 +16,8 @@ interface CommandConfirmPayload {
   command: string;
   spoken_text: string;
   from_llm: boolean;
+  // LLM's explanation of the command (structured output only)
+  explanation?: string | null;
   // Execution options passed from backend
   silent: boolean;
   no_profile: boolean;
@@ -36,6 +39,12 @@ export interface VoiceCommandResultPayload {
   wasOpenedInWindow: boolean;
 }
 
+/** Payload of the backend-owned auto-run countdown tick */
+interface VoiceCommandCountdownPayload {
+  remaining_seconds: number;
+  state: "running" | "paused" | "finished" | "cancelled";
+}
+
 type Status = null | { type: "success"; message: string } | { type: "error"; message: string };
 
 /** Helper to hide the current window - handles the async nature of hide() */
@@ -45,6 +54,11 @@ const hideWindow = () => {
   });
 };
 
+/** Stop the backend auto-run countdown (no-op when none is pending) */
+const cancelCountdown = () => {
+  invoke("cancel_voice_command_countdown").catch(console.error);
+};
+
 export default function CommandConfirmOverlay() {
   const [payload, setPayload] = useState<CommandConfirmPayload | null>(null);
   const [isEditing, setIsEditing] = useState(false);
@@ -54,6 +68,8 @@ export default function CommandConfirmOverlay() {
   // Auto-run countdown state
   const [countdownMs, setCountdownMs] = useState<number>(0);
   const [isPaused, setIsPaused] = useState(false);
+  // Set when the backend countdown finishes; triggers the run
+  const [autoRunDue, setAutoRunDue] = useState(false);
   // Copy button state
   const [copied, setCopied] = useState(false);
   // Double-Enter detection state
@@ -71,6 +87,7 @@ export default function CommandConfirmOverlay() {
       setStatus(null);
       setIsExecuting(false);
       setIsPaused(false);
+      setAutoRunDue(false);
       // Initialize countdown if auto_run is enabled for predefined commands
       if (event.payload.auto_run && !event.payload.from_llm && event.payload.auto_run_seconds) {
         setCountdownMs(event.payload.auto_run_seconds * 1000);
@@ -86,6 +103,35 @@ export default function CommandConfirmOverlay() {
     };
   }, []);
 
+  // The backend owns the countdown so the Cancel shortcut can abort it
+  useEffect(() => {
+    const unlisten = listen<VoiceCommandCountdownPayload>("voice-command-countdown", (event) => {
+      const { remaining_seconds, state } = event.payload;
+      switch (state) {
+        case "running":
+          setIsPaused(false);
+          setCountdownMs(remaining_seconds * 1000);
+          break;
+        case "paused":
+          setIsPaused(true);
+          setCountdownMs(remaining_seconds * 1000);
+          break;
+        case "finished":
+          setCountdownMs(0);
+          setAutoRunDue(true);
+          break;
+        case "cancelled":
+          setIsPaused(false);
+          setCountdownMs(0);
+          break;
+      }
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   // Resize window when error status is displayed
   useEffect(() => {
     if (status?.type === "error") {
@@ -94,7 +140,7 @@ export default function CommandConfirmOverlay() {
     }
   }, [status]);
 
-  // Countdown timer effect
+  // Smooth the progress bar between the backend's one-second ticks
   useEffect(() => {
     if (!payload?.auto_run || payload.from_llm || isPaused || isEditing || status || isExecuting) {
       return;
@@ -117,18 +163,18 @@ export default function CommandConfirmOverlay() {
     return () => clearInterval(interval);
   }, [payload, isPaused, isEditing, status, isExecuting, countdownMs > 0]);
 
-  // Auto-execute when countdown reaches 0
+  // Auto-execute when the backend countdown finishes
   useEffect(() => {
-    if (countdownMs === 0 && payload?.auto_run && !payload.from_llm && !isEditing && !status && !isExecuting) {
-      // Check if we actually had a countdown (auto_run_seconds > 0)
-      if (payload.auto_run_seconds && payload.auto_run_seconds > 0) {
-        handleRun();
-      }
+    if (!autoRunDue) return;
+    setAutoRunDue(false);
+    if (payload?.auto_run && !payload.from_llm && !isEditing && !status && !isExecuting) {
+      handleRun();
     }
-  }, [countdownMs]);
+  }, [autoRunDue]);
 
   const handleRun = async () => {
     if (!payload || isExecuting) return;
+    cancelCountdown();
 
     setIsExecuting(true);
     const commandToRun = isEditing ? editedCommand : payload.command;
@@ -201,6 +247,7 @@ export default function CommandConfirmOverlay() {
     setIsEditing(true);
     setStatus(null);
     setCountdownMs(0); // Stop auto-run when editing
+    cancelCountdown();
   };
 
   const handleContainerClick = (e: React.MouseEvent) => {
@@ -209,11 +256,14 @@ export default function CommandConfirmOverlay() {
       return;
     }
     if (isAutoRunActive || (isPaused && payload?.auto_run && !payload.from_llm && !isEditing && !status)) {
-      setIsPaused((prev) => !prev);
+      const paused = !isPaused;
+      setIsPaused(paused);
+      invoke("pause_voice_command_countdown", { paused }).catch(console.error);
     }
   };
 
   const handleCancel = () => {
+    cancelCountdown();
     hideWindow();
   };
 
@@ -234,6 +284,7 @@ export default function CommandConfirmOverlay() {
     const handleKeyDown = (e: KeyboardEvent) => {
       if (e.key === "Escape") {
         e.preventDefault();
+        cancelCountdown();
         hideWindow();
       }
     };
@@ -347,6 +398,12 @@ export default function CommandConfirmOverlay() {
         </div>
       )}
 
+      {payload.explanation && (
+        <div className="command-confirm-explanation">
+          {payload.explanation}
+        </div>
+      )}
+
       {isEditing ? (
         <textarea
           className="command-confirm-edit-area"
@@ -18,6 +18,7 @@ interface HotkeyCategory {
 /** Maps shortcut IDs to their feature enable setting key */
 const featureEnabledMap: Record<string, keyof AppSettings> = {
   voice_command: "voice_command_enabled",
+  cancel_voice_command_countdown: "voice_command_enabled",
   send_to_extension: "send_to_extension_enabled",
   send_to_extension_with_selection: "send_to_extension_with_selection_enabled",
   send_screenshot_to_extension: "send_screenshot_to_extension_enabled",
@@ -42,13 +43,21 @@ const categorizeHotkeys = (
 
   // Define category mappings
   const categoryMap: Record<string, string[]> = {
-    recording: ["transcribe", "transcribe_default", "cancel", "repaste_last", "cycle_profile"],
+    recording: [
+      "transcribe",
+      "transcribe_default",
+      "cancel",
+      "repaste_last",
+      "cycle_profile",
+      "toggle_ptt_mode",
+    ],
     actions: [
       "ai_replace_selection",
       "send_to_extension",
       "send_to_extension_with_selection",
       "send_screenshot_to_extension",
       "voice_command",
+      "cancel_voice_command_countdown",
     ],
   };
 
@@ -27,9 +27,12 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
         sanitizedWord &&
         !sanitizedWord.includes(" ") &&
         sanitizedWord.length <= 50 &&
-        !customWords.includes(sanitizedWord)
+        !customWords.some((entry) => entry.word === sanitizedWord)
       ) {
-        updateSetting("custom_words", [...customWords, sanitizedWord]);
+        updateSetting("custom_words", [
+          ...customWords,
+          { word: sanitizedWord, threshold: null },
+        ]);
         setNewWord("");
       }
     };
@@ -37,7 +40,7 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
     const handleRemoveWord = (wordToRemove: string) => {
       updateSetting(
         "custom_words",
-        customWords.filter((word) => word !== wordToRemove),
+        customWords.filter((entry) => entry.word !== wordToRemove),
       );
     };
 
@@ -103,7 +106,7 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
           <div
             className={`px-4 p-2 ${grouped ? "" : "rounded-lg border border-mid-gray/20"} flex flex-wrap gap-1`}
           >
-            {customWords.map((word) => (
+            {customWords.map(({ word }) => (
               <Button
                 key={word}
                 onClick={() => handleRemoveWord(word)}
@@ -13,6 +13,31 @@ import { useSettings } from "../../hooks/useSettings";
 import { commands } from "@/bindings";
 import { toast } from "sonner";
 
+// MouseEvent.button -> binding name for the buttons the rdev engine can bind
+// (left/right stay unbindable so normal clicking keeps working)
+const MOUSE_BUTTON_NAMES: Record<number, string> = {
+  1: "mouse3",
+  3: "mouse4",
+  4: "mouse5",
+};
+
+const MODIFIER_KEYS = [
+  "ctrl",
+  "control",
+  "shift",
+  "alt",
+  "option",
+  "meta",
+  "command",
+  "cmd",
+  "super",
+  "win",
+  "windows",
+];
+
+const isModifierKey = (key: string) =>
+  MODIFIER_KEYS.includes(key.toLowerCase());
+
 interface HandyShortcutProps {
   descriptionMode?: "inline" | "tooltip";
   grouped?: boolean;
@@ -119,6 +144,60 @@ export const HandyShortcut: React.FC<HandyShortcutProps> = ({
       }
     };
 
+    // Save the recorded keys as the new shortcut and leave editing mode
+    const commitShortcut = async (keys: string[]) => {
+      // Sort keys so modifiers come first, then the main key
+      const sortedKeys = [...keys].sort((a, b) => {
+        const aIsModifier = isModifierKey(a);
+        const bIsModifier = isModifierKey(b);
+        if (aIsModifier && !bIsModifier) return -1;
+        if (!aIsModifier && bIsModifier) return 1;
+        return 0;
+      });
+      const newShortcut = sortedKeys.join("+");
+
+      if (editingShortcutId && bindings[editingShortcutId]) {
+        try {
+          // changeBinding (called by updateBinding) already registers the new shortcut
+          await updateBinding(editingShortcutId, newShortcut);
+
+          // Warn if modifier-only shortcut (Windows only)
+          if (osType === "windows" && sortedKeys.every(isModifierKey)) {
+            toast.warning(t("settings.general.shortcut.warnings.modifierOnly"), {
+              duration: 6000,
+            });
+          }
+        } catch (error) {
+          console.error("Failed to change binding:", error);
+          toast.error(
+            t("settings.general.shortcut.errors.set", {
+              error: String(error),
+            }),
+          );
+
+          // Reset to original binding on error
+          // changeBinding already handles registration, no need to call resumeBinding
+          if (originalBinding) {
+            try {
+              await updateBinding(editingShortcutId, originalBinding);
+            } catch (resetError) {
+              toast.error(
+                t("settings.general.shortcut.errors.reset", {
+                  error: String(resetError),
+                }),
+              );
+            }
+          }
+        }
+
+        // Exit editing mode and reset states
+        setEditingShortcutId(null);
+        setKeyPressed([]);
+        setRecordedKeys([]);
+        setOriginalBinding("");
+      }
+    };
+
     const handleKeyUp = async (e: KeyboardEvent) => {
       if (cleanup) return;
       e.preventDefault();
@@ -133,79 +212,20 @@ export const HandyShortcut: React.FC<HandyShortcutProps> = ({
       // If no keys are pressed anymore, commit the shortcut
       const updatedKeyPressed = keyPressed.filter((k) => k !== key);
       if (updatedKeyPressed.length === 0 && recordedKeys.length > 0) {
-        // Create the shortcut string from all recorded keys
-        // Sort keys so modifiers come first, then the main key
-        const modifiers = [
-          "ctrl",
-          "control",
-          "shift",
-          "alt",
-          "option",
-          "meta",
-          "command",
-          "cmd",
-          "super",
-          "win",
-          "windows",
-        ];
-        const sortedKeys = recordedKeys.sort((a, b) => {
-          const aIsModifier = modifiers.includes(a.toLowerCase());
-          const bIsModifier = modifiers.includes(b.toLowerCase());
-          if (aIsModifier && !bIsModifier) return -1;
-          if (!aIsModifier && bIsModifier) return 1;
-          return 0;
-        });
-        const newShortcut = sortedKeys.join("+");
-
-        if (editingShortcutId && bindings[editingShortcutId]) {
-          try {
-            // changeBinding (called by updateBinding) already registers the new shortcut
-            await updateBinding(editingShortcutId, newShortcut);
-
-            // Warn if modifier-only shortcut (Windows only)
-            if (osType === "windows") {
-              const isModifierOnly = sortedKeys.every((key) =>
-                modifiers.includes(key.toLowerCase()),
-              );
-              if (isModifierOnly) {
-                toast.warning(
-                  t("settings.general.shortcut.warnings.modifierOnly"),
-                  { duration: 6000 },
-                );
-              }
-            }
-          } catch (error) {
-            console.error("Failed to change binding:", error);
-            toast.error(
-              t("settings.general.shortcut.errors.set", {
-                error: String(error),
-              }),
-            );
-
-            // Reset to original binding on error
-            // changeBinding already handles registration, no need to call resumeBinding
-            if (originalBinding) {
-              try {
-                await updateBinding(editingShortcutId, originalBinding);
-              } catch (resetError) {
-                toast.error(
-                  t("settings.general.shortcut.errors.reset", {
-                    error: String(resetError),
-                  }),
-                );
-              }
-            }
-          }
-
-          // Exit editing mode and reset states
-          setEditingShortcutId(null);
-          setKeyPressed([]);
-          setRecordedKeys([]);
-          setOriginalBinding("");
-        }
+        await commitShortcut(recordedKeys);
       }
     };
 
+    // Middle/side mouse buttons (rdev engine only) commit right away,
+    // together with any modifiers currently held
+    const handleMouseDown = async (e: MouseEvent) => {
+      if (cleanup) return;
+      const button = MOUSE_BUTTON_NAMES[e.button];
+      if (!button) return;
+      e.preventDefault();
+      await commitShortcut([...keyPressed.filter(isModifierKey), button]);
+    };
+
     // Add click outside handler
     const handleClickOutside = async (e: MouseEvent) => {
       if (cleanup) return;
@@ -237,12 +257,14 @@ export const HandyShortcut: React.FC<HandyShortcutProps> = ({
     window.addEventListener("keydown", handleKeyDown);
     window.addEventListener("keyup", handleKeyUp);
     window.addEventListener("click", handleClickOutside);
+    window.addEventListener("mousedown", handleMouseDown);
 
     return () => {
       cleanup = true;
       window.removeEventListener("keydown", handleKeyDown);
       window.removeEventListener("keyup", handleKeyUp);
       window.removeEventListener("click", handleClickOutside);
+      window.removeEventListener("mousedown", handleMouseDown);
     };
   }, [
     keyPressed,
@@ -0,0 +1,46 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface LlmMaxConcurrentRequestsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const LlmMaxConcurrentRequestsSetting: React.FC<LlmMaxConcurrentRequestsProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const limit = (getSetting("llm_max_concurrent_requests") ?? 2) as number;
+
+    return (
+      <SettingContainer
+        title={t("settings.postProcessing.maxConcurrentRequests.title")}
+        description={t("settings.postProcessing.maxConcurrentRequests.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center space-x-2">
+          <Input
+            type="number"
+            min="1"
+            max="8"
+            step="1"
+            value={limit}
+            onChange={(e) => {
+              const val = parseInt(e.target.value, 10);
+              if (!isNaN(val) && val >= 1) {
+                updateSetting("llm_max_concurrent_requests", val);
+              }
+            }}
+            disabled={isUpdating("llm_max_concurrent_requests")}
+            className="w-20"
+          />
+        </div>
+      </SettingContainer>
+    );
+  },
+);
@@ -1,10 +1,19 @@
-import React, { useMemo } from "react";
+import React, { useEffect, useMemo, useState } from "react";
 import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { useSettings } from "../../hooks/useSettings";
 import { commands, type ModelUnloadTimeout } from "@/bindings";
 import { Dropdown } from "../ui/Dropdown";
 import { SettingContainer } from "../ui/SettingContainer";
 
+interface ModelMemoryInfo {
+  model_id: string;
+  approx_bytes: number;
+  device: "cpu" | "gpu";
+  backend: string;
+}
+
 interface ModelUnloadTimeoutProps {
   descriptionMode?: "tooltip" | "inline";
   grouped?: boolean;
@@ -16,6 +25,20 @@ export const ModelUnloadTimeoutSetting: React.FC<ModelUnloadTimeoutProps> = ({
 }) => {
   const { t } = useTranslation();
   const { settings, getSetting, updateSetting } = useSettings();
+  const [memory, setMemory] = useState<ModelMemoryInfo | null>(null);
+
+  // Show the loaded model's footprint so the timeout can be picked sensibly
+  useEffect(() => {
+    const loadMemory = () =>
+      invoke<ModelMemoryInfo | null>("get_model_memory_usage")
+        .then(setMemory)
+        .catch(() => setMemory(null));
+    loadMemory();
+    const unlisten = listen("model-state-changed", loadMemory);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
 
   const timeoutOptions = [
     {
@@ -80,16 +103,26 @@ export const ModelUnloadTimeoutSetting: React.FC<ModelUnloadTimeoutProps> = ({
       descriptionMode={descriptionMode}
       grouped={grouped}
     >
-      <Dropdown
-        options={options}
-        selectedValue={currentValue}
-        onSelect={(value) =>
-          handleChange({
-            target: { value },
-          } as React.ChangeEvent<HTMLSelectElement>)
-        }
-        disabled={false}
-      />
+      <div className="flex flex-col items-end gap-1">
+        <Dropdown
+          options={options}
+          selectedValue={currentValue}
+          onSelect={(value) =>
+            handleChange({
+              target: { value },
+            } as React.ChangeEvent<HTMLSelectElement>)
+          }
+          disabled={false}
+        />
+        {memory && (
+          <span className="text-xs text-mid-gray">
+            {t("settings.advanced.modelUnload.memoryUsage", {
+              size: (memory.approx_bytes / (1024 * 1024)).toFixed(0),
+              device: memory.device === "gpu" ? "VRAM" : "RAM",
+            })}
+          </span>
+        )}
+      </div>
     </SettingContainer>
   );
 };
@@ -0,0 +1,64 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { OverlayMonitor } from "@/bindings";
+
+interface OverlayMonitorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const toOptionValue = (value: OverlayMonitor): string =>
+  typeof value === "string" ? value : `monitor:${value.monitor_index}`;
+
+const fromOptionValue = (value: string): OverlayMonitor =>
+  value.startsWith("monitor:")
+    ? { monitor_index: parseInt(value.slice("monitor:".length), 10) }
+    : (value as OverlayMonitor);
+
+export const OverlayMonitorSetting: React.FC<OverlayMonitorProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [monitors, setMonitors] = useState<string[]>([]);
+
+    useEffect(() => {
+      invoke<string[]>("get_overlay_monitors")
+        .then(setMonitors)
+        .catch((error) => console.error("Failed to list monitors:", error));
+    }, []);
+
+    const options = [
+      { value: "cursor", label: t("settings.advanced.overlayMonitor.options.cursor") },
+      {
+        value: "active_window",
+        label: t("settings.advanced.overlayMonitor.options.activeWindow"),
+      },
+      { value: "primary", label: t("settings.advanced.overlayMonitor.options.primary") },
+      ...monitors.map((name, index) => ({ value: `monitor:${index}`, label: name })),
+    ];
+
+    const selected = toOptionValue(getSetting("overlay_monitor") ?? "cursor");
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.overlayMonitor.title")}
+        description={t("settings.advanced.overlayMonitor.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={options}
+          selectedValue={selected}
+          onSelect={(value) =>
+            updateSetting("overlay_monitor", fromOptionValue(value))
+          }
+          disabled={isUpdating("overlay_monitor")}
+        />
+      </SettingContainer>
+    );
+  },
+);
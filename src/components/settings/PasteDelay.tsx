@@ -0,0 +1,47 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface PasteDelayProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PasteDelaySetting: React.FC<PasteDelayProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const delayMs = (getSetting("paste_delay_ms") ?? 50) as number;
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.pasteDelay.title")}
+        description={t("settings.advanced.pasteDelay.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center space-x-2">
+          <Input
+            type="number"
+            min="0"
+            max="1000"
+            step="10"
+            value={delayMs}
+            onChange={(e) => {
+              const val = parseInt(e.target.value, 10);
+              if (!isNaN(val) && val >= 0) {
+                updateSetting("paste_delay_ms", val);
+              }
+            }}
+            disabled={isUpdating("paste_delay_ms")}
+            className="w-20"
+          />
+          <span className="text-sm text-text">ms</span>
+        </div>
+      </SettingContainer>
+    );
+  },
+);
@@ -67,6 +67,7 @@ export const PasteMethodSetting: React.FC<PasteMethodProps> = React.memo(
     const selectedMethod = (getSetting("paste_method") ||
       "ctrl_v") as PasteMethod;
     const convertLfToCrlf = (getSetting("convert_lf_to_crlf" as any) ?? true) as boolean;
+    const alwaysCopyResult = (getSetting("always_copy_result") ?? false) as boolean;
 
     const pasteMethodOptions = getPasteMethodOptions(osType);
 
@@ -108,6 +109,16 @@ export const PasteMethodSetting: React.FC<PasteMethodProps> = React.memo(
           />
         )}
 
+        <ToggleSwitch
+          checked={alwaysCopyResult}
+          onChange={(enabled) => updateSetting("always_copy_result", enabled)}
+          isUpdating={isUpdating("always_copy_result")}
+          label={t("settings.advanced.pasteMethod.alwaysCopyResult.label")}
+          description={t("settings.advanced.pasteMethod.alwaysCopyResult.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+
         <TellMeMore title={t("settings.advanced.pasteMethod.tellMeMore.title")}>
           <div className="space-y-3">
             <p className="mb-2">
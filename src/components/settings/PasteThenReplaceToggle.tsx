@@ -0,0 +1,29 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface PasteThenReplaceToggleProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PasteThenReplaceToggle: React.FC<PasteThenReplaceToggleProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = (getSetting("post_process_paste_then_replace") as boolean) ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(enabled) => updateSetting("post_process_paste_then_replace", enabled)}
+        isUpdating={isUpdating("post_process_paste_then_replace")}
+        label={t("settings.postProcessing.pasteThenReplace.label")}
+        description={t("settings.postProcessing.pasteThenReplace.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
@@ -1,9 +1,12 @@
 import React from "react";
 import { useTranslation } from "react-i18next";
-import { RefreshCcw } from "lucide-react";
+import { RefreshCcw, RotateCcw } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
 
+import { useSettings } from "../../../hooks/useSettings";
 import { SettingContainer } from "../../ui/SettingContainer";
 import { ResetButton } from "../../ui/ResetButton";
+import { ToggleSwitch } from "../../ui/ToggleSwitch";
 import { ProviderSelect } from "./ProviderSelect";
 import { BaseUrlField } from "./BaseUrlField";
 import { ApiKeyField } from "./ApiKeyField";
@@ -41,6 +44,26 @@ export const LlmConfigSection: React.FC<LlmConfigSectionProps> = ({
   sameAsSummary,
 }) => {
   const { t } = useTranslation();
+  const { updateSetting, isUpdating, refreshSettings } = useSettings();
+
+  // Only the post-processing provider state exposes the unlock (AI Replace hides the base URL)
+  const supportsUnlock = state.isBaseUrlEditable !== undefined;
+  const isBaseUrlEditable =
+    state.isBaseUrlEditable ?? state.selectedProvider?.id === "custom";
+  const isBuiltinBaseUrl =
+    isBaseUrlEditable && !state.selectedProvider?.allow_base_url_edit;
+
+  const handleResetBaseUrl = async () => {
+    if (!state.selectedProvider) return;
+    try {
+      await invoke("reset_post_process_base_url", {
+        providerId: state.selectedProvider.id,
+      });
+      await refreshSettings();
+    } catch (error) {
+      console.error("Failed to reset base URL:", error);
+    }
+  };
 
   return (
     <div className="space-y-4 pt-4">
@@ -108,7 +131,7 @@ export const LlmConfigSection: React.FC<LlmConfigSectionProps> = ({
             </SettingContainer>
           ) : (
             <>
-              {showBaseUrl && state.selectedProvider?.id === "custom" && (
+              {showBaseUrl && isBaseUrlEditable && (
                 <SettingContainer
                   title={t("settings.postProcessing.api.baseUrl.title")}
                   description={t(
@@ -128,10 +151,48 @@ export const LlmConfigSection: React.FC<LlmConfigSectionProps> = ({
                       disabled={state.isBaseUrlUpdating}
                       className="min-w-[380px]"
                     />
+                    {isBuiltinBaseUrl && (
+                      <ResetButton
+                        onClick={handleResetBaseUrl}
+                        disabled={state.isBaseUrlUpdating}
+                        ariaLabel={t(
+                          "settings.postProcessing.api.baseUrl.reset",
+                        )}
+                      >
+                        <RotateCcw className="h-4 w-4" />
+                      </ResetButton>
+                    )}
                   </div>
                 </SettingContainer>
               )}
 
+              {showBaseUrl && isBuiltinBaseUrl && (
+                <div className="px-6 pb-2">
+                  <DisabledNotice>
+                    {t("settings.postProcessing.api.baseUrl.builtinWarning")}
+                  </DisabledNotice>
+                </div>
+              )}
+
+              {showBaseUrl && supportsUnlock && (
+                <ToggleSwitch
+                  checked={state.baseUrlsUnlocked}
+                  onChange={(enabled) =>
+                    void updateSetting(
+                      "unlock_builtin_provider_base_urls",
+                      enabled,
+                    )
+                  }
+                  isUpdating={isUpdating("unlock_builtin_provider_base_urls")}
+                  label={t("settings.postProcessing.api.baseUrl.unlock.title")}
+                  description={t(
+                    "settings.postProcessing.api.baseUrl.unlock.description",
+                  )}
+                  descriptionMode="tooltip"
+                  grouped={true}
+                />
+              )}
+
               <SettingContainer
                 title={t("settings.postProcessing.api.apiKey.title")}
                 description={t(
@@ -13,6 +13,9 @@ type PostProcessProviderState = {
   isAppleProvider: boolean;
   appleIntelligenceUnavailable: boolean;
   baseUrl: string;
+  /** Custom provider, or any HTTP provider when built-in base URLs are unlocked */
+  isBaseUrlEditable: boolean;
+  baseUrlsUnlocked: boolean;
   handleBaseUrlChange: (value: string) => void;
   isBaseUrlUpdating: boolean;
   apiKey: string;
@@ -65,6 +68,11 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
 
   // Use settings directly as single source of truth
   const baseUrl = selectedProvider?.base_url ?? "";
+  const baseUrlsUnlocked =
+    settings?.unlock_builtin_provider_base_urls ?? false;
+  const isBaseUrlEditable =
+    !!selectedProvider?.allow_base_url_edit ||
+    (baseUrlsUnlocked && !!selectedProvider && !isAppleProvider);
   const apiKey = settings?.post_process_api_keys?.[selectedProviderId] ?? "";
   const model = settings?.post_process_models?.[selectedProviderId] ?? "";
 
@@ -99,7 +107,7 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
 
   const handleBaseUrlChange = useCallback(
     (value: string) => {
-      if (!selectedProvider || selectedProvider.id !== "custom") {
+      if (!selectedProvider || !isBaseUrlEditable) {
         return;
       }
       const trimmed = value.trim();
@@ -107,7 +115,7 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
         void updatePostProcessBaseUrl(selectedProvider.id, trimmed);
       }
     },
-    [selectedProvider, baseUrl, updatePostProcessBaseUrl],
+    [selectedProvider, isBaseUrlEditable, baseUrl, updatePostProcessBaseUrl],
   );
 
   const handleApiKeyChange = useCallback(
@@ -199,6 +207,8 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
     isAppleProvider,
     appleIntelligenceUnavailable,
     baseUrl,
+    isBaseUrlEditable,
+    baseUrlsUnlocked,
     handleBaseUrlChange,
     isBaseUrlUpdating,
     apiKey,
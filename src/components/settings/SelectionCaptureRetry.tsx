@@ -0,0 +1,64 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface SelectionCaptureRetryProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SelectionCaptureRetrySetting: React.FC<SelectionCaptureRetryProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const retries = (getSetting("selection_capture_retries") ?? 3) as number;
+    const delayMs = (getSetting("selection_capture_retry_delay_ms") ?? 50) as number;
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.selectionCaptureRetry.title")}
+        description={t("settings.advanced.selectionCaptureRetry.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center space-x-2">
+          <Input
+            type="number"
+            min="0"
+            max="20"
+            value={retries}
+            onChange={(e) => {
+              const val = parseInt(e.target.value, 10);
+              if (!isNaN(val) && val >= 0) {
+                updateSetting("selection_capture_retries", val);
+              }
+            }}
+            disabled={isUpdating("selection_capture_retries")}
+            className="w-16"
+          />
+          <span className="text-sm text-text">
+            {t("settings.advanced.selectionCaptureRetry.retries")}
+          </span>
+          <Input
+            type="number"
+            min="0"
+            max="1000"
+            step="10"
+            value={delayMs}
+            onChange={(e) => {
+              const val = parseInt(e.target.value, 10);
+              if (!isNaN(val) && val >= 0) {
+                updateSetting("selection_capture_retry_delay_ms", val);
+              }
+            }}
+            disabled={isUpdating("selection_capture_retry_delay_ms")}
+            className="w-20"
+          />
+          <span className="text-sm text-text">ms</span>
+        </div>
+      </SettingContainer>
+    );
+  });
@@ -0,0 +1,29 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface StripLlmFormattingToggleProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const StripLlmFormattingToggle: React.FC<StripLlmFormattingToggleProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = (getSetting("strip_llm_formatting") as boolean) ?? true;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(enabled) => updateSetting("strip_llm_formatting", enabled)}
+        isUpdating={isUpdating("strip_llm_formatting")}
+        label={t("settings.postProcessing.stripLlmFormatting.label")}
+        description={t("settings.postProcessing.stripLlmFormatting.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
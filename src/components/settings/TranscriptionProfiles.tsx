@@ -10,7 +10,11 @@ import {
   RefreshCw,
   RefreshCcw,
 } from "lucide-react";
-import { commands, TranscriptionProfile } from "@/bindings";
+import {
+  commands,
+  ProfileSwitchDuringRecording,
+  TranscriptionProfile,
+} from "@/bindings";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 
@@ -52,6 +56,7 @@ interface ExtendedTranscriptionProfile extends TranscriptionProfile {
   include_in_cycle: boolean;
   push_to_talk: boolean;
   stt_prompt_override_enabled: boolean;
+  vad_threshold?: number | null;
 }
 
 interface ProfileCardProps {
@@ -91,6 +96,7 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
   defaultLlmPrompt,
 }) => {
   const { t } = useTranslation();
+  const { settings } = useSettings();
   const [isUpdating, setIsUpdating] = useState(false);
   // Local state for LLM prompt - initializes with override or default
   const [localLlmPrompt, setLocalLlmPrompt] = useState(
@@ -176,6 +182,15 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
     }
   };
 
+  const handleVadThresholdChange = async (newValue: number | null) => {
+    setIsUpdating(true);
+    try {
+      await onUpdate({ ...profile, vad_threshold: newValue });
+    } finally {
+      setIsUpdating(false);
+    }
+  };
+
   const handleSttPromptOverrideChange = async (newValue: boolean) => {
     setIsUpdating(true);
     try {
@@ -355,6 +370,46 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
                 </span>
               </div>
             </div>
+
+            <div className="min-w-0 md:col-span-2">
+              <label className="text-xs font-semibold text-text/70 block mb-2">
+                {t("settings.transcriptionProfiles.vadThreshold.label")}
+              </label>
+              <div className="flex items-center gap-2">
+                <input
+                  type="checkbox"
+                  checked={profile.vad_threshold != null}
+                  onChange={(e) =>
+                    handleVadThresholdChange(
+                      e.target.checked ? (settings?.vad_threshold ?? 0.3) : null,
+                    )
+                  }
+                  disabled={isUpdating}
+                  className="w-4 h-4 rounded border-mid-gray bg-background text-purple-500 focus:ring-purple-500/50"
+                />
+                {profile.vad_threshold != null ? (
+                  <>
+                    <input
+                      type="range"
+                      min="0.1"
+                      max="0.9"
+                      step="0.1"
+                      value={profile.vad_threshold}
+                      onChange={(e) => handleVadThresholdChange(parseFloat(e.target.value))}
+                      disabled={isUpdating}
+                      className="flex-1 accent-purple-500"
+                    />
+                    <span className="text-xs font-mono text-text/70 w-8 text-right">
+                      {profile.vad_threshold.toFixed(1)}
+                    </span>
+                  </>
+                ) : (
+                  <span className="text-xs text-mid-gray leading-snug">
+                    {t("settings.transcriptionProfiles.vadThreshold.inherit")}
+                  </span>
+                )}
+              </div>
+            </div>
           </div>
 
           <div className="grid gap-3 lg:grid-cols-2">
@@ -654,6 +709,8 @@ export const TranscriptionProfiles: React.FC = () => {
   const activeProfileId = (settings as any)?.active_profile_id || "default";
   const overlayEnabled =
     (settings as any)?.profile_switch_overlay_enabled ?? true;
+  const switchDuringRecording: string =
+    settings?.profile_switch_during_recording ?? "next_recording";
 
   // Listen for active profile changes from shortcuts
   useEffect(() => {
@@ -796,6 +853,7 @@ export const TranscriptionProfiles: React.FC = () => {
           promptOverride: profile.llm_prompt_override ?? null,
           modelOverride: profile.llm_model_override ?? null,
         },
+        vadThreshold: profile.vad_threshold ?? null,
       });
       await refreshSettings();
     } catch (error) {
@@ -833,6 +891,15 @@ export const TranscriptionProfiles: React.FC = () => {
     }
   };
 
+  const handleSwitchDuringRecordingChange = async (behavior: string) => {
+    if (updateSetting) {
+      await updateSetting(
+        "profile_switch_during_recording",
+        behavior as ProfileSwitchDuringRecording,
+      );
+    }
+  };
+
   return (
     <SettingsGroup title={t("settings.transcriptionProfiles.title")}>
       {/* Help text */}
@@ -929,6 +996,34 @@ export const TranscriptionProfiles: React.FC = () => {
               />
             </div>
           </div>
+
+          {/* Switching while recording */}
+          <div className="grid gap-2 sm:grid-cols-[minmax(0,1fr)_auto] sm:items-center">
+            <div className="flex flex-col min-w-0">
+              <span className="text-sm font-medium">
+                {t("settings.transcriptionProfiles.switchDuringRecording")}
+              </span>
+              <span className="text-xs text-mid-gray leading-snug">
+                {t("settings.transcriptionProfiles.switchDuringRecordingDescription")}
+              </span>
+            </div>
+            <div className="shrink-0">
+              <Dropdown
+                options={[
+                  {
+                    value: "next_recording",
+                    label: t("settings.transcriptionProfiles.switchDuringRecordingNext"),
+                  },
+                  {
+                    value: "reject",
+                    label: t("settings.transcriptionProfiles.switchDuringRecordingReject"),
+                  },
+                ]}
+                selectedValue={switchDuringRecording}
+                onSelect={handleSwitchDuringRecordingChange}
+              />
+            </div>
+          </div>
         </div>
       </SettingContainer>
 
@@ -1067,6 +1162,15 @@ export const TranscriptionProfiles: React.FC = () => {
                         {t("settings.general.pushToTalk.description")}
                       </span>
                     </div>
+                    <div className="flex items-center justify-between gap-2">
+                      <span className="text-xs text-mid-gray leading-snug">
+                        {t(
+                          "settings.general.pushToTalk.toggleShortcut",
+                          "Shortcut to switch modes on the fly",
+                        )}
+                      </span>
+                      <HandyShortcut shortcutId="toggle_ptt_mode" />
+                    </div>
                   </div>
                 </div>
 
@@ -1,12 +1,15 @@
 import React from "react";
 import { useTranslation } from "react-i18next";
 import { ShowOverlay } from "../ShowOverlay";
+import { OverlayMonitorSetting } from "../OverlayMonitor";
 import { ModelUnloadTimeoutSetting } from "../ModelUnloadTimeout";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { StartHidden } from "../StartHidden";
 import { AutostartToggle } from "../AutostartToggle";
 import { PasteMethodSetting } from "../PasteMethod";
+import { PasteDelaySetting } from "../PasteDelay";
 import { ClipboardHandlingSetting } from "../ClipboardHandling";
+import { SelectionCaptureRetrySetting } from "../SelectionCaptureRetry";
 import { RemoteSttSettings } from "../remote-stt/RemoteSttSettings";
 import { TellMeMore } from "../../ui/TellMeMore";
 
@@ -79,8 +82,11 @@ export const AdvancedSettings: React.FC = () => {
         <StartHidden descriptionMode="tooltip" grouped={true} />
         <AutostartToggle descriptionMode="tooltip" grouped={true} />
         <ShowOverlay descriptionMode="tooltip" grouped={true} />
+        <OverlayMonitorSetting descriptionMode="tooltip" grouped={true} />
         <PasteMethodSetting descriptionMode="tooltip" grouped={true} />
+        <PasteDelaySetting descriptionMode="tooltip" grouped={true} />
         <ClipboardHandlingSetting descriptionMode="tooltip" grouped={true} />
+        <SelectionCaptureRetrySetting descriptionMode="tooltip" grouped={true} />
         <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
         <RemoteSttSettings descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
@@ -1,8 +1,10 @@
 import React from "react";
 import { useTranslation, Trans } from "react-i18next";
 import { Info, Wand2 } from "lucide-react";
+import type { AiReplaceOutputMode } from "@/bindings";
 import { useSettings } from "../../../hooks/useSettings";
 import { HandyShortcut } from "../HandyShortcut";
+import { Dropdown } from "../../ui/Dropdown";
 import { Input } from "../../ui/Input";
 import { SettingContainer } from "../../ui/SettingContainer";
 import { SettingsGroup } from "../../ui/SettingsGroup";
@@ -99,6 +101,25 @@ export const AiReplaceSelectionSettings: React.FC = () => {
             disabled={isUpdating("ai_replace_selection_push_to_talk")}
           />
         </SettingContainer>
+        <SettingContainer
+          title={t("settings.aiReplace.outputMode.title")}
+          description={t("settings.aiReplace.outputMode.description")}
+          descriptionMode="tooltip"
+          grouped={true}
+        >
+          <Dropdown
+            options={[
+              { value: "replace", label: t("settings.aiReplace.outputMode.options.replace") },
+              { value: "clipboard", label: t("settings.aiReplace.outputMode.options.clipboard") },
+              { value: "both", label: t("settings.aiReplace.outputMode.options.both") },
+            ]}
+            selectedValue={getSetting("ai_replace_output_mode") ?? "replace"}
+            onSelect={(value) =>
+              void updateSetting("ai_replace_output_mode", value as AiReplaceOutputMode)
+            }
+            disabled={isUpdating("ai_replace_output_mode")}
+          />
+        </SettingContainer>
       </SettingsGroup>
 
       <SettingsGroup 
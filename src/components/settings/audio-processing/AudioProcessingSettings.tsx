@@ -8,6 +8,8 @@ export const AudioProcessingSettings: React.FC = () => {
   const { t } = useTranslation();
   const { settings, updateSetting, isUpdating } = useSettings();
 
+  const meterHz = (settings?.audio_meter_hz ?? 20) as number;
+
   const handleResetVad = () => {
     updateSetting("vad_threshold", 0.3);
   };
@@ -114,6 +116,34 @@ export const AudioProcessingSettings: React.FC = () => {
             </div>
           </details>
         </div>
+
+        {/* Audio level meter rate */}
+        <div className="px-4 py-4 border-t border-white/[0.05]">
+          <div className="flex items-center justify-between mb-2">
+            <label className="text-sm text-[#f5f5f5]">
+              {t("audioProcessing.meterRate", "Audio Meter Update Rate")}
+            </label>
+            <span className="text-sm text-[#9b5de5] font-mono">
+              {meterHz} Hz
+            </span>
+          </div>
+          <input
+            type="range"
+            min="1"
+            max="60"
+            step="1"
+            value={meterHz}
+            onChange={(e) => updateSetting("audio_meter_hz", parseInt(e.target.value, 10))}
+            className="w-full h-2 bg-[#252525] rounded-lg appearance-none cursor-pointer accent-[#9b5de5]"
+            disabled={isUpdating("audio_meter_hz")}
+          />
+          <p className="text-xs text-[#606060] mt-2">
+            {t(
+              "audioProcessing.meterRateDescription",
+              "How often the recording overlay's level meter updates. Lower it on low-power devices."
+            )}
+          </p>
+        </div>
       </SettingsGroup>
     </div>
   );
@@ -2,7 +2,15 @@ import React, { useState, useEffect, useCallback } from "react";
 import { useTranslation } from "react-i18next";
 import { listen } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/core";
-import { Wifi, WifiOff, Server, AlertTriangle, Copy, Check } from "lucide-react";
+import {
+  Wifi,
+  WifiOff,
+  Server,
+  AlertTriangle,
+  Copy,
+  Check,
+  Stethoscope,
+} from "lucide-react";
 import { SettingContainer } from "../../ui/SettingContainer";
 
 // Types matching Rust backend
@@ -16,6 +24,24 @@ interface ConnectorStatusResponse {
   server_error: string | null;
 }
 
+interface DiagnosisItem {
+  check: string;
+  level: "pass" | "warn" | "fail";
+  message: string;
+  hint: string | null;
+}
+
+interface ConnectorDiagnosis {
+  items: DiagnosisItem[];
+  ok: boolean;
+}
+
+const DIAGNOSIS_LEVEL_CLASS: Record<DiagnosisItem["level"], string> = {
+  pass: "text-green-400",
+  warn: "text-yellow-400",
+  fail: "text-red-400",
+};
+
 interface ConnectorStatusIndicatorProps {
   grouped?: boolean;
   descriptionMode?: "inline" | "tooltip" | "none";
@@ -54,6 +80,7 @@ export const ConnectorStatusIndicator: React.FC<ConnectorStatusIndicatorProps> =
   const [status, setStatus] = useState<ConnectorStatusResponse | null>(null);
   const [lastSeenText, setLastSeenText] = useState<string>("");
   const [errorCopied, setErrorCopied] = useState(false);
+  const [diagnosis, setDiagnosis] = useState<ConnectorDiagnosis | null>(null);
 
   // Fetch status from backend
   const fetchStatus = useCallback(async () => {
@@ -113,6 +140,14 @@ export const ConnectorStatusIndicator: React.FC<ConnectorStatusIndicatorProps> =
     };
   }, [fetchStatus]);
 
+  const runDiagnosis = async () => {
+    try {
+      setDiagnosis(await invoke<ConnectorDiagnosis>("diagnose_connector"));
+    } catch (error) {
+      console.error("Failed to diagnose connector:", error);
+    }
+  };
+
   // Copy error to clipboard
   const handleCopyError = () => {
     if (status?.server_error) {
@@ -209,8 +244,36 @@ export const ConnectorStatusIndicator: React.FC<ConnectorStatusIndicatorProps> =
               {t("settings.browserConnector.status.lastSeen", { time: lastSeenText })}
             </span>
           )}
+
+          <button
+            onClick={runDiagnosis}
+            className="flex items-center gap-1 px-2 py-1 rounded text-xs text-text/60 hover:text-text hover:bg-mid-gray/20 transition-colors"
+            title={t("settings.browserConnector.status.diagnoseTitle")}
+          >
+            <Stethoscope className="w-3.5 h-3.5" />
+            {t("settings.browserConnector.status.diagnose")}
+          </button>
         </div>
 
+        {diagnosis && (
+          <div className="flex flex-col gap-1 p-2 rounded border border-mid-gray/30 text-xs">
+            <div className="font-medium">
+              {diagnosis.ok
+                ? t("settings.browserConnector.status.diagnosisOk")
+                : t("settings.browserConnector.status.diagnosisFailed")}
+            </div>
+            {diagnosis.items.map((item) => (
+              <div key={item.check}>
+                <span className={`font-medium uppercase ${DIAGNOSIS_LEVEL_CLASS[item.level]}`}>
+                  {item.level}
+                </span>{" "}
+                <span className="text-text/80">{item.message}</span>
+                {item.hint && <div className="text-text/50 italic">{item.hint}</div>}
+              </div>
+            ))}
+          </div>
+        )}
+
         {/* Show server error if present */}
         {status?.server_error && (
           <div className="flex flex-col gap-1.5 p-2 rounded border border-red-500/30 bg-red-500/10">
@@ -1,7 +1,6 @@
 import React, { useState, useEffect } from "react";
 import { useTranslation } from "react-i18next";
 import { invoke } from "@tauri-apps/api/core";
-import { relaunch } from "@tauri-apps/plugin-process";
 import { AlertTriangle, Info, RefreshCw, CheckCircle } from "lucide-react";
 import { SettingContainer } from "../../ui/SettingContainer";
 import { TellMeMore } from "../../ui/TellMeMore";
@@ -102,7 +101,7 @@ export const ShortcutEngineSelector: React.FC = () => {
   const handleRestart = async () => {
     setShowRestartConfirm(false);
     try {
-      await relaunch();
+      await invoke("restart_app");
     } catch (err) {
       console.error("Failed to restart app:", err);
       setError(`Failed to restart: ${err}`);
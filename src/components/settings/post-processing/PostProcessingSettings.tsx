@@ -12,6 +12,9 @@ import { Input } from "../../ui/Input";
 import { Dropdown } from "../../ui/Dropdown";
 import { Textarea } from "../../ui/Textarea";
 import { PostProcessingToggle } from "../PostProcessingToggle";
+import { StripLlmFormattingToggle } from "../StripLlmFormattingToggle";
+import { PasteThenReplaceToggle } from "../PasteThenReplaceToggle";
+import { LlmMaxConcurrentRequestsSetting } from "../LlmMaxConcurrentRequests";
 import { ProviderSelect } from "../PostProcessingSettingsApi/ProviderSelect";
 import { BaseUrlField } from "../PostProcessingSettingsApi/BaseUrlField";
 import { ApiKeyField } from "../PostProcessingSettingsApi/ApiKeyField";
@@ -381,6 +384,9 @@ export const PostProcessingSettings: React.FC = () => {
 
       <SettingsGroup title={t("settings.postProcessing.prompts.title")}>
         <PostProcessingToggle descriptionMode="inline" grouped={true} />
+        <StripLlmFormattingToggle descriptionMode="tooltip" grouped={true} />
+        <PasteThenReplaceToggle descriptionMode="tooltip" grouped={true} />
+        <LlmMaxConcurrentRequestsSetting descriptionMode="tooltip" grouped={true} />
         <PostProcessingSettingsPrompts />
       </SettingsGroup>
 
@@ -1,5 +1,6 @@
 import React, { useEffect, useMemo, useState } from "react";
 import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { type } from "@tauri-apps/plugin-os";
 import { toast } from "sonner";
@@ -50,6 +51,7 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
   const [hasKeyStatusLoaded, setHasKeyStatusLoaded] = useState(false);
 
   const [debugLines, setDebugLines] = useState<string[]>([]);
+  const [lastExchange, setLastExchange] = useState<string | null>(null);
   const [connectionStatus, setConnectionStatus] = useState<
     "idle" | "checking" | "success" | "error"
   >("idle");
@@ -269,6 +271,20 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
       console.error("Failed to clear remote debug log:", error);
     } finally {
       setDebugLines([]);
+      setLastExchange(null);
+    }
+  };
+
+  const handleShowLastExchange = async () => {
+    try {
+      const data = await invoke<unknown | null>("get_remote_stt_debug");
+      setLastExchange(
+        data
+          ? JSON.stringify(data, null, 2)
+          : t("settings.advanced.remoteStt.debug.output.noExchange"),
+      );
+    } catch (error) {
+      console.error("Failed to load remote debug capture:", error);
     }
   };
 
@@ -501,7 +517,22 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
                 readOnly
                 className="min-h-[160px] max-h-[300px] overflow-y-auto font-mono text-xs"
               />
-              <div className="flex justify-end">
+              {lastExchange && (
+                <Textarea
+                  value={lastExchange}
+                  readOnly
+                  className="min-h-[160px] max-h-[300px] overflow-y-auto font-mono text-xs"
+                />
+              )}
+              <div className="flex justify-end gap-2">
+                <Button
+                  variant="secondary"
+                  size="sm"
+                  onClick={handleShowLastExchange}
+                  disabled={!debugCapture}
+                >
+                  {t("settings.advanced.remoteStt.debug.output.lastExchange")}
+                </Button>
                 <Button
                   variant="secondary"
                   size="sm"
@@ -1,11 +1,14 @@
 import React, { useState } from "react";
 import { useTranslation } from "react-i18next";
 import { Plus, Trash2, ArrowRight, HelpCircle, ChevronDown, ChevronUp, CaseSensitive, Regex, Check, X } from "lucide-react";
+import type { OutputCaseTransform } from "@/bindings";
 import { useSettings } from "@/hooks/useSettings";
 import { SettingsGroup } from "@/components/ui/SettingsGroup";
 import { Button } from "@/components/ui/Button";
 import { Input } from "@/components/ui/Input";
 import { ToggleSwitch } from "@/components/ui/ToggleSwitch";
+import { Dropdown } from "@/components/ui/Dropdown";
+import { SettingContainer } from "@/components/ui/SettingContainer";
 import { CustomWords } from "@/components/settings/CustomWords";
 import { Slider } from "@/components/ui/Slider";
 import { TellMeMore } from "@/components/ui/TellMeMore";
@@ -17,8 +20,17 @@ interface TextReplacementRule {
   enabled: boolean;
   case_sensitive: boolean;
   is_regex: boolean;
+  fuzzy: boolean;
+  fuzzy_max_distance: number;
+  group: string | null;
+  phase: TextReplacementPhase | null;
 }
 
+type TextReplacementPhase = "before_llm" | "after_llm" | "both";
+
+// Clicking the phase badge cycles through these; null follows the global setting
+const PHASE_CYCLE: (TextReplacementPhase | null)[] = [null, "before_llm", "after_llm", "both"];
+
 export const TextReplacementSettings: React.FC = () => {
   const { t } = useTranslation();
   const { settings, updateSetting, isUpdating } = useSettings();
@@ -27,6 +39,8 @@ export const TextReplacementSettings: React.FC = () => {
   const [newTo, setNewTo] = useState("");
   const [newCaseSensitive, setNewCaseSensitive] = useState(true);
   const [newIsRegex, setNewIsRegex] = useState(false);
+  const [newFuzzy, setNewFuzzy] = useState(false);
+  const [newGroup, setNewGroup] = useState("");
   const [showHelp, setShowHelp] = useState(false);
   
   // Editing state
@@ -38,8 +52,25 @@ export const TextReplacementSettings: React.FC = () => {
     ...r,
     case_sensitive: r.case_sensitive ?? true,
     is_regex: r.is_regex ?? false,
+    fuzzy: r.fuzzy ?? false,
+    fuzzy_max_distance: r.fuzzy_max_distance ?? 2,
+    group: r.group ?? null,
+    phase: r.phase ?? null,
   }));
   const isEnabled = settings?.text_replacements_enabled ?? false;
+  const groupsEnabled: Record<string, boolean> =
+    settings?.text_replacement_groups_enabled ?? {};
+  const groups = Array.from(
+    new Set(replacements.map((r) => r.group).filter((g): g is string => !!g))
+  );
+  const isGroupEnabled = (group: string | null) => !group || (groupsEnabled[group] ?? true);
+
+  const handleToggleGroup = (group: string) => {
+    updateSetting("text_replacement_groups_enabled", {
+      ...groupsEnabled,
+      [group]: !isGroupEnabled(group),
+    });
+  };
 
   const handleAddRule = () => {
     if (!newFrom.trim()) return;
@@ -51,6 +82,9 @@ export const TextReplacementSettings: React.FC = () => {
       enabled: true,
       case_sensitive: newCaseSensitive,
       is_regex: newIsRegex,
+      fuzzy: newFuzzy && !newIsRegex,
+      fuzzy_max_distance: 2,
+      group: newGroup.trim() || null,
     };
 
     updateSetting("text_replacements", [...replacements, newRule]);
@@ -92,6 +126,26 @@ export const TextReplacementSettings: React.FC = () => {
     );
   };
 
+  const handleToggleFuzzy = (id: string) => {
+    updateSetting(
+      "text_replacements",
+      replacements.map((r) =>
+        r.id === id ? { ...r, fuzzy: !r.fuzzy } : r
+      )
+    );
+  };
+
+  const handleCyclePhase = (id: string) => {
+    updateSetting(
+      "text_replacements",
+      replacements.map((r) => {
+        if (r.id !== id) return r;
+        const next = PHASE_CYCLE[(PHASE_CYCLE.indexOf(r.phase) + 1) % PHASE_CYCLE.length];
+        return { ...r, phase: next };
+      })
+    );
+  };
+
   const startEditing = (rule: TextReplacementRule) => {
     setEditingId(rule.id);
     setEditFrom(rule.from);
@@ -189,6 +243,39 @@ export const TextReplacementSettings: React.FC = () => {
           </div>
         )}
 
+        {/* Output Casing - applied after replacements, right before paste */}
+        <div className="border-t border-white/[0.05]">
+          <SettingContainer
+            title={t("textReplacement.outputCase.title")}
+            description={t("textReplacement.outputCase.description")}
+            descriptionMode="inline"
+            grouped={true}
+          >
+            <Dropdown
+              options={[
+                { value: "none", label: t("textReplacement.outputCase.options.none") },
+                { value: "lower", label: t("textReplacement.outputCase.options.lower") },
+                { value: "upper", label: t("textReplacement.outputCase.options.upper") },
+                { value: "title", label: t("textReplacement.outputCase.options.title") },
+              ]}
+              selectedValue={settings?.output_case_transform ?? "none"}
+              onSelect={(value) =>
+                updateSetting("output_case_transform", value as OutputCaseTransform)
+              }
+              disabled={isUpdating("output_case_transform")}
+            />
+          </SettingContainer>
+          <ToggleSwitch
+            checked={settings?.auto_capitalize_sentences ?? false}
+            onChange={(enabled) => updateSetting("auto_capitalize_sentences", enabled)}
+            isUpdating={isUpdating("auto_capitalize_sentences")}
+            label={t("textReplacement.capitalizeSentences.label")}
+            description={t("textReplacement.capitalizeSentences.description")}
+            descriptionMode="inline"
+            grouped={true}
+          />
+        </div>
+
         {/* Help Section */}
         <div className="px-4 py-3 border-t border-white/[0.05]">
           <button
@@ -295,6 +382,12 @@ export const TextReplacementSettings: React.FC = () => {
                     <strong>{t("textReplacement.regexTitle", "Regular Expression")}</strong> — {t("textReplacement.regexDesc", "Enable to use regex patterns for advanced matching. Use $1, $2 in replacement for capture groups.")}
                   </span>
                 </li>
+                <li className="flex items-start gap-2">
+                  <span className="w-4 mt-0.5 text-center text-[#4ade80] shrink-0">≈</span>
+                  <span>
+                    <strong>{t("textReplacement.fuzzyTitle", "Fuzzy Matching")}</strong> — {t("textReplacement.fuzzyDesc", "Also match close spellings and spacing of the whole phrase, ignoring case. Not available for regex rules.")}
+                  </span>
+                </li>
               </ul>
 
               <h4 className="font-medium text-[#f5f5f5] mt-4 mb-2">
@@ -401,6 +494,28 @@ export const TextReplacementSettings: React.FC = () => {
                 <Regex className="w-3.5 h-3.5" />
                 {t("textReplacement.regexShort", ".*")}
               </button>
+              <button
+                onClick={() => setNewFuzzy(!newFuzzy)}
+                disabled={newIsRegex}
+                className={`flex items-center gap-1.5 px-2 py-1 rounded text-xs transition-colors ${
+                  newFuzzy && !newIsRegex
+                    ? "bg-[#4ade80]/20 text-[#4ade80] border border-[#4ade80]/30"
+                    : "bg-[#252525] text-[#606060] border border-[#333333]"
+                }`}
+                title={t("textReplacement.fuzzyTooltip", "Toggle fuzzy matching")}
+              >
+                {t("textReplacement.fuzzyShort", "≈")}
+              </button>
+              <Input
+                type="text"
+                className="w-32"
+                value={newGroup}
+                onChange={(e) => setNewGroup(e.target.value)}
+                onKeyDown={handleKeyPress}
+                placeholder={t("textReplacement.groups.placeholder")}
+                variant="compact"
+                disabled={isUpdating("text_replacements")}
+              />
             </div>
             <Button
               onClick={handleAddRule}
@@ -414,6 +529,29 @@ export const TextReplacementSettings: React.FC = () => {
           </div>
         </div>
 
+        {/* Groups */}
+        {groups.length > 0 && (
+          <div className="px-4 py-3 border-t border-white/[0.05]">
+            <div className="text-xs text-[#808080] mb-2">
+              {t("textReplacement.groups.title")}
+            </div>
+            <div className="flex flex-wrap gap-3">
+              {groups.map((group) => (
+                <label key={group} className="flex items-center gap-2 text-sm text-[#f5f5f5]">
+                  <input
+                    type="checkbox"
+                    checked={isGroupEnabled(group)}
+                    onChange={() => handleToggleGroup(group)}
+                    className="accent-[#9b5de5] w-4 h-4 rounded"
+                    disabled={isUpdating("text_replacement_groups_enabled")}
+                  />
+                  {group}
+                </label>
+              ))}
+            </div>
+          </div>
+        )}
+
         {/* Rules List */}
         {replacements.length > 0 && (
           <div className="px-4 py-3 border-t border-white/[0.05]">
@@ -422,7 +560,7 @@ export const TextReplacementSettings: React.FC = () => {
                 <div
                   key={rule.id}
                   className={`p-3 rounded-lg border transition-all ${
-                    rule.enabled
+                    rule.enabled && isGroupEnabled(rule.group)
                       ? "bg-[#1a1a1a] border-[#333333]"
                       : "bg-[#0f0f0f] border-[#252525] opacity-60"
                   }`}
@@ -568,6 +706,32 @@ export const TextReplacementSettings: React.FC = () => {
                       >
                         <Regex className="w-3 h-3" />
                       </button>
+                      {!rule.is_regex && (
+                        <button
+                          onClick={() => handleToggleFuzzy(rule.id)}
+                          disabled={isUpdating("text_replacements")}
+                          className={`flex items-center gap-1 px-1.5 py-0.5 rounded text-xs transition-colors ${
+                            rule.fuzzy
+                              ? "bg-[#4ade80]/20 text-[#4ade80]"
+                              : "bg-[#252525] text-[#606060]"
+                          }`}
+                          title={t("textReplacement.fuzzyTooltip", "Toggle fuzzy matching")}
+                        >
+                          {t("textReplacement.fuzzyShort", "≈")}
+                        </button>
+                      )}
+                      <button
+                        onClick={() => handleCyclePhase(rule.id)}
+                        disabled={isUpdating("text_replacements")}
+                        className={`flex items-center gap-1 px-1.5 py-0.5 rounded text-xs transition-colors ${
+                          rule.phase
+                            ? "bg-[#38bdf8]/20 text-[#38bdf8]"
+                            : "bg-[#252525] text-[#606060]"
+                        }`}
+                        title={t("textReplacement.phase.tooltip")}
+                      >
+                        {t(`textReplacement.phase.${rule.phase ?? "default"}`)}
+                      </button>
                     </div>
                   )}
                 </div>
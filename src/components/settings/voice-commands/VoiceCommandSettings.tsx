@@ -693,6 +693,39 @@ export default function VoiceCommandSettings() {
                     </>
                   )}
 
+                  <div className="setting-row llm-api-row">
+                    <div className="setting-label">
+                      <span>
+                        {t(
+                          "voiceCommands.structuredOutput",
+                          "Structured output (JSON)",
+                        )}
+                      </span>
+                      <span className="setting-sublabel">
+                        {t(
+                          "voiceCommands.structuredOutputDesc",
+                          "Ask the LLM for a JSON reply with the command, a safety flag and a short explanation",
+                        )}
+                      </span>
+                    </div>
+                    <label className="toggle-switch">
+                      <input
+                        type="checkbox"
+                        checked={
+                          settings.voice_command_structured_output ??
+                          false
+                        }
+                        onChange={(e) =>
+                          updateSetting(
+                            "voice_command_structured_output",
+                            e.target.checked,
+                          )
+                        }
+                      />
+                      <span className="slider"></span>
+                    </label>
+                  </div>
+
                   <div className="llm-api-extended">
                     <ExtendedThinkingSection
                       settingPrefix="voice_command"
@@ -1134,6 +1167,35 @@ export default function VoiceCommandSettings() {
             )}
           </div>
 
+          <div className="setting-row">
+            <div className="setting-label">
+              <span>
+                {t("voiceCommands.metaCommands", "Profile & prompt switching")}
+              </span>
+              <span className="setting-sublabel">
+                {t(
+                  "voiceCommands.metaCommandsDesc",
+                  'Say "switch to <profile>" or "use <prompt>" to change the active profile or prompt',
+                )}
+              </span>
+            </div>
+            <label className="toggle-switch">
+              <input
+                type="checkbox"
+                checked={
+                  settings.voice_command_meta_commands_enabled ?? false
+                }
+                onChange={(e) =>
+                  updateSetting(
+                    "voice_command_meta_commands_enabled",
+                    e.target.checked,
+                  )
+                }
+              />
+              <span className="slider"></span>
+            </label>
+          </div>
+
           <div className="setting-row">
             <div className="setting-label">
               <span>{t("voiceCommands.autoRun", "Auto Run")}</span>
@@ -1175,6 +1237,16 @@ export default function VoiceCommandSettings() {
             </div>
           </div>
 
+          {settings.voice_command_auto_run && (
+            <div className="shortcut-row">
+              <HandyShortcut
+                shortcutId="cancel_voice_command_countdown"
+                descriptionMode="tooltip"
+                grouped={false}
+              />
+            </div>
+          )}
+
           {/* Execution Log Section */}
           <div className="execution-log-section">
             <div className="log-header">
@@ -9,13 +9,19 @@ import {
   ThinkingIcon,
 } from "../components/icons";
 import "./RecordingOverlay.css";
-import { commands } from "@/bindings";
+import { commands, type OverlayAppearance } from "@/bindings";
 import { syncLanguageFromSettings } from "@/i18n";
 import {
   ExtendedOverlayState,
   OverlayPayload,
+  ProfileSwitchPayload,
+  PttModePayload,
   isExtendedPayload,
 } from "./plus_overlay_states";
+import { LANGUAGES } from "../lib/constants/languages";
+
+const languageLabel = (code: string) =>
+  LANGUAGES.find((l) => l.value === code)?.label ?? code.toUpperCase();
 
 const RecordingOverlay: React.FC = () => {
   const { t } = useTranslation();
@@ -23,7 +29,9 @@ const RecordingOverlay: React.FC = () => {
   const [state, setState] = useState<ExtendedOverlayState>("recording");
   const [errorMessage, setErrorMessage] = useState<string | null>(null);
   const [levels, setLevels] = useState<number[]>(Array(16).fill(0));
-  const [profileName, setProfileName] = useState<string>("");
+  const [profileSwitch, setProfileSwitch] = useState<ProfileSwitchPayload | null>(null);
+  const [pttMode, setPttMode] = useState<boolean | null>(null);
+  const [thinkingSeconds, setThinkingSeconds] = useState(0);
   const smoothedLevelsRef = useRef<number[]>(Array(16).fill(0));
 
   useEffect(() => {
@@ -47,16 +55,44 @@ const RecordingOverlay: React.FC = () => {
           setState(payload as ExtendedOverlayState);
           setErrorMessage(null);
         }
+        setThinkingSeconds(0);
         setIsVisible(true);
       });
 
+      // Listen for post-processing heartbeats (elapsed time while waiting on the LLM)
+      const unlistenHeartbeat = await listen<{ elapsed_ms: number }>(
+        "post-process-heartbeat",
+        (event) => {
+          setThinkingSeconds(Math.floor(event.payload.elapsed_ms / 1000));
+        },
+      );
+
       // Listen for profile switch event
-      const unlistenProfileSwitch = await listen<string>("show-profile-switch", (event) => {
-        setProfileName(event.payload);
+      const unlistenProfileSwitch = await listen<ProfileSwitchPayload>("show-profile-switch", (event) => {
+        setProfileSwitch(event.payload);
         setState("profile_switch");
         setIsVisible(true);
       });
 
+      // Listen for recording mode toggles (push-to-talk / toggle)
+      const unlistenPttMode = await listen<PttModePayload>("show-ptt-mode", (event) => {
+        setPttMode(event.payload.push_to_talk);
+        setState("ptt_mode");
+        setIsVisible(true);
+      });
+
+      // Listen for appearance changes (opacity / accent / scale)
+      const unlistenAppearance = await listen<OverlayAppearance>(
+        "overlay-appearance",
+        (event) => {
+          const { background_opacity, accent_color, scale } = event.payload;
+          const root = document.documentElement.style;
+          root.setProperty("--overlay-bg-opacity", String(background_opacity));
+          root.setProperty("--overlay-accent", accent_color);
+          root.setProperty("--overlay-scale", String(scale));
+        },
+      );
+
       // Listen for hide-overlay event from Rust
       const unlistenHide = await listen("hide-overlay", () => {
         setIsVisible(false);
@@ -79,7 +115,10 @@ const RecordingOverlay: React.FC = () => {
       // Cleanup function
       return () => {
         unlistenShow();
+        unlistenHeartbeat();
         unlistenProfileSwitch();
+        unlistenPttMode();
+        unlistenAppearance();
         unlistenHide();
         unlistenLevel();
       };
@@ -100,6 +139,8 @@ const RecordingOverlay: React.FC = () => {
         return <span className="overlay-icon-emoji">❌</span>;
       case "profile_switch":
         return <TranscriptionIcon />;
+      case "ptt_mode":
+        return <MicrophoneIcon />;
       case "transcribing":
       default:
         return <TranscriptionIcon />;
@@ -107,7 +148,14 @@ const RecordingOverlay: React.FC = () => {
   };
 
   return (
-    <div className={`recording-overlay ${isVisible ? "fade-in" : ""} ${state === "error" ? "overlay-error" : ""}`}>
+    <div
+      className={`recording-overlay ${isVisible ? "fade-in" : ""} ${state === "error" ? "overlay-error" : ""}`}
+      onClick={() => {
+        if (state === "error") {
+          commands.dismissErrorOverlay();
+        }
+      }}
+    >
       <div className="overlay-left">{getIcon()}</div>
 
       <div className="overlay-middle">
@@ -130,7 +178,9 @@ const RecordingOverlay: React.FC = () => {
           <div className="sending-text">Sending...</div>
         )}
         {state === "thinking" && (
-          <div className="thinking-text">Thinking...</div>
+          <div className="thinking-text">
+            Thinking...{thinkingSeconds > 0 && ` ${thinkingSeconds}s`}
+          </div>
         )}
         {state === "transcribing" && (
           <div className="transcribing-text">{t("overlay.transcribing")}</div>
@@ -138,8 +188,23 @@ const RecordingOverlay: React.FC = () => {
         {state === "error" && (
           <div className="error-text">{errorMessage || "Failed"}</div>
         )}
-        {state === "profile_switch" && (
-          <div className="transcribing-text">{profileName}</div>
+        {state === "profile_switch" && profileSwitch && (
+          <div className="profile-switch-text" title={profileSwitch.name}>
+            <span className="profile-switch-language">
+              {languageLabel(profileSwitch.language)}
+              {profileSwitch.translate_to_english && " → English"}
+            </span>
+            <span className="profile-switch-name">{profileSwitch.name}</span>
+          </div>
+        )}
+        {state === "ptt_mode" && pttMode !== null && (
+          <div className="profile-switch-text">
+            <span className="profile-switch-name">
+              {pttMode
+                ? t("overlay.pttModeOn", "Push-to-talk")
+                : t("overlay.pttModeOff", "Toggle recording")}
+            </span>
+          </div>
         )}
       </div>
 
@@ -6,7 +6,7 @@
 /**
  * Extended overlay state type including new states
  */
-export type ExtendedOverlayState = "recording" | "sending" | "transcribing" | "thinking" | "error" | "profile_switch";
+export type ExtendedOverlayState = "recording" | "sending" | "transcribing" | "thinking" | "error" | "profile_switch" | "ptt_mode";
 
 /**
  * Error categories matching Rust OverlayErrorCategory enum
@@ -31,6 +31,22 @@ export interface OverlayPayload {
   error_message?: string;
 }
 
+/**
+ * Payload for the show-profile-switch event (matches Rust ProfileSwitchPayload)
+ */
+export interface ProfileSwitchPayload {
+  name: string;
+  language: string;
+  translate_to_english: boolean;
+}
+
+/**
+ * Payload for the show-ptt-mode event (matches Rust PttModePayload)
+ */
+export interface PttModePayload {
+  push_to_talk: boolean;
+}
+
 /**
  * Type guard to check if payload is an extended OverlayPayload object
  */
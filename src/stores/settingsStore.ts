@@ -1,6 +1,15 @@
 import { create } from "zustand";
 import { subscribeWithSelector } from "zustand/middleware";
-import type { AppSettings as Settings, AudioDevice } from "@/bindings";
+import type {
+  AiReplaceOutputMode,
+  AppSettings as Settings,
+  AudioDevice,
+  CustomWord,
+  OutputCaseTransform,
+  OverlayMonitor,
+  ProfileSwitchDuringRecording,
+  TranscriptionLogFormat,
+} from "@/bindings";
 import { commands } from "@/bindings";
 import { invoke } from "@tauri-apps/api/core";
 
@@ -126,7 +135,7 @@ const settingUpdaters: {
   overlay_position: (value) =>
     commands.changeOverlayPositionSetting(value as string),
   debug_mode: (value) => commands.changeDebugModeSetting(value as boolean),
-  custom_words: (value) => commands.updateCustomWords(value as string[]),
+  custom_words: (value) => commands.updateCustomWords(value as CustomWord[]),
   custom_words_enabled: (value) =>
     commands.changeCustomWordsEnabledSetting(value as boolean),
   word_correction_threshold: (value) =>
@@ -209,6 +218,80 @@ const settingUpdaters: {
     commands.changeTranscriptionProviderSetting(value as string),
   vad_threshold: (value) =>
     commands.changeVadThresholdSetting(value as number),
+  voice_command_structured_output: (value) =>
+    commands.changeVoiceCommandStructuredOutputSetting(value as boolean),
+  voice_command_meta_commands_enabled: (value) =>
+    commands.changeVoiceCommandMetaCommandsEnabledSetting(value as boolean),
+  profile_switch_during_recording: (value) =>
+    commands.changeProfileSwitchDuringRecordingSetting(
+      value as ProfileSwitchDuringRecording,
+    ),
+  audio_meter_hz: (value) =>
+    commands.changeAudioMeterHzSetting(value as number),
+  always_copy_result: (value) =>
+    commands.changeAlwaysCopyResultSetting(value as boolean),
+  ai_replace_output_mode: (value) =>
+    commands.changeAiReplaceOutputModeSetting(value as AiReplaceOutputMode),
+  overlay_monitor: (value) =>
+    commands.changeOverlayMonitorSetting(value as OverlayMonitor),
+  paste_delay_ms: (value) =>
+    commands.changePasteDelayMsSetting(value as number),
+  selection_capture_retries: (value) =>
+    commands.changeSelectionCaptureRetriesSetting(value as number),
+  selection_capture_retry_delay_ms: (value) =>
+    commands.changeSelectionCaptureRetryDelayMsSetting(value as number),
+  text_replacement_groups_enabled: (value) =>
+    Promise.all(
+      Object.entries(value ?? {}).map(([group, enabled]) =>
+        commands.changeTextReplacementGroupEnabledSetting(
+          group,
+          enabled ?? true,
+        ),
+      ),
+    ),
+  output_case_transform: (value) =>
+    commands.changeOutputCaseTransformSetting(value as OutputCaseTransform),
+  auto_capitalize_sentences: (value) =>
+    commands.changeAutoCapitalizeSentencesSetting(value as boolean),
+  strip_llm_formatting: (value) =>
+    commands.changeStripLlmFormattingSetting(value as boolean),
+  llm_max_concurrent_requests: (value) =>
+    commands.changeLlmMaxConcurrentRequestsSetting(value as number),
+  post_process_paste_then_replace: (value) =>
+    commands.changePostProcessPasteThenReplaceSetting(value as boolean),
+  post_process_stop_sequences: (value) =>
+    commands.changePostProcessStopSequencesSetting(value as string[]),
+  ai_replace_stop_sequences: (value) =>
+    commands.changeAiReplaceStopSequencesSetting(value as string[]),
+  scratchpad_mode: (value) =>
+    commands.changeScratchpadModeSetting(value as boolean),
+  // One command sets both non-speech gate values
+  skip_non_speech_recordings: (value) =>
+    commands.changeNonSpeechGateSetting(
+      value as boolean,
+      useSettingsStore.getState().settings?.speech_energy_threshold_dbfs ??
+        -55,
+    ),
+  speech_energy_threshold_dbfs: (value) =>
+    commands.changeNonSpeechGateSetting(
+      useSettingsStore.getState().settings?.skip_non_speech_recordings ?? true,
+      value as number,
+    ),
+  // One command sets both transcription log path and format
+  transcription_log_path: (value) =>
+    commands.changeTranscriptionLogSetting(
+      value ?? null,
+      useSettingsStore.getState().settings?.transcription_log_format ?? "text",
+    ),
+  transcription_log_format: (value) =>
+    commands.changeTranscriptionLogSetting(
+      useSettingsStore.getState().settings?.transcription_log_path ?? null,
+      value as TranscriptionLogFormat,
+    ),
+  output_device_fallbacks: (value) =>
+    commands.setOutputDeviceFallbacks(value as string[]),
+  unlock_builtin_provider_base_urls: (value) =>
+    commands.changeUnlockBuiltinProviderBaseUrlsSetting(value as boolean),
 };
 
 // Fork-specific settings not yet present in generated bindings.